@@ -1,14 +1,30 @@
 use crate::{
+    clock::SystemClock,
     controller,
-    model::app::{area_of_life as aol, thought},
+    event::EventBus,
+    identifier::{IdStrategy, UuidV7Generator},
+    metrics::{Instrumented, Metrics},
+    model::app::{area_of_life as aol, integrity, relation, revision, tag, thought, trash},
     presenter::Present,
 };
-use cawr_application::{gateway::repository as repo, identifier::NewId};
-use std::{collections::HashSet, sync::Arc};
+use cawr_application::{
+    clock::Clock,
+    gateway::{
+        integrity::Integrity, repository as repo, search_index::SearchIndex,
+        unit_of_work::UnitOfWork,
+    },
+    identifier::NewId,
+};
+use std::{collections::HashSet, sync::Arc, time::Duration};
+use tracing::Instrument;
 
 pub struct Api<D, P> {
     db: Arc<D>,
     presenter: P,
+    clock: Arc<dyn Clock + Send + Sync>,
+    events: Arc<EventBus>,
+    metrics: Arc<Metrics>,
+    ids: IdStrategy,
 }
 
 impl<D, P> Clone for Api<D, P>
@@ -18,7 +34,18 @@ where
     fn clone(&self) -> Self {
         let db = Arc::clone(&self.db);
         let presenter = self.presenter.clone();
-        Self { db, presenter }
+        let clock = Arc::clone(&self.clock);
+        let events = Arc::clone(&self.events);
+        let metrics = Arc::clone(&self.metrics);
+        let ids = self.ids;
+        Self {
+            db,
+            presenter,
+            clock,
+            events,
+            metrics,
+            ids,
+        }
     }
 }
 
@@ -26,71 +53,537 @@ impl<D, P> Api<D, P>
 where
     D: repo::thought::Repo
         + repo::area_of_life::Repo
+        + repo::tag::Repo
+        + repo::relation::Repo
+        + repo::revision::Repo
+        + SearchIndex
+        + Integrity
+        + UnitOfWork
         + 'static
         + NewId<cawr_domain::thought::Id>
-        + NewId<cawr_domain::area_of_life::Id>,
+        + NewId<cawr_domain::area_of_life::Id>
+        + NewId<cawr_domain::tag::Id>,
     P: Present<thought::create::Result>
         + Present<thought::delete::Result>
         + Present<thought::find_by_id::Result>
         + Present<thought::read_all::Result>
         + Present<thought::update::Result>
+        + Present<thought::change_status::Result>
+        + Present<thought::due::Result>
+        + Present<thought::search::Result>
+        + Present<thought::batch::Result>
         + Present<aol::create::Result>
         + Present<aol::delete::Result>
+        + Present<aol::find_by_id::Result>
+        + Present<aol::merge::Result>
         + Present<aol::read_all::Result>
-        + Present<aol::update::Result>,
+        + Present<aol::read_subtree::Result>
+        + Present<aol::thoughts::Result>
+        + Present<aol::update::Result>
+        + Present<tag::create::Result>
+        + Present<tag::delete::Result>
+        + Present<tag::read_all::Result>
+        + Present<tag::update::Result>
+        + Present<relation::link::Result>
+        + Present<relation::unlink::Result>
+        + Present<relation::read_links::Result>
+        + Present<revision::read_all::Result>
+        + Present<revision::diff::Result>
+        + Present<revision::revert::Result>
+        + Present<trash::read_all::Result>
+        + Present<trash::restore::Result>
+        + Present<trash::purge::Result>,
 {
-    pub const fn new(db: Arc<D>, presenter: P) -> Self {
-        Self { db, presenter }
+    pub fn new(db: Arc<D>, presenter: P) -> Self {
+        let clock = Arc::new(SystemClock);
+        let events = Arc::new(EventBus::default());
+        let metrics = Arc::new(Metrics::default());
+        let ids = IdStrategy::default();
+        Self {
+            db,
+            presenter,
+            clock,
+            events,
+            metrics,
+            ids,
+        }
+    }
+    /// Replace the [`SystemClock`] e.g. by a fixed clock for testing.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        self.clock = clock;
+        self
+    }
+    /// Choose how the IDs of new entities are generated.
+    #[must_use]
+    pub const fn with_id_strategy(mut self, ids: IdStrategy) -> Self {
+        self.ids = ids;
+        self
+    }
+    /// The bus that publishes the events of all usecases
+    /// (shared by all clones of this API).
+    pub fn events(&self) -> &EventBus {
+        &self.events
+    }
+    /// Counters and latencies of all usecases
+    /// (shared by all clones of this API).
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+    fn instrumented(&self, usecase: &'static str) -> Instrumented<'_, P> {
+        Instrumented::new(&self.presenter, &self.metrics, usecase)
+    }
+    fn id_gen<I>(&self) -> &dyn NewId<I>
+    where
+        D: NewId<I>,
+        UuidV7Generator: NewId<I>,
+    {
+        match self.ids {
+            IdStrategy::Sequential => self.db.as_ref(),
+            IdStrategy::UuidV7 => &UuidV7Generator,
+        }
+    }
+    fn thought_controller<'a>(
+        &'a self,
+        presenter: &'a Instrumented<'a, P>,
+    ) -> controller::thought::Controller<'a, 'a, 'a, 'a, 'a, D, Instrumented<'a, P>> {
+        controller::thought::Controller::new(
+            &self.db,
+            self.id_gen(),
+            presenter,
+            self.clock.as_ref(),
+            self.events.as_ref(),
+        )
+    }
+    fn aol_controller<'a>(
+        &'a self,
+        presenter: &'a Instrumented<'a, P>,
+    ) -> controller::area_of_life::Controller<'a, 'a, 'a, 'a, 'a, D, Instrumented<'a, P>> {
+        controller::area_of_life::Controller::new(
+            &self.db,
+            self.id_gen(),
+            presenter,
+            self.clock.as_ref(),
+            self.events.as_ref(),
+        )
+    }
+    fn relation_controller<'a>(
+        &'a self,
+        presenter: &'a Instrumented<'a, P>,
+    ) -> controller::relation::Controller<'a, 'a, 'a, D, Instrumented<'a, P>> {
+        controller::relation::Controller::new(&self.db, presenter, self.events.as_ref())
     }
-    fn thought_controller(&self) -> controller::thought::Controller<D, P> {
-        controller::thought::Controller::new(&self.db, &self.presenter)
+    fn revision_controller<'a>(
+        &'a self,
+        presenter: &'a Instrumented<'a, P>,
+    ) -> controller::revision::Controller<'a, 'a, 'a, 'a, D, Instrumented<'a, P>> {
+        controller::revision::Controller::new(
+            &self.db,
+            presenter,
+            self.clock.as_ref(),
+            self.events.as_ref(),
+        )
     }
-    fn aol_controller(&self) -> controller::area_of_life::Controller<D, P> {
-        controller::area_of_life::Controller::new(&self.db, &self.presenter)
+    fn trash_controller<'a>(
+        &'a self,
+        presenter: &'a Instrumented<'a, P>,
+    ) -> controller::trash::Controller<'a, 'a, 'a, 'a, D, Instrumented<'a, P>> {
+        controller::trash::Controller::new(
+            &self.db,
+            presenter,
+            self.clock.as_ref(),
+            self.events.as_ref(),
+        )
     }
-    pub fn create_thought(
+    fn integrity_controller<'a>(
+        &'a self,
+        presenter: &'a Instrumented<'a, P>,
+    ) -> controller::integrity::Controller<'a, 'a, 'a, 'a, D, Instrumented<'a, P>>
+    where
+        P: Present<integrity::check::Result>,
+    {
+        controller::integrity::Controller::new(
+            &self.db,
+            presenter,
+            self.clock.as_ref(),
+            self.events.as_ref(),
+        )
+    }
+    fn tag_controller<'a>(
+        &'a self,
+        presenter: &'a Instrumented<'a, P>,
+    ) -> controller::tag::Controller<'a, 'a, 'a, 'a, 'a, D, Instrumented<'a, P>> {
+        controller::tag::Controller::new(
+            &self.db,
+            self.id_gen(),
+            presenter,
+            self.clock.as_ref(),
+            self.events.as_ref(),
+        )
+    }
+    pub async fn create_thought(
         &self,
         title: impl Into<String>,
+        body: Option<String>,
         areas_of_life: &HashSet<String>,
+        tags: &HashSet<String>,
+        schedule: thought::Schedule,
     ) -> <P as Present<thought::create::Result>>::ViewModel {
-        self.thought_controller()
-            .create_thought(title, areas_of_life)
+        let presenter = self.instrumented("create_thought");
+        self.thought_controller(&presenter)
+            .create_thought(title, body, areas_of_life, tags, schedule)
+            .instrument(presenter.span())
+            .await
     }
-    pub fn update_thought(
+    /// Update a thought.
+    ///
+    /// If a `version` is given, the update is rejected
+    /// as soon as the thought was modified in the meantime.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_thought(
         &self,
         id: &str,
         title: impl Into<String>,
+        body: Option<String>,
         areas_of_life: &HashSet<String>,
+        tags: &HashSet<String>,
+        schedule: thought::Schedule,
+        version: Option<u64>,
     ) -> <P as Present<thought::update::Result>>::ViewModel {
-        self.thought_controller()
-            .update_thought(id, title, areas_of_life)
+        let presenter = self.instrumented("update_thought");
+        self.thought_controller(&presenter)
+            .update_thought(id, title, body, areas_of_life, tags, schedule, version)
+            .instrument(presenter.span())
+            .await
+    }
+    pub async fn delete_thought(
+        &self,
+        id: &str,
+    ) -> <P as Present<thought::delete::Result>>::ViewModel {
+        let presenter = self.instrumented("delete_thought");
+        self.thought_controller(&presenter)
+            .delete_thought(id)
+            .instrument(presenter.span())
+            .await
+    }
+    pub async fn find_thought(
+        &self,
+        id: &str,
+    ) -> <P as Present<thought::find_by_id::Result>>::ViewModel {
+        let presenter = self.instrumented("find_thought");
+        self.thought_controller(&presenter)
+            .find_thought(id)
+            .instrument(presenter.span())
+            .await
+    }
+    pub async fn change_thought_status(
+        &self,
+        id: &str,
+        status: &str,
+    ) -> <P as Present<thought::change_status::Result>>::ViewModel {
+        let presenter = self.instrumented("change_thought_status");
+        self.thought_controller(&presenter)
+            .change_thought_status(id, status)
+            .instrument(presenter.span())
+            .await
     }
-    pub fn delete_thought(&self, id: &str) -> <P as Present<thought::delete::Result>>::ViewModel {
-        self.thought_controller().delete_thought(id)
+    /// Read a sorted page of the thoughts that match the filter `params`.
+    pub async fn read_all_thoughts(
+        &self,
+        params: &thought::read_all::Params,
+    ) -> <P as Present<thought::read_all::Result>>::ViewModel {
+        let presenter = self.instrumented("read_all_thoughts");
+        self.thought_controller(&presenter)
+            .read_all_thoughts(params)
+            .instrument(presenter.span())
+            .await
+    }
+    /// Read overdue thoughts and those that are due within the `horizon`.
+    pub async fn read_due_thoughts(
+        &self,
+        horizon: Duration,
+    ) -> <P as Present<thought::due::Result>>::ViewModel {
+        let presenter = self.instrumented("read_due_thoughts");
+        self.thought_controller(&presenter)
+            .read_due_thoughts(horizon)
+            .instrument(presenter.span())
+            .await
     }
-    pub fn find_thought(&self, id: &str) -> <P as Present<thought::find_by_id::Result>>::ViewModel {
-        self.thought_controller().find_thought(id)
+    /// Find thoughts whose title or body contain all words of the `query`.
+    pub async fn search_thoughts(
+        &self,
+        query: impl Into<String>,
+    ) -> <P as Present<thought::search::Result>>::ViewModel {
+        let presenter = self.instrumented("search_thoughts");
+        self.thought_controller(&presenter)
+            .search_thoughts(query)
+            .instrument(presenter.span())
+            .await
     }
-    pub fn read_all_thoughts(&self) -> <P as Present<thought::read_all::Result>>::ViewModel {
-        self.thought_controller().read_all_thoughts()
+    /// Apply the `operation` to each of the thoughts
+    /// and report the outcome per thought.
+    pub async fn batch_thoughts(
+        &self,
+        ids: &[String],
+        operation: thought::batch::Operation,
+    ) -> <P as Present<thought::batch::Result>>::ViewModel {
+        let presenter = self.instrumented("batch_thoughts");
+        self.thought_controller(&presenter)
+            .batch_thoughts(ids, operation)
+            .instrument(presenter.span())
+            .await
     }
-    pub fn create_area_of_life(
+    pub async fn create_area_of_life(
         &self,
         name: impl Into<String>,
+        parent: Option<&str>,
     ) -> <P as Present<aol::create::Result>>::ViewModel {
-        self.aol_controller().create_area_of_life(name)
+        let presenter = self.instrumented("create_area_of_life");
+        self.aol_controller(&presenter)
+            .create_area_of_life(name, parent)
+            .instrument(presenter.span())
+            .await
     }
-    pub fn update_area_of_life(
+    /// Update an area of life.
+    ///
+    /// If a `version` is given, the update is rejected
+    /// as soon as the area of life was modified in the meantime.
+    pub async fn update_area_of_life(
         &self,
         id: &str,
         name: impl Into<String>,
+        parent: Option<&str>,
+        version: Option<u64>,
     ) -> <P as Present<aol::update::Result>>::ViewModel {
-        self.aol_controller().update_area_of_life(id, name)
+        let presenter = self.instrumented("update_area_of_life");
+        self.aol_controller(&presenter)
+            .update_area_of_life(id, name, parent, version)
+            .instrument(presenter.span())
+            .await
+    }
+    pub async fn delete_area_of_life(
+        &self,
+        id: &str,
+        cascade: bool,
+        references: aol::delete::References,
+    ) -> <P as Present<aol::delete::Result>>::ViewModel {
+        let presenter = self.instrumented("delete_area_of_life");
+        self.aol_controller(&presenter)
+            .delete_area_of_life(id, cascade, references)
+            .instrument(presenter.span())
+            .await
+    }
+    pub async fn merge_areas_of_life(
+        &self,
+        source: &str,
+        target: &str,
+    ) -> <P as Present<aol::merge::Result>>::ViewModel {
+        let presenter = self.instrumented("merge_areas_of_life");
+        self.aol_controller(&presenter)
+            .merge_areas_of_life(source, target)
+            .instrument(presenter.span())
+            .await
+    }
+    pub async fn find_area_of_life(
+        &self,
+        id: &str,
+    ) -> <P as Present<aol::find_by_id::Result>>::ViewModel {
+        let presenter = self.instrumented("find_area_of_life");
+        self.aol_controller(&presenter)
+            .find_area_of_life(id)
+            .instrument(presenter.span())
+            .await
+    }
+    /// Read the sorted thoughts of an area of life.
+    pub async fn read_area_of_life_thoughts(
+        &self,
+        id: &str,
+        params: &aol::thoughts::Params,
+    ) -> <P as Present<aol::thoughts::Result>>::ViewModel {
+        let presenter = self.instrumented("read_area_of_life_thoughts");
+        self.aol_controller(&presenter)
+            .read_area_of_life_thoughts(id, params)
+            .instrument(presenter.span())
+            .await
+    }
+    pub async fn read_area_of_life_subtree(
+        &self,
+        id: &str,
+    ) -> <P as Present<aol::read_subtree::Result>>::ViewModel {
+        let presenter = self.instrumented("read_area_of_life_subtree");
+        self.aol_controller(&presenter)
+            .read_area_of_life_subtree(id)
+            .instrument(presenter.span())
+            .await
+    }
+    /// Read a sorted page of all areas of life.
+    pub async fn read_all_areas_of_life(
+        &self,
+        params: &aol::read_all::Params,
+    ) -> <P as Present<aol::read_all::Result>>::ViewModel {
+        let presenter = self.instrumented("read_all_areas_of_life");
+        self.aol_controller(&presenter)
+            .read_all_areas_of_life(params)
+            .instrument(presenter.span())
+            .await
+    }
+    pub async fn create_tag(
+        &self,
+        name: impl Into<String>,
+    ) -> <P as Present<tag::create::Result>>::ViewModel {
+        let presenter = self.instrumented("create_tag");
+        self.tag_controller(&presenter)
+            .create_tag(name)
+            .instrument(presenter.span())
+            .await
+    }
+    pub async fn update_tag(
+        &self,
+        id: &str,
+        name: impl Into<String>,
+    ) -> <P as Present<tag::update::Result>>::ViewModel {
+        let presenter = self.instrumented("update_tag");
+        self.tag_controller(&presenter)
+            .update_tag(id, name)
+            .instrument(presenter.span())
+            .await
+    }
+    pub async fn delete_tag(&self, id: &str) -> <P as Present<tag::delete::Result>>::ViewModel {
+        let presenter = self.instrumented("delete_tag");
+        self.tag_controller(&presenter)
+            .delete_tag(id)
+            .instrument(presenter.span())
+            .await
+    }
+    pub async fn read_all_tags(&self) -> <P as Present<tag::read_all::Result>>::ViewModel {
+        let presenter = self.instrumented("read_all_tags");
+        self.tag_controller(&presenter)
+            .read_all_tags()
+            .instrument(presenter.span())
+            .await
+    }
+    pub async fn link_thoughts(
+        &self,
+        source: &str,
+        kind: &str,
+        target: &str,
+    ) -> <P as Present<relation::link::Result>>::ViewModel {
+        let presenter = self.instrumented("link_thoughts");
+        self.relation_controller(&presenter)
+            .link_thoughts(source, kind, target)
+            .instrument(presenter.span())
+            .await
+    }
+    pub async fn unlink_thoughts(
+        &self,
+        source: &str,
+        kind: &str,
+        target: &str,
+    ) -> <P as Present<relation::unlink::Result>>::ViewModel {
+        let presenter = self.instrumented("unlink_thoughts");
+        self.relation_controller(&presenter)
+            .unlink_thoughts(source, kind, target)
+            .instrument(presenter.span())
+            .await
+    }
+    pub async fn read_thought_links(
+        &self,
+        id: &str,
+    ) -> <P as Present<relation::read_links::Result>>::ViewModel {
+        let presenter = self.instrumented("read_thought_links");
+        self.relation_controller(&presenter)
+            .read_thought_links(id)
+            .instrument(presenter.span())
+            .await
+    }
+    pub async fn read_thought_revisions(
+        &self,
+        id: &str,
+    ) -> <P as Present<revision::read_all::Result>>::ViewModel {
+        let presenter = self.instrumented("read_thought_revisions");
+        self.revision_controller(&presenter)
+            .read_thought_revisions(id)
+            .instrument(presenter.span())
+            .await
+    }
+    /// Compare the revisions `from` and `to` of a thought field by field.
+    pub async fn diff_thought_revisions(
+        &self,
+        id: &str,
+        from: u64,
+        to: u64,
+    ) -> <P as Present<revision::diff::Result>>::ViewModel {
+        let presenter = self.instrumented("diff_thought_revisions");
+        self.revision_controller(&presenter)
+            .diff_thought_revisions(id, from, to)
+            .instrument(presenter.span())
+            .await
+    }
+    /// Restore the content of a thought as it was in the given revision.
+    pub async fn revert_thought(
+        &self,
+        id: &str,
+        version: u64,
+    ) -> <P as Present<revision::revert::Result>>::ViewModel {
+        let presenter = self.instrumented("revert_thought");
+        self.revision_controller(&presenter)
+            .revert_thought(id, version)
+            .instrument(presenter.span())
+            .await
     }
-    pub fn delete_area_of_life(&self, id: &str) -> <P as Present<aol::delete::Result>>::ViewModel {
-        self.aol_controller().delete_area_of_life(id)
+    pub async fn read_trash(&self) -> <P as Present<trash::read_all::Result>>::ViewModel {
+        let presenter = self.instrumented("read_trash");
+        self.trash_controller(&presenter)
+            .read_trash()
+            .instrument(presenter.span())
+            .await
     }
-    pub fn read_all_areas_of_life(&self) -> <P as Present<aol::read_all::Result>>::ViewModel {
-        self.aol_controller().read_all_areas_of_life()
+    pub async fn restore_thought(
+        &self,
+        id: &str,
+    ) -> <P as Present<trash::restore::Result>>::ViewModel {
+        let presenter = self.instrumented("restore_thought");
+        self.trash_controller(&presenter)
+            .restore_thought(id)
+            .instrument(presenter.span())
+            .await
+    }
+    pub async fn restore_area_of_life(
+        &self,
+        id: &str,
+    ) -> <P as Present<trash::restore::Result>>::ViewModel {
+        let presenter = self.instrumented("restore_area_of_life");
+        self.trash_controller(&presenter)
+            .restore_area_of_life(id)
+            .instrument(presenter.span())
+            .await
+    }
+    /// Permanently delete everything that has been in the trash
+    /// for longer than the `retention` period.
+    pub async fn purge_trash(
+        &self,
+        retention: Duration,
+    ) -> <P as Present<trash::purge::Result>>::ViewModel {
+        let presenter = self.instrumented("purge_trash");
+        self.trash_controller(&presenter)
+            .purge_trash(retention)
+            .instrument(presenter.span())
+            .await
+    }
+    /// Look for broken data and repair it if `fix` is set.
+    ///
+    /// Only the CLI presents the findings.
+    pub async fn check_integrity(
+        &self,
+        fix: bool,
+    ) -> <P as Present<integrity::check::Result>>::ViewModel
+    where
+        P: Present<integrity::check::Result>,
+    {
+        let presenter = self.instrumented("check_integrity");
+        self.integrity_controller(&presenter)
+            .check_integrity(fix)
+            .instrument(presenter.span())
+            .await
     }
 }
@@ -0,0 +1,17 @@
+use cawr_application::clock::Clock;
+use cawr_domain::Timestamp;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A [`Clock`] that reads the system time.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Timestamp {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+            .unwrap_or_default();
+        Timestamp::from_unix_millis(millis)
+    }
+}
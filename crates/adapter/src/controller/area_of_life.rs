@@ -1,82 +1,321 @@
 use crate::{
-    model::app::area_of_life::{self as app, Id},
+    model::app::{
+        area_of_life::{self as app, Id},
+        page, parse_direction, thought as thought_app,
+    },
     presenter::Present,
 };
 use cawr_application::{
-    gateway::repository::area_of_life::Repo, identifier::NewId, usecase::area_of_life as uc,
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{
+        repository::{self as repo, query::Sort},
+        search_index::SearchIndex,
+        unit_of_work::UnitOfWork,
+    },
+    identifier::NewId,
+    usecase::area_of_life as uc,
 };
-use cawr_domain::area_of_life as aol;
+use cawr_domain::{area_of_life as aol, Version};
 
-pub struct Controller<'d, 'p, D, P> {
+pub struct Controller<'d, 'g, 'p, 'c, 'e, D, P> {
     db: &'d D,
+    id_gen: &'g dyn NewId<aol::Id>,
     presenter: &'p P,
+    clock: &'c dyn Clock,
+    events: &'e dyn EventPublisher,
 }
 
-impl<'d, 'p, D, P> Controller<'d, 'p, D, P>
+impl<'d, 'g, 'p, 'c, 'e, D, P> Controller<'d, 'g, 'p, 'c, 'e, D, P>
 where
-    D: Repo + 'static + NewId<aol::Id>,
+    D: repo::area_of_life::Repo + repo::thought::Repo + SearchIndex + UnitOfWork + 'static,
     P: Present<app::create::Result>
         + Present<app::delete::Result>
+        + Present<app::find_by_id::Result>
+        + Present<app::merge::Result>
         + Present<app::read_all::Result>
+        + Present<app::read_subtree::Result>
+        + Present<app::thoughts::Result>
         + Present<app::update::Result>,
 {
-    pub const fn new(db: &'d D, presenter: &'p P) -> Self {
-        Self { db, presenter }
+    pub const fn new(
+        db: &'d D,
+        id_gen: &'g dyn NewId<aol::Id>,
+        presenter: &'p P,
+        clock: &'c dyn Clock,
+        events: &'e dyn EventPublisher,
+    ) -> Self {
+        Self {
+            db,
+            id_gen,
+            presenter,
+            clock,
+            events,
+        }
     }
-    pub fn create_area_of_life(
+    pub async fn create_area_of_life(
         &self,
         name: impl Into<String>,
+        parent: Option<&str>,
     ) -> <P as Present<app::create::Result>>::ViewModel {
         let name = name.into();
         log::debug!("Create area of life '{}'", name);
-        let req = app::create::Request { name };
-        let interactor = uc::create::CreateAreaOfLife::new(self.db, self.db);
-        let res = interactor.exec(req);
+        let req = parse_parent_id(parent)
+            .map_err(|_| app::create::Error::ParentId)
+            .map(|parent| app::create::Request { name, parent });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::create::CreateAreaOfLife::new(
+                    self.db,
+                    self.id_gen,
+                    self.clock,
+                    self.events,
+                );
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
         self.presenter.present(res)
     }
-    pub fn update_area_of_life(
+    pub async fn update_area_of_life(
         &self,
         id: &str,
         name: impl Into<String>,
+        parent: Option<&str>,
+        version: Option<u64>,
     ) -> <P as Present<app::update::Result>>::ViewModel {
         let name = name.into();
         log::debug!("Update area of life '{:?}'", id);
-        let res = id
+        let req = id
             .parse::<Id>()
             .map_err(|_| app::update::Error::Id)
             .and_then(|id| {
-                let req = app::update::Request {
+                let parent = parse_parent_id(parent).map_err(|_| app::update::Error::ParentId)?;
+                Ok(app::update::Request {
                     id: id.into(),
                     name,
-                };
-                let interactor = uc::update::UpdateAreaOfLife::new(self.db);
-                interactor.exec(req).map_err(Into::into)
+                    parent,
+                    version: version.map(Version::new),
+                })
             });
+        let res = match req {
+            Ok(req) => {
+                let interactor =
+                    uc::update::UpdateAreaOfLife::new(self.db, self.clock, self.events);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
         self.presenter.present(res)
     }
-    pub fn delete_area_of_life(&self, id: &str) -> <P as Present<app::delete::Result>>::ViewModel {
+    /// Delete an area of life.
+    ///
+    /// Nested areas of life are deleted as well if `cascade` is set,
+    /// otherwise they are moved up to the parent of the deleted one.
+    /// Thoughts that reference a deleted area of life
+    /// are handled according to `references`.
+    pub async fn delete_area_of_life(
+        &self,
+        id: &str,
+        cascade: bool,
+        references: app::delete::References,
+    ) -> <P as Present<app::delete::Result>>::ViewModel {
         log::debug!("Delete area of life {}", id);
-        let res = id
+        let children = if cascade {
+            app::delete::Children::Cascade
+        } else {
+            app::delete::Children::Reparent
+        };
+        let req = id
             .parse::<Id>()
             .map_err(|_| app::delete::Error::Id)
-            .map(Into::into)
-            .map(|id| app::delete::Request { id })
-            .and_then(|req| {
-                let interactor = uc::delete::Delete::new(self.db);
-                interactor.exec(req).map_err(|e| {
-                    // TODO: impl From<uc::Error> for app::Error
-                    match e {
-                        uc::delete::Error::Repo => app::delete::Error::Repo,
-                        uc::delete::Error::NotFound => app::delete::Error::NotFound,
-                    }
+            .and_then(|id| {
+                let references = parse_references(references)?;
+                Ok(app::delete::Request {
+                    id: id.into(),
+                    children,
+                    references,
+                })
+            });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::delete::Delete::new(self.db, self.clock, self.events);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
+        self.presenter.present(res)
+    }
+    /// Merge an area of life into another one.
+    ///
+    /// The thoughts and nested areas of life of the source
+    /// are moved to the target and the source is removed.
+    pub async fn merge_areas_of_life(
+        &self,
+        source: &str,
+        target: &str,
+    ) -> <P as Present<app::merge::Result>>::ViewModel {
+        log::debug!("Merge area of life {} into {}", source, target);
+        let req = source
+            .parse::<Id>()
+            .map_err(|_| app::merge::Error::Id)
+            .and_then(|source| {
+                let target = target
+                    .parse::<Id>()
+                    .map_err(|_| app::merge::Error::TargetId)?;
+                Ok(app::merge::Request {
+                    source: source.into(),
+                    target: target.into(),
                 })
             });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::merge::Merge::new(self.db, self.clock, self.events);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
         self.presenter.present(res)
     }
-    pub fn read_all_areas_of_life(&self) -> <P as Present<app::read_all::Result>>::ViewModel {
-        log::debug!("Read all areas of life");
-        let interactor = uc::read_all::ReadAll::new(self.db);
-        let res = interactor.exec(app::read_all::Request {});
+    pub async fn find_area_of_life(
+        &self,
+        id: &str,
+    ) -> <P as Present<app::find_by_id::Result>>::ViewModel {
+        log::debug!("Find area of life {}", id);
+        let req = id
+            .parse::<Id>()
+            .map_err(|_| app::find_by_id::Error::Id)
+            .map(|id| app::find_by_id::Request { id: id.into() });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::find_by_id::FindById::new(self.db);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
         self.presenter.present(res)
     }
+    /// Read the thoughts that belong to an area of life.
+    pub async fn read_area_of_life_thoughts(
+        &self,
+        id: &str,
+        params: &app::thoughts::Params,
+    ) -> <P as Present<app::thoughts::Result>>::ViewModel {
+        log::debug!("Read thoughts of area of life {}: {:?}", id, params);
+        let req = id
+            .parse::<Id>()
+            .map_err(|_| app::thoughts::Error::Id)
+            .and_then(|id| {
+                let sort = parse_thoughts_sort(params)?;
+                Ok(app::thoughts::Request {
+                    id: id.into(),
+                    sort,
+                })
+            });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::thoughts::ReadThoughts::new(self.db);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
+        self.presenter.present(res)
+    }
+    pub async fn read_area_of_life_subtree(
+        &self,
+        id: &str,
+    ) -> <P as Present<app::read_subtree::Result>>::ViewModel {
+        log::debug!("Read subtree of area of life {}", id);
+        let req = id
+            .parse::<Id>()
+            .map_err(|_| app::read_subtree::Error::Id)
+            .map(|id| app::read_subtree::Request { id: id.into() });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::read_subtree::ReadSubtree::new(self.db);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
+        self.presenter.present(res)
+    }
+    pub async fn read_all_areas_of_life(
+        &self,
+        params: &app::read_all::Params,
+    ) -> <P as Present<app::read_all::Result>>::ViewModel {
+        log::debug!("Read all areas of life: {:?}", params);
+        let req = parse_read_all_query(params).map(|query| app::read_all::Request { query });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::read_all::ReadAll::new(self.db);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
+        self.presenter.present(res)
+    }
+}
+
+fn parse_read_all_query(
+    params: &app::read_all::Params,
+) -> Result<repo::area_of_life::Query, app::read_all::Error> {
+    let key = params
+        .sort
+        .as_deref()
+        .map(app::parse_sort_key)
+        .transpose()
+        .map_err(|_| app::read_all::Error::Sort)?
+        .unwrap_or_default();
+    let direction = params
+        .direction
+        .as_deref()
+        .map(parse_direction)
+        .transpose()
+        .map_err(|_| app::read_all::Error::Direction)?
+        .unwrap_or_default();
+    Ok(repo::area_of_life::Query {
+        sort: Sort { key, direction },
+        page: page(params.offset, params.limit),
+    })
+}
+
+fn parse_thoughts_sort(
+    params: &app::thoughts::Params,
+) -> Result<Sort<repo::thought::SortKey>, app::thoughts::Error> {
+    let key = params
+        .sort
+        .as_deref()
+        .map(thought_app::parse_sort_key)
+        .transpose()
+        .map_err(|_| app::thoughts::Error::Sort)?
+        .unwrap_or_default();
+    let direction = params
+        .direction
+        .as_deref()
+        .map(parse_direction)
+        .transpose()
+        .map_err(|_| app::thoughts::Error::Direction)?
+        .unwrap_or_default();
+    Ok(Sort { key, direction })
+}
+
+fn parse_references(
+    references: app::delete::References,
+) -> Result<uc::delete::References, app::delete::Error> {
+    Ok(match references {
+        app::delete::References::Restrict => uc::delete::References::Restrict,
+        app::delete::References::Detach => uc::delete::References::Detach,
+        app::delete::References::Reassign(target) => {
+            let target = target
+                .parse::<Id>()
+                .map_err(|_| app::delete::Error::TargetId)?;
+            uc::delete::References::Reassign(target.into())
+        }
+    })
+}
+
+fn parse_parent_id(parent: Option<&str>) -> Result<Option<aol::Id>, app::ParseIdError> {
+    parent
+        .map(|id| id.parse::<Id>().map(Into::into))
+        .transpose()
 }
@@ -0,0 +1,55 @@
+use crate::{model::app::integrity as app, presenter::Present};
+use cawr_application::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{
+        integrity::Integrity, repository as repo, search_index::SearchIndex,
+        unit_of_work::UnitOfWork,
+    },
+    usecase::integrity as uc,
+};
+
+pub struct Controller<'d, 'p, 'c, 'e, D, P> {
+    db: &'d D,
+    presenter: &'p P,
+    clock: &'c dyn Clock,
+    events: &'e dyn EventPublisher,
+}
+
+impl<'d, 'p, 'c, 'e, D, P> Controller<'d, 'p, 'c, 'e, D, P>
+where
+    D: Integrity
+        + repo::thought::Repo
+        + repo::area_of_life::Repo
+        + repo::tag::Repo
+        + SearchIndex
+        + UnitOfWork
+        + 'static,
+    P: Present<app::check::Result>,
+{
+    pub const fn new(
+        db: &'d D,
+        presenter: &'p P,
+        clock: &'c dyn Clock,
+        events: &'e dyn EventPublisher,
+    ) -> Self {
+        Self {
+            db,
+            presenter,
+            clock,
+            events,
+        }
+    }
+    /// Check the integrity of the stored data
+    /// and repair what can be repaired if `fix` is set.
+    pub async fn check_integrity(
+        &self,
+        fix: bool,
+    ) -> <P as Present<app::check::Result>>::ViewModel {
+        log::debug!("Check integrity (fix: {})", fix);
+        let req = app::check::Request { fix };
+        let interactor = uc::check::Check::new(self.db, self.clock, self.events);
+        let res = interactor.exec(req).await.map_err(Into::into);
+        self.presenter.present(res)
+    }
+}
@@ -12,4 +12,9 @@
 //! [^1]: <https://www.plainionist.net/Implementing-Clean-Architecture-Controller-Presenter/>
 
 pub mod area_of_life;
+pub mod integrity;
+pub mod relation;
+pub mod revision;
+pub mod tag;
 pub mod thought;
+pub mod trash;
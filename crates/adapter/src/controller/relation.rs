@@ -0,0 +1,119 @@
+use crate::{
+    model::app::{
+        relation::{self as app, RelationKind},
+        thought::Id,
+    },
+    presenter::Present,
+};
+use cawr_application::{
+    event::EventPublisher, gateway::repository as repo, usecase::relation as uc,
+};
+use cawr_domain::{relation::Kind, thought};
+
+pub struct Controller<'d, 'p, 'e, D, P> {
+    db: &'d D,
+    presenter: &'p P,
+    events: &'e dyn EventPublisher,
+}
+
+impl<'d, 'p, 'e, D, P> Controller<'d, 'p, 'e, D, P>
+where
+    D: repo::thought::Repo + repo::relation::Repo + 'static,
+    P: Present<app::link::Result> + Present<app::unlink::Result> + Present<app::read_links::Result>,
+{
+    pub const fn new(db: &'d D, presenter: &'p P, events: &'e dyn EventPublisher) -> Self {
+        Self {
+            db,
+            presenter,
+            events,
+        }
+    }
+    pub async fn link_thoughts(
+        &self,
+        source: &str,
+        kind: &str,
+        target: &str,
+    ) -> <P as Present<app::link::Result>>::ViewModel {
+        log::debug!("Link thought {} to {} ({})", source, target, kind);
+        let req = parse_link(source, kind, target)
+            .map_err(|err| match err {
+                LinkParseError::Id => app::link::Error::Id,
+                LinkParseError::Kind => app::link::Error::Kind,
+            })
+            .map(|(source, kind, target)| app::link::Request {
+                source,
+                kind,
+                target,
+            });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::link::LinkThoughts::new(self.db, self.events);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
+        self.presenter.present(res)
+    }
+    pub async fn unlink_thoughts(
+        &self,
+        source: &str,
+        kind: &str,
+        target: &str,
+    ) -> <P as Present<app::unlink::Result>>::ViewModel {
+        log::debug!("Unlink thought {} from {} ({})", source, target, kind);
+        let req = parse_link(source, kind, target)
+            .map_err(|err| match err {
+                LinkParseError::Id => app::unlink::Error::Id,
+                LinkParseError::Kind => app::unlink::Error::Kind,
+            })
+            .map(|(source, kind, target)| app::unlink::Request {
+                source,
+                kind,
+                target,
+            });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::unlink::UnlinkThoughts::new(self.db, self.events);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
+        self.presenter.present(res)
+    }
+    pub async fn read_thought_links(
+        &self,
+        id: &str,
+    ) -> <P as Present<app::read_links::Result>>::ViewModel {
+        log::debug!("Read links of thought {}", id);
+        let req = id
+            .parse::<Id>()
+            .map_err(|_| app::read_links::Error::Id)
+            .map(|id| app::read_links::Request { id: id.into() });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::read_links::ReadLinks::new(self.db);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
+        self.presenter.present(res)
+    }
+}
+
+enum LinkParseError {
+    Id,
+    Kind,
+}
+
+fn parse_link(
+    source: &str,
+    kind: &str,
+    target: &str,
+) -> Result<(thought::Id, Kind, thought::Id), LinkParseError> {
+    let source = source.parse::<Id>().map_err(|_| LinkParseError::Id)?;
+    let kind = kind
+        .parse::<RelationKind>()
+        .map_err(|_| LinkParseError::Kind)?;
+    let target = target.parse::<Id>().map_err(|_| LinkParseError::Id)?;
+    Ok((source.into(), kind.into(), target.into()))
+}
@@ -0,0 +1,108 @@
+use crate::{
+    model::app::{revision as app, thought::Id},
+    presenter::Present,
+};
+use cawr_application::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{repository as repo, search_index::SearchIndex, unit_of_work::UnitOfWork},
+    usecase::revision as uc,
+};
+use cawr_domain::Version;
+
+pub struct Controller<'d, 'p, 'c, 'e, D, P> {
+    db: &'d D,
+    presenter: &'p P,
+    clock: &'c dyn Clock,
+    events: &'e dyn EventPublisher,
+}
+
+impl<'d, 'p, 'c, 'e, D, P> Controller<'d, 'p, 'c, 'e, D, P>
+where
+    D: repo::thought::Repo
+        + repo::area_of_life::Repo
+        + repo::tag::Repo
+        + repo::revision::Repo
+        + SearchIndex
+        + UnitOfWork
+        + 'static,
+    P: Present<app::read_all::Result> + Present<app::diff::Result> + Present<app::revert::Result>,
+{
+    pub const fn new(
+        db: &'d D,
+        presenter: &'p P,
+        clock: &'c dyn Clock,
+        events: &'e dyn EventPublisher,
+    ) -> Self {
+        Self {
+            db,
+            presenter,
+            clock,
+            events,
+        }
+    }
+    pub async fn read_thought_revisions(
+        &self,
+        id: &str,
+    ) -> <P as Present<app::read_all::Result>>::ViewModel {
+        log::debug!("Read revisions of thought {}", id);
+        let req = id
+            .parse::<Id>()
+            .map_err(|_| app::read_all::Error::Id)
+            .map(|id| app::read_all::Request { id: id.into() });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::read_all::ReadRevisions::new(self.db);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
+        self.presenter.present(res)
+    }
+    pub async fn diff_thought_revisions(
+        &self,
+        id: &str,
+        from: u64,
+        to: u64,
+    ) -> <P as Present<app::diff::Result>>::ViewModel {
+        log::debug!("Diff revisions {} and {} of thought {}", from, to, id);
+        let req = id
+            .parse::<Id>()
+            .map_err(|_| app::diff::Error::Id)
+            .map(|id| app::diff::Request {
+                id: id.into(),
+                from: Version::new(from),
+                to: Version::new(to),
+            });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::diff::DiffRevisions::new(self.db);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
+        self.presenter.present(res)
+    }
+    pub async fn revert_thought(
+        &self,
+        id: &str,
+        version: u64,
+    ) -> <P as Present<app::revert::Result>>::ViewModel {
+        log::debug!("Revert thought {} to revision {}", id, version);
+        let req = id
+            .parse::<Id>()
+            .map_err(|_| app::revert::Error::Id)
+            .map(|id| app::revert::Request {
+                id: id.into(),
+                version: Version::new(version),
+            });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::revert::RevertThought::new(self.db, self.clock, self.events);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
+        self.presenter.present(res)
+    }
+}
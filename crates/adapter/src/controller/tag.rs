@@ -0,0 +1,112 @@
+use crate::{
+    model::app::tag::{self as app, Id},
+    presenter::Present,
+};
+use cawr_application::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{
+        repository::{tag::Repo, thought as thought_repo},
+        search_index::SearchIndex,
+        unit_of_work::UnitOfWork,
+    },
+    identifier::NewId,
+    usecase::tag as uc,
+};
+use cawr_domain::tag;
+
+pub struct Controller<'d, 'g, 'p, 'c, 'e, D, P> {
+    db: &'d D,
+    id_gen: &'g dyn NewId<tag::Id>,
+    presenter: &'p P,
+    clock: &'c dyn Clock,
+    events: &'e dyn EventPublisher,
+}
+
+impl<'d, 'g, 'p, 'c, 'e, D, P> Controller<'d, 'g, 'p, 'c, 'e, D, P>
+where
+    D: Repo + thought_repo::Repo + SearchIndex + UnitOfWork + 'static,
+    P: Present<app::create::Result>
+        + Present<app::delete::Result>
+        + Present<app::read_all::Result>
+        + Present<app::update::Result>,
+{
+    pub const fn new(
+        db: &'d D,
+        id_gen: &'g dyn NewId<tag::Id>,
+        presenter: &'p P,
+        clock: &'c dyn Clock,
+        events: &'e dyn EventPublisher,
+    ) -> Self {
+        Self {
+            db,
+            id_gen,
+            presenter,
+            clock,
+            events,
+        }
+    }
+    pub async fn create_tag(
+        &self,
+        name: impl Into<String>,
+    ) -> <P as Present<app::create::Result>>::ViewModel {
+        let name = name.into();
+        log::debug!("Create tag '{}'", name);
+        let req = app::create::Request { name };
+        let interactor = uc::create::CreateTag::new(self.db, self.id_gen, self.clock, self.events);
+        let res = interactor.exec(req).await;
+        self.presenter.present(res)
+    }
+    pub async fn update_tag(
+        &self,
+        id: &str,
+        name: impl Into<String>,
+    ) -> <P as Present<app::update::Result>>::ViewModel {
+        let name = name.into();
+        log::debug!("Update tag '{:?}'", id);
+        let req = id
+            .parse::<Id>()
+            .map_err(|_| app::update::Error::Id)
+            .map(|id| app::update::Request {
+                id: id.into(),
+                name,
+            });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::update::UpdateTag::new(self.db, self.clock, self.events);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
+        self.presenter.present(res)
+    }
+    pub async fn delete_tag(&self, id: &str) -> <P as Present<app::delete::Result>>::ViewModel {
+        log::debug!("Delete tag {}", id);
+        let req = id
+            .parse::<Id>()
+            .map_err(|_| app::delete::Error::Id)
+            .map(Into::into)
+            .map(|id| app::delete::Request { id });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::delete::Delete::new(self.db, self.clock, self.events);
+                interactor.exec(req).await.map_err(|e| {
+                    // TODO: impl From<uc::Error> for app::Error
+                    match e {
+                        uc::delete::Error::Repo => app::delete::Error::Repo,
+                        uc::delete::Error::NotFound => app::delete::Error::NotFound,
+                        uc::delete::Error::Conflict => app::delete::Error::Conflict,
+                    }
+                })
+            }
+            Err(err) => Err(err),
+        };
+        self.presenter.present(res)
+    }
+    pub async fn read_all_tags(&self) -> <P as Present<app::read_all::Result>>::ViewModel {
+        log::debug!("Read all tags");
+        let interactor = uc::read_all::ReadAll::new(self.db);
+        let res = interactor.exec(app::read_all::Request {}).await;
+        self.presenter.present(res)
+    }
+}
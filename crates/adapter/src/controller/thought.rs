@@ -1,113 +1,330 @@
 use crate::{
     model::app::{
-        area_of_life as aol,
-        thought::{self as app, Id},
+        area_of_life as aol, page, parse_direction, tag,
+        thought::{self as app, Id, Schedule},
     },
     presenter::Present,
 };
-use cawr_application::{gateway::repository as repo, identifier::NewId, usecase::thought as uc};
-use std::collections::HashSet;
+use cawr_application::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{
+        repository::{self as repo, query::Sort},
+        search_index::SearchIndex,
+        unit_of_work::UnitOfWork,
+    },
+    identifier::NewId,
+    usecase::thought as uc,
+};
+use cawr_domain::{Timestamp, Version};
+use std::{collections::HashSet, time::Duration};
 
-pub struct Controller<'d, 'p, D, P> {
+pub struct Controller<'d, 'g, 'p, 'c, 'e, D, P> {
     db: &'d D,
+    id_gen: &'g dyn NewId<cawr_domain::thought::Id>,
     presenter: &'p P,
+    clock: &'c dyn Clock,
+    events: &'e dyn EventPublisher,
 }
 
-impl<'d, 'p, D, P> Controller<'d, 'p, D, P>
+impl<'d, 'g, 'p, 'c, 'e, D, P> Controller<'d, 'g, 'p, 'c, 'e, D, P>
 where
-    D: repo::thought::Repo + repo::area_of_life::Repo + 'static + NewId<cawr_domain::thought::Id>,
+    D: repo::thought::Repo
+        + repo::area_of_life::Repo
+        + repo::tag::Repo
+        + repo::relation::Repo
+        + repo::revision::Repo
+        + SearchIndex
+        + UnitOfWork
+        + 'static,
     P: Present<app::create::Result>
         + Present<app::delete::Result>
         + Present<app::find_by_id::Result>
         + Present<app::read_all::Result>
-        + Present<app::update::Result>,
+        + Present<app::update::Result>
+        + Present<app::change_status::Result>
+        + Present<app::due::Result>
+        + Present<app::search::Result>
+        + Present<app::batch::Result>,
 {
-    pub const fn new(db: &'d D, presenter: &'p P) -> Self {
-        Self { db, presenter }
+    pub const fn new(
+        db: &'d D,
+        id_gen: &'g dyn NewId<cawr_domain::thought::Id>,
+        presenter: &'p P,
+        clock: &'c dyn Clock,
+        events: &'e dyn EventPublisher,
+    ) -> Self {
+        Self {
+            db,
+            id_gen,
+            presenter,
+            clock,
+            events,
+        }
     }
 
-    pub fn create_thought(
+    pub async fn create_thought(
         &self,
         title: impl Into<String>,
+        body: Option<String>,
         areas_of_life: &HashSet<String>,
+        tags: &HashSet<String>,
+        schedule: Schedule,
     ) -> <P as Present<app::create::Result>>::ViewModel {
         let title = title.into();
         log::debug!("Create thought '{}'", title);
-        let res = parse_area_of_life_ids(areas_of_life)
-            .map_err(Into::into)
-            .and_then(|areas_of_life: HashSet<_>| {
-                let req = app::create::Request {
-                    title,
-                    areas_of_life,
-                };
-                let interactor = uc::create::CreateThought::new(self.db, self.db);
-                interactor.exec(req).map_err(Into::into)
+        let req = parse_area_of_life_ids(areas_of_life)
+            .map_err(app::create::Error::from)
+            .and_then(|areas_of_life| Ok((areas_of_life, parse_tag_ids(tags)?)))
+            .map(|(areas_of_life, tags)| app::create::Request {
+                title,
+                body,
+                areas_of_life,
+                tags,
+                due_at: schedule.due_at.map(Timestamp::from_unix_millis),
+                reminder_offset: schedule.reminder_offset,
             });
+        let res = match req {
+            Ok(req) => {
+                let interactor =
+                    uc::create::CreateThought::new(self.db, self.id_gen, self.clock, self.events);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
         self.presenter.present(res)
     }
 
-    pub fn update_thought(
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_thought(
         &self,
         id: &str,
         title: impl Into<String>,
+        body: Option<String>,
         areas_of_life: &HashSet<String>,
+        tags: &HashSet<String>,
+        schedule: Schedule,
+        version: Option<u64>,
     ) -> <P as Present<app::update::Result>>::ViewModel {
         let title = title.into();
         log::debug!("Update thought '{:?}'", id);
-        let res = id
+        let req = id
             .parse::<Id>()
             .map_err(|_| app::update::Error::Id)
             .and_then(|id| {
-                parse_area_of_life_ids(areas_of_life)
-                    .map_err(Into::into)
-                    .and_then(|areas_of_life: HashSet<_>| {
-                        let req = app::update::Request {
-                            id: id.into(),
-                            title,
-                            areas_of_life,
-                        };
-                        let interactor = uc::update::UpdateThought::new(self.db);
-                        interactor.exec(req).map_err(Into::into)
-                    })
+                let areas_of_life = parse_area_of_life_ids(areas_of_life)?;
+                let tags = parse_tag_ids(tags)?;
+                Ok(app::update::Request {
+                    id: id.into(),
+                    title,
+                    body,
+                    areas_of_life,
+                    tags,
+                    due_at: schedule.due_at.map(Timestamp::from_unix_millis),
+                    reminder_offset: schedule.reminder_offset,
+                    version: version.map(Version::new),
+                })
             });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::update::UpdateThought::new(self.db, self.clock, self.events);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
         self.presenter.present(res)
     }
 
-    pub fn delete_thought(&self, id: &str) -> <P as Present<app::delete::Result>>::ViewModel {
+    pub async fn delete_thought(&self, id: &str) -> <P as Present<app::delete::Result>>::ViewModel {
         log::debug!("Delete thought {}", id);
-        let res = id
+        let req = id
             .parse::<Id>()
             .map_err(|_| app::delete::Error::Id)
             .map(Into::into)
-            .map(|id| app::delete::Request { id })
-            .and_then(|req| {
-                let interactor = uc::delete::Delete::new(self.db);
-                interactor.exec(req).map_err(app::delete::Error::from)
-            });
+            .map(|id| app::delete::Request { id });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::delete::Delete::new(self.db, self.clock, self.events);
+                interactor.exec(req).await.map_err(app::delete::Error::from)
+            }
+            Err(err) => Err(err),
+        };
         self.presenter.present(res)
     }
 
-    pub fn find_thought(&self, id: &str) -> <P as Present<app::find_by_id::Result>>::ViewModel {
+    pub async fn batch_thoughts(
+        &self,
+        ids: &[String],
+        operation: app::batch::Operation,
+    ) -> <P as Present<app::batch::Result>>::ViewModel {
+        log::debug!("Apply {:?} to {} thoughts", operation, ids.len());
+        let req = parse_batch_request(ids, operation);
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::batch::Batch::new(self.db, self.clock, self.events);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
+        self.presenter.present(res)
+    }
+
+    pub async fn find_thought(
+        &self,
+        id: &str,
+    ) -> <P as Present<app::find_by_id::Result>>::ViewModel {
         log::debug!("Find thought {}", id);
-        let res = id
+        let req = id
             .parse::<Id>()
             .map_err(|_| app::find_by_id::Error::Id)
             .map(Into::into)
-            .map(|id| app::find_by_id::Request { id })
-            .and_then(|req| {
+            .map(|id| app::find_by_id::Request { id });
+        let res = match req {
+            Ok(req) => {
                 let interactor = uc::find_by_id::FindById::new(self.db);
-                interactor.exec(req).map_err(app::find_by_id::Error::from)
+                interactor
+                    .exec(req)
+                    .await
+                    .map_err(app::find_by_id::Error::from)
+            }
+            Err(err) => Err(err),
+        };
+        self.presenter.present(res)
+    }
+    pub async fn change_thought_status(
+        &self,
+        id: &str,
+        status: &str,
+    ) -> <P as Present<app::change_status::Result>>::ViewModel {
+        log::debug!("Change status of thought {} to '{}'", id, status);
+        let req = id
+            .parse::<Id>()
+            .map_err(|_| app::change_status::Error::Id)
+            .and_then(|id| {
+                let status =
+                    app::parse_status(status).map_err(|_| app::change_status::Error::Status)?;
+                Ok(app::change_status::Request {
+                    id: id.into(),
+                    status,
+                })
             });
+        let res = match req {
+            Ok(req) => {
+                let interactor =
+                    uc::change_status::ChangeStatus::new(self.db, self.clock, self.events);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
         self.presenter.present(res)
     }
-    pub fn read_all_thoughts(&self) -> <P as Present<app::read_all::Result>>::ViewModel {
-        log::debug!("Read all thoughts");
-        let interactor = uc::read_all::ReadAll::new(self.db);
-        let res = interactor.exec(app::read_all::Request {});
+    pub async fn read_all_thoughts(
+        &self,
+        params: &app::read_all::Params,
+    ) -> <P as Present<app::read_all::Result>>::ViewModel {
+        log::debug!("Read all thoughts: {:?}", params);
+        let req = parse_read_all_query(params).map(|query| app::read_all::Request { query });
+        let res = match req {
+            Ok(req) => {
+                let interactor = uc::read_all::ReadAll::new(self.db);
+                interactor.exec(req).await.map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
+        self.presenter.present(res)
+    }
+
+    pub async fn read_due_thoughts(
+        &self,
+        horizon: Duration,
+    ) -> <P as Present<app::due::Result>>::ViewModel {
+        log::debug!("Read thoughts that are due within {:?}", horizon);
+        let interactor = uc::due::ReadDue::new(self.db, self.clock);
+        let req = app::due::Request { horizon };
+        let res = interactor.exec(&req).await.map_err(Into::into);
+        self.presenter.present(res)
+    }
+
+    pub async fn search_thoughts(
+        &self,
+        query: impl Into<String>,
+    ) -> <P as Present<app::search::Result>>::ViewModel {
+        let query = query.into();
+        log::debug!("Search thoughts matching '{}'", query);
+        let interactor = uc::search::Search::new(self.db);
+        let req = app::search::Request { query };
+        let res = interactor.exec(req).await.map_err(Into::into);
         self.presenter.present(res)
     }
 }
 
+fn parse_read_all_query(
+    params: &app::read_all::Params,
+) -> Result<repo::thought::Query, app::read_all::Error> {
+    use app::read_all::Error as E;
+    let status = params
+        .status
+        .as_deref()
+        .map(app::parse_status)
+        .transpose()
+        .map_err(|_| E::Status)?;
+    let area_of_life = params
+        .area_of_life
+        .as_deref()
+        .map(str::parse::<aol::Id>)
+        .transpose()
+        .map_err(|_| E::AreaOfLife)?
+        .map(Into::into);
+    let key = params
+        .sort
+        .as_deref()
+        .map(app::parse_sort_key)
+        .transpose()
+        .map_err(|_| E::Sort)?
+        .unwrap_or_default();
+    let direction = params
+        .direction
+        .as_deref()
+        .map(parse_direction)
+        .transpose()
+        .map_err(|_| E::Direction)?
+        .unwrap_or_default();
+    Ok(repo::thought::Query {
+        filter: repo::thought::Filter {
+            status,
+            area_of_life,
+        },
+        sort: Sort { key, direction },
+        page: page(params.offset, params.limit),
+    })
+}
+
+fn parse_batch_request(
+    ids: &[String],
+    operation: app::batch::Operation,
+) -> Result<app::batch::Request, app::batch::Error> {
+    use app::batch::{Error as E, Operation as Op};
+    let ids = ids
+        .iter()
+        .map(|id| id.parse::<Id>().map(Into::into))
+        .collect::<Result<_, _>>()
+        .map_err(|_| E::Id)?;
+    let parse_aol_id = |id: &str| {
+        id.parse::<aol::Id>()
+            .map(Into::into)
+            .map_err(|_| E::AreaOfLifeId)
+    };
+    let operation = match operation {
+        Op::Delete => uc::batch::Operation::Delete,
+        Op::AssignAreaOfLife(id) => uc::batch::Operation::AssignAreaOfLife(parse_aol_id(&id)?),
+        Op::UnassignAreaOfLife(id) => uc::batch::Operation::UnassignAreaOfLife(parse_aol_id(&id)?),
+        Op::ReplaceInTitle { find, replace } => {
+            uc::batch::Operation::ReplaceInTitle { find, replace }
+        }
+    };
+    Ok(app::batch::Request { ids, operation })
+}
+
 fn parse_area_of_life_ids(
     areas_of_life: &HashSet<String>,
 ) -> Result<HashSet<cawr_domain::area_of_life::Id>, aol::ParseIdError> {
@@ -117,3 +334,12 @@ fn parse_area_of_life_ids(
         .collect::<Result<HashSet<aol::Id>, _>>()
         .map(|ids| ids.into_iter().map(Into::into).collect())
 }
+
+fn parse_tag_ids(
+    tags: &HashSet<String>,
+) -> Result<HashSet<cawr_domain::tag::Id>, tag::ParseIdError> {
+    tags.iter()
+        .map(|id| id.parse())
+        .collect::<Result<HashSet<tag::Id>, _>>()
+        .map(|ids| ids.into_iter().map(Into::into).collect())
+}
@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use crate::{
+    model::app::{area_of_life as aol, thought, trash as app},
+    presenter::Present,
+};
+use cawr_application::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{repository as repo, search_index::SearchIndex, unit_of_work::UnitOfWork},
+    usecase::trash::{self as uc, Item},
+};
+
+pub struct Controller<'d, 'p, 'c, 'e, D, P> {
+    db: &'d D,
+    presenter: &'p P,
+    clock: &'c dyn Clock,
+    events: &'e dyn EventPublisher,
+}
+
+impl<'d, 'p, 'c, 'e, D, P> Controller<'d, 'p, 'c, 'e, D, P>
+where
+    D: repo::thought::Repo
+        + repo::area_of_life::Repo
+        + repo::relation::Repo
+        + repo::revision::Repo
+        + SearchIndex
+        + UnitOfWork
+        + 'static,
+    P: Present<app::read_all::Result> + Present<app::restore::Result> + Present<app::purge::Result>,
+{
+    pub const fn new(
+        db: &'d D,
+        presenter: &'p P,
+        clock: &'c dyn Clock,
+        events: &'e dyn EventPublisher,
+    ) -> Self {
+        Self {
+            db,
+            presenter,
+            clock,
+            events,
+        }
+    }
+    pub async fn read_trash(&self) -> <P as Present<app::read_all::Result>>::ViewModel {
+        log::debug!("Read the trash");
+        let interactor = uc::read_all::ReadAll::new(self.db);
+        let res = interactor
+            .exec(app::read_all::Request {})
+            .await
+            .map_err(Into::into);
+        self.presenter.present(res)
+    }
+    pub async fn restore_thought(
+        &self,
+        id: &str,
+    ) -> <P as Present<app::restore::Result>>::ViewModel {
+        log::debug!("Restore thought {} from the trash", id);
+        let res = match id.parse::<thought::Id>() {
+            Ok(id) => self.restore(Item::Thought(id.into())).await,
+            Err(_) => Err(app::restore::Error::ThoughtId),
+        };
+        self.presenter.present(res)
+    }
+    pub async fn restore_area_of_life(
+        &self,
+        id: &str,
+    ) -> <P as Present<app::restore::Result>>::ViewModel {
+        log::debug!("Restore area of life {} from the trash", id);
+        let res = match id.parse::<aol::Id>() {
+            Ok(id) => self.restore(Item::AreaOfLife(id.into())).await,
+            Err(_) => Err(app::restore::Error::AreaOfLifeId),
+        };
+        self.presenter.present(res)
+    }
+    async fn restore(&self, item: Item) -> app::restore::Result {
+        let req = app::restore::Request { item };
+        let interactor = uc::restore::Restore::new(self.db, self.clock, self.events);
+        interactor.exec(req).await.map_err(Into::into)
+    }
+    pub async fn purge_trash(
+        &self,
+        retention: Duration,
+    ) -> <P as Present<app::purge::Result>>::ViewModel {
+        log::debug!("Purge the trash (retention: {:?})", retention);
+        let req = app::purge::Request { retention };
+        let interactor = uc::purge::Purge::new(self.db, self.clock, self.events);
+        let res = interactor.exec(&req).await.map_err(Into::into);
+        self.presenter.present(res)
+    }
+}
@@ -1,10 +1,23 @@
-use cawr_application::{gateway::repository as repo, identifier::NewId};
+use cawr_application::{
+    gateway::{
+        integrity::Integrity, repository as repo, search_index::SearchIndex,
+        unit_of_work::UnitOfWork,
+    },
+    identifier::NewId,
+};
 
 pub trait Db:
     repo::thought::Repo
     + NewId<cawr_domain::thought::Id>
     + repo::area_of_life::Repo
     + NewId<cawr_domain::area_of_life::Id>
+    + repo::tag::Repo
+    + repo::relation::Repo
+    + repo::revision::Repo
+    + NewId<cawr_domain::tag::Id>
+    + SearchIndex
+    + Integrity
+    + UnitOfWork
     + 'static
 {
 }
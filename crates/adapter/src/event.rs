@@ -0,0 +1,47 @@
+use cawr_application::event::EventPublisher;
+use cawr_domain::Event;
+use std::{fmt, sync::RwLock};
+
+type Subscriber = Box<dyn Fn(&Event) + Send + Sync>;
+
+/// An in-process [`EventPublisher`] that synchronously
+/// hands every event to all of its subscribers.
+///
+/// Subscribers are called in the order they subscribed
+/// and must not subscribe to the same bus while handling an event.
+#[derive(Default)]
+pub struct EventBus {
+    subscribers: RwLock<Vec<Subscriber>>,
+}
+
+impl EventBus {
+    /// Call `subscriber` for every event that is published from now on.
+    pub fn subscribe(&self, subscriber: impl Fn(&Event) + Send + Sync + 'static) {
+        self.subscribers
+            .write()
+            .unwrap_or_else(|err| err.into_inner())
+            .push(Box::new(subscriber));
+    }
+}
+
+impl EventPublisher for EventBus {
+    fn publish(&self, event: Event) {
+        log::debug!("Publish {:?}", event);
+        let subscribers = self
+            .subscribers
+            .read()
+            .unwrap_or_else(|err| err.into_inner());
+        for subscriber in subscribers.iter() {
+            subscriber(&event);
+        }
+    }
+}
+
+impl fmt::Debug for EventBus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let count = self.subscribers.read().map(|s| s.len()).unwrap_or_default();
+        f.debug_struct("EventBus")
+            .field("subscribers", &count)
+            .finish()
+    }
+}
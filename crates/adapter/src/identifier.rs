@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use cawr_application::identifier::{NewId, NewIdError};
+use cawr_domain::Id;
+
+/// How new entity IDs are generated.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum IdStrategy {
+    /// Let the database count up the IDs.
+    #[default]
+    Sequential,
+    /// Use [`UuidV7Generator`] to generate globally unique IDs,
+    /// e.g. to be able to merge the data of multiple machines.
+    UuidV7,
+}
+
+/// A [`NewId`] service that generates time-ordered UUIDs (version 7)
+/// independent of any repository.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct UuidV7Generator;
+
+#[async_trait]
+impl<T> NewId<Id<T>> for UuidV7Generator {
+    async fn new_id(&self) -> Result<Id<T>, NewIdError> {
+        Ok(Id::from_u128(uuid::Uuid::now_v7().as_u128()))
+    }
+}
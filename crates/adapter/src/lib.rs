@@ -1,5 +1,9 @@
 pub mod api;
+pub mod clock;
 mod controller;
 pub mod db;
+pub mod event;
+pub mod identifier;
+pub mod metrics;
 pub mod model;
 pub mod presenter;
@@ -0,0 +1,174 @@
+//! Timings and outcomes of the usecases.
+
+use crate::presenter::Present;
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Write as _},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+use tracing::Span;
+
+/// Upper bounds (in seconds) of the latency histogram buckets.
+const BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Outcome of a usecase that finished without an error.
+const OK: &str = "ok";
+
+/// Counters and latency histograms of all usecases
+/// that were executed through the [`Api`](crate::api::Api).
+#[derive(Debug, Default)]
+pub struct Metrics {
+    usecases: Mutex<BTreeMap<&'static str, Usecase>>,
+}
+
+#[derive(Debug, Default)]
+struct Usecase {
+    outcomes: BTreeMap<String, u64>,
+    /// Cumulative counts per bucket of [`BUCKETS`].
+    buckets: [u64; BUCKETS.len()],
+    sum: Duration,
+    count: u64,
+}
+
+impl Metrics {
+    /// Count a finished call of the `usecase`.
+    ///
+    /// The `outcome` is either `ok` or the name of the error variant.
+    pub fn record(&self, usecase: &'static str, outcome: &str, duration: Duration) {
+        let mut usecases = self.usecases.lock().unwrap_or_else(|err| err.into_inner());
+        let stats = usecases.entry(usecase).or_default();
+        *stats.outcomes.entry(outcome.to_string()).or_default() += 1;
+        let secs = duration.as_secs_f64();
+        for (count, le) in stats.buckets.iter_mut().zip(BUCKETS) {
+            if secs <= le {
+                *count += 1;
+            }
+        }
+        stats.sum += duration;
+        stats.count += 1;
+    }
+
+    /// Render all metrics in the Prometheus text exposition format.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let usecases = self.usecases.lock().unwrap_or_else(|err| err.into_inner());
+        let mut out = String::new();
+        // Writing into a `String` can't fail.
+        let _ = render(&mut out, &usecases);
+        out
+    }
+}
+
+fn render(out: &mut String, usecases: &BTreeMap<&'static str, Usecase>) -> fmt::Result {
+    writeln!(
+        out,
+        "# HELP cawr_usecase_calls_total Number of finished usecase calls by outcome."
+    )?;
+    writeln!(out, "# TYPE cawr_usecase_calls_total counter")?;
+    for (usecase, stats) in usecases {
+        for (outcome, count) in &stats.outcomes {
+            writeln!(
+                out,
+                "cawr_usecase_calls_total{{usecase=\"{usecase}\",outcome=\"{outcome}\"}} {count}"
+            )?;
+        }
+    }
+    writeln!(
+        out,
+        "# HELP cawr_usecase_duration_seconds Time it took to execute a usecase."
+    )?;
+    writeln!(out, "# TYPE cawr_usecase_duration_seconds histogram")?;
+    for (usecase, stats) in usecases {
+        for (le, count) in BUCKETS.iter().zip(stats.buckets) {
+            writeln!(
+                out,
+                "cawr_usecase_duration_seconds_bucket{{usecase=\"{usecase}\",le=\"{le}\"}} {count}"
+            )?;
+        }
+        writeln!(
+            out,
+            "cawr_usecase_duration_seconds_bucket{{usecase=\"{usecase}\",le=\"+Inf\"}} {}",
+            stats.count
+        )?;
+        writeln!(
+            out,
+            "cawr_usecase_duration_seconds_sum{{usecase=\"{usecase}\"}} {}",
+            stats.sum.as_secs_f64()
+        )?;
+        writeln!(
+            out,
+            "cawr_usecase_duration_seconds_count{{usecase=\"{usecase}\"}} {}",
+            stats.count
+        )?;
+    }
+    Ok(())
+}
+
+/// A presenter that records the outcome and duration
+/// of a single usecase call before it presents the result.
+pub(crate) struct Instrumented<'a, P> {
+    presenter: &'a P,
+    metrics: &'a Metrics,
+    usecase: &'static str,
+    span: Span,
+    started: Instant,
+}
+
+impl<'a, P> Instrumented<'a, P> {
+    pub fn new(presenter: &'a P, metrics: &'a Metrics, usecase: &'static str) -> Self {
+        let request_id = uuid::Uuid::now_v7();
+        let span = tracing::debug_span!(
+            "usecase",
+            usecase,
+            %request_id,
+            outcome = tracing::field::Empty
+        );
+        Self {
+            presenter,
+            metrics,
+            usecase,
+            span,
+            started: Instant::now(),
+        }
+    }
+    /// The span in which the usecase is executed.
+    pub fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl<T, E, P> Present<Result<T, E>> for Instrumented<'_, P>
+where
+    P: Present<Result<T, E>>,
+    E: fmt::Debug,
+{
+    type ViewModel = P::ViewModel;
+    fn present(&self, result: Result<T, E>) -> Self::ViewModel {
+        let duration = self.started.elapsed();
+        let outcome = match &result {
+            Ok(_) => OK.to_string(),
+            Err(err) => variant_name(err),
+        };
+        self.span.record("outcome", outcome.as_str());
+        tracing::debug!(
+            duration_ms = duration.as_secs_f64() * 1000.0,
+            outcome,
+            "Finished {}",
+            self.usecase
+        );
+        self.metrics.record(self.usecase, &outcome, duration);
+        self.presenter.present(result)
+    }
+}
+
+/// The name of the variant of an error, e.g. `NotFound` for `NotFound(Id(3))`.
+fn variant_name(err: &impl fmt::Debug) -> String {
+    format!("{err:?}")
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
@@ -2,28 +2,29 @@ use std::{fmt, str::FromStr};
 
 use thiserror::Error;
 
+use cawr_application::gateway::repository::area_of_life::SortKey;
 use cawr_domain::area_of_life as aol;
 
 /// This is the public ID of an area of life.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Id(u64);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Id(u128);
 
 impl Id {
     #[must_use]
-    pub const fn to_u64(self) -> u64 {
+    pub const fn to_u128(self) -> u128 {
         self.0
     }
 }
 
 impl From<aol::Id> for Id {
     fn from(id: aol::Id) -> Self {
-        Self(id.to_u64())
+        Self(id.to_u128())
     }
 }
 
 impl From<Id> for aol::Id {
     fn from(id: Id) -> Self {
-        Self::new(id.0)
+        Self::from_u128(id.0)
     }
 }
 
@@ -31,28 +32,73 @@ impl From<Id> for aol::Id {
 #[error("Unable to parse area of life ID")]
 pub struct ParseIdError;
 
+#[derive(Debug, Error)]
+#[error("Unable to parse sort key of areas of life")]
+pub struct ParseSortKeyError;
+
+/// Parse a [`SortKey`] from its snake case name.
+pub fn parse_sort_key(s: &str) -> Result<SortKey, ParseSortKeyError> {
+    match s {
+        "name" => Ok(SortKey::Name),
+        "created_at" => Ok(SortKey::CreatedAt),
+        "updated_at" => Ok(SortKey::UpdatedAt),
+        _ => Err(ParseSortKeyError),
+    }
+}
+
 impl FromStr for Id {
     type Err = ParseIdError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let id = s.parse().map_err(|_| ParseIdError)?;
-        Ok(Self(id))
+        super::parse_id(s).map(Self).ok_or(ParseIdError)
     }
 }
 
 impl fmt::Display for Id {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.0)
+        aol::Id::from(*self).fmt(f)
     }
 }
 
 pub mod create {
-    use cawr_application::usecase::area_of_life::create as uc;
+    use super::{Id, ParseIdError};
+    use cawr_application::usecase::area_of_life::{create as uc, validate::AreaOfLifeInvalidity};
     use std::result;
+    use thiserror::Error;
 
     pub type Request = uc::Request;
     pub type Response = uc::Response;
     pub type Result = result::Result<Response, Error>;
-    pub type Error = uc::Error;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", ParseIdError)]
+        ParentId,
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+        #[error("{}", uc::Error::NewId)]
+        NewId,
+        #[error(transparent)]
+        Invalidity(#[from] AreaOfLifeInvalidity),
+        #[error("Parent area of life {0:?} not found")]
+        ParentNotFound(Id),
+        #[error("Area of life {0:?} can't be nested below one of its descendants")]
+        Cycle(Id),
+        #[error("An area of life named '{0}' already exists")]
+        NameAlreadyExists(String),
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(from: uc::Error) -> Self {
+            match from {
+                uc::Error::Repo => Self::Repo,
+                uc::Error::NewId => Self::NewId,
+                uc::Error::Invalidity(i) => Self::Invalidity(i),
+                uc::Error::ParentNotFound(id) => Self::ParentNotFound(id.into()),
+                uc::Error::Cycle(id) => Self::Cycle(id.into()),
+                uc::Error::NameAlreadyExists(name) => Self::NameAlreadyExists(name),
+            }
+        }
+    }
 }
 
 pub mod update {
@@ -69,12 +115,22 @@ pub mod update {
     pub enum Error {
         #[error("{}", ParseIdError)]
         Id,
+        #[error("{}", ParseIdError)]
+        ParentId,
         #[error("Area of life {0:?} not found")]
         NotFound(Id),
         #[error("{}", uc::Error::Repo)]
         Repo,
         #[error(transparent)]
         Invalidity(#[from] AreaOfLifeInvalidity),
+        #[error("Parent area of life {0:?} not found")]
+        ParentNotFound(Id),
+        #[error("Area of life {0:?} can't be nested below one of its descendants")]
+        Cycle(Id),
+        #[error("An area of life named '{0}' already exists")]
+        NameAlreadyExists(String),
+        #[error("{}", uc::Error::Conflict)]
+        Conflict,
     }
 
     impl From<ParseIdError> for Error {
@@ -89,23 +145,158 @@ pub mod update {
                 uc::Error::NotFound(id) => Self::NotFound(id.into()),
                 uc::Error::Invalidity(i) => Self::Invalidity(i),
                 uc::Error::Repo => Self::Repo,
+                uc::Error::ParentNotFound(id) => Self::ParentNotFound(id.into()),
+                uc::Error::Cycle(id) => Self::Cycle(id.into()),
+                uc::Error::NameAlreadyExists(name) => Self::NameAlreadyExists(name),
+                uc::Error::Conflict => Self::Conflict,
             }
         }
     }
 }
 
 pub mod read_all {
+    use super::ParseSortKeyError;
+    use crate::model::app::ParseDirectionError;
     use cawr_application::usecase::area_of_life::read_all as uc;
     use std::result;
+    use thiserror::Error;
 
     pub type Request = uc::Request;
     pub type Response = uc::Response;
     pub type Result = result::Result<Response, Error>;
-    pub type Error = uc::Error;
+
+    /// The unparsed query parameters.
+    #[derive(Debug, Default)]
+    pub struct Params {
+        pub sort: Option<String>,
+        pub direction: Option<String>,
+        pub offset: Option<u64>,
+        pub limit: Option<u64>,
+    }
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", ParseSortKeyError)]
+        Sort,
+        #[error("{}", ParseDirectionError)]
+        Direction,
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(e: uc::Error) -> Self {
+            match e {
+                uc::Error::Repo => Self::Repo,
+            }
+        }
+    }
+}
+
+pub mod read_subtree {
+    use super::{Id, ParseIdError};
+    use cawr_application::usecase::area_of_life::read_subtree as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", ParseIdError)]
+        Id,
+        #[error("Area of life {0:?} not found")]
+        NotFound(Id),
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(from: uc::Error) -> Self {
+            match from {
+                uc::Error::NotFound(id) => Self::NotFound(id.into()),
+                uc::Error::Repo => Self::Repo,
+            }
+        }
+    }
+}
+
+pub mod find_by_id {
+    use super::{Id, ParseIdError};
+    use cawr_application::usecase::area_of_life::find_by_id as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", ParseIdError)]
+        Id,
+        #[error("Area of life {0:?} not found")]
+        NotFound(Id),
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(from: uc::Error) -> Self {
+            match from {
+                uc::Error::NotFound(id) => Self::NotFound(id.into()),
+                uc::Error::Repo => Self::Repo,
+            }
+        }
+    }
+}
+
+pub mod thoughts {
+    use super::{Id, ParseIdError};
+    use crate::model::app::{thought::ParseSortKeyError, ParseDirectionError};
+    use cawr_application::usecase::area_of_life::thoughts as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    /// The unparsed query parameters.
+    #[derive(Debug, Default)]
+    pub struct Params {
+        pub sort: Option<String>,
+        pub direction: Option<String>,
+    }
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", ParseIdError)]
+        Id,
+        #[error("{}", ParseSortKeyError)]
+        Sort,
+        #[error("{}", ParseDirectionError)]
+        Direction,
+        #[error("Area of life {0:?} not found")]
+        NotFound(Id),
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(from: uc::Error) -> Self {
+            match from {
+                uc::Error::NotFound(id) => Self::NotFound(id.into()),
+                uc::Error::Repo => Self::Repo,
+            }
+        }
+    }
 }
 
 pub mod delete {
-    use super::ParseIdError;
+    use super::{Id, ParseIdError};
     use cawr_application::usecase::area_of_life::delete as uc;
     use std::result;
     use thiserror::Error;
@@ -113,14 +304,88 @@ pub mod delete {
     pub type Request = uc::Request;
     pub type Response = uc::Response;
     pub type Result = result::Result<Response, Error>;
+    pub type Children = uc::Children;
+
+    /// The unparsed [`uc::References`].
+    #[derive(Debug, Default, Clone)]
+    pub enum References {
+        Restrict,
+        #[default]
+        Detach,
+        Reassign(String),
+    }
 
     #[derive(Debug, Error)]
     pub enum Error {
         #[error("{}", ParseIdError)]
         Id,
+        #[error("{}", ParseIdError)]
+        TargetId,
         #[error("{}", uc::Error::NotFound)]
         NotFound,
+        #[error("Area of life is still referenced by {0} thoughts")]
+        Referenced(usize),
+        #[error("Area of life {0:?} not found")]
+        TargetNotFound(Id),
+        #[error("Area of life {0:?} is deleted as well")]
+        TargetDeleted(Id),
         #[error("{}", uc::Error::Repo)]
         Repo,
+        #[error("{}", uc::Error::Conflict)]
+        Conflict,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(e: uc::Error) -> Self {
+            match e {
+                uc::Error::NotFound => Self::NotFound,
+                uc::Error::Referenced(count) => Self::Referenced(count),
+                uc::Error::TargetNotFound(id) => Self::TargetNotFound(id.into()),
+                uc::Error::TargetDeleted(id) => Self::TargetDeleted(id.into()),
+                uc::Error::Repo => Self::Repo,
+                uc::Error::Conflict => Self::Conflict,
+            }
+        }
+    }
+}
+
+pub mod merge {
+    use super::{Id, ParseIdError};
+    use cawr_application::usecase::area_of_life::merge as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", ParseIdError)]
+        Id,
+        #[error("{}", ParseIdError)]
+        TargetId,
+        #[error("Area of life {0:?} not found")]
+        NotFound(Id),
+        #[error("Area of life {0:?} can't be merged into itself")]
+        Same(Id),
+        #[error("Area of life {0:?} can't be merged into one of its descendants")]
+        Cycle(Id),
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+        #[error("{}", uc::Error::Conflict)]
+        Conflict,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(e: uc::Error) -> Self {
+            match e {
+                uc::Error::NotFound(id) => Self::NotFound(id.into()),
+                uc::Error::Same(id) => Self::Same(id.into()),
+                uc::Error::Cycle(id) => Self::Cycle(id.into()),
+                uc::Error::Repo => Self::Repo,
+                uc::Error::Conflict => Self::Conflict,
+            }
+        }
     }
 }
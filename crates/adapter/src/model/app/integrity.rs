@@ -0,0 +1,26 @@
+pub mod check {
+    use cawr_application::usecase::integrity::check as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+        #[error("{}", uc::Error::Conflict)]
+        Conflict,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(e: uc::Error) -> Self {
+            match e {
+                uc::Error::Repo => Self::Repo,
+                uc::Error::Conflict => Self::Conflict,
+            }
+        }
+    }
+}
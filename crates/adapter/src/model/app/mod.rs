@@ -1,2 +1,41 @@
+use cawr_application::gateway::repository::query::{Direction, Page};
+use thiserror::Error;
+
 pub mod area_of_life;
+pub mod integrity;
+pub mod relation;
+pub mod revision;
+pub mod tag;
 pub mod thought;
+pub mod trash;
+
+#[derive(Debug, Error)]
+#[error("Unable to parse sort direction")]
+pub struct ParseDirectionError;
+
+/// Parse a sort [`Direction`] from `asc` or `desc`.
+pub fn parse_direction(s: &str) -> Result<Direction, ParseDirectionError> {
+    match s {
+        "asc" => Ok(Direction::Asc),
+        "desc" => Ok(Direction::Desc),
+        _ => Err(ParseDirectionError),
+    }
+}
+
+/// The [`Page`] of at most `limit` entries that starts at `offset`.
+#[must_use]
+pub fn page(offset: Option<u64>, limit: Option<u64>) -> Page {
+    let to_usize = |n: u64| usize::try_from(n).unwrap_or(usize::MAX);
+    Page {
+        offset: offset.map_or(0, to_usize),
+        limit: limit.map(to_usize),
+    }
+}
+
+/// Parse a sequential ID or a UUID.
+fn parse_id(s: &str) -> Option<u128> {
+    s.parse::<u64>()
+        .map(u128::from)
+        .ok()
+        .or_else(|| uuid::Uuid::try_parse(s).ok().map(|id| id.as_u128()))
+}
@@ -0,0 +1,131 @@
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use cawr_domain::relation::Kind;
+
+/// This is the public representation of a relation kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelationKind(Kind);
+
+impl From<RelationKind> for Kind {
+    fn from(kind: RelationKind) -> Self {
+        kind.0
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("Unable to parse relation kind")]
+pub struct ParseKindError;
+
+impl FromStr for RelationKind {
+    type Err = ParseKindError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let kind = match s {
+            "depends-on" => Kind::DependsOn,
+            "relates-to" => Kind::RelatesTo,
+            "supersedes" => Kind::Supersedes,
+            _ => return Err(ParseKindError),
+        };
+        Ok(Self(kind))
+    }
+}
+
+pub mod link {
+    use super::ParseKindError;
+    use crate::model::app::thought::{Id, ParseIdError};
+    use cawr_application::usecase::relation::link as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", ParseIdError)]
+        Id,
+        #[error("{}", ParseKindError)]
+        Kind,
+        #[error("Thought {0:?} not found")]
+        ThoughtNotFound(Id),
+        #[error("{}", uc::Error::SelfLink)]
+        SelfLink,
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(from: uc::Error) -> Self {
+            match from {
+                uc::Error::Repo => Self::Repo,
+                uc::Error::ThoughtNotFound(id) => Self::ThoughtNotFound(id.into()),
+                uc::Error::SelfLink => Self::SelfLink,
+            }
+        }
+    }
+}
+
+pub mod unlink {
+    use super::ParseKindError;
+    use crate::model::app::thought::ParseIdError;
+    use cawr_application::usecase::relation::unlink as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", ParseIdError)]
+        Id,
+        #[error("{}", ParseKindError)]
+        Kind,
+        #[error("{}", uc::Error::NotFound)]
+        NotFound,
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(from: uc::Error) -> Self {
+            match from {
+                uc::Error::Repo => Self::Repo,
+                uc::Error::NotFound => Self::NotFound,
+            }
+        }
+    }
+}
+
+pub mod read_links {
+    use crate::model::app::thought::ParseIdError;
+    use cawr_application::usecase::relation::read_links as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", ParseIdError)]
+        Id,
+        #[error("{}", uc::Error::NotFound)]
+        NotFound,
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(from: uc::Error) -> Self {
+            match from {
+                uc::Error::Repo => Self::Repo,
+                uc::Error::NotFound => Self::NotFound,
+            }
+        }
+    }
+}
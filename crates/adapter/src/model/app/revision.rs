@@ -0,0 +1,98 @@
+pub mod read_all {
+    use crate::model::app::thought::ParseIdError;
+    use cawr_application::usecase::revision::read_all as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", ParseIdError)]
+        Id,
+        #[error("{}", uc::Error::NotFound)]
+        NotFound,
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(e: uc::Error) -> Self {
+            match e {
+                uc::Error::NotFound => Self::NotFound,
+                uc::Error::Repo => Self::Repo,
+            }
+        }
+    }
+}
+
+pub mod diff {
+    use crate::model::app::thought::ParseIdError;
+    use cawr_application::usecase::revision::diff as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", ParseIdError)]
+        Id,
+        #[error("{}", uc::Error::ThoughtNotFound)]
+        ThoughtNotFound,
+        #[error("Revision {0} not found")]
+        RevisionNotFound(u64),
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(e: uc::Error) -> Self {
+            match e {
+                uc::Error::ThoughtNotFound => Self::ThoughtNotFound,
+                uc::Error::RevisionNotFound(v) => Self::RevisionNotFound(v.to_u64()),
+                uc::Error::Repo => Self::Repo,
+            }
+        }
+    }
+}
+
+pub mod revert {
+    use crate::model::app::thought::ParseIdError;
+    use cawr_application::usecase::revision::revert as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", ParseIdError)]
+        Id,
+        #[error("{}", uc::Error::ThoughtNotFound)]
+        ThoughtNotFound,
+        #[error("Revision {0} not found")]
+        RevisionNotFound(u64),
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+        #[error("{}", uc::Error::Conflict)]
+        Conflict,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(e: uc::Error) -> Self {
+            match e {
+                uc::Error::ThoughtNotFound => Self::ThoughtNotFound,
+                uc::Error::RevisionNotFound(v) => Self::RevisionNotFound(v.to_u64()),
+                uc::Error::Repo => Self::Repo,
+                uc::Error::Conflict => Self::Conflict,
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::{fmt, str::FromStr};
+
+use thiserror::Error;
+
+use cawr_domain::tag;
+
+/// This is the public ID of a tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Id(u128);
+
+impl Id {
+    #[must_use]
+    pub const fn to_u128(self) -> u128 {
+        self.0
+    }
+}
+
+impl From<tag::Id> for Id {
+    fn from(id: tag::Id) -> Self {
+        Self(id.to_u128())
+    }
+}
+
+impl From<Id> for tag::Id {
+    fn from(id: Id) -> Self {
+        Self::from_u128(id.0)
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("Unable to parse tag ID")]
+pub struct ParseIdError;
+
+impl FromStr for Id {
+    type Err = ParseIdError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        super::parse_id(s).map(Self).ok_or(ParseIdError)
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        tag::Id::from(*self).fmt(f)
+    }
+}
+
+pub mod create {
+    use cawr_application::usecase::tag::create as uc;
+    use std::result;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+    pub type Error = uc::Error;
+}
+
+pub mod update {
+    use super::{Id, ParseIdError};
+    use cawr_application::usecase::tag::{update as uc, validate::TagInvalidity};
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", ParseIdError)]
+        Id,
+        #[error("Tag {0:?} not found")]
+        NotFound(Id),
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+        #[error(transparent)]
+        Invalidity(#[from] TagInvalidity),
+    }
+
+    impl From<ParseIdError> for Error {
+        fn from(_: ParseIdError) -> Self {
+            Self::Id
+        }
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(from: uc::Error) -> Self {
+            match from {
+                uc::Error::NotFound(id) => Self::NotFound(id.into()),
+                uc::Error::Invalidity(i) => Self::Invalidity(i),
+                uc::Error::Repo => Self::Repo,
+            }
+        }
+    }
+}
+
+pub mod read_all {
+    use cawr_application::usecase::tag::read_all as uc;
+    use std::result;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+    pub type Error = uc::Error;
+}
+
+pub mod delete {
+    use super::ParseIdError;
+    use cawr_application::usecase::tag::delete as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", ParseIdError)]
+        Id,
+        #[error("{}", uc::Error::NotFound)]
+        NotFound,
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+        #[error("{}", uc::Error::Conflict)]
+        Conflict,
+    }
+}
@@ -1,52 +1,92 @@
-use std::{fmt, str::FromStr};
+use std::{fmt, str::FromStr, time::Duration};
 
 use thiserror::Error;
 
+use cawr_application::gateway::repository::thought::SortKey;
 use cawr_domain::thought;
 
 /// This is the public ID of a thought.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Id(u64);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Id(u128);
 
 impl Id {
     #[must_use]
-    pub const fn to_u64(self) -> u64 {
+    pub const fn to_u128(self) -> u128 {
         self.0
     }
 }
 
 impl From<thought::Id> for Id {
     fn from(id: thought::Id) -> Self {
-        Self(id.to_u64())
+        Self(id.to_u128())
     }
 }
 
 impl From<Id> for thought::Id {
     fn from(id: Id) -> Self {
-        Self::new(id.0)
+        Self::from_u128(id.0)
     }
 }
 
+/// When a thought is due and when to be reminded of it.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Schedule {
+    /// Unix timestamp in milliseconds
+    pub due_at: Option<u64>,
+    /// How long before the due date a reminder should fire.
+    pub reminder_offset: Option<Duration>,
+}
+
 #[derive(Debug, Error)]
 #[error("Unable to parse thought ID")]
 pub struct ParseIdError;
 
+#[derive(Debug, Error)]
+#[error("Unable to parse thought status")]
+pub struct ParseStatusError;
+
+/// Parse a [`thought::Status`] from its lowercase name.
+pub fn parse_status(s: &str) -> Result<thought::Status, ParseStatusError> {
+    use thought::Status as S;
+    match s {
+        "inbox" => Ok(S::Inbox),
+        "active" => Ok(S::Active),
+        "done" => Ok(S::Done),
+        "archived" => Ok(S::Archived),
+        _ => Err(ParseStatusError),
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("Unable to parse sort key of thoughts")]
+pub struct ParseSortKeyError;
+
+/// Parse a [`SortKey`] from its snake case name.
+pub fn parse_sort_key(s: &str) -> Result<SortKey, ParseSortKeyError> {
+    match s {
+        "created_at" => Ok(SortKey::CreatedAt),
+        "updated_at" => Ok(SortKey::UpdatedAt),
+        "title" => Ok(SortKey::Title),
+        "due_at" => Ok(SortKey::DueAt),
+        _ => Err(ParseSortKeyError),
+    }
+}
+
 impl FromStr for Id {
     type Err = ParseIdError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let id = s.parse().map_err(|_| ParseIdError)?;
-        Ok(Self(id))
+        super::parse_id(s).map(Self).ok_or(ParseIdError)
     }
 }
 
 impl fmt::Display for Id {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.0)
+        thought::Id::from(*self).fmt(f)
     }
 }
 
 pub mod create {
-    use crate::model::app::area_of_life as aol;
+    use crate::model::app::{area_of_life as aol, tag};
     use cawr_application::usecase::thought::{create as uc, validate::ThoughtInvalidity};
     use std::{collections::HashSet, result};
     use thiserror::Error;
@@ -59,6 +99,8 @@ pub mod create {
     pub enum Error {
         #[error("{}", aol::ParseIdError)]
         AreaOfLifeId,
+        #[error("{}", tag::ParseIdError)]
+        TagId,
         #[error("{}", uc::Error::NewId)]
         NewId,
         #[error("{}", uc::Error::Repo)]
@@ -67,6 +109,8 @@ pub mod create {
         Invalidity(#[from] ThoughtInvalidity),
         #[error("Areas of life {0:?} not found")]
         AreasOfLifeNotFound(HashSet<aol::Id>),
+        #[error("Tags {0:?} not found")]
+        TagsNotFound(HashSet<tag::Id>),
     }
 
     impl From<aol::ParseIdError> for Error {
@@ -74,6 +118,11 @@ pub mod create {
             Self::AreaOfLifeId
         }
     }
+    impl From<tag::ParseIdError> for Error {
+        fn from(_: tag::ParseIdError) -> Self {
+            Self::TagId
+        }
+    }
     impl From<uc::Error> for Error {
         fn from(from: uc::Error) -> Self {
             match from {
@@ -83,6 +132,9 @@ pub mod create {
                 uc::Error::AreasOfLifeNotFound(ids) => {
                     Self::AreasOfLifeNotFound(ids.into_iter().map(Into::into).collect())
                 }
+                uc::Error::TagsNotFound(ids) => {
+                    Self::TagsNotFound(ids.into_iter().map(Into::into).collect())
+                }
             }
         }
     }
@@ -90,7 +142,7 @@ pub mod create {
 
 pub mod update {
     use super::ParseIdError;
-    use crate::model::app::{area_of_life as aol, thought::Id};
+    use crate::model::app::{area_of_life as aol, tag, thought::Id};
     use cawr_application::usecase::thought::{update as uc, validate::ThoughtInvalidity};
     use std::{collections::HashSet, result};
     use thiserror::Error;
@@ -107,12 +159,18 @@ pub mod update {
         NotFound(Id),
         #[error("{}", aol::ParseIdError)]
         AreaOfLifeId,
+        #[error("{}", tag::ParseIdError)]
+        TagId,
         #[error("{}", uc::Error::Repo)]
         Repo,
         #[error(transparent)]
         Invalidity(#[from] ThoughtInvalidity),
         #[error("Areas of life {0:?} not found")]
         AreasOfLifeNotFound(HashSet<aol::Id>),
+        #[error("Tags {0:?} not found")]
+        TagsNotFound(HashSet<tag::Id>),
+        #[error("{}", uc::Error::Conflict)]
+        Conflict,
     }
 
     impl From<aol::ParseIdError> for Error {
@@ -120,6 +178,11 @@ pub mod update {
             Self::AreaOfLifeId
         }
     }
+    impl From<tag::ParseIdError> for Error {
+        fn from(_: tag::ParseIdError) -> Self {
+            Self::TagId
+        }
+    }
     impl From<uc::Error> for Error {
         fn from(from: uc::Error) -> Self {
             match from {
@@ -129,6 +192,10 @@ pub mod update {
                 uc::Error::AreasOfLifeNotFound(ids) => {
                     Self::AreasOfLifeNotFound(ids.into_iter().map(Into::into).collect())
                 }
+                uc::Error::TagsNotFound(ids) => {
+                    Self::TagsNotFound(ids.into_iter().map(Into::into).collect())
+                }
+                uc::Error::Conflict => Self::Conflict,
             }
         }
     }
@@ -165,13 +232,186 @@ pub mod find_by_id {
 }
 
 pub mod read_all {
+    use super::{ParseSortKeyError, ParseStatusError};
+    use crate::model::app::{area_of_life::ParseIdError, ParseDirectionError};
     use cawr_application::usecase::thought::read_all as uc;
     use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    /// The unparsed query parameters.
+    #[derive(Debug, Default)]
+    pub struct Params {
+        pub status: Option<String>,
+        pub area_of_life: Option<String>,
+        pub sort: Option<String>,
+        pub direction: Option<String>,
+        pub offset: Option<u64>,
+        pub limit: Option<u64>,
+    }
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", ParseStatusError)]
+        Status,
+        #[error("{}", ParseIdError)]
+        AreaOfLife,
+        #[error("{}", ParseSortKeyError)]
+        Sort,
+        #[error("{}", ParseDirectionError)]
+        Direction,
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(e: uc::Error) -> Self {
+            match e {
+                uc::Error::Repo => Self::Repo,
+            }
+        }
+    }
+}
+
+pub mod due {
+    use cawr_application::usecase::thought::due as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(e: uc::Error) -> Self {
+            match e {
+                uc::Error::Repo => Self::Repo,
+            }
+        }
+    }
+}
+
+pub mod search {
+    use cawr_application::usecase::thought::search as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", uc::Error::EmptyQuery)]
+        EmptyQuery,
+        #[error("{}", uc::Error::Index)]
+        Index,
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(e: uc::Error) -> Self {
+            match e {
+                uc::Error::EmptyQuery => Self::EmptyQuery,
+                uc::Error::Index => Self::Index,
+                uc::Error::Repo => Self::Repo,
+            }
+        }
+    }
+}
+
+pub mod change_status {
+    use super::{Id, ParseIdError, ParseStatusError};
+    use cawr_application::usecase::thought::change_status as uc;
+    use cawr_domain::thought::InvalidTransition;
+    use std::result;
+    use thiserror::Error;
 
     pub type Request = uc::Request;
     pub type Response = uc::Response;
     pub type Result = result::Result<Response, Error>;
-    pub type Error = uc::Error;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", ParseIdError)]
+        Id,
+        #[error("{}", ParseStatusError)]
+        Status,
+        #[error("Thought {0:?} not found")]
+        NotFound(Id),
+        #[error(transparent)]
+        InvalidTransition(InvalidTransition),
+        #[error("{}", uc::Error::Conflict)]
+        Conflict,
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(e: uc::Error) -> Self {
+            match e {
+                uc::Error::Repo => Self::Repo,
+                uc::Error::NotFound(id) => Self::NotFound(id.into()),
+                uc::Error::InvalidTransition(t) => Self::InvalidTransition(t),
+                uc::Error::Conflict => Self::Conflict,
+            }
+        }
+    }
+}
+
+pub mod batch {
+    use super::ParseIdError;
+    use crate::model::app::area_of_life as aol;
+    use cawr_application::usecase::thought::batch as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    /// The unparsed operation.
+    #[derive(Debug, Clone)]
+    pub enum Operation {
+        Delete,
+        AssignAreaOfLife(String),
+        UnassignAreaOfLife(String),
+        ReplaceInTitle { find: String, replace: String },
+    }
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", ParseIdError)]
+        Id,
+        #[error("{}", aol::ParseIdError)]
+        AreaOfLifeId,
+        #[error("{}", uc::Error::EmptyFind)]
+        EmptyFind,
+        #[error("Area of life {0} not found")]
+        AreaOfLifeNotFound(aol::Id),
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(e: uc::Error) -> Self {
+            match e {
+                uc::Error::EmptyFind => Self::EmptyFind,
+                uc::Error::AreaOfLifeNotFound(id) => Self::AreaOfLifeNotFound(id.into()),
+                uc::Error::Repo => Self::Repo,
+            }
+        }
+    }
 }
 
 pub mod delete {
@@ -192,6 +432,8 @@ pub mod delete {
         NotFound,
         #[error("{}", uc::Error::Repo)]
         Repo,
+        #[error("{}", uc::Error::Conflict)]
+        Conflict,
     }
 
     impl From<uc::Error> for Error {
@@ -199,6 +441,7 @@ pub mod delete {
             match e {
                 uc::Error::Repo => Error::Repo,
                 uc::Error::NotFound => Error::NotFound,
+                uc::Error::Conflict => Error::Conflict,
             }
         }
     }
@@ -0,0 +1,103 @@
+use cawr_application::usecase::trash as uc;
+
+use crate::model::app::{area_of_life as aol, thought};
+
+/// This is the public representation of a trashed entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Item {
+    Thought(thought::Id),
+    AreaOfLife(aol::Id),
+}
+
+impl From<uc::Item> for Item {
+    fn from(item: uc::Item) -> Self {
+        match item {
+            uc::Item::Thought(id) => Self::Thought(id.into()),
+            uc::Item::AreaOfLife(id) => Self::AreaOfLife(id.into()),
+        }
+    }
+}
+
+pub mod read_all {
+    use cawr_application::usecase::trash::read_all as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(e: uc::Error) -> Self {
+            match e {
+                uc::Error::Repo => Self::Repo,
+            }
+        }
+    }
+}
+
+pub mod restore {
+    use super::Item;
+    use crate::model::app::{area_of_life as aol, thought};
+    use cawr_application::usecase::trash::restore as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", thought::ParseIdError)]
+        ThoughtId,
+        #[error("{}", aol::ParseIdError)]
+        AreaOfLifeId,
+        #[error("The trash does not contain {0:?}")]
+        NotFound(Item),
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+        #[error("{}", uc::Error::Conflict)]
+        Conflict,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(e: uc::Error) -> Self {
+            match e {
+                uc::Error::NotFound(item) => Self::NotFound(item.into()),
+                uc::Error::Repo => Self::Repo,
+                uc::Error::Conflict => Self::Conflict,
+            }
+        }
+    }
+}
+
+pub mod purge {
+    use cawr_application::usecase::trash::purge as uc;
+    use std::result;
+    use thiserror::Error;
+
+    pub type Request = uc::Request;
+    pub type Response = uc::Response;
+    pub type Result = result::Result<Response, Error>;
+
+    #[derive(Debug, Error)]
+    pub enum Error {
+        #[error("{}", uc::Error::Repo)]
+        Repo,
+    }
+
+    impl From<uc::Error> for Error {
+        fn from(e: uc::Error) -> Self {
+            match e {
+                uc::Error::Repo => Self::Repo,
+            }
+        }
+    }
+}
@@ -1,13 +1,37 @@
 pub mod thought {
     pub use cawr_json_boundary::{
-        domain::{Thought, ThoughtId},
+        domain::{Agenda, Thought, ThoughtId, ThoughtStatus},
         usecase::thought::*,
     };
 }
 pub mod area_of_life {
     pub use cawr_json_boundary::{
-        domain::{AreaOfLife, AreaOfLifeId},
+        domain::{AreaOfLife, AreaOfLifeId, AreaOfLifeTree},
         usecase::area_of_life::*,
     };
 }
-pub use cawr_json_boundary::{Error, Response, Result, StatusCode};
+pub mod relation {
+    pub use cawr_json_boundary::{
+        domain::{RelationKind, ThoughtLink, ThoughtLinks},
+        usecase::relation::*,
+    };
+}
+pub mod revision {
+    pub use cawr_json_boundary::{
+        domain::{ThoughtChange, ThoughtRevision},
+        usecase::revision::*,
+    };
+}
+pub mod tag {
+    pub use cawr_json_boundary::{
+        domain::{Tag, TagId},
+        usecase::tag::*,
+    };
+}
+pub mod trash {
+    pub use cawr_json_boundary::{
+        domain::{TrashEntry, TrashItem},
+        usecase::trash::*,
+    };
+}
+pub use cawr_json_boundary::{query::SortDirection, Error, Response, Result, StatusCode};
@@ -1,7 +1,15 @@
 use crate::{
-    model::app::{area_of_life, thought},
+    model::app::{area_of_life, integrity, relation, revision, tag, thought, trash},
     presenter::Present,
 };
+use cawr_application::{
+    gateway::integrity::{Entity, StorageIssue},
+    usecase::{
+        area_of_life as area_of_life_uc, integrity::check::Finding, thought::batch as batch_uc,
+    },
+};
+use cawr_domain::{area_of_life as aol, thought::Id};
+use std::collections::{HashMap, HashSet};
 
 #[derive(Default)]
 pub struct Presenter;
@@ -20,7 +28,7 @@ impl Present<thought::update::Result> for Presenter {
     type ViewModel = String;
     fn present(&self, result: thought::update::Result) -> Self::ViewModel {
         match result {
-            Ok(()) => "Updated thought".to_string(),
+            Ok(_) => "Updated thought".to_string(),
             Err(err) => format!("Undable to update thought: {err}"),
         }
     }
@@ -30,7 +38,10 @@ impl Present<thought::find_by_id::Result> for Presenter {
     type ViewModel = String;
     fn present(&self, result: thought::find_by_id::Result) -> Self::ViewModel {
         match result {
-            Ok(thought) => format!("{} ({})", thought.title, thought.id),
+            Ok(thought) => match thought.body {
+                Some(body) => format!("{} ({})\n\n{}", thought.title, thought.id, body),
+                None => format!("{} ({})", thought.title, thought.id),
+            },
             Err(err) => format!("Unable find thought: {err}"),
         }
     }
@@ -51,16 +62,98 @@ impl Present<thought::read_all::Result> for Presenter {
     }
 }
 
+impl Present<thought::search::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: thought::search::Result) -> Self::ViewModel {
+        match result {
+            Ok(resp) if resp.thoughts.is_empty() => "No matching thoughts".to_string(),
+            Ok(resp) => resp
+                .thoughts
+                .into_iter()
+                .map(|t| format!("- {} ({})", t.title, t.id))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Err(err) => format!("Unable to search thoughts: {err}"),
+        }
+    }
+}
+
+impl Present<thought::due::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: thought::due::Result) -> Self::ViewModel {
+        match result {
+            Ok(resp) => {
+                let mut lines = vec![];
+                for (heading, thoughts) in [("Overdue", resp.overdue), ("Upcoming", resp.upcoming)]
+                {
+                    if thoughts.is_empty() {
+                        continue;
+                    }
+                    lines.push(format!("{heading}:"));
+                    for t in thoughts {
+                        let due_at = t.due_at.map(|at| at.to_string()).unwrap_or_default();
+                        lines.push(format!("- {} ({}) due at {due_at}", t.title, t.id));
+                    }
+                }
+                if lines.is_empty() {
+                    "Nothing is due".to_string()
+                } else {
+                    lines.join("\n")
+                }
+            }
+            Err(err) => format!("Unable to read the agenda: {err}"),
+        }
+    }
+}
+
+impl Present<thought::change_status::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: thought::change_status::Result) -> Self::ViewModel {
+        match result {
+            Ok(()) => "Changed thought status".to_string(),
+            Err(err) => format!("Unable to change thought status: {err}"),
+        }
+    }
+}
+
 impl Present<thought::delete::Result> for Presenter {
     type ViewModel = String;
     fn present(&self, result: thought::delete::Result) -> Self::ViewModel {
         match result {
-            Ok(_) => "Successfully deleted thought".to_string(),
+            Ok(_) => "Moved thought to the trash".to_string(),
             Err(err) => format!("Unable delete thought: {err}"),
         }
     }
 }
 
+impl Present<thought::batch::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: thought::batch::Result) -> Self::ViewModel {
+        match result {
+            Ok(resp) => {
+                let (mut changed, mut unchanged) = (0, 0);
+                let mut failures = vec![];
+                for report in resp.reports {
+                    match report.outcome {
+                        batch_uc::Outcome::Changed => changed += 1,
+                        batch_uc::Outcome::Unchanged => unchanged += 1,
+                        batch_uc::Outcome::Failed(err) => {
+                            failures.push(format!("- {}: {err}", report.id));
+                        }
+                    }
+                }
+                let mut lines = vec![format!(
+                    "Changed {changed}, skipped {unchanged} and failed to change {} thoughts",
+                    failures.len()
+                )];
+                lines.extend(failures);
+                lines.join("\n")
+            }
+            Err(err) => format!("Unable to change thoughts: {err}"),
+        }
+    }
+}
+
 impl Present<area_of_life::create::Result> for Presenter {
     type ViewModel = String;
     fn present(&self, result: area_of_life::create::Result) -> Self::ViewModel {
@@ -75,7 +168,7 @@ impl Present<area_of_life::update::Result> for Presenter {
     type ViewModel = String;
     fn present(&self, result: area_of_life::update::Result) -> Self::ViewModel {
         match result {
-            Ok(()) => "Updated area of life".to_string(),
+            Ok(_) => "Updated area of life".to_string(),
             Err(err) => format!("Undable to update area of life: {err}"),
         }
     }
@@ -84,24 +177,396 @@ impl Present<area_of_life::update::Result> for Presenter {
 impl Present<area_of_life::read_all::Result> for Presenter {
     type ViewModel = String;
     fn present(&self, result: area_of_life::read_all::Result) -> Self::ViewModel {
+        match result {
+            Ok(resp) => {
+                let ids = resp
+                    .areas_of_life
+                    .iter()
+                    .map(|aol| aol.id)
+                    .collect::<HashSet<_>>();
+                let mut children = HashMap::<_, Vec<_>>::new();
+                for aol in &resp.areas_of_life {
+                    // Areas with an unknown parent are shown at the top level
+                    let parent = aol.parent.filter(|id| ids.contains(id));
+                    children.entry(parent).or_default().push(aol);
+                }
+                let mut lines = vec![];
+                write_area_of_life_tree(&mut lines, &mut children, None, 0);
+                lines.join("\n")
+            }
+            Err(err) => format!("Unable read all areas of life: {err}"),
+        }
+    }
+}
+
+fn write_area_of_life_tree(
+    lines: &mut Vec<String>,
+    children: &mut HashMap<Option<aol::Id>, Vec<&area_of_life_uc::read_all::AreaOfLife>>,
+    parent: Option<aol::Id>,
+    depth: usize,
+) {
+    let mut direct_children = children.remove(&parent).unwrap_or_default();
+    direct_children.sort_by_key(|aol| aol.id);
+    for aol in direct_children {
+        let indent = "  ".repeat(depth);
+        lines.push(format!("{indent}- {} ({})", aol.name, aol.id));
+        write_area_of_life_tree(lines, children, Some(aol.id), depth + 1);
+    }
+}
+
+impl Present<area_of_life::find_by_id::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: area_of_life::find_by_id::Result) -> Self::ViewModel {
+        match result {
+            Ok(resp) => {
+                let aol = &resp.area_of_life;
+                let counts = &resp.thoughts;
+                format!(
+                    "{} ({})\n\n{} nested areas of life\n{} thoughts: {} inbox, {} active, {} done, {} archived",
+                    aol.name,
+                    aol.id,
+                    resp.children,
+                    counts.total,
+                    counts.inbox,
+                    counts.active,
+                    counts.done,
+                    counts.archived
+                )
+            }
+            Err(err) => format!("Unable find area of life: {err}"),
+        }
+    }
+}
+
+impl Present<area_of_life::thoughts::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: area_of_life::thoughts::Result) -> Self::ViewModel {
         match result {
             Ok(resp) => resp
-                .areas_of_life
+                .thoughts
                 .into_iter()
-                .map(|t| format!("- {} ({})", t.name, t.id))
+                .map(|t| format!("- {} ({})", t.title, t.id))
                 .collect::<Vec<_>>()
                 .join("\n"),
-            Err(err) => format!("Unable read all areas of life: {err}"),
+            Err(err) => format!("Unable read thoughts of area of life: {err}"),
+        }
+    }
+}
+
+impl Present<area_of_life::read_subtree::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: area_of_life::read_subtree::Result) -> Self::ViewModel {
+        match result {
+            Ok(resp) => {
+                let mut lines = vec![];
+                write_area_of_life_node(&mut lines, &resp.root, 0);
+                lines.join("\n")
+            }
+            Err(err) => format!("Unable read area of life: {err}"),
         }
     }
 }
 
+fn write_area_of_life_node(
+    lines: &mut Vec<String>,
+    node: &area_of_life_uc::read_subtree::Node,
+    depth: usize,
+) {
+    let indent = "  ".repeat(depth);
+    let aol = &node.area_of_life;
+    lines.push(format!("{indent}- {} ({})", aol.name, aol.id));
+    for child in &node.children {
+        write_area_of_life_node(lines, child, depth + 1);
+    }
+}
+
 impl Present<area_of_life::delete::Result> for Presenter {
     type ViewModel = String;
     fn present(&self, result: area_of_life::delete::Result) -> Self::ViewModel {
         match result {
-            Ok(_) => "Successfully deleted area of life".to_string(),
+            Ok(data) => format!(
+                "Moved area of life to the trash and changed {} thoughts",
+                data.thoughts
+            ),
             Err(err) => format!("Unable delete aref of life: {err}"),
         }
     }
 }
+
+impl Present<area_of_life::merge::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: area_of_life::merge::Result) -> Self::ViewModel {
+        match result {
+            Ok(data) => format!("Merged area of life and moved {} thoughts", data.thoughts),
+            Err(err) => format!("Unable to merge area of life: {err}"),
+        }
+    }
+}
+
+impl Present<tag::create::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: tag::create::Result) -> Self::ViewModel {
+        match result {
+            Ok(data) => format!("Created a new tag (ID = {})", data.id),
+            Err(err) => format!("Undable to create a new tag: {err}"),
+        }
+    }
+}
+
+impl Present<tag::update::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: tag::update::Result) -> Self::ViewModel {
+        match result {
+            Ok(()) => "Updated tag".to_string(),
+            Err(err) => format!("Undable to update tag: {err}"),
+        }
+    }
+}
+
+impl Present<tag::read_all::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: tag::read_all::Result) -> Self::ViewModel {
+        match result {
+            Ok(resp) => resp
+                .tags
+                .into_iter()
+                .map(|t| format!("- {} ({})", t.name, t.id))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Err(err) => format!("Unable read all tags: {err}"),
+        }
+    }
+}
+
+impl Present<tag::delete::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: tag::delete::Result) -> Self::ViewModel {
+        match result {
+            Ok(_) => "Successfully deleted tag".to_string(),
+            Err(err) => format!("Unable delete tag: {err}"),
+        }
+    }
+}
+
+impl Present<relation::link::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: relation::link::Result) -> Self::ViewModel {
+        match result {
+            Ok(()) => "Linked thoughts".to_string(),
+            Err(err) => format!("Unable to link thoughts: {err}"),
+        }
+    }
+}
+
+impl Present<relation::unlink::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: relation::unlink::Result) -> Self::ViewModel {
+        match result {
+            Ok(()) => "Unlinked thoughts".to_string(),
+            Err(err) => format!("Unable to unlink thoughts: {err}"),
+        }
+    }
+}
+
+impl Present<relation::read_links::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: relation::read_links::Result) -> Self::ViewModel {
+        match result {
+            Ok(resp) => resp
+                .outgoing
+                .into_iter()
+                .map(|l| format!("-> {} ({})", l.thought, l.kind))
+                .chain(
+                    resp.backlinks
+                        .into_iter()
+                        .map(|l| format!("<- {} ({})", l.thought, l.kind)),
+                )
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Err(err) => format!("Unable to read links: {err}"),
+        }
+    }
+}
+
+impl Present<revision::read_all::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: revision::read_all::Result) -> Self::ViewModel {
+        match result {
+            Ok(resp) => resp
+                .revisions
+                .into_iter()
+                .map(|r| format!("{}: {} ({})", r.version, r.title, r.recorded_at))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Err(err) => format!("Unable to read revisions: {err}"),
+        }
+    }
+}
+
+impl Present<revision::diff::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: revision::diff::Result) -> Self::ViewModel {
+        use cawr_application::usecase::revision::diff::Change as C;
+        fn opt<T: std::fmt::Debug>(value: Option<T>) -> String {
+            value.map_or_else(|| "-".to_string(), |v| format!("{v:?}"))
+        }
+        match result {
+            Ok(resp) if resp.changes.is_empty() => "No changes".to_string(),
+            Ok(resp) => resp
+                .changes
+                .into_iter()
+                .map(|change| match change {
+                    C::Title { old, new } => format!("title: {old:?} -> {new:?}"),
+                    C::Body { old, new } => format!("body: {} -> {}", opt(old), opt(new)),
+                    C::AreasOfLife { added, removed } => {
+                        format!("areas of life: +{added:?} -{removed:?}")
+                    }
+                    C::Tags { added, removed } => format!("tags: +{added:?} -{removed:?}"),
+                    C::Status { old, new } => format!("status: {old} -> {new}"),
+                    C::DueAt { old, new } => format!("due at: {} -> {}", opt(old), opt(new)),
+                    C::ReminderOffset { old, new } => {
+                        format!("reminder offset: {} -> {}", opt(old), opt(new))
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Err(err) => format!("Unable to compare revisions: {err}"),
+        }
+    }
+}
+
+impl Present<revision::revert::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: revision::revert::Result) -> Self::ViewModel {
+        match result {
+            Ok(resp) => format!("Reverted thought (new revision = {})", resp.version),
+            Err(err) => format!("Unable to revert thought: {err}"),
+        }
+    }
+}
+
+impl Present<trash::read_all::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: trash::read_all::Result) -> Self::ViewModel {
+        match result {
+            Ok(resp) if resp.entries.is_empty() => "The trash is empty".to_string(),
+            Ok(resp) => resp
+                .entries
+                .into_iter()
+                .map(|e| match trash::Item::from(e.item) {
+                    trash::Item::Thought(id) => format!("- thought {} ({id})", e.label),
+                    trash::Item::AreaOfLife(id) => format!("- area of life {} ({id})", e.label),
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Err(err) => format!("Unable to read the trash: {err}"),
+        }
+    }
+}
+
+impl Present<trash::restore::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: trash::restore::Result) -> Self::ViewModel {
+        match result {
+            Ok(_) => "Restored from the trash".to_string(),
+            Err(err) => format!("Unable to restore from the trash: {err}"),
+        }
+    }
+}
+
+impl Present<trash::purge::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: trash::purge::Result) -> Self::ViewModel {
+        match result {
+            Ok(resp) => format!("Purged {} entries from the trash", resp.purged.len()),
+            Err(err) => format!("Unable to purge the trash: {err}"),
+        }
+    }
+}
+
+impl Present<integrity::check::Result> for Presenter {
+    type ViewModel = String;
+    fn present(&self, result: integrity::check::Result) -> Self::ViewModel {
+        match result {
+            Ok(resp) if resp.findings.is_empty() => "No problems found".to_string(),
+            Ok(resp) => {
+                let mut lines = resp
+                    .findings
+                    .iter()
+                    .map(|f| {
+                        let hint = if f.is_repairable() {
+                            ""
+                        } else {
+                            " (fix manually)"
+                        };
+                        format!("- {}{hint}", describe_finding(f))
+                    })
+                    .collect::<Vec<_>>();
+                lines.push(format!(
+                    "Found {} problems and repaired {}",
+                    resp.findings.len(),
+                    resp.repaired
+                ));
+                lines.join("\n")
+            }
+            Err(err) => format!("Unable to check integrity: {err}"),
+        }
+    }
+}
+
+fn describe_finding(finding: &Finding) -> String {
+    match finding {
+        Finding::Storage(issue) => describe_storage_issue(issue),
+        Finding::DanglingAreaOfLife {
+            thought,
+            area_of_life,
+        } => format!("thought {thought} references missing area of life {area_of_life}"),
+        Finding::DanglingTag { thought, tag } => {
+            format!("thought {thought} references missing tag {tag}")
+        }
+        Finding::DanglingParent {
+            area_of_life,
+            parent,
+        } => format!("area of life {area_of_life} is nested below missing area of life {parent}"),
+        Finding::InvalidThought {
+            thought,
+            invalidity,
+        } => format!("thought {thought} is invalid: {invalidity}"),
+        Finding::InvalidAreaOfLife {
+            area_of_life,
+            invalidity,
+        } => format!("area of life {area_of_life} is invalid: {invalidity}"),
+        Finding::InvalidTag { tag, invalidity } => format!("tag {tag} is invalid: {invalidity}"),
+    }
+}
+
+fn describe_storage_issue(issue: &StorageIssue) -> String {
+    let entity = |entity: &Entity| match entity {
+        Entity::Thought => "thought",
+        Entity::AreaOfLife => "area of life",
+        Entity::Tag => "tag",
+    };
+    // All kinds of IDs are displayed the same way.
+    let id = |id: &u128| Id::from_u128(*id);
+    match issue {
+        StorageIssue::OrphanedId { entity: e, id: i } => {
+            format!("{} ID {} points to a missing record", entity(e), id(i))
+        }
+        StorageIssue::MissingId { entity: e, id: i } => {
+            format!("{} {} can't be found by its ID", entity(e), id(i))
+        }
+        StorageIssue::DuplicateId {
+            entity: e,
+            id: i,
+            count,
+        } => format!("{} ID {} is used by {count} records", entity(e), id(i)),
+        StorageIssue::IdCounterDrift {
+            entity: e,
+            counter,
+            max,
+        } => format!(
+            "{} ID counter {counter} is behind the highest ID {max}",
+            entity(e)
+        ),
+    }
+}
@@ -12,7 +12,7 @@ mod thought {
     use super::{to_json, Error, Present, Presenter, Response, Result, StatusCode};
     use crate::model::{
         app::thought as app,
-        view::json::{area_of_life::AreaOfLifeId, thought as view},
+        view::json::{area_of_life::AreaOfLifeId, tag::TagId, thought as view},
     };
 
     // -- Create -- //
@@ -33,6 +33,11 @@ mod thought {
                             status: StatusCode::BAD_REQUEST,
                             details: Some(view::create::Error::AreaOfLifeId),
                         },
+                        E::TagId => Error {
+                            msg: Some(err.to_string()),
+                            status: StatusCode::BAD_REQUEST,
+                            details: Some(view::create::Error::TagId),
+                        },
                         E::Invalidity(invalidity) => Error {
                             msg: Some(invalidity.to_string()),
                             status: StatusCode::BAD_REQUEST,
@@ -47,6 +52,13 @@ mod thought {
                                 ids.clone().into_iter().map(AreaOfLifeId::from).collect(),
                             )),
                         },
+                        E::TagsNotFound(ref ids) => Error {
+                            msg: Some(err.to_string()),
+                            status: StatusCode::BAD_REQUEST,
+                            details: Some(view::create::Error::TagsNotFound(
+                                ids.clone().into_iter().map(TagId::from).collect(),
+                            )),
+                        },
                         E::Repo | E::NewId => Error::internal(),
                     }
                 })
@@ -56,10 +68,12 @@ mod thought {
     // -- Update -- //
 
     impl Present<app::update::Result> for Presenter {
-        type ViewModel = Result<(), view::update::Error>;
+        type ViewModel = Result<view::update::Response, view::update::Error>;
         fn present(&self, res: app::update::Result) -> Self::ViewModel {
-            res.map(|()| Response {
-                data: None,
+            res.map(|res| Response {
+                data: Some(view::update::Response {
+                    version: res.version.to_u64(),
+                }),
                 status: StatusCode::OK,
             })
             .map_err(|err| {
@@ -80,6 +94,11 @@ mod thought {
                         status: StatusCode::BAD_REQUEST,
                         details: Some(view::update::Error::AreaOfLifeId),
                     },
+                    E::TagId => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::update::Error::TagId),
+                    },
                     E::Invalidity(invalidity) => Error {
                         msg: Some(invalidity.to_string()),
                         status: StatusCode::BAD_REQUEST,
@@ -94,6 +113,18 @@ mod thought {
                             ids.clone().into_iter().map(AreaOfLifeId::from).collect(),
                         )),
                     },
+                    E::TagsNotFound(ref ids) => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::update::Error::TagsNotFound(
+                            ids.clone().into_iter().map(TagId::from).collect(),
+                        )),
+                    },
+                    E::Conflict => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::PRECONDITION_FAILED,
+                        details: Some(view::update::Error::Conflict),
+                    },
                     E::Repo => Error::internal(),
                 }
             })
@@ -129,8 +160,36 @@ mod thought {
     // -- Read all -- //
 
     impl Present<app::read_all::Result> for Presenter {
-        type ViewModel = Result<Vec<view::Thought>, view::read_all::Error>;
+        type ViewModel = Result<view::read_all::Response, view::read_all::Error>;
         fn present(&self, res: app::read_all::Result) -> Self::ViewModel {
+            res.map(to_json::thought::read_all::from_response)
+                .map(|data| Response {
+                    data: Some(data),
+                    status: StatusCode::OK,
+                })
+                .map_err(|err| {
+                    use app::read_all::Error as E;
+                    let details = match err {
+                        E::Status => view::read_all::Error::Status,
+                        E::AreaOfLife => view::read_all::Error::AreaOfLife,
+                        E::Sort => view::read_all::Error::Sort,
+                        E::Direction => view::read_all::Error::Direction,
+                        E::Repo => return Error::internal(),
+                    };
+                    Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(details),
+                    }
+                })
+        }
+    }
+
+    // -- Search -- //
+
+    impl Present<app::search::Result> for Presenter {
+        type ViewModel = Result<Vec<view::Thought>, view::search::Error>;
+        fn present(&self, res: app::search::Result) -> Self::ViewModel {
             res.map(|resp| {
                 resp.thoughts
                     .into_iter()
@@ -142,7 +201,104 @@ mod thought {
                 status: StatusCode::OK,
             })
             .map_err(|err| match err {
-                app::read_all::Error::Repo => Error::internal(),
+                app::search::Error::EmptyQuery => Error {
+                    msg: Some(err.to_string()),
+                    status: StatusCode::BAD_REQUEST,
+                    details: Some(view::search::Error::EmptyQuery),
+                },
+                app::search::Error::Index | app::search::Error::Repo => Error::internal(),
+            })
+        }
+    }
+
+    // -- Batch -- //
+
+    impl Present<app::batch::Result> for Presenter {
+        type ViewModel = Result<view::batch::Response, view::batch::Error>;
+        fn present(&self, res: app::batch::Result) -> Self::ViewModel {
+            res.map(to_json::thought::batch::from_response)
+                .map(|data| Response {
+                    data: Some(data),
+                    status: StatusCode::OK,
+                })
+                .map_err(|err| {
+                    use app::batch::Error as E;
+                    let details = match err {
+                        E::Id => view::batch::Error::Id,
+                        E::AreaOfLifeId => view::batch::Error::AreaOfLifeId,
+                        E::EmptyFind => view::batch::Error::EmptyFind,
+                        E::AreaOfLifeNotFound(id) => {
+                            view::batch::Error::AreaOfLifeNotFound(id.into())
+                        }
+                        E::Repo => return Error::internal(),
+                    };
+                    Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(details),
+                    }
+                })
+        }
+    }
+
+    // -- Due -- //
+
+    impl Present<app::due::Result> for Presenter {
+        type ViewModel = Result<view::Agenda, view::due::Error>;
+        fn present(&self, res: app::due::Result) -> Self::ViewModel {
+            res.map(to_json::thought::due::from_response)
+                .map(|data| Response {
+                    data: Some(data),
+                    status: StatusCode::OK,
+                })
+                .map_err(|err| match err {
+                    app::due::Error::Repo => Error::internal(),
+                })
+        }
+    }
+
+    // -- Change status -- //
+
+    impl Present<app::change_status::Result> for Presenter {
+        type ViewModel = Result<(), view::change_status::Error>;
+        fn present(&self, res: app::change_status::Result) -> Self::ViewModel {
+            res.map(|()| Response {
+                data: None,
+                status: StatusCode::OK,
+            })
+            .map_err(|err| {
+                use app::change_status::Error as E;
+                match err {
+                    E::Id => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::change_status::Error::Id),
+                    },
+                    E::Status => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::change_status::Error::Status),
+                    },
+                    E::NotFound(id) => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::NOT_FOUND,
+                        details: Some(view::change_status::Error::NotFound(id.into())),
+                    },
+                    E::InvalidTransition(t) => Error {
+                        msg: Some(t.to_string()),
+                        status: StatusCode::CONFLICT,
+                        details: Some(view::change_status::Error::InvalidTransition {
+                            from: to_json::thought::status_to_json(t.from),
+                            to: to_json::thought::status_to_json(t.to),
+                        }),
+                    },
+                    E::Conflict => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::CONFLICT,
+                        details: Some(view::change_status::Error::Conflict),
+                    },
+                    E::Repo => Error::internal(),
+                }
             })
         }
     }
@@ -167,6 +323,11 @@ mod thought {
                     status: StatusCode::NOT_FOUND,
                     details: Some(view::delete::Error::NotFound),
                 },
+                app::delete::Error::Conflict => Error {
+                    msg: Some(err.to_string()),
+                    status: StatusCode::CONFLICT,
+                    details: Some(view::delete::Error::Conflict),
+                },
                 app::delete::Error::Repo => Error::internal(),
             })
         }
@@ -183,6 +344,319 @@ mod area_of_life {
         type ViewModel = Result<view::AreaOfLifeId, view::create::Error>;
         fn present(&self, res: app::create::Result) -> Self::ViewModel {
             res.map(to_json::area_of_life::create::from_response)
+                .map(|id| Response {
+                    data: Some(id),
+                    status: StatusCode::CREATED,
+                })
+                .map_err(|err| {
+                    use app::create::Error as E;
+                    match err {
+                        E::ParentId => Error {
+                            msg: Some(err.to_string()),
+                            status: StatusCode::BAD_REQUEST,
+                            details: Some(view::create::Error::ParentId),
+                        },
+                        E::Invalidity(invalidity) => Error {
+                            msg: Some(invalidity.to_string()),
+                            status: StatusCode::BAD_REQUEST,
+                            details: Some(
+                                to_json::area_of_life::create::from_area_of_life_invalidity(
+                                    invalidity,
+                                ),
+                            ),
+                        },
+                        E::ParentNotFound(id) => Error {
+                            msg: Some(err.to_string()),
+                            status: StatusCode::BAD_REQUEST,
+                            details: Some(view::create::Error::ParentNotFound(id.into())),
+                        },
+                        E::Cycle(id) => Error {
+                            msg: Some(err.to_string()),
+                            status: StatusCode::CONFLICT,
+                            details: Some(view::create::Error::Cycle(id.into())),
+                        },
+                        E::NameAlreadyExists(ref name) => Error {
+                            msg: Some(err.to_string()),
+                            status: StatusCode::CONFLICT,
+                            details: Some(view::create::Error::NameAlreadyExists(name.clone())),
+                        },
+                        E::Repo | E::NewId => Error::internal(),
+                    }
+                })
+        }
+    }
+
+    // -- Update -- //
+
+    impl Present<app::update::Result> for Presenter {
+        type ViewModel = Result<view::update::Response, view::update::Error>;
+        fn present(&self, res: app::update::Result) -> Self::ViewModel {
+            res.map(|res| Response {
+                data: Some(view::update::Response {
+                    version: res.version.to_u64(),
+                }),
+                status: StatusCode::OK,
+            })
+            .map_err(|err| {
+                use app::update::Error as E;
+                match err {
+                    E::Id => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::update::Error::Id),
+                    },
+                    E::ParentId => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::update::Error::ParentId),
+                    },
+                    E::NotFound(_) => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::NOT_FOUND,
+                        details: Some(view::update::Error::NotFound),
+                    },
+                    E::ParentNotFound(id) => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::update::Error::ParentNotFound(id.into())),
+                    },
+                    E::Cycle(id) => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::CONFLICT,
+                        details: Some(view::update::Error::Cycle(id.into())),
+                    },
+                    E::NameAlreadyExists(ref name) => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::CONFLICT,
+                        details: Some(view::update::Error::NameAlreadyExists(name.clone())),
+                    },
+                    E::Invalidity(invalidity) => Error {
+                        msg: Some(invalidity.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(to_json::area_of_life::update::from_area_of_life_invalidity(
+                            invalidity,
+                        )),
+                    },
+                    E::Conflict => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::PRECONDITION_FAILED,
+                        details: Some(view::update::Error::Conflict),
+                    },
+                    E::Repo => Error::internal(),
+                }
+            })
+        }
+    }
+
+    // -- Read all -- //
+
+    impl Present<app::read_all::Result> for Presenter {
+        type ViewModel = Result<view::read_all::Response, view::read_all::Error>;
+        fn present(&self, res: app::read_all::Result) -> Self::ViewModel {
+            res.map(to_json::area_of_life::read_all::from_response)
+                .map(|data| Response {
+                    data: Some(data),
+                    status: StatusCode::OK,
+                })
+                .map_err(|err| {
+                    use app::read_all::Error as E;
+                    let details = match err {
+                        E::Sort => view::read_all::Error::Sort,
+                        E::Direction => view::read_all::Error::Direction,
+                        E::Repo => return Error::internal(),
+                    };
+                    Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(details),
+                    }
+                })
+        }
+    }
+
+    // -- Find by ID -- //
+
+    impl Present<app::find_by_id::Result> for Presenter {
+        type ViewModel = Result<view::find_by_id::Response, view::find_by_id::Error>;
+        fn present(&self, res: app::find_by_id::Result) -> Self::ViewModel {
+            res.map(to_json::area_of_life::find_by_id::from_response)
+                .map(|data| Response {
+                    data: Some(data),
+                    status: StatusCode::OK,
+                })
+                .map_err(|err| match err {
+                    app::find_by_id::Error::Id => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::find_by_id::Error::Id),
+                    },
+                    app::find_by_id::Error::NotFound(_) => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::NOT_FOUND,
+                        details: Some(view::find_by_id::Error::NotFound),
+                    },
+                    app::find_by_id::Error::Repo => Error::internal(),
+                })
+        }
+    }
+
+    // -- Read thoughts -- //
+
+    impl Present<app::thoughts::Result> for Presenter {
+        type ViewModel = Result<view::thoughts::Response, view::thoughts::Error>;
+        fn present(&self, res: app::thoughts::Result) -> Self::ViewModel {
+            res.map(|resp| view::thoughts::Response {
+                thoughts: resp
+                    .thoughts
+                    .into_iter()
+                    .map(to_json::thought::read_all::from_thought)
+                    .collect(),
+            })
+            .map(|data| Response {
+                data: Some(data),
+                status: StatusCode::OK,
+            })
+            .map_err(|err| {
+                use app::thoughts::Error as E;
+                let (status, details) = match err {
+                    E::Id => (StatusCode::BAD_REQUEST, view::thoughts::Error::Id),
+                    E::Sort => (StatusCode::BAD_REQUEST, view::thoughts::Error::Sort),
+                    E::Direction => (StatusCode::BAD_REQUEST, view::thoughts::Error::Direction),
+                    E::NotFound(_) => (StatusCode::NOT_FOUND, view::thoughts::Error::NotFound),
+                    E::Repo => return Error::internal(),
+                };
+                Error {
+                    msg: Some(err.to_string()),
+                    status,
+                    details: Some(details),
+                }
+            })
+        }
+    }
+
+    // -- Read subtree -- //
+
+    impl Present<app::read_subtree::Result> for Presenter {
+        type ViewModel = Result<view::AreaOfLifeTree, view::read_subtree::Error>;
+        fn present(&self, res: app::read_subtree::Result) -> Self::ViewModel {
+            res.map(|resp| to_json::area_of_life::read_subtree::from_node(resp.root))
+                .map(|data| Response {
+                    data: Some(data),
+                    status: StatusCode::OK,
+                })
+                .map_err(|err| match err {
+                    app::read_subtree::Error::Id => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::read_subtree::Error::Id),
+                    },
+                    app::read_subtree::Error::NotFound(_) => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::NOT_FOUND,
+                        details: Some(view::read_subtree::Error::NotFound),
+                    },
+                    app::read_subtree::Error::Repo => Error::internal(),
+                })
+        }
+    }
+
+    // -- Delete by ID -- //
+
+    impl Present<app::delete::Result> for Presenter {
+        type ViewModel = Result<view::delete::Response, view::delete::Error>;
+        fn present(&self, res: app::delete::Result) -> Self::ViewModel {
+            res.map(|res| Response {
+                data: Some(view::delete::Response {
+                    thoughts: res.thoughts,
+                }),
+                status: StatusCode::OK,
+            })
+            .map_err(|err| match err {
+                app::delete::Error::Id => Error {
+                    msg: Some(err.to_string()),
+                    status: StatusCode::BAD_REQUEST,
+                    details: Some(view::delete::Error::Id),
+                },
+                app::delete::Error::TargetId => Error {
+                    msg: Some(err.to_string()),
+                    status: StatusCode::BAD_REQUEST,
+                    details: Some(view::delete::Error::TargetId),
+                },
+                app::delete::Error::NotFound => Error {
+                    msg: Some("Could not find area of life".to_string()),
+                    status: StatusCode::NOT_FOUND,
+                    details: Some(view::delete::Error::NotFound),
+                },
+                app::delete::Error::Referenced(count) => Error {
+                    msg: Some(err.to_string()),
+                    status: StatusCode::CONFLICT,
+                    details: Some(view::delete::Error::Referenced(count)),
+                },
+                app::delete::Error::TargetNotFound(id) => Error {
+                    msg: Some(err.to_string()),
+                    status: StatusCode::BAD_REQUEST,
+                    details: Some(view::delete::Error::TargetNotFound(id.into())),
+                },
+                app::delete::Error::TargetDeleted(id) => Error {
+                    msg: Some(err.to_string()),
+                    status: StatusCode::BAD_REQUEST,
+                    details: Some(view::delete::Error::TargetDeleted(id.into())),
+                },
+                app::delete::Error::Conflict => Error {
+                    msg: Some(err.to_string()),
+                    status: StatusCode::CONFLICT,
+                    details: Some(view::delete::Error::Conflict),
+                },
+                app::delete::Error::Repo => Error::internal(),
+            })
+        }
+    }
+
+    // -- Merge -- //
+
+    impl Present<app::merge::Result> for Presenter {
+        type ViewModel = Result<view::merge::Response, view::merge::Error>;
+        fn present(&self, res: app::merge::Result) -> Self::ViewModel {
+            res.map(|res| Response {
+                data: Some(view::merge::Response {
+                    thoughts: res.thoughts,
+                }),
+                status: StatusCode::OK,
+            })
+            .map_err(|err| {
+                use app::merge::Error as E;
+                let (status, details) = match err {
+                    E::Id => (StatusCode::BAD_REQUEST, view::merge::Error::Id),
+                    E::TargetId => (StatusCode::BAD_REQUEST, view::merge::Error::TargetId),
+                    E::NotFound(id) => (
+                        StatusCode::NOT_FOUND,
+                        view::merge::Error::NotFound(id.into()),
+                    ),
+                    E::Same(_) => (StatusCode::BAD_REQUEST, view::merge::Error::Same),
+                    E::Cycle(id) => (StatusCode::CONFLICT, view::merge::Error::Cycle(id.into())),
+                    E::Conflict => (StatusCode::CONFLICT, view::merge::Error::Conflict),
+                    E::Repo => return Error::internal(),
+                };
+                Error {
+                    msg: Some(err.to_string()),
+                    status,
+                    details: Some(details),
+                }
+            })
+        }
+    }
+}
+
+mod tag {
+    use super::{to_json, Error, Present, Presenter, Response, Result, StatusCode};
+    use crate::model::{app::tag as app, view::json::tag as view};
+
+    // -- Create -- //
+
+    impl Present<app::create::Result> for Presenter {
+        type ViewModel = Result<view::TagId, view::create::Error>;
+        fn present(&self, res: app::create::Result) -> Self::ViewModel {
+            res.map(to_json::tag::create::from_response)
                 .map(|id| Response {
                     data: Some(id),
                     status: StatusCode::CREATED,
@@ -193,7 +667,7 @@ mod area_of_life {
                         E::Invalidity(invalidity) => Error {
                             msg: Some(invalidity.to_string()),
                             status: StatusCode::BAD_REQUEST,
-                            details: to_json::area_of_life::create::try_from_error(err).ok(),
+                            details: to_json::tag::create::try_from_error(err).ok(),
                         },
                         E::Repo | E::NewId => Error::internal(),
                     }
@@ -226,9 +700,7 @@ mod area_of_life {
                     E::Invalidity(invalidity) => Error {
                         msg: Some(invalidity.to_string()),
                         status: StatusCode::BAD_REQUEST,
-                        details: Some(to_json::area_of_life::update::from_area_of_life_invalidity(
-                            invalidity,
-                        )),
+                        details: Some(to_json::tag::update::from_tag_invalidity(invalidity)),
                     },
                     E::Repo => Error::internal(),
                 }
@@ -239,12 +711,12 @@ mod area_of_life {
     // -- Read all -- //
 
     impl Present<app::read_all::Result> for Presenter {
-        type ViewModel = Result<Vec<view::AreaOfLife>, view::read_all::Error>;
+        type ViewModel = Result<Vec<view::Tag>, view::read_all::Error>;
         fn present(&self, res: app::read_all::Result) -> Self::ViewModel {
             res.map(|resp| {
-                resp.areas_of_life
+                resp.tags
                     .into_iter()
-                    .map(to_json::area_of_life::read_all::from_area_of_life)
+                    .map(to_json::tag::read_all::from_tag)
                     .collect()
             })
             .map(|data| Response {
@@ -273,12 +745,314 @@ mod area_of_life {
                     details: Some(view::delete::Error::Id),
                 },
                 app::delete::Error::NotFound => Error {
-                    msg: Some("Could not find area of life".to_string()),
+                    msg: Some("Could not find tag".to_string()),
                     status: StatusCode::NOT_FOUND,
                     details: Some(view::delete::Error::NotFound),
                 },
+                app::delete::Error::Conflict => Error {
+                    msg: Some(err.to_string()),
+                    status: StatusCode::CONFLICT,
+                    details: Some(view::delete::Error::Conflict),
+                },
                 app::delete::Error::Repo => Error::internal(),
             })
         }
     }
 }
+
+mod relation {
+    use super::{to_json, Error, Present, Presenter, Response, Result, StatusCode};
+    use crate::model::{app::relation as app, view::json::relation as view};
+
+    // -- Link -- //
+
+    impl Present<app::link::Result> for Presenter {
+        type ViewModel = Result<(), view::link::Error>;
+        fn present(&self, res: app::link::Result) -> Self::ViewModel {
+            res.map(|()| Response {
+                data: None,
+                status: StatusCode::CREATED,
+            })
+            .map_err(|err| {
+                use app::link::Error as E;
+                match err {
+                    E::Id => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::link::Error::Id),
+                    },
+                    E::Kind => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::link::Error::Kind),
+                    },
+                    E::ThoughtNotFound(id) => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::NOT_FOUND,
+                        details: Some(view::link::Error::ThoughtNotFound(id.into())),
+                    },
+                    E::SelfLink => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::link::Error::SelfLink),
+                    },
+                    E::Repo => Error::internal(),
+                }
+            })
+        }
+    }
+
+    // -- Unlink -- //
+
+    impl Present<app::unlink::Result> for Presenter {
+        type ViewModel = Result<(), view::unlink::Error>;
+        fn present(&self, res: app::unlink::Result) -> Self::ViewModel {
+            res.map(|()| Response {
+                data: None,
+                status: StatusCode::OK,
+            })
+            .map_err(|err| {
+                use app::unlink::Error as E;
+                match err {
+                    E::Id => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::unlink::Error::Id),
+                    },
+                    E::Kind => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::unlink::Error::Kind),
+                    },
+                    E::NotFound => Error {
+                        msg: Some("Could not find link".to_string()),
+                        status: StatusCode::NOT_FOUND,
+                        details: Some(view::unlink::Error::NotFound),
+                    },
+                    E::Repo => Error::internal(),
+                }
+            })
+        }
+    }
+
+    // -- Read links -- //
+
+    impl Present<app::read_links::Result> for Presenter {
+        type ViewModel = Result<view::ThoughtLinks, view::read_links::Error>;
+        fn present(&self, res: app::read_links::Result) -> Self::ViewModel {
+            res.map(to_json::relation::from_response)
+                .map(|data| Response {
+                    data: Some(data),
+                    status: StatusCode::OK,
+                })
+                .map_err(|err| match err {
+                    app::read_links::Error::Id => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::read_links::Error::Id),
+                    },
+                    app::read_links::Error::NotFound => Error {
+                        msg: Some("Could not find thought".to_string()),
+                        status: StatusCode::NOT_FOUND,
+                        details: Some(view::read_links::Error::NotFound),
+                    },
+                    app::read_links::Error::Repo => Error::internal(),
+                })
+        }
+    }
+}
+
+mod revision {
+    use super::{to_json, Error, Present, Presenter, Response, Result, StatusCode};
+    use crate::model::{app::revision as app, view::json::revision as view};
+
+    // -- Read all -- //
+
+    impl Present<app::read_all::Result> for Presenter {
+        type ViewModel = Result<Vec<view::ThoughtRevision>, view::read_all::Error>;
+        fn present(&self, res: app::read_all::Result) -> Self::ViewModel {
+            res.map(|resp| {
+                resp.revisions
+                    .into_iter()
+                    .map(to_json::revision::from_revision)
+                    .collect()
+            })
+            .map(|data| Response {
+                data: Some(data),
+                status: StatusCode::OK,
+            })
+            .map_err(|err| match err {
+                app::read_all::Error::Id => Error {
+                    msg: Some(err.to_string()),
+                    status: StatusCode::BAD_REQUEST,
+                    details: Some(view::read_all::Error::Id),
+                },
+                app::read_all::Error::NotFound => Error {
+                    msg: Some("Could not find thought".to_string()),
+                    status: StatusCode::NOT_FOUND,
+                    details: Some(view::read_all::Error::NotFound),
+                },
+                app::read_all::Error::Repo => Error::internal(),
+            })
+        }
+    }
+
+    // -- Diff -- //
+
+    impl Present<app::diff::Result> for Presenter {
+        type ViewModel = Result<Vec<view::ThoughtChange>, view::diff::Error>;
+        fn present(&self, res: app::diff::Result) -> Self::ViewModel {
+            res.map(|resp| {
+                resp.changes
+                    .into_iter()
+                    .map(to_json::revision::from_change)
+                    .collect()
+            })
+            .map(|data| Response {
+                data: Some(data),
+                status: StatusCode::OK,
+            })
+            .map_err(|err| {
+                use app::diff::Error as E;
+                match err {
+                    E::Id => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::diff::Error::Id),
+                    },
+                    E::ThoughtNotFound => Error {
+                        msg: Some("Could not find thought".to_string()),
+                        status: StatusCode::NOT_FOUND,
+                        details: Some(view::diff::Error::NotFound),
+                    },
+                    E::RevisionNotFound(version) => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::NOT_FOUND,
+                        details: Some(view::diff::Error::RevisionNotFound(version)),
+                    },
+                    E::Repo => Error::internal(),
+                }
+            })
+        }
+    }
+
+    // -- Revert -- //
+
+    impl Present<app::revert::Result> for Presenter {
+        type ViewModel = Result<view::revert::Response, view::revert::Error>;
+        fn present(&self, res: app::revert::Result) -> Self::ViewModel {
+            res.map(|res| Response {
+                data: Some(view::revert::Response {
+                    version: res.version.to_u64(),
+                }),
+                status: StatusCode::OK,
+            })
+            .map_err(|err| {
+                use app::revert::Error as E;
+                match err {
+                    E::Id => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::revert::Error::Id),
+                    },
+                    E::ThoughtNotFound => Error {
+                        msg: Some("Could not find thought".to_string()),
+                        status: StatusCode::NOT_FOUND,
+                        details: Some(view::revert::Error::NotFound),
+                    },
+                    E::RevisionNotFound(version) => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::NOT_FOUND,
+                        details: Some(view::revert::Error::RevisionNotFound(version)),
+                    },
+                    E::Conflict => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::CONFLICT,
+                        details: Some(view::revert::Error::Conflict),
+                    },
+                    E::Repo => Error::internal(),
+                }
+            })
+        }
+    }
+}
+
+mod trash {
+    use super::{to_json, Error, Present, Presenter, Response, Result, StatusCode};
+    use crate::model::{app::trash as app, view::json::trash as view};
+
+    // -- Read all -- //
+
+    impl Present<app::read_all::Result> for Presenter {
+        type ViewModel = Result<Vec<view::TrashEntry>, view::read_all::Error>;
+        fn present(&self, res: app::read_all::Result) -> Self::ViewModel {
+            res.map(|resp| {
+                resp.entries
+                    .into_iter()
+                    .map(to_json::trash::from_entry)
+                    .collect()
+            })
+            .map(|data| Response {
+                data: Some(data),
+                status: StatusCode::OK,
+            })
+            .map_err(|err| match err {
+                app::read_all::Error::Repo => Error::internal(),
+            })
+        }
+    }
+
+    // -- Restore -- //
+
+    impl Present<app::restore::Result> for Presenter {
+        type ViewModel = Result<(), view::restore::Error>;
+        fn present(&self, res: app::restore::Result) -> Self::ViewModel {
+            res.map(|_| Response {
+                data: None,
+                status: StatusCode::OK,
+            })
+            .map_err(|err| {
+                use app::restore::Error as E;
+                match err {
+                    E::ThoughtId | E::AreaOfLifeId => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::BAD_REQUEST,
+                        details: Some(view::restore::Error::Id),
+                    },
+                    E::NotFound(_) => Error {
+                        msg: Some("Could not find entry in the trash".to_string()),
+                        status: StatusCode::NOT_FOUND,
+                        details: Some(view::restore::Error::NotFound),
+                    },
+                    E::Conflict => Error {
+                        msg: Some(err.to_string()),
+                        status: StatusCode::CONFLICT,
+                        details: Some(view::restore::Error::Conflict),
+                    },
+                    E::Repo => Error::internal(),
+                }
+            })
+        }
+    }
+
+    // -- Purge -- //
+
+    impl Present<app::purge::Result> for Presenter {
+        type ViewModel = Result<Vec<view::TrashItem>, view::purge::Error>;
+        fn present(&self, res: app::purge::Result) -> Self::ViewModel {
+            res.map(|resp| {
+                resp.purged
+                    .into_iter()
+                    .map(to_json::trash::item_to_json)
+                    .collect()
+            })
+            .map(|data| Response {
+                data: Some(data),
+                status: StatusCode::OK,
+            })
+            .map_err(|err| match err {
+                app::purge::Error::Repo => Error::internal(),
+            })
+        }
+    }
+}
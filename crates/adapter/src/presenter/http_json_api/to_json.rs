@@ -2,18 +2,42 @@ use crate::model::{app, view::json};
 
 impl From<app::area_of_life::Id> for json::area_of_life::AreaOfLifeId {
     fn from(from: app::area_of_life::Id) -> Self {
-        from.to_u64().into()
+        from.to_u128().into()
+    }
+}
+
+impl From<app::tag::Id> for json::tag::TagId {
+    fn from(from: app::tag::Id) -> Self {
+        from.to_u128().into()
     }
 }
 
 impl From<app::thought::Id> for json::thought::ThoughtId {
     fn from(from: app::thought::Id) -> Self {
-        from.to_u64().into()
+        from.to_u128().into()
     }
 }
 
 pub(crate) mod thought {
+    use crate::model::view::json::thought::ThoughtStatus;
+    use cawr_domain::thought::Status;
+    use std::time::Duration;
+
+    pub const fn status_to_json(from: Status) -> ThoughtStatus {
+        match from {
+            Status::Inbox => ThoughtStatus::Inbox,
+            Status::Active => ThoughtStatus::Active,
+            Status::Done => ThoughtStatus::Done,
+            Status::Archived => ThoughtStatus::Archived,
+        }
+    }
+
+    pub fn duration_to_json(from: Duration) -> u64 {
+        u64::try_from(from.as_millis()).unwrap_or(u64::MAX)
+    }
+
     pub mod create {
+        use super::duration_to_json;
         use crate::model::{
             app::thought::create::Response, view::json::thought::create::Error,
             view::json::thought::ThoughtId,
@@ -21,72 +45,237 @@ pub(crate) mod thought {
         use cawr_application::usecase::thought::validate::{self, ThoughtInvalidity};
 
         pub fn thought_id_from_response(res: Response) -> ThoughtId {
-            res.id.to_u64().into()
+            res.id.to_u128().into()
         }
 
-        pub const fn from_thought_invalidity(from: ThoughtInvalidity) -> Error {
-            let ThoughtInvalidity::Title(e) = from;
-            use validate::TitleInvalidity as T;
-            match e {
-                T::MinLength { min, actual } => Error::TitleMinLength { min, actual },
-                T::MaxLength { max, actual } => Error::TitleMaxLength { max, actual },
+        pub fn from_thought_invalidity(from: ThoughtInvalidity) -> Error {
+            use validate::{BodyInvalidity as B, DueInvalidity as D, TitleInvalidity as T};
+            match from {
+                ThoughtInvalidity::Title(e) => match e {
+                    T::MinLength { min, actual } => Error::TitleMinLength { min, actual },
+                    T::MaxLength { max, actual } => Error::TitleMaxLength { max, actual },
+                    T::ControlCharacter { position } => Error::TitleControlCharacter { position },
+                },
+                ThoughtInvalidity::Body(e) => match e {
+                    B::MaxLength { max, actual } => Error::BodyMaxLength { max, actual },
+                    B::ControlCharacter { position } => Error::BodyControlCharacter { position },
+                },
+                ThoughtInvalidity::Due(e) => match e {
+                    D::ReminderWithoutDueDate => Error::ReminderWithoutDueDate,
+                    D::ReminderMaxOffset { max, actual } => Error::ReminderMaxOffset {
+                        max: duration_to_json(max),
+                        actual: duration_to_json(actual),
+                    },
+                },
             }
         }
     }
     pub mod update {
+        use super::duration_to_json;
         use crate::model::view::json::thought::update::Error;
         use cawr_application::usecase::thought::validate::{self, ThoughtInvalidity};
 
-        pub const fn from_thought_invalidity(from: ThoughtInvalidity) -> Error {
-            let ThoughtInvalidity::Title(e) = from;
-            use validate::TitleInvalidity as T;
-            match e {
-                T::MinLength { min, actual } => Error::TitleMinLength { min, actual },
-                T::MaxLength { max, actual } => Error::TitleMaxLength { max, actual },
+        pub fn from_thought_invalidity(from: ThoughtInvalidity) -> Error {
+            use validate::{BodyInvalidity as B, DueInvalidity as D, TitleInvalidity as T};
+            match from {
+                ThoughtInvalidity::Title(e) => match e {
+                    T::MinLength { min, actual } => Error::TitleMinLength { min, actual },
+                    T::MaxLength { max, actual } => Error::TitleMaxLength { max, actual },
+                    T::ControlCharacter { position } => Error::TitleControlCharacter { position },
+                },
+                ThoughtInvalidity::Body(e) => match e {
+                    B::MaxLength { max, actual } => Error::BodyMaxLength { max, actual },
+                    B::ControlCharacter { position } => Error::BodyControlCharacter { position },
+                },
+                ThoughtInvalidity::Due(e) => match e {
+                    D::ReminderWithoutDueDate => Error::ReminderWithoutDueDate,
+                    D::ReminderMaxOffset { max, actual } => Error::ReminderMaxOffset {
+                        max: duration_to_json(max),
+                        actual: duration_to_json(actual),
+                    },
+                },
+            }
+        }
+    }
+    pub mod batch {
+        use super::duration_to_json;
+        use crate::model::{
+            app::thought::batch::Response,
+            view::json::thought::batch::{ItemError, Outcome, Report, Response as Reports},
+        };
+        use cawr_application::usecase::thought::{
+            batch as uc,
+            validate::{self, ThoughtInvalidity},
+        };
+
+        pub fn from_response(from: Response) -> Reports {
+            let reports = from
+                .reports
+                .into_iter()
+                .map(|report| {
+                    let id = report.id.to_u128().into();
+                    match report.outcome {
+                        uc::Outcome::Changed => Report {
+                            id,
+                            outcome: Outcome::Changed,
+                            error: None,
+                        },
+                        uc::Outcome::Unchanged => Report {
+                            id,
+                            outcome: Outcome::Unchanged,
+                            error: None,
+                        },
+                        uc::Outcome::Failed(err) => Report {
+                            id,
+                            outcome: Outcome::Failed,
+                            error: Some(from_item_error(err)),
+                        },
+                    }
+                })
+                .collect();
+            Reports { reports }
+        }
+
+        fn from_item_error(from: uc::ItemError) -> ItemError {
+            match from {
+                uc::ItemError::NotFound => ItemError::NotFound,
+                uc::ItemError::Invalidity(invalidity) => from_thought_invalidity(invalidity),
+                uc::ItemError::Conflict => ItemError::Conflict,
+                uc::ItemError::Repo => ItemError::Internal,
+            }
+        }
+
+        fn from_thought_invalidity(from: ThoughtInvalidity) -> ItemError {
+            use validate::{BodyInvalidity as B, DueInvalidity as D, TitleInvalidity as T};
+            match from {
+                ThoughtInvalidity::Title(e) => match e {
+                    T::MinLength { min, actual } => ItemError::TitleMinLength { min, actual },
+                    T::MaxLength { max, actual } => ItemError::TitleMaxLength { max, actual },
+                    T::ControlCharacter { position } => {
+                        ItemError::TitleControlCharacter { position }
+                    }
+                },
+                ThoughtInvalidity::Body(e) => match e {
+                    B::MaxLength { max, actual } => ItemError::BodyMaxLength { max, actual },
+                    B::ControlCharacter { position } => {
+                        ItemError::BodyControlCharacter { position }
+                    }
+                },
+                ThoughtInvalidity::Due(e) => match e {
+                    D::ReminderWithoutDueDate => ItemError::ReminderWithoutDueDate,
+                    D::ReminderMaxOffset { max, actual } => ItemError::ReminderMaxOffset {
+                        max: duration_to_json(max),
+                        actual: duration_to_json(actual),
+                    },
+                },
             }
         }
     }
     pub mod read_all {
-        use crate::model::view::json::thought::Thought;
+        use super::{duration_to_json, status_to_json};
+        use crate::model::view::json::thought::{read_all::Response, Thought};
         use cawr_application::usecase::thought::read_all as uc;
+        use cawr_domain::Timestamp;
+
+        pub fn from_response(from: uc::Response) -> Response {
+            let uc::Response {
+                thoughts,
+                total,
+                next_offset,
+            } = from;
+            Response {
+                thoughts: thoughts.into_iter().map(from_thought).collect(),
+                total: total as u64,
+                next_offset: next_offset.map(|offset| offset as u64),
+            }
+        }
 
         pub fn from_thought(from: uc::Thought) -> Thought {
             let uc::Thought {
                 id,
                 title,
+                body,
                 areas_of_life,
+                tags,
+                status,
+                due_at,
+                reminder_offset,
+                created_at,
+                updated_at,
+                version,
             } = from;
-            let id = id.to_u64().into();
+            let id = id.to_u128().into();
             let areas_of_life = areas_of_life
                 .into_iter()
-                .map(|id| id.to_u64().into())
+                .map(|id| id.to_u128().into())
                 .collect();
+            let tags = tags.into_iter().map(|id| id.to_u128().into()).collect();
             Thought {
                 id,
                 title,
+                body,
                 areas_of_life,
+                tags,
+                status: status_to_json(status),
+                due_at: due_at.map(Timestamp::to_unix_millis),
+                reminder_offset: reminder_offset.map(duration_to_json),
+                created_at: created_at.to_unix_millis(),
+                updated_at: updated_at.to_unix_millis(),
+                version: version.to_u64(),
+            }
+        }
+    }
+    pub mod due {
+        use super::read_all::from_thought;
+        use crate::model::view::json::thought::Agenda;
+        use cawr_application::usecase::thought::due as uc;
+
+        pub fn from_response(from: uc::Response) -> Agenda {
+            let uc::Response { overdue, upcoming } = from;
+            Agenda {
+                overdue: overdue.into_iter().map(from_thought).collect(),
+                upcoming: upcoming.into_iter().map(from_thought).collect(),
             }
         }
     }
     pub mod find_by_id {
+        use super::{duration_to_json, status_to_json};
         use crate::model::view::json::thought::Thought;
         use cawr_application::usecase::thought::find_by_id as uc;
+        use cawr_domain::Timestamp;
 
         pub fn from_response(from: uc::Response) -> Thought {
             let uc::Response {
                 id,
                 title,
+                body,
                 areas_of_life,
+                tags,
+                status,
+                due_at,
+                reminder_offset,
+                created_at,
+                updated_at,
+                version,
             } = from;
-            let id = id.to_u64().into();
+            let id = id.to_u128().into();
             let areas_of_life = areas_of_life
                 .into_iter()
-                .map(|id| id.to_u64().into())
+                .map(|id| id.to_u128().into())
                 .collect();
+            let tags = tags.into_iter().map(|id| id.to_u128().into()).collect();
             Thought {
                 id,
                 title,
+                body,
                 areas_of_life,
+                tags,
+                status: status_to_json(status),
+                due_at: due_at.map(Timestamp::to_unix_millis),
+                reminder_offset: reminder_offset.map(duration_to_json),
+                created_at: created_at.to_unix_millis(),
+                updated_at: updated_at.to_unix_millis(),
+                version: version.to_u64(),
             }
         }
     }
@@ -95,49 +284,282 @@ pub(crate) mod thought {
 pub(crate) mod area_of_life {
     pub mod create {
         use crate::model::view::json::area_of_life::{create::Error, AreaOfLifeId};
-        use cawr_application::usecase::area_of_life::{create as uc, validate};
+        use cawr_application::usecase::area_of_life::{
+            create as uc,
+            validate::{AreaOfLifeInvalidity, NameInvalidity},
+        };
 
         pub fn from_response(from: uc::Response) -> AreaOfLifeId {
-            from.id.to_u64().into()
+            from.id.to_u128().into()
+        }
+
+        pub const fn from_area_of_life_invalidity(from: AreaOfLifeInvalidity) -> Error {
+            let AreaOfLifeInvalidity::Name(e) = from;
+            use NameInvalidity as T;
+            match e {
+                T::MinLength { min, actual } => Error::NameMinLength { min, actual },
+                T::MaxLength { max, actual } => Error::NameMaxLength { max, actual },
+                T::ControlCharacter { position } => Error::NameControlCharacter { position },
+            }
+        }
+    }
+    pub mod update {
+        use crate::model::view::json::area_of_life::update::Error;
+        use cawr_application::usecase::area_of_life::validate::{
+            AreaOfLifeInvalidity, NameInvalidity,
+        };
+
+        pub const fn from_area_of_life_invalidity(from: AreaOfLifeInvalidity) -> Error {
+            let AreaOfLifeInvalidity::Name(e) = from;
+            use NameInvalidity as T;
+            match e {
+                T::MinLength { min, actual } => Error::NameMinLength { min, actual },
+                T::MaxLength { max, actual } => Error::NameMaxLength { max, actual },
+                T::ControlCharacter { position } => Error::NameControlCharacter { position },
+            }
+        }
+    }
+    pub mod read_all {
+        use crate::model::view::json::area_of_life::{read_all::Response, AreaOfLife};
+        use cawr_application::usecase::area_of_life::read_all as uc;
+
+        pub fn from_response(from: uc::Response) -> Response {
+            let uc::Response {
+                areas_of_life,
+                total,
+                next_offset,
+            } = from;
+            Response {
+                areas_of_life: areas_of_life.into_iter().map(from_area_of_life).collect(),
+                total: total as u64,
+                next_offset: next_offset.map(|offset| offset as u64),
+            }
+        }
+
+        pub fn from_area_of_life(from: uc::AreaOfLife) -> AreaOfLife {
+            let uc::AreaOfLife {
+                id,
+                name,
+                parent,
+                created_at,
+                updated_at,
+                version,
+            } = from;
+            let id = id.to_u128().into();
+            let parent = parent.map(|id| id.to_u128().into());
+            AreaOfLife {
+                id,
+                name,
+                parent,
+                created_at: created_at.to_unix_millis(),
+                updated_at: updated_at.to_unix_millis(),
+                version: version.to_u64(),
+            }
+        }
+    }
+    pub mod find_by_id {
+        use super::read_all::from_area_of_life;
+        use crate::model::view::json::area_of_life::find_by_id::{Response, ThoughtCounts};
+        use cawr_application::usecase::area_of_life::find_by_id as uc;
+
+        pub fn from_response(from: uc::Response) -> Response {
+            let uc::Response {
+                area_of_life,
+                children,
+                thoughts,
+            } = from;
+            let uc::Counts {
+                total,
+                inbox,
+                active,
+                done,
+                archived,
+            } = thoughts;
+            Response {
+                area_of_life: from_area_of_life(area_of_life),
+                children,
+                thoughts: ThoughtCounts {
+                    total,
+                    inbox,
+                    active,
+                    done,
+                    archived,
+                },
+            }
+        }
+    }
+    pub mod read_subtree {
+        use super::read_all::from_area_of_life;
+        use crate::model::view::json::area_of_life::AreaOfLifeTree;
+        use cawr_application::usecase::area_of_life::read_subtree as uc;
+
+        pub fn from_node(from: uc::Node) -> AreaOfLifeTree {
+            let uc::Node {
+                area_of_life,
+                children,
+            } = from;
+            AreaOfLifeTree {
+                area_of_life: from_area_of_life(area_of_life),
+                children: children.into_iter().map(from_node).collect(),
+            }
+        }
+    }
+}
+
+pub(crate) mod tag {
+    pub mod create {
+        use crate::model::view::json::tag::{create::Error, TagId};
+        use cawr_application::usecase::tag::{create as uc, validate};
+
+        pub fn from_response(from: uc::Response) -> TagId {
+            from.id.to_u128().into()
         }
 
         pub const fn try_from_error(from: uc::Error) -> Result<Error, ()> {
             match from {
                 uc::Error::Repo | uc::Error::NewId => Err(()),
                 uc::Error::Invalidity(e) => {
-                    let validate::AreaOfLifeInvalidity::Name(e) = e;
+                    let validate::TagInvalidity::Name(e) = e;
                     use validate::NameInvalidity as T;
                     Ok(match e {
                         T::MinLength { min, actual } => Error::NameMinLength { min, actual },
                         T::MaxLength { max, actual } => Error::NameMaxLength { max, actual },
+                        T::ControlCharacter { position } => {
+                            Error::NameControlCharacter { position }
+                        }
                     })
                 }
             }
         }
     }
     pub mod update {
-        use crate::model::view::json::area_of_life::update::Error;
-        use cawr_application::usecase::area_of_life::validate::{
-            AreaOfLifeInvalidity, NameInvalidity,
-        };
+        use crate::model::view::json::tag::update::Error;
+        use cawr_application::usecase::tag::validate::{NameInvalidity, TagInvalidity};
 
-        pub const fn from_area_of_life_invalidity(from: AreaOfLifeInvalidity) -> Error {
-            let AreaOfLifeInvalidity::Name(e) = from;
+        pub const fn from_tag_invalidity(from: TagInvalidity) -> Error {
+            let TagInvalidity::Name(e) = from;
             use NameInvalidity as T;
             match e {
                 T::MinLength { min, actual } => Error::NameMinLength { min, actual },
                 T::MaxLength { max, actual } => Error::NameMaxLength { max, actual },
+                T::ControlCharacter { position } => Error::NameControlCharacter { position },
             }
         }
     }
     pub mod read_all {
-        use crate::model::view::json::area_of_life::AreaOfLife;
-        use cawr_application::usecase::area_of_life::read_all as uc;
+        use crate::model::view::json::tag::Tag;
+        use cawr_application::usecase::tag::read_all as uc;
 
-        pub fn from_area_of_life(from: uc::AreaOfLife) -> AreaOfLife {
-            let uc::AreaOfLife { id, name } = from;
-            let id = id.to_u64().into();
-            AreaOfLife { id, name }
+        pub fn from_tag(from: uc::Tag) -> Tag {
+            let uc::Tag {
+                id,
+                name,
+                created_at,
+                updated_at,
+            } = from;
+            let id = id.to_u128().into();
+            Tag {
+                id,
+                name,
+                created_at: created_at.to_unix_millis(),
+                updated_at: updated_at.to_unix_millis(),
+            }
+        }
+    }
+}
+
+pub(crate) mod relation {
+    use crate::model::view::json::relation::{RelationKind, ThoughtLink, ThoughtLinks};
+    use cawr_application::usecase::relation::read_links as uc;
+    use cawr_domain::relation::Kind;
+
+    pub const fn kind_to_json(from: Kind) -> RelationKind {
+        match from {
+            Kind::DependsOn => RelationKind::DependsOn,
+            Kind::RelatesTo => RelationKind::RelatesTo,
+            Kind::Supersedes => RelationKind::Supersedes,
+        }
+    }
+
+    fn from_link(from: uc::Link) -> ThoughtLink {
+        ThoughtLink {
+            kind: kind_to_json(from.kind),
+            thought: from.thought.to_u128().into(),
+        }
+    }
+
+    pub fn from_response(from: uc::Response) -> ThoughtLinks {
+        ThoughtLinks {
+            outgoing: from.outgoing.into_iter().map(from_link).collect(),
+            backlinks: from.backlinks.into_iter().map(from_link).collect(),
+        }
+    }
+}
+
+pub(crate) mod revision {
+    use super::thought::{duration_to_json, status_to_json};
+    use crate::model::view::json::revision::{ThoughtChange, ThoughtRevision};
+    use cawr_application::usecase::revision::{diff, read_all};
+    use cawr_domain::Timestamp;
+
+    pub fn from_revision(from: read_all::Revision) -> ThoughtRevision {
+        ThoughtRevision {
+            version: from.version.to_u64(),
+            title: from.title,
+            recorded_at: from.recorded_at.to_unix_millis(),
+        }
+    }
+
+    pub fn from_change(from: diff::Change) -> ThoughtChange {
+        use diff::Change as C;
+        match from {
+            C::Title { old, new } => ThoughtChange::Title { old, new },
+            C::Body { old, new } => ThoughtChange::Body { old, new },
+            C::AreasOfLife { added, removed } => ThoughtChange::AreasOfLife {
+                added: added.into_iter().map(|id| id.to_u128().into()).collect(),
+                removed: removed.into_iter().map(|id| id.to_u128().into()).collect(),
+            },
+            C::Tags { added, removed } => ThoughtChange::Tags {
+                added: added.into_iter().map(|id| id.to_u128().into()).collect(),
+                removed: removed.into_iter().map(|id| id.to_u128().into()).collect(),
+            },
+            C::Status { old, new } => ThoughtChange::Status {
+                old: status_to_json(old),
+                new: status_to_json(new),
+            },
+            C::DueAt { old, new } => ThoughtChange::DueAt {
+                old: old.map(Timestamp::to_unix_millis),
+                new: new.map(Timestamp::to_unix_millis),
+            },
+            C::ReminderOffset { old, new } => ThoughtChange::ReminderOffset {
+                old: old.map(duration_to_json),
+                new: new.map(duration_to_json),
+            },
+        }
+    }
+}
+
+pub(crate) mod trash {
+    use crate::model::view::json::trash::{TrashEntry, TrashItem};
+    use cawr_application::usecase::trash::{read_all as uc, Item};
+
+    pub fn item_to_json(from: Item) -> TrashItem {
+        match from {
+            Item::Thought(id) => TrashItem::Thought(id.to_u128().into()),
+            Item::AreaOfLife(id) => TrashItem::AreaOfLife(id.to_u128().into()),
+        }
+    }
+
+    pub fn from_entry(from: uc::Entry) -> TrashEntry {
+        let uc::Entry {
+            item,
+            label,
+            deleted_at,
+        } = from;
+        TrashEntry {
+            item: item_to_json(item),
+            label,
+            deleted_at: deleted_at.to_unix_millis(),
         }
     }
 }
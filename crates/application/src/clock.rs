@@ -0,0 +1,10 @@
+use cawr_domain::Timestamp;
+
+/// A service that tells the current time.
+// Interactors must not read the system time directly,
+// otherwise their results could not be reproduced in tests.
+// Like the ID generation (see `identifier::NewId`) the clock
+// is therefore injected e.g. into a specific usecase.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Timestamp;
+}
@@ -0,0 +1,10 @@
+use cawr_domain::Event;
+
+/// A service that forwards domain events to whoever is interested.
+// Like the clock (see `clock::Clock`) the publisher is injected
+// into the usecases that change the domain state.
+// Publishing can't fail: a subscriber must never be able
+// to undo a change that was already stored.
+pub trait EventPublisher: Send + Sync {
+    fn publish(&self, event: Event);
+}
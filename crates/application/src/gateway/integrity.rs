@@ -0,0 +1,55 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CheckError {
+    #[error("Storage connection problem")]
+    Connection,
+}
+
+#[derive(Debug, Error)]
+pub enum RepairError {
+    #[error("Storage connection problem")]
+    Connection,
+}
+
+/// The kind of entity that a [`StorageIssue`] belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Entity {
+    Thought,
+    AreaOfLife,
+    Tag,
+}
+
+/// A problem of the storage itself that is hidden behind the repositories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StorageIssue {
+    /// An ID points to a record that does not exist.
+    OrphanedId { entity: Entity, id: u128 },
+    /// A record can't be found by its ID.
+    MissingId { entity: Entity, id: u128 },
+    /// Several records are stored with the same ID.
+    DuplicateId {
+        entity: Entity,
+        id: u128,
+        count: usize,
+    },
+    /// The counter of sequential IDs is behind the highest stored ID,
+    /// so new records would reuse an existing ID.
+    IdCounterDrift {
+        entity: Entity,
+        counter: u64,
+        max: u64,
+    },
+}
+
+/// Low-level integrity checks of a storage backend.
+#[async_trait]
+pub trait Integrity: Send + Sync {
+    async fn check_storage(&self) -> Result<Vec<StorageIssue>, CheckError>;
+    /// Repair an issue that was reported by [`Integrity::check_storage`].
+    ///
+    /// Duplicates are resolved by keeping one of the records
+    /// and removing the others.
+    async fn repair_storage(&self, issue: StorageIssue) -> Result<(), RepairError>;
+}
@@ -1 +1,4 @@
+pub mod integrity;
 pub mod repository;
+pub mod search_index;
+pub mod unit_of_work;
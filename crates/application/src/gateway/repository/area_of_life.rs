@@ -1,4 +1,7 @@
+use super::query::{Page, Paginated, Sort};
+use async_trait::async_trait;
 use cawr_domain::area_of_life::{AreaOfLife, Id};
+use std::{borrow::Borrow, cmp::Ordering};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -13,6 +16,8 @@ pub enum GetError {
 pub enum SaveError {
     #[error("Area of life repository connection problem")]
     Connection,
+    #[error("Area of life was modified concurrently")]
+    Conflict,
 }
 
 #[derive(Debug, Error)]
@@ -34,10 +39,76 @@ pub struct Record {
     pub area_of_life: AreaOfLife,
 }
 
-// TODO: make it async
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    #[default]
+    Name,
+    CreatedAt,
+    UpdatedAt,
+}
+
+impl Sort<SortKey> {
+    /// Compare two areas of life, falling back to their IDs
+    /// to get a stable order.
+    #[must_use]
+    pub fn compare(&self, a: &AreaOfLife, b: &AreaOfLife) -> Ordering {
+        let ordering = match self.key {
+            SortKey::Name => a.name().normalized().cmp(&b.name().normalized()),
+            SortKey::CreatedAt => a.created_at().cmp(&b.created_at()),
+            SortKey::UpdatedAt => a.updated_at().cmp(&b.updated_at()),
+        };
+        self.directed(ordering.then_with(|| a.id().cmp(&b.id())))
+    }
+}
+
+/// Select areas of life that are not in the trash.
+#[derive(Debug, Default, Clone)]
+pub struct Query {
+    pub sort: Sort<SortKey>,
+    pub page: Page,
+}
+
+impl Query {
+    /// Answer the query from the given records.
+    pub fn apply<R, I>(&self, records: I) -> Paginated<R>
+    where
+        R: Borrow<Record>,
+        I: IntoIterator<Item = R>,
+    {
+        let matches = records
+            .into_iter()
+            .filter(|r| !r.borrow().area_of_life.is_deleted())
+            .collect();
+        Paginated::new(matches, self.page, |a, b| {
+            self.sort
+                .compare(&a.borrow().area_of_life, &b.borrow().area_of_life)
+        })
+    }
+}
+
+#[async_trait]
 pub trait Repo: Send + Sync {
-    fn save(&self, record: Record) -> Result<(), SaveError>;
-    fn get(&self, id: Id) -> Result<Record, GetError>;
-    fn get_all(&self) -> Result<Vec<Record>, GetAllError>;
-    fn delete(&self, id: Id) -> Result<(), DeleteError>;
+    /// Save an area of life.
+    ///
+    /// If the area of life already exists, the version of the record
+    /// must directly follow the stored version.
+    /// Otherwise it was modified in the meantime
+    /// and the record is rejected with [`SaveError::Conflict`].
+    async fn save(&self, record: Record) -> Result<(), SaveError>;
+    async fn get(&self, id: Id) -> Result<Record, GetError>;
+    /// Look up an area of life by its [normalized](cawr_domain::area_of_life::Name::normalize) name.
+    async fn get_by_normalized_name(&self, name: &str) -> Result<Record, GetError>;
+    async fn get_all(&self) -> Result<Vec<Record>, GetAllError>;
+    /// Get a sorted page of the areas of life.
+    ///
+    /// The default implementation loads all areas of life,
+    /// backends should override it if they can do better.
+    async fn query(&self, query: &Query) -> Result<Paginated<Record>, GetAllError> {
+        Ok(query.apply(self.get_all().await?))
+    }
+    /// Delete the record of an area of life.
+    ///
+    /// Thoughts that reference it are left untouched,
+    /// dealing with them is up to the usecases.
+    async fn delete(&self, id: Id) -> Result<(), DeleteError>;
 }
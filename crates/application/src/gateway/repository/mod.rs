@@ -1,2 +1,6 @@
 pub mod area_of_life;
+pub mod query;
+pub mod relation;
+pub mod revision;
+pub mod tag;
 pub mod thought;
@@ -0,0 +1,129 @@
+//! Building blocks of the queries the repositories answer.
+
+use std::cmp::Ordering;
+
+/// The order in which records are returned.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// Sort records by the key `K`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Sort<K> {
+    pub key: K,
+    pub direction: Direction,
+}
+
+impl<K> Sort<K> {
+    /// Apply the direction to an ascending ordering.
+    #[must_use]
+    pub const fn directed(&self, ordering: Ordering) -> Ordering {
+        match self.direction {
+            Direction::Asc => ordering,
+            Direction::Desc => ordering.reverse(),
+        }
+    }
+}
+
+/// A slice of the sorted records.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Page {
+    /// The number of records to skip.
+    pub offset: usize,
+    /// The maximum number of records to return (all if `None`).
+    pub limit: Option<usize>,
+}
+
+/// A page of records together with the number of all matching records.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    pub total: usize,
+    /// The offset of the following page if there are more records.
+    pub next_offset: Option<usize>,
+}
+
+impl<T> Paginated<T> {
+    /// Sort the matching `items` and cut out the requested `page`.
+    pub fn new<F>(mut items: Vec<T>, page: Page, compare: F) -> Self
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        items.sort_by(compare);
+        let total = items.len();
+        let end = page
+            .limit
+            .map_or(total, |limit| page.offset.saturating_add(limit).min(total));
+        let items = if page.offset < end {
+            items.drain(page.offset..end).collect()
+        } else {
+            vec![]
+        };
+        let next_offset = Some(end).filter(|end| *end < total);
+        Self {
+            items,
+            total,
+            next_offset,
+        }
+    }
+    pub fn map<U, F>(self, f: F) -> Paginated<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        let Self {
+            items,
+            total,
+            next_offset,
+        } = self;
+        Paginated {
+            items: items.into_iter().map(f).collect(),
+            total,
+            next_offset,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paginate(offset: usize, limit: Option<usize>) -> Paginated<u8> {
+        Paginated::new(vec![3, 1, 4, 2, 5], Page { offset, limit }, Ord::cmp)
+    }
+
+    #[test]
+    fn sort_and_slice() {
+        let page = paginate(0, None);
+        assert_eq!(page.items, vec![1, 2, 3, 4, 5]);
+        assert_eq!(page.total, 5);
+        assert_eq!(page.next_offset, None);
+
+        let page = paginate(1, Some(2));
+        assert_eq!(page.items, vec![2, 3]);
+        assert_eq!(page.next_offset, Some(3));
+
+        let page = paginate(3, Some(2));
+        assert_eq!(page.items, vec![4, 5]);
+        assert_eq!(page.next_offset, None);
+    }
+
+    #[test]
+    fn offset_beyond_the_end() {
+        let page = paginate(7, Some(2));
+        assert!(page.items.is_empty());
+        assert_eq!(page.total, 5);
+        assert_eq!(page.next_offset, None);
+    }
+
+    #[test]
+    fn reverse_direction() {
+        let sort = Sort {
+            key: (),
+            direction: Direction::Desc,
+        };
+        assert_eq!(sort.directed(1.cmp(&2)), Ordering::Greater);
+    }
+}
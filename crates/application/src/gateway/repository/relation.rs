@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use cawr_domain::{relation::Relation, thought};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SaveError {
+    #[error("Relation repository connection problem")]
+    Connection,
+}
+
+#[derive(Debug, Error)]
+pub enum GetAllError {
+    #[error("Relation repository connection problem")]
+    Connection,
+}
+
+#[derive(Debug, Error)]
+pub enum DeleteError {
+    #[error("Relation not found")]
+    NotFound,
+    #[error("Relation repository connection problem")]
+    Connection,
+}
+
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub relation: Relation,
+}
+
+#[async_trait]
+pub trait Repo: Send + Sync {
+    /// Save a relation (saving an existing relation again has no effect).
+    async fn save(&self, record: Record) -> Result<(), SaveError>;
+    /// Get all relations where the thought is either the source or the target.
+    async fn get_by_thought(&self, id: thought::Id) -> Result<Vec<Record>, GetAllError>;
+    async fn delete(&self, relation: &Relation) -> Result<(), DeleteError>;
+}
@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use cawr_domain::{thought, Thought, Timestamp, Version};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SaveError {
+    #[error("Revision repository connection problem")]
+    Connection,
+}
+
+#[derive(Debug, Error)]
+pub enum GetError {
+    #[error("Revision not found")]
+    NotFound,
+    #[error("Revision repository connection problem")]
+    Connection,
+}
+
+#[derive(Debug, Error)]
+pub enum GetAllError {
+    #[error("Revision repository connection problem")]
+    Connection,
+}
+
+#[derive(Debug, Error)]
+pub enum DeleteError {
+    #[error("Revision repository connection problem")]
+    Connection,
+}
+
+/// A snapshot of a thought as it was saved
+/// (identified by the thought ID and its [`Version`]).
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub thought: Thought,
+    pub recorded_at: Timestamp,
+}
+
+#[async_trait]
+pub trait Repo: Send + Sync {
+    /// Append a revision (saving the same version again replaces it).
+    async fn save(&self, record: Record) -> Result<(), SaveError>;
+    async fn get(&self, id: thought::Id, version: Version) -> Result<Record, GetError>;
+    /// Get all revisions of a thought, oldest first.
+    async fn get_by_thought(&self, id: thought::Id) -> Result<Vec<Record>, GetAllError>;
+    /// Delete the whole history of a thought.
+    async fn delete_by_thought(&self, id: thought::Id) -> Result<(), DeleteError>;
+}
@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use cawr_domain::tag::{Id, Tag};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum GetError {
+    #[error("Tag not found")]
+    NotFound,
+    #[error("Tag repository connection problem")]
+    Connection,
+}
+
+#[derive(Debug, Error)]
+pub enum SaveError {
+    #[error("Tag repository connection problem")]
+    Connection,
+}
+
+#[derive(Debug, Error)]
+pub enum GetAllError {
+    #[error("Tag repository connection problem")]
+    Connection,
+}
+
+#[derive(Debug, Error)]
+pub enum DeleteError {
+    #[error("Tag not found")]
+    NotFound,
+    #[error("Tag repository connection problem")]
+    Connection,
+}
+
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub tag: Tag,
+}
+
+#[async_trait]
+pub trait Repo: Send + Sync {
+    async fn save(&self, record: Record) -> Result<(), SaveError>;
+    async fn get(&self, id: Id) -> Result<Record, GetError>;
+    async fn get_all(&self) -> Result<Vec<Record>, GetAllError>;
+    /// Delete the tag only, references in thoughts are not touched.
+    async fn delete(&self, id: Id) -> Result<(), DeleteError>;
+}
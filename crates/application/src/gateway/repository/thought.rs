@@ -1,4 +1,10 @@
-use cawr_domain::thought::{Id, Thought};
+use super::query::{Page, Paginated, Sort};
+use async_trait::async_trait;
+use cawr_domain::{
+    area_of_life as aol,
+    thought::{Id, Status, Thought},
+};
+use std::{borrow::Borrow, cmp::Ordering};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -13,6 +19,8 @@ pub enum GetError {
 pub enum SaveError {
     #[error("Thought repository connection problem")]
     Connection,
+    #[error("Thought was modified concurrently")]
+    Conflict,
 }
 
 #[derive(Debug, Error)]
@@ -34,10 +42,110 @@ pub struct Record {
     pub thought: Thought,
 }
 
-// TODO: make it async
+/// Select thoughts that are not in the trash.
+#[derive(Debug, Default, Clone)]
+pub struct Filter {
+    pub status: Option<Status>,
+    pub area_of_life: Option<aol::Id>,
+}
+
+impl Filter {
+    #[must_use]
+    pub fn matches(&self, thought: &Thought) -> bool {
+        !thought.is_deleted()
+            && self.status.map_or(true, |s| thought.status() == s)
+            && self
+                .area_of_life
+                .map_or(true, |id| thought.areas_of_life().contains(&id))
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    #[default]
+    CreatedAt,
+    UpdatedAt,
+    Title,
+    /// Thoughts without a due date come last.
+    DueAt,
+}
+
+impl Sort<SortKey> {
+    /// Compare two thoughts, falling back to their IDs
+    /// to get a stable order.
+    #[must_use]
+    pub fn compare(&self, a: &Thought, b: &Thought) -> Ordering {
+        let ordering = match self.key {
+            SortKey::CreatedAt => self.directed(a.created_at().cmp(&b.created_at())),
+            SortKey::UpdatedAt => self.directed(a.updated_at().cmp(&b.updated_at())),
+            SortKey::Title => self.directed(
+                a.title()
+                    .as_ref()
+                    .to_lowercase()
+                    .cmp(&b.title().as_ref().to_lowercase()),
+            ),
+            SortKey::DueAt => match (a.due_at(), b.due_at()) {
+                (Some(a), Some(b)) => self.directed(a.cmp(&b)),
+                (a, b) => a.is_none().cmp(&b.is_none()),
+            },
+        };
+        ordering.then_with(|| self.directed(a.id().cmp(&b.id())))
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Query {
+    pub filter: Filter,
+    pub sort: Sort<SortKey>,
+    pub page: Page,
+}
+
+impl Query {
+    /// Answer the query from the given records.
+    pub fn apply<R, I>(&self, records: I) -> Paginated<R>
+    where
+        R: Borrow<Record>,
+        I: IntoIterator<Item = R>,
+    {
+        let matches = records
+            .into_iter()
+            .filter(|r| self.filter.matches(&r.borrow().thought))
+            .collect();
+        Paginated::new(matches, self.page, |a, b| {
+            self.sort.compare(&a.borrow().thought, &b.borrow().thought)
+        })
+    }
+}
+
+#[async_trait]
 pub trait Repo: Send + Sync {
-    fn save(&self, record: Record) -> Result<(), SaveError>;
-    fn get(&self, id: Id) -> Result<Record, GetError>;
-    fn get_all(&self) -> Result<Vec<Record>, GetAllError>;
-    fn delete(&self, id: Id) -> Result<(), DeleteError>;
+    /// Save a thought.
+    ///
+    /// If the thought already exists, the version of the record
+    /// must directly follow the stored version.
+    /// Otherwise it was modified in the meantime
+    /// and the record is rejected with [`SaveError::Conflict`].
+    async fn save(&self, record: Record) -> Result<(), SaveError>;
+    async fn get(&self, id: Id) -> Result<Record, GetError>;
+    async fn get_all(&self) -> Result<Vec<Record>, GetAllError>;
+    /// Get a sorted page of the thoughts that match the filter.
+    ///
+    /// The default implementation loads all thoughts,
+    /// backends should override it if they can do better.
+    async fn query(&self, query: &Query) -> Result<Paginated<Record>, GetAllError> {
+        Ok(query.apply(self.get_all().await?))
+    }
+    /// Get all thoughts that belong to an area of life,
+    /// including those in the trash.
+    ///
+    /// The default implementation loads all thoughts,
+    /// backends should override it if they can do better.
+    async fn get_by_area_of_life(&self, id: aol::Id) -> Result<Vec<Record>, GetAllError> {
+        let records = self.get_all().await?;
+        Ok(records
+            .into_iter()
+            .filter(|r| r.thought.areas_of_life().contains(&id))
+            .collect())
+    }
+    async fn delete(&self, id: Id) -> Result<(), DeleteError>;
 }
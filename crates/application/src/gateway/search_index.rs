@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use cawr_domain::thought::{Id, Thought};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum IndexError {
+    #[error("Search index connection problem")]
+    Connection,
+}
+
+#[derive(Debug, Error)]
+pub enum SearchError {
+    #[error("Search index connection problem")]
+    Connection,
+}
+
+/// A thought that matches a search query.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hit {
+    pub id: Id,
+    /// The relevance of the thought (higher is better).
+    pub score: f64,
+}
+
+/// A full-text index of the titles and bodies of thoughts.
+#[async_trait]
+pub trait SearchIndex: Send + Sync {
+    /// Add the thought to the index or replace its previous entry.
+    async fn index(&self, thought: &Thought) -> Result<(), IndexError>;
+    async fn remove(&self, id: Id) -> Result<(), IndexError>;
+    /// Find the thoughts that contain all terms of the query,
+    /// best matches first.
+    async fn search(&self, query: &str) -> Result<Vec<Hit>, SearchError>;
+}
@@ -0,0 +1,175 @@
+use crate::gateway::repository::{area_of_life, revision, thought};
+use async_trait::async_trait;
+use cawr_domain::{area_of_life as aol, relation::Relation, tag, thought::Id, Version};
+use std::collections::HashMap;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum CommitError {
+    #[error("Repository connection problem")]
+    Connection,
+    #[error("A record was modified concurrently")]
+    Conflict,
+}
+
+/// A single repository write.
+#[derive(Debug, Clone)]
+pub enum Change {
+    SaveThought(thought::Record),
+    DeleteThought(Id),
+    SaveAreaOfLife(area_of_life::Record),
+    /// Delete the area of life only, references in thoughts are not touched.
+    DeleteAreaOfLife(aol::Id),
+    /// Delete the tag only, references in thoughts are not touched.
+    DeleteTag(tag::Id),
+    SaveRevision(revision::Record),
+    /// Delete the whole history of a thought.
+    DeleteRevisions(Id),
+    DeleteRelation(Relation),
+}
+
+/// The writes of a unit of work in the order they were staged.
+#[derive(Debug, Default, Clone)]
+pub struct Changes(Vec<Change>);
+
+impl Changes {
+    pub fn push(&mut self, change: Change) {
+        self.0.push(change);
+    }
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn iter(&self) -> impl Iterator<Item = &Change> {
+        self.0.iter()
+    }
+    /// Check that every saved thought and area of life
+    /// directly follows the version it replaces.
+    ///
+    /// The stored versions are looked up with the given functions
+    /// (`None` if there is no such record), earlier changes take precedence.
+    pub fn check_versions<T, A>(
+        &self,
+        mut thought_version: T,
+        mut area_of_life_version: A,
+    ) -> Result<(), CommitError>
+    where
+        T: FnMut(Id) -> Result<Option<Version>, CommitError>,
+        A: FnMut(aol::Id) -> Result<Option<Version>, CommitError>,
+    {
+        let mut thoughts = HashMap::new();
+        let mut areas_of_life = HashMap::new();
+        for change in &self.0 {
+            match change {
+                Change::SaveThought(record) => {
+                    let id = record.thought.id();
+                    let current = match thoughts.get(&id) {
+                        Some(staged) => *staged,
+                        None => thought_version(id)?,
+                    };
+                    check_version(current, record.thought.version())?;
+                    thoughts.insert(id, Some(record.thought.version()));
+                }
+                Change::DeleteThought(id) => {
+                    thoughts.insert(*id, None);
+                }
+                Change::SaveAreaOfLife(record) => {
+                    let id = record.area_of_life.id();
+                    let current = match areas_of_life.get(&id) {
+                        Some(staged) => *staged,
+                        None => area_of_life_version(id)?,
+                    };
+                    check_version(current, record.area_of_life.version())?;
+                    areas_of_life.insert(id, Some(record.area_of_life.version()));
+                }
+                Change::DeleteAreaOfLife(id) => {
+                    areas_of_life.insert(*id, None);
+                }
+                Change::DeleteTag(_)
+                | Change::SaveRevision(_)
+                | Change::DeleteRevisions(_)
+                | Change::DeleteRelation(_) => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_version(current: Option<Version>, new: Version) -> Result<(), CommitError> {
+    match current {
+        Some(current) if current.next() != new => Err(CommitError::Conflict),
+        _ => Ok(()),
+    }
+}
+
+impl IntoIterator for Changes {
+    type Item = Change;
+    type IntoIter = std::vec::IntoIter<Change>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Apply several repository writes as a whole.
+#[async_trait]
+pub trait UnitOfWork: Send + Sync {
+    /// Apply all changes or none of them.
+    ///
+    /// Saving a record that already exists follows the rules of the repositories:
+    /// if its version does not directly follow the stored (or previously staged) one,
+    /// nothing is written and [`CommitError::Conflict`] is returned.
+    /// Deleting a record that does not exist is not an error.
+    async fn commit(&self, changes: Changes) -> Result<(), CommitError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cawr_domain::{
+        thought::{Thought, Title},
+        Timestamp,
+    };
+
+    fn save(id: u64, version: u64) -> Change {
+        let now = Timestamp::from_unix_millis(0);
+        let thought = Thought::new(
+            Id::new(id),
+            Title::new("foo".to_string()),
+            [].into(),
+            now,
+            now,
+        )
+        .with_version(Version::new(version));
+        Change::SaveThought(thought::Record { thought })
+    }
+
+    fn check(changes: Vec<Change>) -> Result<(), CommitError> {
+        let mut staged = Changes::default();
+        for change in changes {
+            staged.push(change);
+        }
+        staged.check_versions(
+            |id| Ok((id == Id::new(1)).then(|| Version::new(2))),
+            |_| Ok(None),
+        )
+    }
+
+    #[test]
+    fn follow_the_stored_version() {
+        assert!(check(vec![save(1, 3), save(2, 5)]).is_ok());
+        assert!(matches!(
+            check(vec![save(1, 2)]),
+            Err(CommitError::Conflict)
+        ));
+    }
+
+    #[test]
+    fn follow_previous_changes() {
+        assert!(check(vec![save(1, 3), save(1, 4)]).is_ok());
+        assert!(matches!(
+            check(vec![save(1, 3), save(1, 3)]),
+            Err(CommitError::Conflict)
+        ));
+        assert!(check(vec![Change::DeleteThought(Id::new(1)), save(1, 1)]).is_ok());
+    }
+}
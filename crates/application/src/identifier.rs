@@ -1,3 +1,4 @@
+use async_trait::async_trait;
 use thiserror::Error;
 
 /// A service that generates a new entity ID.
@@ -5,8 +6,9 @@ use thiserror::Error;
 // To do that we delegate the generation of a new ID to a separate
 // service that can be injected e.g. into a specific usecase.
 // See: https://matthiasnoback.nl/2018/05/when-and-where-to-determine-the-id-of-an-entity/
-pub trait NewId<Id> {
-    fn new_id(&self) -> Result<Id, NewIdError>;
+#[async_trait]
+pub trait NewId<Id>: Send + Sync {
+    async fn new_id(&self) -> Result<Id, NewIdError>;
 }
 
 #[derive(Debug, Error)]
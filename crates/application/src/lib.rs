@@ -1,3 +1,5 @@
+pub mod clock;
+pub mod event;
 pub mod gateway;
 pub mod identifier;
 pub mod usecase;
@@ -27,10 +27,10 @@ impl<'r, R> CheckAreasOfLifeExistence<'r, R>
 where
     R: Repo,
 {
-    pub fn exec(&self, req: Request) -> Result<(), Error> {
+    pub async fn exec(&self, req: Request<'_>) -> Result<(), Error> {
         let mut not_found = HashSet::new();
         for id in req {
-            match self.repo.get(*id) {
+            match super::get_untrashed(self.repo, *id).await {
                 Err(GetError::Connection) => {
                     return Err(Error::Repo);
                 }
@@ -0,0 +1,147 @@
+use crate::gateway::repository::area_of_life::{GetAllError, Repo};
+use cawr_domain::area_of_life::Id;
+use std::collections::{HashMap, HashSet};
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request {
+    /// The area of life that should be nested.
+    pub id: Id,
+    /// The new parent.
+    pub parent: Id,
+}
+
+/// Validate that an area of life can be nested below a parent
+/// without creating a cycle.
+pub struct CheckHierarchy<'r, R> {
+    repo: &'r R,
+}
+
+impl<'r, R> CheckHierarchy<'r, R> {
+    pub const fn new(repo: &'r R) -> Self {
+        Self { repo }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{}", GetAllError::Connection)]
+    Repo,
+    #[error("Parent area of life {0} not found")]
+    ParentNotFound(Id),
+    #[error("Area of life {0} can't be nested below one of its descendants")]
+    Cycle(Id),
+}
+
+impl From<GetAllError> for Error {
+    fn from(e: GetAllError) -> Self {
+        match e {
+            GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl<'r, R> CheckHierarchy<'r, R>
+where
+    R: Repo,
+{
+    pub async fn exec(&self, req: &Request) -> Result<(), Error> {
+        log::debug!("Check area of life hierarchy: {:?}", req);
+        if req.id == req.parent {
+            return Err(Error::Cycle(req.id));
+        }
+        let parents = self
+            .repo
+            .get_all()
+            .await?
+            .into_iter()
+            .filter(|r| !r.area_of_life.is_deleted())
+            .map(|r| (r.area_of_life.id(), r.area_of_life.parent()))
+            .collect::<HashMap<_, _>>();
+        if !parents.contains_key(&req.parent) {
+            return Err(Error::ParentNotFound(req.parent));
+        }
+        let mut visited = HashSet::new();
+        let mut current = Some(req.parent);
+        while let Some(id) = current {
+            if id == req.id {
+                return Err(Error::Cycle(req.id));
+            }
+            if !visited.insert(id) {
+                // The stored hierarchy is already broken
+                // but that is not caused by this request.
+                break;
+            }
+            current = parents.get(&id).copied().flatten();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gateway::repository::area_of_life::{DeleteError, GetError, Record, SaveError};
+    use async_trait::async_trait;
+    use cawr_domain::{
+        area_of_life::{AreaOfLife, Name},
+        Timestamp,
+    };
+
+    /// Career → Project X → Research
+    struct MockRepo;
+
+    #[async_trait]
+    impl Repo for MockRepo {
+        async fn save(&self, _: Record) -> Result<(), SaveError> {
+            todo!()
+        }
+        async fn get(&self, _: Id) -> Result<Record, GetError> {
+            todo!()
+        }
+        async fn get_by_normalized_name(&self, _: &str) -> Result<Record, GetError> {
+            todo!()
+        }
+        async fn get_all(&self) -> Result<Vec<Record>, GetAllError> {
+            let now = Timestamp::from_unix_millis(0);
+            let aol = |id, name: &str, parent: Option<u64>| Record {
+                area_of_life: AreaOfLife::new(Id::new(id), Name::new(name.to_string()), now, now)
+                    .with_parent(parent.map(Id::new)),
+            };
+            Ok(vec![
+                aol(1, "Career", None),
+                aol(2, "Project X", Some(1)),
+                aol(3, "Research", Some(2)),
+            ])
+        }
+        async fn delete(&self, _: Id) -> Result<(), DeleteError> {
+            todo!()
+        }
+    }
+
+    async fn check(id: u64, parent: u64) -> Result<(), Error> {
+        let req = Request {
+            id: Id::new(id),
+            parent: Id::new(parent),
+        };
+        CheckHierarchy::new(&MockRepo).exec(&req).await
+    }
+
+    #[tokio::test]
+    async fn allow_valid_nesting() {
+        assert!(check(4, 3).await.is_ok());
+        assert!(check(3, 1).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn reject_unknown_parent() {
+        assert!(matches!(check(3, 99).await, Err(Error::ParentNotFound(id)) if id == Id::new(99)));
+    }
+
+    #[tokio::test]
+    async fn reject_cycles() {
+        assert!(matches!(check(1, 1).await, Err(Error::Cycle(_))));
+        assert!(matches!(check(1, 3).await, Err(Error::Cycle(_))));
+        assert!(matches!(check(2, 3).await, Err(Error::Cycle(_))));
+    }
+}
@@ -0,0 +1,114 @@
+use crate::gateway::repository::area_of_life::{GetError, Repo};
+use cawr_domain::area_of_life::{Id, Name};
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    /// The (not yet normalized) name.
+    pub name: &'a str,
+    /// The area of life that may already own the name.
+    pub id: Option<Id>,
+}
+
+/// Validate that no other area of life has the same normalized name.
+///
+/// Areas of life in the trash keep their names
+/// so that they can be restored without a conflict.
+pub struct CheckUniqueName<'r, R> {
+    repo: &'r R,
+}
+
+impl<'r, R> CheckUniqueName<'r, R> {
+    pub const fn new(repo: &'r R) -> Self {
+        Self { repo }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{}", GetError::Connection)]
+    Repo,
+    #[error("An area of life named '{0}' already exists")]
+    NameAlreadyExists(String),
+}
+
+impl<'r, R> CheckUniqueName<'r, R>
+where
+    R: Repo,
+{
+    pub async fn exec(&self, req: &Request<'_>) -> Result<(), Error> {
+        log::debug!("Check area of life name uniqueness: {:?}", req);
+        match self
+            .repo
+            .get_by_normalized_name(&Name::normalize(req.name))
+            .await
+        {
+            Ok(record) if Some(record.area_of_life.id()) != req.id => Err(
+                Error::NameAlreadyExists(record.area_of_life.name().as_ref().to_string()),
+            ),
+            Ok(_) | Err(GetError::NotFound) => Ok(()),
+            Err(GetError::Connection) => Err(Error::Repo),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gateway::repository::area_of_life::{DeleteError, GetAllError, Record, SaveError};
+    use async_trait::async_trait;
+    use cawr_domain::{area_of_life::AreaOfLife, Timestamp};
+
+    struct MockRepo;
+
+    #[async_trait]
+    impl Repo for MockRepo {
+        async fn save(&self, _: Record) -> Result<(), SaveError> {
+            todo!()
+        }
+        async fn get(&self, _: Id) -> Result<Record, GetError> {
+            todo!()
+        }
+        async fn get_by_normalized_name(&self, name: &str) -> Result<Record, GetError> {
+            if name != "health" {
+                return Err(GetError::NotFound);
+            }
+            let now = Timestamp::from_unix_millis(0);
+            let name = Name::new("Health".to_string());
+            let area_of_life = AreaOfLife::new(Id::new(1), name, now, now);
+            Ok(Record { area_of_life })
+        }
+        async fn get_all(&self) -> Result<Vec<Record>, GetAllError> {
+            todo!()
+        }
+        async fn delete(&self, _: Id) -> Result<(), DeleteError> {
+            todo!()
+        }
+    }
+
+    async fn check(name: &str, id: Option<u64>) -> Result<(), Error> {
+        let req = Request {
+            name,
+            id: id.map(Id::new),
+        };
+        CheckUniqueName::new(&MockRepo).exec(&req).await
+    }
+
+    #[tokio::test]
+    async fn reject_names_that_only_differ_in_case() {
+        assert!(matches!(
+            check("health", None).await,
+            Err(Error::NameAlreadyExists(name)) if name == "Health"
+        ));
+        assert!(matches!(
+            check(" HEALTH ", Some(2)).await,
+            Err(Error::NameAlreadyExists(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn allow_renaming_the_owner() {
+        assert!(check("HEALTH", Some(1)).await.is_ok());
+        assert!(check("Family", None).await.is_ok());
+    }
+}
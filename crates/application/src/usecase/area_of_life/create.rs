@@ -1,17 +1,29 @@
 use crate::{
+    clock::Clock,
+    event::EventPublisher,
     gateway::repository::area_of_life::{Record, Repo, SaveError},
     identifier::{NewId, NewIdError},
-    usecase::area_of_life::validate::{
-        self, validate_area_of_life_properties, AreaOfLifeInvalidity,
+    usecase::{
+        area_of_life::{
+            check_hierarchy::{self, CheckHierarchy},
+            check_name::{self, CheckUniqueName},
+            validate::{self, validate_area_of_life_properties, AreaOfLifeInvalidity},
+        },
+        text::normalize_line,
     },
 };
-use cawr_domain::area_of_life::{AreaOfLife, Id, Name};
+use cawr_domain::{
+    area_of_life::{AreaOfLife, Id, Name},
+    Event,
+};
 use thiserror::Error;
 
 #[derive(Debug)]
 pub struct Request {
     /// The title of the new area of life.
     pub name: String,
+    /// An optional parent area of life.
+    pub parent: Option<Id>,
 }
 
 #[derive(Debug)]
@@ -21,14 +33,31 @@ pub struct Response {
 }
 
 /// Create area of life usecase interactor
-pub struct CreateAreaOfLife<'r, 'g, R, G> {
+pub struct CreateAreaOfLife<'r, 'g, 'c, 'e, R, G: ?Sized, C: ?Sized, E: ?Sized> {
     repo: &'r R,
     id_gen: &'g G,
+    clock: &'c C,
+    events: &'e E,
+    policy: validate::Policy,
 }
 
-impl<'r, 'g, R, G> CreateAreaOfLife<'r, 'g, R, G> {
-    pub const fn new(repo: &'r R, id_gen: &'g G) -> Self {
-        Self { repo, id_gen }
+impl<'r, 'g, 'c, 'e, R, G: ?Sized, C: ?Sized, E: ?Sized>
+    CreateAreaOfLife<'r, 'g, 'c, 'e, R, G, C, E>
+{
+    pub const fn new(repo: &'r R, id_gen: &'g G, clock: &'c C, events: &'e E) -> Self {
+        Self {
+            repo,
+            id_gen,
+            clock,
+            events,
+            policy: validate::Policy::DEFAULT,
+        }
+    }
+    /// Replace the default validation [`validate::Policy`].
+    #[must_use]
+    pub const fn with_policy(mut self, policy: validate::Policy) -> Self {
+        self.policy = policy;
+        self
     }
 }
 
@@ -40,33 +69,79 @@ pub enum Error {
     NewId,
     #[error(transparent)]
     Invalidity(#[from] AreaOfLifeInvalidity),
+    #[error("Parent area of life {0} not found")]
+    ParentNotFound(Id),
+    #[error("Area of life {0} can't be nested below one of its descendants")]
+    Cycle(Id),
+    #[error("An area of life named '{0}' already exists")]
+    NameAlreadyExists(String),
+}
+
+impl From<check_name::Error> for Error {
+    fn from(e: check_name::Error) -> Self {
+        use check_name::Error as E;
+        match e {
+            E::Repo => Self::Repo,
+            E::NameAlreadyExists(name) => Self::NameAlreadyExists(name),
+        }
+    }
+}
+
+impl From<check_hierarchy::Error> for Error {
+    fn from(e: check_hierarchy::Error) -> Self {
+        use check_hierarchy::Error as E;
+        match e {
+            E::Repo => Self::Repo,
+            E::ParentNotFound(id) => Self::ParentNotFound(id),
+            E::Cycle(id) => Self::Cycle(id),
+        }
+    }
 }
 
 impl From<SaveError> for Error {
     fn from(e: SaveError) -> Self {
         match e {
             SaveError::Connection => Self::Repo,
+            // A new ID can't be taken already
+            // unless the storage is broken.
+            SaveError::Conflict => Self::Repo,
         }
     }
 }
 
-impl<'r, 'g, R, G> CreateAreaOfLife<'r, 'g, R, G>
+impl<'r, 'g, 'c, 'e, R, G, C, E> CreateAreaOfLife<'r, 'g, 'c, 'e, R, G, C, E>
 where
     R: Repo,
-    G: NewId<Id>,
+    G: NewId<Id> + ?Sized,
+    C: Clock + ?Sized,
+    E: EventPublisher + ?Sized,
 {
     /// Create a new area of life with the given name.
-    pub fn exec(&self, req: Request) -> Result<Response, Error> {
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
         log::debug!("Create new area of life: {:?}", req);
-        validate_area_of_life_properties(&validate::Request { name: &req.name })?;
-        let name = Name::new(req.name);
-        let id = self.id_gen.new_id().map_err(|err| {
+        let name = normalize_line(&req.name);
+        validate_area_of_life_properties(&validate::Request { name: &name }, &self.policy)?;
+        CheckUniqueName::new(self.repo)
+            .exec(&check_name::Request {
+                name: &name,
+                id: None,
+            })
+            .await?;
+        let name = Name::new(name);
+        let id = self.id_gen.new_id().await.map_err(|err| {
             log::warn!("{}", err);
             Error::NewId
         })?;
-        let area_of_life = AreaOfLife::new(id, name);
+        if let Some(parent) = req.parent {
+            CheckHierarchy::new(self.repo)
+                .exec(&check_hierarchy::Request { id, parent })
+                .await?;
+        }
+        let now = self.clock.now();
+        let area_of_life = AreaOfLife::new(id, name, now, now).with_parent(req.parent);
         let record = Record { area_of_life };
-        self.repo.save(record)?;
+        self.repo.save(record).await?;
+        self.events.publish(Event::AreaOfLifeCreated(id));
         Ok(Response { id })
     }
 }
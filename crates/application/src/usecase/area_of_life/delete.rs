@@ -1,54 +1,278 @@
-use std::fmt::Debug;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+};
 
 use thiserror::Error;
 
-use cawr_domain::area_of_life::Id;
+use cawr_domain::{
+    area_of_life::{Deletion, Id},
+    thought, Event, Timestamp,
+};
 
-use crate::gateway::repository::area_of_life::{DeleteError, Repo};
+use crate::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{
+        repository::{
+            area_of_life::{GetAllError, Record, Repo, SaveError},
+            thought as thought_repo,
+        },
+        search_index::SearchIndex,
+        unit_of_work::{Change, Changes, CommitError, UnitOfWork},
+    },
+    usecase::thought::{commit_and_reindex, push_with_revision},
+};
 
 #[derive(Debug)]
 pub struct Request {
     pub id: Id,
+    /// What happens to nested areas of life.
+    pub children: Children,
+    /// What happens to thoughts that reference a deleted area of life.
+    pub references: References,
+}
+
+/// How to deal with the children of a deleted area of life
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Children {
+    /// Move the children up to the parent of the deleted area of life.
+    #[default]
+    Reparent,
+    /// Delete the whole subtree.
+    Cascade,
+}
+
+/// How to deal with thoughts that reference a deleted area of life
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum References {
+    /// Refuse to delete an area of life that is still referenced.
+    Restrict,
+    /// Remove the area of life from the thoughts.
+    #[default]
+    Detach,
+    /// Replace the area of life by another one.
+    Reassign(Id),
 }
 
 #[derive(Debug)]
-pub struct Response;
+pub struct Response {
+    /// The number of thoughts that were changed.
+    pub thoughts: usize,
+}
 
-/// Delete area of life by ID usecase interactor
-pub struct Delete<'r, R> {
+/// Move area of life to the trash usecase interactor
+///
+/// Unless deletion is restricted, the area of life is removed
+/// from all thoughts, but they get it back as soon as it is restored.
+pub struct Delete<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> {
     repo: &'r R,
+    clock: &'c C,
+    events: &'e E,
 }
 
-impl<'r, R> Delete<'r, R> {
-    pub const fn new(repo: &'r R) -> Self {
-        Self { repo }
+impl<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> Delete<'r, 'c, 'e, R, C, E> {
+    pub const fn new(repo: &'r R, clock: &'c C, events: &'e E) -> Self {
+        Self {
+            repo,
+            clock,
+            events,
+        }
     }
 }
 
 #[derive(Debug, Error)]
 pub enum Error {
-    #[error("{}", DeleteError::NotFound)]
+    #[error("Area of life not found")]
     NotFound,
-    #[error("{}", DeleteError::Connection)]
+    #[error("Area of life is still referenced by {0} thoughts")]
+    Referenced(usize),
+    #[error("Area of life {0} not found")]
+    TargetNotFound(Id),
+    #[error("Area of life {0} is deleted as well")]
+    TargetDeleted(Id),
+    #[error("{}", GetAllError::Connection)]
     Repo,
+    #[error("{}", SaveError::Conflict)]
+    Conflict,
 }
 
-impl From<DeleteError> for Error {
-    fn from(e: DeleteError) -> Self {
+impl From<GetAllError> for Error {
+    fn from(e: GetAllError) -> Self {
         match e {
-            DeleteError::NotFound => Self::NotFound,
-            DeleteError::Connection => Self::Repo,
+            GetAllError::Connection => Self::Repo,
         }
     }
 }
 
-impl<'r, R> Delete<'r, R>
+impl From<CommitError> for Error {
+    fn from(e: CommitError) -> Self {
+        match e {
+            CommitError::Connection => Self::Repo,
+            CommitError::Conflict => Self::Conflict,
+        }
+    }
+}
+
+impl From<thought_repo::GetAllError> for Error {
+    fn from(e: thought_repo::GetAllError) -> Self {
+        match e {
+            thought_repo::GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl<'r, 'c, 'e, R, C, E> Delete<'r, 'c, 'e, R, C, E>
 where
-    R: Repo,
+    R: Repo + thought_repo::Repo + SearchIndex + UnitOfWork,
+    C: Clock + ?Sized,
+    E: EventPublisher + ?Sized,
 {
-    pub fn exec(&self, req: Request) -> Result<Response, Error> {
-        log::debug!("Delete area of life by ID: {:?}", req);
-        self.repo.delete(req.id)?;
-        Ok(Response {})
+    /// All changes are committed as a whole,
+    /// so a failure leaves the thoughts and areas of life untouched.
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Move area of life to the trash: {:?}", req);
+        let records = Repo::get_all(self.repo)
+            .await?
+            .into_iter()
+            .map(|r| (r.area_of_life.id(), r))
+            .collect::<HashMap<_, _>>();
+        let parent = records
+            .get(&req.id)
+            .filter(|r| !r.area_of_life.is_deleted())
+            .ok_or(Error::NotFound)?
+            .area_of_life
+            .parent();
+        let mut deleted = match req.children {
+            Children::Reparent => vec![],
+            Children::Cascade => descendants(req.id, &records),
+        };
+        deleted.push(req.id);
+        if let References::Reassign(target) = req.references {
+            if deleted.contains(&target) {
+                return Err(Error::TargetDeleted(target));
+            }
+            if !records
+                .get(&target)
+                .is_some_and(|r| !r.area_of_life.is_deleted())
+            {
+                return Err(Error::TargetNotFound(target));
+            }
+        }
+        let referencing = thought_repo::Repo::get_all(self.repo)
+            .await?
+            .into_iter()
+            .filter(|r| {
+                r.thought
+                    .areas_of_life()
+                    .iter()
+                    .any(|id| deleted.contains(id))
+            })
+            .collect::<Vec<_>>();
+        if req.references == References::Restrict && !referencing.is_empty() {
+            return Err(Error::Referenced(referencing.len()));
+        }
+        let mut changes = Changes::default();
+        let mut events = vec![];
+        if req.children == Children::Reparent {
+            for record in records.values() {
+                if record.area_of_life.parent() == Some(req.id) && !record.area_of_life.is_deleted()
+                {
+                    log::debug!("Move {} up to {:?}", record.area_of_life.id(), parent);
+                    let version = record.area_of_life.version().next();
+                    let area_of_life = record
+                        .area_of_life
+                        .clone()
+                        .with_parent(parent)
+                        .with_version(version);
+                    let id = area_of_life.id();
+                    changes.push(Change::SaveAreaOfLife(Record { area_of_life }));
+                    events.push(Event::AreaOfLifeUpdated(id));
+                }
+            }
+        }
+        let replacement = match req.references {
+            References::Reassign(target) => Some(target),
+            References::Restrict | References::Detach => None,
+        };
+        let thoughts = referencing.len();
+        let deleted_at = self.clock.now();
+        let mut detached = detach_from_thoughts(
+            referencing,
+            &deleted,
+            replacement,
+            deleted_at,
+            &mut changes,
+            &mut events,
+        );
+        for id in deleted {
+            log::debug!("Move area of life {} to the trash", id);
+            let area_of_life = &records[&id].area_of_life;
+            let deletion = Deletion {
+                deleted_at,
+                thoughts: detached.remove(&id).unwrap_or_default(),
+            };
+            let version = area_of_life.version().next();
+            let area_of_life = area_of_life
+                .clone()
+                .with_deletion(Some(deletion))
+                .with_version(version);
+            changes.push(Change::SaveAreaOfLife(Record { area_of_life }));
+            events.push(Event::AreaOfLifeDeleted(id));
+        }
+        commit_and_reindex(self.repo, changes).await?;
+        for event in events {
+            self.events.publish(event);
+        }
+        Ok(Response { thoughts })
+    }
+}
+
+/// Remove the areas of life from the thoughts (optionally adding
+/// the replacement) and remember the thoughts each of them was removed from.
+fn detach_from_thoughts(
+    records: Vec<thought_repo::Record>,
+    ids: &[Id],
+    replacement: Option<Id>,
+    recorded_at: Timestamp,
+    changes: &mut Changes,
+    events: &mut Vec<Event>,
+) -> HashMap<Id, HashSet<thought::Id>> {
+    let mut detached = HashMap::<_, HashSet<_>>::new();
+    for thought_repo::Record { thought } in records {
+        let (removed, mut kept): (HashSet<_>, HashSet<_>) = thought
+            .areas_of_life()
+            .iter()
+            .partition(|id| ids.contains(id));
+        let id = thought.id();
+        for aol_id in removed {
+            detached.entry(aol_id).or_default().insert(id);
+        }
+        kept.extend(replacement);
+        let version = thought.version().next();
+        let thought = thought.with_areas_of_life(kept).with_version(version);
+        push_with_revision(changes, thought, recorded_at);
+        events.push(Event::ThoughtUpdated(id));
+    }
+    detached
+}
+
+/// All nested areas of life that are not in the trash yet.
+fn descendants(id: Id, records: &HashMap<Id, Record>) -> Vec<Id> {
+    let mut found = vec![];
+    let mut queue = vec![id];
+    while let Some(current) = queue.pop() {
+        for record in records.values() {
+            let child = record.area_of_life.id();
+            if record.area_of_life.parent() == Some(current)
+                && !record.area_of_life.is_deleted()
+                && child != id
+                && !found.contains(&child)
+            {
+                found.push(child);
+                queue.push(child);
+            }
+        }
     }
+    found
 }
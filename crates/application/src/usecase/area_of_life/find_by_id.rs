@@ -0,0 +1,113 @@
+use crate::{
+    gateway::repository::{
+        area_of_life::{GetAllError, GetError, Repo},
+        thought as thought_repo,
+    },
+    usecase::area_of_life::read_all::AreaOfLife,
+};
+use cawr_domain::{area_of_life::Id, thought::Status};
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request {
+    pub id: Id,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    pub area_of_life: AreaOfLife,
+    /// The number of directly nested areas of life.
+    pub children: usize,
+    pub thoughts: Counts,
+}
+
+/// The number of thoughts that belong to an area of life
+///
+/// Thoughts in the trash are not counted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Counts {
+    pub total: usize,
+    pub inbox: usize,
+    pub active: usize,
+    pub done: usize,
+    pub archived: usize,
+}
+
+impl Counts {
+    fn add(&mut self, status: Status) {
+        self.total += 1;
+        match status {
+            Status::Inbox => self.inbox += 1,
+            Status::Active => self.active += 1,
+            Status::Done => self.done += 1,
+            Status::Archived => self.archived += 1,
+        }
+    }
+}
+
+/// Find area of life by ID usecase interactor
+pub struct FindById<'r, R> {
+    repo: &'r R,
+}
+
+impl<'r, R> FindById<'r, R> {
+    pub const fn new(repo: &'r R) -> Self {
+        Self { repo }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Area of life {0} not found")]
+    NotFound(Id),
+    #[error("{}", GetError::Connection)]
+    Repo,
+}
+
+impl From<GetAllError> for Error {
+    fn from(e: GetAllError) -> Self {
+        match e {
+            GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<thought_repo::GetAllError> for Error {
+    fn from(e: thought_repo::GetAllError) -> Self {
+        match e {
+            thought_repo::GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl<'r, R> FindById<'r, R>
+where
+    R: Repo + thought_repo::Repo,
+{
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Find area of life by ID: {:?}", req);
+        let Request { id } = req;
+        let record = super::get_untrashed(self.repo, id)
+            .await
+            .map_err(|e| match e {
+                GetError::NotFound => Error::NotFound(id),
+                GetError::Connection => Error::Repo,
+            })?;
+        let children = Repo::get_all(self.repo)
+            .await?
+            .iter()
+            .filter(|r| !r.area_of_life.is_deleted() && r.area_of_life.parent() == Some(id))
+            .count();
+        let mut thoughts = Counts::default();
+        for record in thought_repo::Repo::get_by_area_of_life(self.repo, id).await? {
+            if !record.thought.is_deleted() {
+                thoughts.add(record.thought.status());
+            }
+        }
+        Ok(Response {
+            area_of_life: AreaOfLife::from(record),
+            children,
+            thoughts,
+        })
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::{HashMap, HashSet};
+
+use thiserror::Error;
+
+use cawr_domain::{area_of_life::Id, Event};
+
+use crate::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{
+        repository::{
+            area_of_life::{GetAllError, Record, Repo, SaveError},
+            thought as thought_repo,
+        },
+        search_index::SearchIndex,
+        unit_of_work::{Change, Changes, CommitError, UnitOfWork},
+    },
+    usecase::thought::{commit_and_reindex, push_with_revision},
+};
+
+#[derive(Debug)]
+pub struct Request {
+    /// The area of life that is merged and removed.
+    pub source: Id,
+    /// The area of life that takes over the thoughts and children.
+    pub target: Id,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    /// The number of thoughts that were moved to the target.
+    pub thoughts: usize,
+}
+
+/// Merge area of life usecase interactor
+///
+/// All thoughts and nested areas of life of the source
+/// are moved to the target before the source is removed for good.
+pub struct Merge<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> {
+    repo: &'r R,
+    clock: &'c C,
+    events: &'e E,
+}
+
+impl<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> Merge<'r, 'c, 'e, R, C, E> {
+    pub const fn new(repo: &'r R, clock: &'c C, events: &'e E) -> Self {
+        Self {
+            repo,
+            clock,
+            events,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Area of life {0} not found")]
+    NotFound(Id),
+    #[error("Area of life {0} can't be merged into itself")]
+    Same(Id),
+    #[error("Area of life {0} can't be merged into one of its descendants")]
+    Cycle(Id),
+    #[error("{}", GetAllError::Connection)]
+    Repo,
+    #[error("{}", SaveError::Conflict)]
+    Conflict,
+}
+
+impl From<GetAllError> for Error {
+    fn from(e: GetAllError) -> Self {
+        match e {
+            GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<thought_repo::GetAllError> for Error {
+    fn from(e: thought_repo::GetAllError) -> Self {
+        match e {
+            thought_repo::GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<CommitError> for Error {
+    fn from(e: CommitError) -> Self {
+        match e {
+            CommitError::Connection => Self::Repo,
+            CommitError::Conflict => Self::Conflict,
+        }
+    }
+}
+
+impl<'r, 'c, 'e, R, C, E> Merge<'r, 'c, 'e, R, C, E>
+where
+    R: Repo + thought_repo::Repo + SearchIndex + UnitOfWork,
+    C: Clock + ?Sized,
+    E: EventPublisher + ?Sized,
+{
+    /// All changes are committed as a whole,
+    /// so a failure leaves the thoughts and areas of life untouched.
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Merge areas of life: {:?}", req);
+        let Request { source, target } = req;
+        if source == target {
+            return Err(Error::Same(source));
+        }
+        let records = Repo::get_all(self.repo)
+            .await?
+            .into_iter()
+            .map(|r| (r.area_of_life.id(), r))
+            .collect::<HashMap<_, _>>();
+        for id in [source, target] {
+            if !records
+                .get(&id)
+                .is_some_and(|r| !r.area_of_life.is_deleted())
+            {
+                return Err(Error::NotFound(id));
+            }
+        }
+        if is_nested(target, source, &records) {
+            return Err(Error::Cycle(source));
+        }
+        let mut changes = Changes::default();
+        let mut events = vec![];
+        for record in records.values() {
+            if record.area_of_life.parent() == Some(source) {
+                log::debug!("Move {} to {}", record.area_of_life.id(), target);
+                let version = record.area_of_life.version().next();
+                let area_of_life = record
+                    .area_of_life
+                    .clone()
+                    .with_parent(Some(target))
+                    .with_version(version);
+                let id = area_of_life.id();
+                changes.push(Change::SaveAreaOfLife(Record { area_of_life }));
+                events.push(Event::AreaOfLifeUpdated(id));
+            }
+        }
+        let mut thoughts = 0;
+        let recorded_at = self.clock.now();
+        for thought_repo::Record { thought } in thought_repo::Repo::get_all(self.repo).await? {
+            if !thought.areas_of_life().contains(&source) {
+                continue;
+            }
+            let id = thought.id();
+            let areas_of_life = thought
+                .areas_of_life()
+                .iter()
+                .copied()
+                .filter(|id| *id != source)
+                .chain([target])
+                .collect::<HashSet<_>>();
+            let version = thought.version().next();
+            let thought = thought
+                .with_areas_of_life(areas_of_life)
+                .with_version(version);
+            push_with_revision(&mut changes, thought, recorded_at);
+            events.push(Event::ThoughtUpdated(id));
+            thoughts += 1;
+        }
+        changes.push(Change::DeleteAreaOfLife(source));
+        events.push(Event::AreaOfLifeMerged { source, target });
+        commit_and_reindex(self.repo, changes).await?;
+        for event in events {
+            self.events.publish(event);
+        }
+        Ok(Response { thoughts })
+    }
+}
+
+/// Tell whether `id` is nested somewhere below `ancestor`.
+fn is_nested(id: Id, ancestor: Id, records: &HashMap<Id, Record>) -> bool {
+    let mut visited = HashSet::new();
+    let mut current = records.get(&id).and_then(|r| r.area_of_life.parent());
+    while let Some(parent) = current {
+        if parent == ancestor {
+            return true;
+        }
+        if !visited.insert(parent) {
+            break;
+        }
+        current = records.get(&parent).and_then(|r| r.area_of_life.parent());
+    }
+    false
+}
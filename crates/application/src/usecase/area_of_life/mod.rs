@@ -1,6 +1,27 @@
 pub mod check_existence;
+pub mod check_hierarchy;
+pub mod check_name;
 pub mod create;
 pub mod delete;
+pub mod find_by_id;
+pub mod merge;
 pub mod read_all;
+pub mod read_subtree;
+pub mod thoughts;
 pub mod update;
 pub mod validate;
+
+use crate::gateway::repository::area_of_life::{GetError, Record, Repo};
+use cawr_domain::area_of_life::Id;
+
+/// Get an area of life unless it was moved to the trash.
+pub(crate) async fn get_untrashed<R>(repo: &R, id: Id) -> Result<Record, GetError>
+where
+    R: Repo + ?Sized,
+{
+    let record = repo.get(id).await?;
+    if record.area_of_life.is_deleted() {
+        return Err(GetError::NotFound);
+    }
+    Ok(record)
+}
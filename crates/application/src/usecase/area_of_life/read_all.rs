@@ -1,20 +1,31 @@
-use crate::gateway::repository::area_of_life::{GetAllError, Record, Repo};
-use cawr_domain::area_of_life::Id;
+use crate::gateway::repository::area_of_life::{GetAllError, Query, Record, Repo};
+use cawr_domain::{area_of_life::Id, Timestamp, Version};
 use std::fmt::Debug;
 use thiserror::Error;
 
-#[derive(Debug)]
-pub struct Request;
+#[derive(Debug, Default)]
+pub struct Request {
+    /// Which areas of life to return in which order.
+    pub query: Query,
+}
 
 #[derive(Debug)]
 pub struct Response {
     pub areas_of_life: Vec<AreaOfLife>,
+    /// The number of all areas of life.
+    pub total: usize,
+    /// The offset of the following page if there are more areas of life.
+    pub next_offset: Option<usize>,
 }
 
 #[derive(Debug)]
 pub struct AreaOfLife {
     pub id: Id,
     pub name: String,
+    pub parent: Option<Id>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+    pub version: Version,
 }
 
 impl From<Record> for AreaOfLife {
@@ -22,7 +33,18 @@ impl From<Record> for AreaOfLife {
         let Record { area_of_life } = r;
         let name = String::from(area_of_life.name().as_ref());
         let id = area_of_life.id();
-        Self { id, name }
+        let parent = area_of_life.parent();
+        let created_at = area_of_life.created_at();
+        let updated_at = area_of_life.updated_at();
+        let version = area_of_life.version();
+        Self {
+            id,
+            name,
+            parent,
+            created_at,
+            updated_at,
+            version,
+        }
     }
 }
 
@@ -55,14 +77,13 @@ impl<'r, R> ReadAll<'r, R>
 where
     R: Repo,
 {
-    pub fn exec(&self, _: Request) -> Result<Response, Error> {
-        log::debug!("Read all areas of life");
-        let areas_of_life = self
-            .repo
-            .get_all()?
-            .into_iter()
-            .map(AreaOfLife::from)
-            .collect();
-        Ok(Response { areas_of_life })
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Read all areas of life: {:?}", req);
+        let page = self.repo.query(&req.query).await?.map(AreaOfLife::from);
+        Ok(Response {
+            areas_of_life: page.items,
+            total: page.total,
+            next_offset: page.next_offset,
+        })
     }
 }
@@ -0,0 +1,92 @@
+use crate::{
+    gateway::repository::area_of_life::{GetAllError, Repo},
+    usecase::area_of_life::read_all::AreaOfLife,
+};
+use cawr_domain::area_of_life::Id;
+use std::collections::HashMap;
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request {
+    /// The root of the subtree.
+    pub id: Id,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    pub root: Node,
+}
+
+/// An area of life together with all of its descendants
+#[derive(Debug)]
+pub struct Node {
+    pub area_of_life: AreaOfLife,
+    pub children: Vec<Node>,
+}
+
+/// Read an area of life with all its nested areas usecase interactor
+pub struct ReadSubtree<'r, R> {
+    repo: &'r R,
+}
+
+impl<'r, R> ReadSubtree<'r, R> {
+    pub const fn new(repo: &'r R) -> Self {
+        Self { repo }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Area of life {0} not found")]
+    NotFound(Id),
+    #[error("{}", GetAllError::Connection)]
+    Repo,
+}
+
+impl From<GetAllError> for Error {
+    fn from(e: GetAllError) -> Self {
+        match e {
+            GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl<'r, R> ReadSubtree<'r, R>
+where
+    R: Repo,
+{
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Read subtree of area of life: {:?}", req);
+        let mut root = None;
+        let mut children: HashMap<Id, Vec<AreaOfLife>> = HashMap::new();
+        for record in self.repo.get_all().await? {
+            if record.area_of_life.is_deleted() {
+                continue;
+            }
+            let aol = AreaOfLife::from(record);
+            if aol.id == req.id {
+                root = Some(aol);
+            } else if let Some(parent) = aol.parent {
+                children.entry(parent).or_default().push(aol);
+            }
+        }
+        let root = root.ok_or(Error::NotFound(req.id))?;
+        let root = build_node(root, &mut children);
+        Ok(Response { root })
+    }
+}
+
+fn build_node(area_of_life: AreaOfLife, children: &mut HashMap<Id, Vec<AreaOfLife>>) -> Node {
+    // Removing the entry also prevents endless recursion
+    // if the stored hierarchy contains a cycle.
+    let mut direct_children = children.remove(&area_of_life.id).unwrap_or_default();
+    direct_children.sort_by_key(|aol| aol.id);
+    let children = direct_children
+        .into_iter()
+        .map(|child| build_node(child, children))
+        .collect();
+    Node {
+        area_of_life,
+        children,
+    }
+}
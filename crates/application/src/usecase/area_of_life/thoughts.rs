@@ -0,0 +1,76 @@
+use crate::{
+    gateway::repository::{
+        area_of_life::{self as aol_repo, GetError},
+        query::Sort,
+        thought::{GetAllError, Repo, SortKey},
+    },
+    usecase::thought::read_all::Thought,
+};
+use cawr_domain::area_of_life::Id;
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request {
+    pub id: Id,
+    pub sort: Sort<SortKey>,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    /// The thoughts of the area of life
+    /// without those in the trash.
+    pub thoughts: Vec<Thought>,
+}
+
+/// Read the thoughts of an area of life usecase interactor
+pub struct ReadThoughts<'r, R> {
+    repo: &'r R,
+}
+
+impl<'r, R> ReadThoughts<'r, R> {
+    pub const fn new(repo: &'r R) -> Self {
+        Self { repo }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Area of life {0} not found")]
+    NotFound(Id),
+    #[error("{}", GetAllError::Connection)]
+    Repo,
+}
+
+impl From<GetAllError> for Error {
+    fn from(e: GetAllError) -> Self {
+        match e {
+            GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl<'r, R> ReadThoughts<'r, R>
+where
+    R: aol_repo::Repo + Repo,
+{
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Read thoughts of area of life: {:?}", req);
+        let Request { id, sort } = req;
+        super::get_untrashed(self.repo, id)
+            .await
+            .map_err(|e| match e {
+                GetError::NotFound => Error::NotFound(id),
+                GetError::Connection => Error::Repo,
+            })?;
+        let mut records = self
+            .repo
+            .get_by_area_of_life(id)
+            .await?
+            .into_iter()
+            .filter(|r| !r.thought.is_deleted())
+            .collect::<Vec<_>>();
+        records.sort_by(|a, b| sort.compare(&a.thought, &b.thought));
+        let thoughts = records.into_iter().map(Thought::from).collect();
+        Ok(Response { thoughts })
+    }
+}
@@ -1,10 +1,20 @@
 use crate::{
+    clock::Clock,
+    event::EventPublisher,
     gateway::repository::area_of_life::{GetError, Record, Repo, SaveError},
-    usecase::area_of_life::validate::{
-        self, validate_area_of_life_properties, AreaOfLifeInvalidity,
+    usecase::{
+        area_of_life::{
+            check_hierarchy::{self, CheckHierarchy},
+            check_name::{self, CheckUniqueName},
+            validate::{self, validate_area_of_life_properties, AreaOfLifeInvalidity},
+        },
+        text::normalize_line,
     },
 };
-use cawr_domain::area_of_life::{AreaOfLife, Id, Name};
+use cawr_domain::{
+    area_of_life::{AreaOfLife, Id, Name},
+    Event, Version,
+};
 use thiserror::Error;
 
 #[derive(Debug)]
@@ -13,18 +23,43 @@ pub struct Request {
     pub id: Id,
     /// The name of the area of life.
     pub name: String,
+    /// An optional parent area of life.
+    pub parent: Option<Id>,
+    /// The version the changes are based on.
+    ///
+    /// If it is given but no longer the current version,
+    /// the update is rejected with [`Error::Conflict`].
+    pub version: Option<Version>,
 }
 
-pub type Response = ();
+#[derive(Debug)]
+pub struct Response {
+    /// The version of the updated area of life.
+    pub version: Version,
+}
 
 /// Update area of life usecase interactor
-pub struct UpdateAreaOfLife<'r, R> {
+pub struct UpdateAreaOfLife<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> {
     repo: &'r R,
+    clock: &'c C,
+    events: &'e E,
+    policy: validate::Policy,
 }
 
-impl<'r, R> UpdateAreaOfLife<'r, R> {
-    pub const fn new(repo: &'r R) -> Self {
-        Self { repo }
+impl<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> UpdateAreaOfLife<'r, 'c, 'e, R, C, E> {
+    pub const fn new(repo: &'r R, clock: &'c C, events: &'e E) -> Self {
+        Self {
+            repo,
+            clock,
+            events,
+            policy: validate::Policy::DEFAULT,
+        }
+    }
+    /// Replace the default validation [`validate::Policy`].
+    #[must_use]
+    pub const fn with_policy(mut self, policy: validate::Policy) -> Self {
+        self.policy = policy;
+        self
     }
 }
 
@@ -34,14 +69,44 @@ pub enum Error {
     NotFound(Id),
     #[error(transparent)]
     Invalidity(#[from] AreaOfLifeInvalidity),
+    #[error("Parent area of life {0} not found")]
+    ParentNotFound(Id),
+    #[error("Area of life {0} can't be nested below one of its descendants")]
+    Cycle(Id),
+    #[error("An area of life named '{0}' already exists")]
+    NameAlreadyExists(String),
+    #[error("{}", SaveError::Conflict)]
+    Conflict,
     #[error("{}", SaveError::Connection)]
     Repo,
 }
 
+impl From<check_name::Error> for Error {
+    fn from(e: check_name::Error) -> Self {
+        use check_name::Error as E;
+        match e {
+            E::Repo => Self::Repo,
+            E::NameAlreadyExists(name) => Self::NameAlreadyExists(name),
+        }
+    }
+}
+
+impl From<check_hierarchy::Error> for Error {
+    fn from(e: check_hierarchy::Error) -> Self {
+        use check_hierarchy::Error as E;
+        match e {
+            E::Repo => Self::Repo,
+            E::ParentNotFound(id) => Self::ParentNotFound(id),
+            E::Cycle(id) => Self::Cycle(id),
+        }
+    }
+}
+
 impl From<SaveError> for Error {
     fn from(err: SaveError) -> Self {
         match err {
             SaveError::Connection => Self::Repo,
+            SaveError::Conflict => Self::Conflict,
         }
     }
 }
@@ -55,19 +120,46 @@ impl From<(GetError, Id)> for Error {
     }
 }
 
-impl<'r, R> UpdateAreaOfLife<'r, R>
+impl<'r, 'c, 'e, R, C, E> UpdateAreaOfLife<'r, 'c, 'e, R, C, E>
 where
     R: Repo,
+    C: Clock + ?Sized,
+    E: EventPublisher + ?Sized,
 {
     /// Update a area of life.
-    pub fn exec(&self, req: Request) -> Result<Response, Error> {
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
         log::debug!("Update area of life: {:?}", req);
-        validate_area_of_life_properties(&validate::Request { name: &req.name })?;
-        let name = Name::new(req.name);
-        let area_of_life = AreaOfLife::new(req.id, name);
+        let name = normalize_line(&req.name);
+        validate_area_of_life_properties(&validate::Request { name: &name }, &self.policy)?;
+        let current = super::get_untrashed(self.repo, req.id)
+            .await
+            .map_err(|err| (err, req.id))?;
+        let current_version = current.area_of_life.version();
+        if req.version.is_some_and(|v| v != current_version) {
+            log::debug!("Area of life {} is at version {}", req.id, current_version);
+            return Err(Error::Conflict);
+        }
+        CheckUniqueName::new(self.repo)
+            .exec(&check_name::Request {
+                name: &name,
+                id: Some(req.id),
+            })
+            .await?;
+        if let Some(parent) = req.parent {
+            CheckHierarchy::new(self.repo)
+                .exec(&check_hierarchy::Request { id: req.id, parent })
+                .await?;
+        }
+        let name = Name::new(name);
+        let created_at = current.area_of_life.created_at();
+        let updated_at = self.clock.now().max(created_at);
+        let version = current_version.next();
+        let area_of_life = AreaOfLife::new(req.id, name, created_at, updated_at)
+            .with_parent(req.parent)
+            .with_version(version);
         let record = Record { area_of_life };
-        let _ = self.repo.get(req.id).map_err(|err| (err, req.id))?;
-        self.repo.save(record)?;
-        Ok(())
+        self.repo.save(record).await?;
+        self.events.publish(Event::AreaOfLifeUpdated(req.id));
+        Ok(Response { version })
     }
 }
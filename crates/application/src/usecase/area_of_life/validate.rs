@@ -1,3 +1,4 @@
+use crate::usecase::text::{find_control_character, grapheme_count, normalize_line, LengthLimits};
 use cawr_domain::area_of_life::Name;
 use thiserror::Error;
 
@@ -7,6 +8,28 @@ pub struct Request<'a> {
 }
 pub type Response = Result<(), AreaOfLifeInvalidity>;
 
+/// The limits that are applied to the properties of an area of life
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Policy {
+    pub name: LengthLimits,
+}
+
+impl Policy {
+    /// Use the limits of the domain model.
+    pub const DEFAULT: Self = Self {
+        name: LengthLimits {
+            min: Name::min_len(),
+            max: Name::max_len(),
+        },
+    };
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum AreaOfLifeInvalidity {
     #[error(transparent)]
@@ -19,24 +42,71 @@ pub enum NameInvalidity {
     MinLength { min: usize, actual: usize },
     #[error("The name must have at most {max} but has {actual} chars")]
     MaxLength { max: usize, actual: usize },
+    #[error("The name contains a control character at position {position}")]
+    ControlCharacter { position: usize },
 }
 
-pub fn validate_area_of_life_properties(req: &Request) -> Response {
+/// Validate the (not yet normalized) properties of an area of life.
+pub fn validate_area_of_life_properties(req: &Request, policy: &Policy) -> Response {
     log::debug!("Validate area of life properties {:?}", req);
-    validate_name(req.name).map_err(AreaOfLifeInvalidity::Name)?;
+    validate_name(&normalize_line(req.name), policy.name).map_err(AreaOfLifeInvalidity::Name)?;
     Ok(())
 }
 
-const fn validate_name(name: &str) -> Result<(), NameInvalidity> {
-    let actual = name.len();
-    let min = Name::min_len();
-
+fn validate_name(name: &str, limits: LengthLimits) -> Result<(), NameInvalidity> {
+    let actual = grapheme_count(name);
+    let min = limits.required_min();
+    let max = limits.max;
     if actual < min {
         return Err(NameInvalidity::MinLength { min, actual });
     }
-    let max = Name::max_len();
     if actual > max {
         return Err(NameInvalidity::MaxLength { max, actual });
     }
+    if let Some(position) = find_control_character(name, false) {
+        return Err(NameInvalidity::ControlCharacter { position });
+    }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accept_30_non_ascii_chars() {
+        let name = "Größere Übungen für Ärztinnen";
+        assert_eq!(name.chars().count(), 29);
+        let name = format!("{name}ß");
+        assert!(name.len() > 30);
+        assert!(validate_name(&name, Policy::default().name).is_ok());
+    }
+
+    #[test]
+    fn reject_whitespace_only_names() {
+        let req = Request {
+            name: " \u{3000}  ",
+        };
+        assert!(matches!(
+            validate_area_of_life_properties(&req, &Policy::default()),
+            Err(AreaOfLifeInvalidity::Name(NameInvalidity::MinLength {
+                actual: 0,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn reject_blank_names_even_if_the_policy_allows_empty_names() {
+        let policy = Policy {
+            name: LengthLimits { min: 0, max: 30 },
+        };
+        assert!(matches!(
+            validate_area_of_life_properties(&Request { name: "  " }, &policy),
+            Err(AreaOfLifeInvalidity::Name(NameInvalidity::MinLength {
+                min: 1,
+                actual: 0
+            }))
+        ));
+    }
+}
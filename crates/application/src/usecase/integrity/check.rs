@@ -0,0 +1,344 @@
+use crate::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{
+        integrity::{CheckError, Integrity, RepairError, StorageIssue},
+        repository::{area_of_life, tag, thought},
+        search_index::SearchIndex,
+        unit_of_work::{Change, Changes, CommitError, UnitOfWork},
+    },
+    usecase::{
+        area_of_life::validate::{
+            validate_area_of_life_properties, AreaOfLifeInvalidity, Policy as AreaOfLifePolicy,
+            Request as AreaOfLifeProperties,
+        },
+        tag::validate::{
+            validate_tag_properties, Policy as TagPolicy, Request as TagProperties, TagInvalidity,
+        },
+        thought::validate::{
+            validate_thought_properties, Policy as ThoughtPolicy, Request as ThoughtProperties,
+            ThoughtInvalidity,
+        },
+        thought::{commit_and_reindex, push_with_revision},
+    },
+};
+use cawr_domain::{area_of_life as aol, tag as tg, thought::Id, Event};
+use std::collections::HashSet;
+use thiserror::Error;
+
+#[derive(Debug, Default)]
+pub struct Request {
+    /// Repair all findings that can be repaired automatically.
+    pub fix: bool,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    pub findings: Vec<Finding>,
+    /// The number of findings that were repaired.
+    pub repaired: usize,
+}
+
+/// An integrity problem
+#[derive(Debug)]
+pub enum Finding {
+    Storage(StorageIssue),
+    /// A thought references an area of life that does not exist.
+    DanglingAreaOfLife {
+        thought: Id,
+        area_of_life: aol::Id,
+    },
+    /// A thought references a tag that does not exist.
+    DanglingTag {
+        thought: Id,
+        tag: tg::Id,
+    },
+    /// An area of life is nested below one that does not exist.
+    DanglingParent {
+        area_of_life: aol::Id,
+        parent: aol::Id,
+    },
+    InvalidThought {
+        thought: Id,
+        invalidity: ThoughtInvalidity,
+    },
+    InvalidAreaOfLife {
+        area_of_life: aol::Id,
+        invalidity: AreaOfLifeInvalidity,
+    },
+    InvalidTag {
+        tag: tg::Id,
+        invalidity: TagInvalidity,
+    },
+}
+
+impl Finding {
+    /// Invalid properties are left to the user,
+    /// everything else can be repaired automatically.
+    #[must_use]
+    pub const fn is_repairable(&self) -> bool {
+        !matches!(
+            self,
+            Self::InvalidThought { .. } | Self::InvalidAreaOfLife { .. } | Self::InvalidTag { .. }
+        )
+    }
+}
+
+/// The validation policies that stored records are checked against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Policies {
+    pub thought: ThoughtPolicy,
+    pub area_of_life: AreaOfLifePolicy,
+    pub tag: TagPolicy,
+}
+
+impl Policies {
+    /// Use the limits of the domain model.
+    pub const DEFAULT: Self = Self {
+        thought: ThoughtPolicy::DEFAULT,
+        area_of_life: AreaOfLifePolicy::DEFAULT,
+        tag: TagPolicy::DEFAULT,
+    };
+}
+
+impl Default for Policies {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Check integrity usecase interactor
+pub struct Check<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> {
+    repo: &'r R,
+    clock: &'c C,
+    events: &'e E,
+    policies: Policies,
+}
+
+impl<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> Check<'r, 'c, 'e, R, C, E> {
+    pub const fn new(repo: &'r R, clock: &'c C, events: &'e E) -> Self {
+        Self {
+            repo,
+            clock,
+            events,
+            policies: Policies::DEFAULT,
+        }
+    }
+    /// Replace the default validation [`Policies`].
+    #[must_use]
+    pub const fn with_policies(mut self, policies: Policies) -> Self {
+        self.policies = policies;
+        self
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{}", CheckError::Connection)]
+    Repo,
+    #[error("{}", CommitError::Conflict)]
+    Conflict,
+}
+
+impl From<CheckError> for Error {
+    fn from(e: CheckError) -> Self {
+        match e {
+            CheckError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<RepairError> for Error {
+    fn from(e: RepairError) -> Self {
+        match e {
+            RepairError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<thought::GetAllError> for Error {
+    fn from(e: thought::GetAllError) -> Self {
+        match e {
+            thought::GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<area_of_life::GetAllError> for Error {
+    fn from(e: area_of_life::GetAllError) -> Self {
+        match e {
+            area_of_life::GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<tag::GetAllError> for Error {
+    fn from(e: tag::GetAllError) -> Self {
+        match e {
+            tag::GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<CommitError> for Error {
+    fn from(e: CommitError) -> Self {
+        match e {
+            CommitError::Connection => Self::Repo,
+            CommitError::Conflict => Self::Conflict,
+        }
+    }
+}
+
+impl<'r, 'c, 'e, R, C, E> Check<'r, 'c, 'e, R, C, E>
+where
+    R: Integrity + thought::Repo + area_of_life::Repo + tag::Repo + SearchIndex + UnitOfWork,
+    C: Clock + ?Sized,
+    E: EventPublisher + ?Sized,
+{
+    /// The storage is repaired before the records are checked,
+    /// so the records are read from a consistent storage.
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Check integrity: {:?}", req);
+        let mut findings = vec![];
+        let mut repaired = 0;
+        for issue in self.repo.check_storage().await? {
+            log::warn!("Found storage issue: {:?}", issue);
+            if req.fix {
+                self.repo.repair_storage(issue).await?;
+                repaired += 1;
+            }
+            findings.push(Finding::Storage(issue));
+        }
+        let mut changes = Changes::default();
+        let mut events = vec![];
+        let record_findings = self.check_records(&mut changes, &mut events).await?;
+        if req.fix && !changes.is_empty() {
+            commit_and_reindex(self.repo, changes).await?;
+            for event in events {
+                self.events.publish(event);
+            }
+            repaired += record_findings.iter().filter(|f| f.is_repairable()).count();
+        }
+        findings.extend(record_findings);
+        Ok(Response { findings, repaired })
+    }
+
+    /// Find problems of the records and stage their repair.
+    async fn check_records(
+        &self,
+        changes: &mut Changes,
+        events: &mut Vec<Event>,
+    ) -> Result<Vec<Finding>, Error> {
+        let mut findings = vec![];
+        let areas_of_life = area_of_life::Repo::get_all(self.repo).await?;
+        let aol_ids = areas_of_life
+            .iter()
+            .map(|r| r.area_of_life.id())
+            .collect::<HashSet<_>>();
+        let tags = tag::Repo::get_all(self.repo).await?;
+        let tag_ids = tags.iter().map(|r| r.tag.id()).collect::<HashSet<_>>();
+
+        for area_of_life::Record { area_of_life } in areas_of_life {
+            let id = area_of_life.id();
+            let props = AreaOfLifeProperties {
+                name: area_of_life.name().as_ref(),
+            };
+            if let Err(invalidity) =
+                validate_area_of_life_properties(&props, &self.policies.area_of_life)
+            {
+                findings.push(Finding::InvalidAreaOfLife {
+                    area_of_life: id,
+                    invalidity,
+                });
+            }
+            let Some(parent) = area_of_life.parent().filter(|p| !aol_ids.contains(p)) else {
+                continue;
+            };
+            findings.push(Finding::DanglingParent {
+                area_of_life: id,
+                parent,
+            });
+            let version = area_of_life.version().next();
+            let area_of_life = area_of_life.with_parent(None).with_version(version);
+            changes.push(Change::SaveAreaOfLife(area_of_life::Record {
+                area_of_life,
+            }));
+            events.push(Event::AreaOfLifeUpdated(id));
+        }
+
+        for tag::Record { tag } in tags {
+            let props = TagProperties {
+                name: tag.name().as_ref(),
+            };
+            if let Err(invalidity) = validate_tag_properties(&props, &self.policies.tag) {
+                findings.push(Finding::InvalidTag {
+                    tag: tag.id(),
+                    invalidity,
+                });
+            }
+        }
+
+        let recorded_at = self.clock.now();
+        for thought::Record { thought } in thought::Repo::get_all(self.repo).await? {
+            let id = thought.id();
+            let props = ThoughtProperties {
+                title: thought.title().as_ref(),
+                body: thought.body().map(AsRef::as_ref),
+                due_at: thought.due_at(),
+                reminder_offset: thought.reminder_offset(),
+            };
+            if let Err(invalidity) = validate_thought_properties(&props, &self.policies.thought) {
+                findings.push(Finding::InvalidThought {
+                    thought: id,
+                    invalidity,
+                });
+            }
+            let dangling_areas_of_life = thought
+                .areas_of_life()
+                .iter()
+                .copied()
+                .filter(|id| !aol_ids.contains(id))
+                .collect::<Vec<_>>();
+            let dangling_tags = thought
+                .tags()
+                .iter()
+                .copied()
+                .filter(|id| !tag_ids.contains(id))
+                .collect::<Vec<_>>();
+            if dangling_areas_of_life.is_empty() && dangling_tags.is_empty() {
+                continue;
+            }
+            findings.extend(dangling_areas_of_life.iter().map(|&area_of_life| {
+                Finding::DanglingAreaOfLife {
+                    thought: id,
+                    area_of_life,
+                }
+            }));
+            findings.extend(
+                dangling_tags
+                    .iter()
+                    .map(|&tag| Finding::DanglingTag { thought: id, tag }),
+            );
+            let areas_of_life = thought
+                .areas_of_life()
+                .iter()
+                .copied()
+                .filter(|id| aol_ids.contains(id))
+                .collect();
+            let tags = thought
+                .tags()
+                .iter()
+                .copied()
+                .filter(|id| tag_ids.contains(id))
+                .collect();
+            let version = thought.version().next();
+            let thought = thought
+                .with_areas_of_life(areas_of_life)
+                .with_tags(tags)
+                .with_version(version);
+            push_with_revision(changes, thought, recorded_at);
+            events.push(Event::ThoughtUpdated(id));
+        }
+        Ok(findings)
+    }
+}
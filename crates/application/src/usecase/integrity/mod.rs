@@ -0,0 +1,4 @@
+//! Find and repair data that bypassed the rules of the usecases,
+//! e.g. because of a crash, a bug or a manual edit of the storage.
+
+pub mod check;
@@ -1,2 +1,11 @@
 pub mod area_of_life;
+pub mod integrity;
+pub mod relation;
+pub mod revision;
+pub mod tag;
+pub mod text;
 pub mod thought;
+pub mod trash;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,87 @@
+use crate::{
+    event::EventPublisher,
+    gateway::repository::{
+        relation::{self, Record, SaveError},
+        thought::{self, GetError},
+    },
+    usecase::thought::get_untrashed,
+};
+use cawr_domain::{
+    relation::{Kind, Relation},
+    thought::Id,
+    Event,
+};
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request {
+    /// The thought the link starts from.
+    pub source: Id,
+    /// The meaning of the link.
+    pub kind: Kind,
+    /// The thought the link points to.
+    pub target: Id,
+}
+
+pub type Response = ();
+
+/// Link two thoughts usecase interactor
+pub struct LinkThoughts<'r, 'e, R, E: ?Sized> {
+    repo: &'r R,
+    events: &'e E,
+}
+
+impl<'r, 'e, R, E: ?Sized> LinkThoughts<'r, 'e, R, E> {
+    pub const fn new(repo: &'r R, events: &'e E) -> Self {
+        Self { repo, events }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{}", SaveError::Connection)]
+    Repo,
+    #[error("Thought {0} not found")]
+    ThoughtNotFound(Id),
+    #[error("A thought can't be linked to itself")]
+    SelfLink,
+}
+
+impl From<SaveError> for Error {
+    fn from(e: SaveError) -> Self {
+        match e {
+            SaveError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<(Id, GetError)> for Error {
+    fn from((id, err): (Id, GetError)) -> Self {
+        match err {
+            GetError::Connection => Self::Repo,
+            GetError::NotFound => Self::ThoughtNotFound(id),
+        }
+    }
+}
+
+impl<'r, 'e, R, E> LinkThoughts<'r, 'e, R, E>
+where
+    R: thought::Repo + relation::Repo,
+    E: EventPublisher + ?Sized,
+{
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Link thoughts: {:?}", req);
+        if req.source == req.target {
+            return Err(Error::SelfLink);
+        }
+        for id in [req.source, req.target] {
+            get_untrashed(self.repo, id)
+                .await
+                .map_err(|err| (id, err))?;
+        }
+        let relation = Relation::new(req.source, req.kind, req.target);
+        relation::Repo::save(self.repo, Record { relation }).await?;
+        self.events.publish(Event::ThoughtsLinked(relation));
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{
+    gateway::repository::{
+        relation::{self, GetAllError},
+        thought::{self, GetError},
+    },
+    usecase::thought::get_untrashed,
+};
+use cawr_domain::{relation::Kind, thought::Id};
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request {
+    pub id: Id,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    /// Links from the requested thought to other thoughts.
+    pub outgoing: Vec<Link>,
+    /// Links from other thoughts to the requested thought.
+    pub backlinks: Vec<Link>,
+}
+
+#[derive(Debug)]
+pub struct Link {
+    pub kind: Kind,
+    /// The thought on the other end of the link.
+    pub thought: Id,
+}
+
+/// Read outgoing links and backlinks of a thought usecase interactor
+pub struct ReadLinks<'r, R> {
+    repo: &'r R,
+}
+
+impl<'r, R> ReadLinks<'r, R> {
+    pub const fn new(repo: &'r R) -> Self {
+        Self { repo }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{}", GetError::NotFound)]
+    NotFound,
+    #[error("{}", GetAllError::Connection)]
+    Repo,
+}
+
+impl From<GetError> for Error {
+    fn from(e: GetError) -> Self {
+        match e {
+            GetError::NotFound => Self::NotFound,
+            GetError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<GetAllError> for Error {
+    fn from(e: GetAllError) -> Self {
+        match e {
+            GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl<'r, R> ReadLinks<'r, R>
+where
+    R: thought::Repo + relation::Repo,
+{
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Read links of thought: {:?}", req);
+        get_untrashed(self.repo, req.id).await?;
+        let mut outgoing = vec![];
+        let mut backlinks = vec![];
+        for record in relation::Repo::get_by_thought(self.repo, req.id).await? {
+            let relation = record.relation;
+            let other = if relation.source() == req.id {
+                relation.target()
+            } else {
+                relation.source()
+            };
+            // Hide links to thoughts in the trash
+            match get_untrashed(self.repo, other).await {
+                Ok(_) => {}
+                Err(GetError::NotFound) => continue,
+                Err(err) => return Err(err.into()),
+            }
+            if relation.source() == req.id {
+                outgoing.push(Link {
+                    kind: relation.kind(),
+                    thought: relation.target(),
+                });
+            } else if relation.target() == req.id {
+                backlinks.push(Link {
+                    kind: relation.kind(),
+                    thought: relation.source(),
+                });
+            }
+        }
+        outgoing.sort_by_key(|l| (l.thought, l.kind));
+        backlinks.sort_by_key(|l| (l.thought, l.kind));
+        Ok(Response {
+            outgoing,
+            backlinks,
+        })
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    event::EventPublisher,
+    gateway::repository::relation::{DeleteError, Repo},
+};
+use cawr_domain::{
+    relation::{Kind, Relation},
+    thought::Id,
+    Event,
+};
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request {
+    /// The thought the link starts from.
+    pub source: Id,
+    /// The meaning of the link.
+    pub kind: Kind,
+    /// The thought the link points to.
+    pub target: Id,
+}
+
+pub type Response = ();
+
+/// Unlink two thoughts usecase interactor
+pub struct UnlinkThoughts<'r, 'e, R, E: ?Sized> {
+    repo: &'r R,
+    events: &'e E,
+}
+
+impl<'r, 'e, R, E: ?Sized> UnlinkThoughts<'r, 'e, R, E> {
+    pub const fn new(repo: &'r R, events: &'e E) -> Self {
+        Self { repo, events }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{}", DeleteError::NotFound)]
+    NotFound,
+    #[error("{}", DeleteError::Connection)]
+    Repo,
+}
+
+impl From<DeleteError> for Error {
+    fn from(e: DeleteError) -> Self {
+        match e {
+            DeleteError::NotFound => Self::NotFound,
+            DeleteError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl<'r, 'e, R, E> UnlinkThoughts<'r, 'e, R, E>
+where
+    R: Repo,
+    E: EventPublisher + ?Sized,
+{
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Unlink thoughts: {:?}", req);
+        if req.source == req.target {
+            return Err(Error::NotFound);
+        }
+        let relation = Relation::new(req.source, req.kind, req.target);
+        self.repo.delete(&relation).await?;
+        self.events.publish(Event::ThoughtsUnlinked(relation));
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{
+    gateway::repository::{revision, thought},
+    usecase::thought::get_untrashed,
+};
+use cawr_domain::{
+    area_of_life as aol, tag as tg,
+    thought::{Id, Status, Thought},
+    Timestamp, Version,
+};
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request {
+    pub id: Id,
+    /// The older revision.
+    pub from: Version,
+    /// The newer revision.
+    pub to: Version,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    /// The fields that differ between both revisions.
+    pub changes: Vec<Change>,
+}
+
+/// A field of a thought that has changed between two revisions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    Title {
+        old: String,
+        new: String,
+    },
+    Body {
+        old: Option<String>,
+        new: Option<String>,
+    },
+    AreasOfLife {
+        added: Vec<aol::Id>,
+        removed: Vec<aol::Id>,
+    },
+    Tags {
+        added: Vec<tg::Id>,
+        removed: Vec<tg::Id>,
+    },
+    Status {
+        old: Status,
+        new: Status,
+    },
+    DueAt {
+        old: Option<Timestamp>,
+        new: Option<Timestamp>,
+    },
+    ReminderOffset {
+        old: Option<Duration>,
+        new: Option<Duration>,
+    },
+}
+
+/// Compare two revisions of a thought usecase interactor
+pub struct DiffRevisions<'r, R> {
+    repo: &'r R,
+}
+
+impl<'r, R> DiffRevisions<'r, R> {
+    pub const fn new(repo: &'r R) -> Self {
+        Self { repo }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{}", thought::GetError::NotFound)]
+    ThoughtNotFound,
+    #[error("Revision {0} not found")]
+    RevisionNotFound(Version),
+    #[error("{}", revision::GetError::Connection)]
+    Repo,
+}
+
+impl From<thought::GetError> for Error {
+    fn from(e: thought::GetError) -> Self {
+        match e {
+            thought::GetError::NotFound => Self::ThoughtNotFound,
+            thought::GetError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<(Version, revision::GetError)> for Error {
+    fn from((version, e): (Version, revision::GetError)) -> Self {
+        match e {
+            revision::GetError::NotFound => Self::RevisionNotFound(version),
+            revision::GetError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl<'r, R> DiffRevisions<'r, R>
+where
+    R: thought::Repo + revision::Repo,
+{
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Diff revisions of thought: {:?}", req);
+        get_untrashed(self.repo, req.id).await?;
+        let old = self.get(req.id, req.from).await?;
+        let new = self.get(req.id, req.to).await?;
+        let changes = diff(&old, &new);
+        Ok(Response { changes })
+    }
+
+    async fn get(&self, id: Id, version: Version) -> Result<Thought, Error> {
+        revision::Repo::get(self.repo, id, version)
+            .await
+            .map(|r| r.thought)
+            .map_err(|err| (version, err).into())
+    }
+}
+
+/// Field-level differences between two states of a thought.
+#[must_use]
+pub fn diff(old: &Thought, new: &Thought) -> Vec<Change> {
+    let mut changes = vec![];
+    let (old_title, new_title) = (old.title().as_ref(), new.title().as_ref());
+    if old_title != new_title {
+        changes.push(Change::Title {
+            old: old_title.to_string(),
+            new: new_title.to_string(),
+        });
+    }
+    let (old_body, new_body) = (
+        old.body().map(AsRef::<str>::as_ref),
+        new.body().map(AsRef::<str>::as_ref),
+    );
+    if old_body != new_body {
+        changes.push(Change::Body {
+            old: old_body.map(ToString::to_string),
+            new: new_body.map(ToString::to_string),
+        });
+    }
+    if old.areas_of_life() != new.areas_of_life() {
+        changes.push(Change::AreasOfLife {
+            added: sorted(new.areas_of_life().difference(old.areas_of_life())),
+            removed: sorted(old.areas_of_life().difference(new.areas_of_life())),
+        });
+    }
+    if old.tags() != new.tags() {
+        changes.push(Change::Tags {
+            added: sorted(new.tags().difference(old.tags())),
+            removed: sorted(old.tags().difference(new.tags())),
+        });
+    }
+    if old.status() != new.status() {
+        changes.push(Change::Status {
+            old: old.status(),
+            new: new.status(),
+        });
+    }
+    if old.due_at() != new.due_at() {
+        changes.push(Change::DueAt {
+            old: old.due_at(),
+            new: new.due_at(),
+        });
+    }
+    if old.reminder_offset() != new.reminder_offset() {
+        changes.push(Change::ReminderOffset {
+            old: old.reminder_offset(),
+            new: new.reminder_offset(),
+        });
+    }
+    changes
+}
+
+fn sorted<'a, T: Ord + Copy + 'a>(ids: impl Iterator<Item = &'a T>) -> Vec<T> {
+    let mut ids = ids.copied().collect::<Vec<_>>();
+    ids.sort();
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cawr_domain::thought::{Body, Title};
+    use std::collections::HashSet;
+
+    const NOW: Timestamp = Timestamp::from_unix_millis(1_700_000_000_000);
+
+    fn thought(title: &str) -> Thought {
+        Thought::new(
+            Id::new(1),
+            Title::new(title.to_string()),
+            HashSet::new(),
+            NOW,
+            NOW,
+        )
+    }
+
+    #[test]
+    fn no_changes() {
+        assert!(diff(&thought("foo"), &thought("foo")).is_empty());
+    }
+
+    #[test]
+    fn changed_fields() {
+        let old = thought("foo").with_tags([tg::Id::new(1), tg::Id::new(2)].into());
+        let new = thought("bar")
+            .with_body(Some(Body::new("baz".to_string())))
+            .with_tags([tg::Id::new(2), tg::Id::new(3)].into())
+            .with_due(Some(NOW), None);
+        assert_eq!(
+            diff(&old, &new),
+            vec![
+                Change::Title {
+                    old: "foo".to_string(),
+                    new: "bar".to_string()
+                },
+                Change::Body {
+                    old: None,
+                    new: Some("baz".to_string())
+                },
+                Change::Tags {
+                    added: vec![tg::Id::new(3)],
+                    removed: vec![tg::Id::new(1)]
+                },
+                Change::DueAt {
+                    old: None,
+                    new: Some(NOW)
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,3 @@
+pub mod diff;
+pub mod read_all;
+pub mod revert;
@@ -0,0 +1,82 @@
+use crate::{
+    gateway::repository::{revision, thought},
+    usecase::thought::get_untrashed,
+};
+use cawr_domain::{thought::Id, Timestamp, Version};
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request {
+    pub id: Id,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    /// All revisions of the thought, oldest first.
+    pub revisions: Vec<Revision>,
+}
+
+#[derive(Debug)]
+pub struct Revision {
+    pub version: Version,
+    /// The title of the thought at that time.
+    pub title: String,
+    pub recorded_at: Timestamp,
+}
+
+/// Read the revision history of a thought usecase interactor
+pub struct ReadRevisions<'r, R> {
+    repo: &'r R,
+}
+
+impl<'r, R> ReadRevisions<'r, R> {
+    pub const fn new(repo: &'r R) -> Self {
+        Self { repo }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{}", thought::GetError::NotFound)]
+    NotFound,
+    #[error("{}", revision::GetAllError::Connection)]
+    Repo,
+}
+
+impl From<thought::GetError> for Error {
+    fn from(e: thought::GetError) -> Self {
+        match e {
+            thought::GetError::NotFound => Self::NotFound,
+            thought::GetError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<revision::GetAllError> for Error {
+    fn from(e: revision::GetAllError) -> Self {
+        match e {
+            revision::GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl<'r, R> ReadRevisions<'r, R>
+where
+    R: thought::Repo + revision::Repo,
+{
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Read revisions of thought {}", req.id);
+        get_untrashed(self.repo, req.id).await?;
+        let mut revisions = revision::Repo::get_by_thought(self.repo, req.id)
+            .await?
+            .into_iter()
+            .map(|r| Revision {
+                version: r.thought.version(),
+                title: r.thought.title().as_ref().to_string(),
+                recorded_at: r.recorded_at,
+            })
+            .collect::<Vec<_>>();
+        revisions.sort_by_key(|r| r.version);
+        Ok(Response { revisions })
+    }
+}
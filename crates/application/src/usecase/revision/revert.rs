@@ -0,0 +1,152 @@
+use crate::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{
+        repository::{area_of_life, revision, tag, thought},
+        search_index::SearchIndex,
+        unit_of_work::{CommitError, UnitOfWork},
+    },
+    usecase::{
+        area_of_life::get_untrashed as get_untrashed_aol,
+        thought::{get_untrashed, reindex, save_with_revision},
+    },
+};
+use cawr_domain::{
+    thought::{Id, Thought},
+    Event, Version,
+};
+use std::collections::HashSet;
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request {
+    pub id: Id,
+    /// The revision to go back to.
+    pub version: Version,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    /// The version of the reverted thought.
+    pub version: Version,
+}
+
+/// Revert a thought to an earlier revision usecase interactor
+///
+/// The content of the revision is saved as a new revision,
+/// so the history is kept. The status of the thought does not change
+/// and references to areas of life or tags that no longer exist are dropped.
+pub struct RevertThought<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> {
+    repo: &'r R,
+    clock: &'c C,
+    events: &'e E,
+}
+
+impl<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> RevertThought<'r, 'c, 'e, R, C, E> {
+    pub const fn new(repo: &'r R, clock: &'c C, events: &'e E) -> Self {
+        Self {
+            repo,
+            clock,
+            events,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{}", thought::GetError::NotFound)]
+    ThoughtNotFound,
+    #[error("Revision {0} not found")]
+    RevisionNotFound(Version),
+    #[error("{}", thought::SaveError::Connection)]
+    Repo,
+    #[error("{}", thought::SaveError::Conflict)]
+    Conflict,
+}
+
+impl From<thought::GetError> for Error {
+    fn from(e: thought::GetError) -> Self {
+        match e {
+            thought::GetError::NotFound => Self::ThoughtNotFound,
+            thought::GetError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<(Version, revision::GetError)> for Error {
+    fn from((version, e): (Version, revision::GetError)) -> Self {
+        match e {
+            revision::GetError::NotFound => Self::RevisionNotFound(version),
+            revision::GetError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<CommitError> for Error {
+    fn from(e: CommitError) -> Self {
+        match e {
+            CommitError::Connection => Self::Repo,
+            CommitError::Conflict => Self::Conflict,
+        }
+    }
+}
+
+impl<'r, 'c, 'e, R, C, E> RevertThought<'r, 'c, 'e, R, C, E>
+where
+    R: thought::Repo + area_of_life::Repo + tag::Repo + revision::Repo + SearchIndex + UnitOfWork,
+    C: Clock + ?Sized,
+    E: EventPublisher + ?Sized,
+{
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Revert thought: {:?}", req);
+        let current = get_untrashed(self.repo, req.id).await?.thought;
+        let revision = revision::Repo::get(self.repo, req.id, req.version)
+            .await
+            .map_err(|err| (req.version, err))?
+            .thought;
+        let mut areas_of_life = HashSet::new();
+        for &id in revision.areas_of_life() {
+            match get_untrashed_aol(self.repo, id).await {
+                Ok(_) => {
+                    areas_of_life.insert(id);
+                }
+                Err(area_of_life::GetError::NotFound) => {
+                    log::debug!("Drop reference to missing area of life {}", id);
+                }
+                Err(area_of_life::GetError::Connection) => return Err(Error::Repo),
+            }
+        }
+        let mut tags = HashSet::new();
+        for &id in revision.tags() {
+            match tag::Repo::get(self.repo, id).await {
+                Ok(_) => {
+                    tags.insert(id);
+                }
+                Err(tag::GetError::NotFound) => {
+                    log::debug!("Drop reference to missing tag {}", id);
+                }
+                Err(tag::GetError::Connection) => return Err(Error::Repo),
+            }
+        }
+        let version = current.version().next();
+        let updated_at = self.clock.now().max(current.created_at());
+        let thought = Thought::new(
+            req.id,
+            revision.title().clone(),
+            areas_of_life,
+            current.created_at(),
+            updated_at,
+        )
+        .with_body(revision.body().cloned())
+        .with_tags(tags)
+        .with_due(revision.due_at(), revision.reminder_offset())
+        .with_status(current.status())
+        .with_version(version);
+        self.repo
+            .commit(save_with_revision(thought.clone(), updated_at))
+            .await?;
+        reindex(self.repo, &thought).await;
+        self.events.publish(Event::ThoughtUpdated(req.id));
+        Ok(Response { version })
+    }
+}
@@ -0,0 +1,49 @@
+use crate::gateway::repository::tag::{GetError, Repo};
+use cawr_domain::tag::Id;
+use std::collections::HashSet;
+use thiserror::Error;
+
+pub type Request<'a> = &'a HashSet<Id>;
+
+pub struct CheckTagsExistence<'r, R> {
+    repo: &'r R,
+}
+
+impl<'r, R> CheckTagsExistence<'r, R> {
+    pub const fn new(repo: &'r R) -> Self {
+        Self { repo }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{}", GetError::Connection)]
+    Repo,
+    #[error("Tag {0:?} not found")]
+    NotFound(HashSet<Id>),
+}
+
+impl<'r, R> CheckTagsExistence<'r, R>
+where
+    R: Repo,
+{
+    pub async fn exec(&self, req: Request<'_>) -> Result<(), Error> {
+        let mut not_found = HashSet::new();
+        for id in req {
+            match self.repo.get(*id).await {
+                Err(GetError::Connection) => {
+                    return Err(Error::Repo);
+                }
+                Err(GetError::NotFound) => {
+                    not_found.insert(*id);
+                }
+                Ok(_) => {}
+            }
+        }
+        if not_found.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::NotFound(not_found))
+        }
+    }
+}
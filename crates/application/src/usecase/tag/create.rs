@@ -0,0 +1,98 @@
+use crate::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::repository::tag::{Record, Repo, SaveError},
+    identifier::{NewId, NewIdError},
+    usecase::{
+        tag::validate::{self, validate_tag_properties, TagInvalidity},
+        text::normalize_line,
+    },
+};
+use cawr_domain::{
+    tag::{Id, Name, Tag},
+    Event,
+};
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request {
+    /// The name of the new tag.
+    pub name: String,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    /// The ID of the newly created tag.
+    pub id: Id,
+}
+
+/// Create tag usecase interactor
+pub struct CreateTag<'r, 'g, 'c, 'e, R, G: ?Sized, C: ?Sized, E: ?Sized> {
+    repo: &'r R,
+    id_gen: &'g G,
+    clock: &'c C,
+    events: &'e E,
+    policy: validate::Policy,
+}
+
+impl<'r, 'g, 'c, 'e, R, G: ?Sized, C: ?Sized, E: ?Sized> CreateTag<'r, 'g, 'c, 'e, R, G, C, E> {
+    pub const fn new(repo: &'r R, id_gen: &'g G, clock: &'c C, events: &'e E) -> Self {
+        Self {
+            repo,
+            id_gen,
+            clock,
+            events,
+            policy: validate::Policy::DEFAULT,
+        }
+    }
+    /// Replace the default validation [`validate::Policy`].
+    #[must_use]
+    pub const fn with_policy(mut self, policy: validate::Policy) -> Self {
+        self.policy = policy;
+        self
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{}", SaveError::Connection)]
+    Repo,
+    #[error("{}", NewIdError)]
+    NewId,
+    #[error(transparent)]
+    Invalidity(#[from] TagInvalidity),
+}
+
+impl From<SaveError> for Error {
+    fn from(e: SaveError) -> Self {
+        match e {
+            SaveError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl<'r, 'g, 'c, 'e, R, G, C, E> CreateTag<'r, 'g, 'c, 'e, R, G, C, E>
+where
+    R: Repo,
+    G: NewId<Id> + ?Sized,
+    C: Clock + ?Sized,
+    E: EventPublisher + ?Sized,
+{
+    /// Create a new tag with the given name.
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Create new tag: {:?}", req);
+        let name = normalize_line(&req.name);
+        validate_tag_properties(&validate::Request { name: &name }, &self.policy)?;
+        let name = Name::new(name);
+        let id = self.id_gen.new_id().await.map_err(|err| {
+            log::warn!("{}", err);
+            Error::NewId
+        })?;
+        let now = self.clock.now();
+        let tag = Tag::new(id, name, now, now);
+        let record = Record { tag };
+        self.repo.save(record).await?;
+        self.events.publish(Event::TagCreated(id));
+        Ok(Response { id })
+    }
+}
@@ -0,0 +1,115 @@
+use std::fmt::Debug;
+
+use thiserror::Error;
+
+use cawr_domain::{tag::Id, Event};
+
+use crate::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{
+        repository::{
+            tag::{GetError, Repo},
+            thought as thought_repo,
+        },
+        search_index::SearchIndex,
+        unit_of_work::{Change, Changes, CommitError, UnitOfWork},
+    },
+    usecase::thought::{commit_and_reindex, push_with_revision},
+};
+
+#[derive(Debug)]
+pub struct Request {
+    pub id: Id,
+}
+
+#[derive(Debug)]
+pub struct Response;
+
+/// Delete tag by ID usecase interactor
+pub struct Delete<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> {
+    repo: &'r R,
+    clock: &'c C,
+    events: &'e E,
+}
+
+impl<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> Delete<'r, 'c, 'e, R, C, E> {
+    pub const fn new(repo: &'r R, clock: &'c C, events: &'e E) -> Self {
+        Self {
+            repo,
+            clock,
+            events,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{}", GetError::NotFound)]
+    NotFound,
+    #[error("{}", GetError::Connection)]
+    Repo,
+    #[error("{}", CommitError::Conflict)]
+    Conflict,
+}
+
+impl From<GetError> for Error {
+    fn from(e: GetError) -> Self {
+        match e {
+            GetError::NotFound => Self::NotFound,
+            GetError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<thought_repo::GetAllError> for Error {
+    fn from(e: thought_repo::GetAllError) -> Self {
+        match e {
+            thought_repo::GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<CommitError> for Error {
+    fn from(e: CommitError) -> Self {
+        match e {
+            CommitError::Connection => Self::Repo,
+            CommitError::Conflict => Self::Conflict,
+        }
+    }
+}
+
+impl<'r, 'c, 'e, R, C, E> Delete<'r, 'c, 'e, R, C, E>
+where
+    R: Repo + thought_repo::Repo + SearchIndex + UnitOfWork,
+    C: Clock + ?Sized,
+    E: EventPublisher + ?Sized,
+{
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Delete tag by ID: {:?}", req);
+        Repo::get(self.repo, req.id).await?;
+        let mut changes = Changes::default();
+        let mut events = vec![];
+        let recorded_at = self.clock.now();
+        for thought_repo::Record { thought } in thought_repo::Repo::get_all(self.repo).await? {
+            if !thought.tags().contains(&req.id) {
+                continue;
+            }
+            let id = thought.id();
+            log::debug!("Remove tag {} from thought {}", req.id, id);
+            let mut tags = thought.tags().clone();
+            tags.remove(&req.id);
+            let version = thought.version().next();
+            let thought = thought.with_tags(tags).with_version(version);
+            push_with_revision(&mut changes, thought, recorded_at);
+            events.push(Event::ThoughtUpdated(id));
+        }
+        changes.push(Change::DeleteTag(req.id));
+        events.push(Event::TagDeleted(req.id));
+        commit_and_reindex(self.repo, changes).await?;
+        for event in events {
+            self.events.publish(event);
+        }
+        Ok(Response {})
+    }
+}
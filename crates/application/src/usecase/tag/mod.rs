@@ -0,0 +1,6 @@
+pub mod check_existence;
+pub mod create;
+pub mod delete;
+pub mod read_all;
+pub mod update;
+pub mod validate;
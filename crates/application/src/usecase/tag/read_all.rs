@@ -0,0 +1,78 @@
+use crate::gateway::repository::tag::{GetAllError, Record, Repo};
+use cawr_domain::{tag::Id, Timestamp};
+use std::fmt::Debug;
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request;
+
+#[derive(Debug)]
+pub struct Response {
+    pub tags: Vec<Tag>,
+}
+
+#[derive(Debug)]
+pub struct Tag {
+    pub id: Id,
+    pub name: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+impl From<Record> for Tag {
+    fn from(r: Record) -> Self {
+        let Record { tag } = r;
+        let name = String::from(tag.name().as_ref());
+        let id = tag.id();
+        let created_at = tag.created_at();
+        let updated_at = tag.updated_at();
+        Self {
+            id,
+            name,
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+/// Read all tags usecase interactor
+pub struct ReadAll<'r, R> {
+    repo: &'r R,
+}
+
+impl<'r, R> ReadAll<'r, R> {
+    pub const fn new(repo: &'r R) -> Self {
+        Self { repo }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{}", GetAllError::Connection)]
+    Repo,
+}
+
+impl From<GetAllError> for Error {
+    fn from(e: GetAllError) -> Self {
+        match e {
+            GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl<'r, R> ReadAll<'r, R>
+where
+    R: Repo,
+{
+    pub async fn exec(&self, _: Request) -> Result<Response, Error> {
+        log::debug!("Read all tags");
+        let tags = self
+            .repo
+            .get_all()
+            .await?
+            .into_iter()
+            .map(Tag::from)
+            .collect();
+        Ok(Response { tags })
+    }
+}
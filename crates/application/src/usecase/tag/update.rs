@@ -0,0 +1,99 @@
+use crate::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::repository::tag::{GetError, Record, Repo, SaveError},
+    usecase::{
+        tag::validate::{self, validate_tag_properties, TagInvalidity},
+        text::normalize_line,
+    },
+};
+use cawr_domain::{
+    tag::{Id, Name, Tag},
+    Event,
+};
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request {
+    /// The id of the tag.
+    pub id: Id,
+    /// The name of the tag.
+    pub name: String,
+}
+
+pub type Response = ();
+
+/// Update tag usecase interactor
+pub struct UpdateTag<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> {
+    repo: &'r R,
+    clock: &'c C,
+    events: &'e E,
+    policy: validate::Policy,
+}
+
+impl<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> UpdateTag<'r, 'c, 'e, R, C, E> {
+    pub const fn new(repo: &'r R, clock: &'c C, events: &'e E) -> Self {
+        Self {
+            repo,
+            clock,
+            events,
+            policy: validate::Policy::DEFAULT,
+        }
+    }
+    /// Replace the default validation [`validate::Policy`].
+    #[must_use]
+    pub const fn with_policy(mut self, policy: validate::Policy) -> Self {
+        self.policy = policy;
+        self
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Tag {0} not found")]
+    NotFound(Id),
+    #[error(transparent)]
+    Invalidity(#[from] TagInvalidity),
+    #[error("{}", SaveError::Connection)]
+    Repo,
+}
+
+impl From<SaveError> for Error {
+    fn from(err: SaveError) -> Self {
+        match err {
+            SaveError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<(GetError, Id)> for Error {
+    fn from((err, id): (GetError, Id)) -> Self {
+        match err {
+            GetError::NotFound => Self::NotFound(id),
+            GetError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl<'r, 'c, 'e, R, C, E> UpdateTag<'r, 'c, 'e, R, C, E>
+where
+    R: Repo,
+    C: Clock + ?Sized,
+    E: EventPublisher + ?Sized,
+{
+    /// Update a tag.
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Update tag: {:?}", req);
+        let name = normalize_line(&req.name);
+        validate_tag_properties(&validate::Request { name: &name }, &self.policy)?;
+        let current = self.repo.get(req.id).await.map_err(|err| (err, req.id))?;
+        let name = Name::new(name);
+        let created_at = current.tag.created_at();
+        let updated_at = self.clock.now().max(created_at);
+        let tag = Tag::new(req.id, name, created_at, updated_at);
+        let record = Record { tag };
+        self.repo.save(record).await?;
+        self.events.publish(Event::TagUpdated(req.id));
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use crate::usecase::text::{find_control_character, grapheme_count, normalize_line, LengthLimits};
+use cawr_domain::tag::Name;
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    pub name: &'a str,
+}
+pub type Response = Result<(), TagInvalidity>;
+
+/// The limits that are applied to the properties of a tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Policy {
+    pub name: LengthLimits,
+}
+
+impl Policy {
+    /// Use the limits of the domain model.
+    pub const DEFAULT: Self = Self {
+        name: LengthLimits {
+            min: Name::min_len(),
+            max: Name::max_len(),
+        },
+    };
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum TagInvalidity {
+    #[error(transparent)]
+    Name(#[from] NameInvalidity),
+}
+
+#[derive(Debug, Error)]
+pub enum NameInvalidity {
+    #[error("The name must have at least {min} but has {actual} chars")]
+    MinLength { min: usize, actual: usize },
+    #[error("The name must have at most {max} but has {actual} chars")]
+    MaxLength { max: usize, actual: usize },
+    #[error("The name contains a control character at position {position}")]
+    ControlCharacter { position: usize },
+}
+
+/// Validate the (not yet normalized) properties of a tag.
+pub fn validate_tag_properties(req: &Request, policy: &Policy) -> Response {
+    log::debug!("Validate tag properties {:?}", req);
+    validate_name(&normalize_line(req.name), policy.name).map_err(TagInvalidity::Name)?;
+    Ok(())
+}
+
+fn validate_name(name: &str, limits: LengthLimits) -> Result<(), NameInvalidity> {
+    let actual = grapheme_count(name);
+    let min = limits.required_min();
+    let max = limits.max;
+    if actual < min {
+        return Err(NameInvalidity::MinLength { min, actual });
+    }
+    if actual > max {
+        return Err(NameInvalidity::MaxLength { max, actual });
+    }
+    if let Some(position) = find_control_character(name, false) {
+        return Err(NameInvalidity::ControlCharacter { position });
+    }
+    Ok(())
+}
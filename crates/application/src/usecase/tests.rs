@@ -0,0 +1,1523 @@
+//! Assert the exact events that each usecase emits.
+
+use crate::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{
+        integrity::{self, Integrity, StorageIssue},
+        repository::{area_of_life, relation, revision, tag, thought},
+        search_index::{self, Hit, SearchIndex},
+        unit_of_work::{Change, Changes, CommitError, UnitOfWork},
+    },
+    identifier::{NewId, NewIdError},
+    usecase,
+};
+use async_trait::async_trait;
+use cawr_domain::{
+    area_of_life::{self as aol, AreaOfLife},
+    relation::{Kind, Relation},
+    tag::{self as tg, Tag},
+    thought::{Id, Status, Thought, Title},
+    Event, Timestamp, Version,
+};
+use parking_lot::RwLock;
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+const NOW: Timestamp = Timestamp::from_unix_millis(1_700_000_000_000);
+
+#[derive(Default)]
+struct MockDb {
+    thoughts: RwLock<HashMap<Id, thought::Record>>,
+    areas_of_life: RwLock<HashMap<aol::Id, area_of_life::Record>>,
+    tags: RwLock<HashMap<tg::Id, tag::Record>>,
+    relations: RwLock<HashSet<Relation>>,
+    revisions: RwLock<HashMap<(Id, Version), revision::Record>>,
+    /// The indexed version and lowercase text of each thought.
+    search_index: RwLock<HashMap<Id, (Version, String)>>,
+    storage_issues: RwLock<Vec<StorageIssue>>,
+    last_id: RwLock<u64>,
+}
+
+impl MockDb {
+    fn next_id(&self) -> u64 {
+        let mut id = self.last_id.write();
+        *id += 1;
+        *id
+    }
+    fn add_thought(&self, id: u64) -> Id {
+        let id = Id::new(id);
+        let thought = Thought::new(id, Title::new("foo".into()), HashSet::new(), NOW, NOW);
+        self.thoughts
+            .write()
+            .insert(id, thought::Record { thought });
+        id
+    }
+    fn add_area_of_life(&self, id: u64, parent: Option<u64>) -> aol::Id {
+        let id = aol::Id::new(id);
+        let area_of_life = AreaOfLife::new(id, aol::Name::new(format!("aol {id}")), NOW, NOW)
+            .with_parent(parent.map(aol::Id::new));
+        self.areas_of_life
+            .write()
+            .insert(id, area_of_life::Record { area_of_life });
+        id
+    }
+    fn add_tag(&self, id: u64) -> tg::Id {
+        let id = tg::Id::new(id);
+        let tag = Tag::new(id, tg::Name::new(format!("tag {id}")), NOW, NOW);
+        self.tags.write().insert(id, tag::Record { tag });
+        id
+    }
+    /// Every version saved by a usecase must be recorded as a revision
+    /// (the fixtures above are stored without one).
+    fn assert_revisions_recorded(&self) {
+        let revisions = self.revisions.read();
+        for (&id, thought::Record { thought }) in self.thoughts.read().iter() {
+            let version = thought.version();
+            assert!(
+                version == Version::INITIAL || revisions.contains_key(&(id, version)),
+                "Thought {id} has no revision of version {version:?}"
+            );
+        }
+    }
+    fn indexed_version(&self, id: Id) -> Option<Version> {
+        self.search_index
+            .read()
+            .get(&id)
+            .map(|(version, _)| *version)
+    }
+}
+
+#[async_trait]
+impl thought::Repo for MockDb {
+    async fn save(&self, record: thought::Record) -> Result<(), thought::SaveError> {
+        self.thoughts.write().insert(record.thought.id(), record);
+        Ok(())
+    }
+    async fn get(&self, id: Id) -> Result<thought::Record, thought::GetError> {
+        self.thoughts
+            .read()
+            .get(&id)
+            .cloned()
+            .ok_or(thought::GetError::NotFound)
+    }
+    async fn get_all(&self) -> Result<Vec<thought::Record>, thought::GetAllError> {
+        Ok(self.thoughts.read().values().cloned().collect())
+    }
+    async fn delete(&self, id: Id) -> Result<(), thought::DeleteError> {
+        self.thoughts
+            .write()
+            .remove(&id)
+            .map(|_| ())
+            .ok_or(thought::DeleteError::NotFound)
+    }
+}
+
+#[async_trait]
+impl area_of_life::Repo for MockDb {
+    async fn save(&self, record: area_of_life::Record) -> Result<(), area_of_life::SaveError> {
+        self.areas_of_life
+            .write()
+            .insert(record.area_of_life.id(), record);
+        Ok(())
+    }
+    async fn get(&self, id: aol::Id) -> Result<area_of_life::Record, area_of_life::GetError> {
+        self.areas_of_life
+            .read()
+            .get(&id)
+            .cloned()
+            .ok_or(area_of_life::GetError::NotFound)
+    }
+    async fn get_by_normalized_name(
+        &self,
+        name: &str,
+    ) -> Result<area_of_life::Record, area_of_life::GetError> {
+        self.areas_of_life
+            .read()
+            .values()
+            .find(|r| r.area_of_life.name().normalized() == name)
+            .cloned()
+            .ok_or(area_of_life::GetError::NotFound)
+    }
+    async fn get_all(&self) -> Result<Vec<area_of_life::Record>, area_of_life::GetAllError> {
+        Ok(self.areas_of_life.read().values().cloned().collect())
+    }
+    async fn delete(&self, id: aol::Id) -> Result<(), area_of_life::DeleteError> {
+        self.areas_of_life
+            .write()
+            .remove(&id)
+            .map(|_| ())
+            .ok_or(area_of_life::DeleteError::NotFound)
+    }
+}
+
+#[async_trait]
+impl tag::Repo for MockDb {
+    async fn save(&self, record: tag::Record) -> Result<(), tag::SaveError> {
+        self.tags.write().insert(record.tag.id(), record);
+        Ok(())
+    }
+    async fn get(&self, id: tg::Id) -> Result<tag::Record, tag::GetError> {
+        self.tags
+            .read()
+            .get(&id)
+            .cloned()
+            .ok_or(tag::GetError::NotFound)
+    }
+    async fn get_all(&self) -> Result<Vec<tag::Record>, tag::GetAllError> {
+        Ok(self.tags.read().values().cloned().collect())
+    }
+    async fn delete(&self, id: tg::Id) -> Result<(), tag::DeleteError> {
+        self.tags
+            .write()
+            .remove(&id)
+            .map(|_| ())
+            .ok_or(tag::DeleteError::NotFound)
+    }
+}
+
+#[async_trait]
+impl relation::Repo for MockDb {
+    async fn save(&self, record: relation::Record) -> Result<(), relation::SaveError> {
+        self.relations.write().insert(record.relation);
+        Ok(())
+    }
+    async fn get_by_thought(&self, id: Id) -> Result<Vec<relation::Record>, relation::GetAllError> {
+        Ok(self
+            .relations
+            .read()
+            .iter()
+            .filter(|r| r.source() == id || r.target() == id)
+            .map(|&relation| relation::Record { relation })
+            .collect())
+    }
+    async fn delete(&self, relation: &Relation) -> Result<(), relation::DeleteError> {
+        if self.relations.write().remove(relation) {
+            Ok(())
+        } else {
+            Err(relation::DeleteError::NotFound)
+        }
+    }
+}
+
+#[async_trait]
+impl revision::Repo for MockDb {
+    async fn save(&self, record: revision::Record) -> Result<(), revision::SaveError> {
+        let key = (record.thought.id(), record.thought.version());
+        self.revisions.write().insert(key, record);
+        Ok(())
+    }
+    async fn get(&self, id: Id, version: Version) -> Result<revision::Record, revision::GetError> {
+        self.revisions
+            .read()
+            .get(&(id, version))
+            .cloned()
+            .ok_or(revision::GetError::NotFound)
+    }
+    async fn get_by_thought(&self, id: Id) -> Result<Vec<revision::Record>, revision::GetAllError> {
+        let mut records = self
+            .revisions
+            .read()
+            .values()
+            .filter(|r| r.thought.id() == id)
+            .cloned()
+            .collect::<Vec<_>>();
+        records.sort_by_key(|r| r.thought.version());
+        Ok(records)
+    }
+    async fn delete_by_thought(&self, id: Id) -> Result<(), revision::DeleteError> {
+        self.revisions
+            .write()
+            .retain(|(thought, _), _| *thought != id);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for MockDb {
+    async fn commit(&self, changes: Changes) -> Result<(), CommitError> {
+        let thoughts = self.thoughts.read();
+        let areas_of_life = self.areas_of_life.read();
+        changes.check_versions(
+            |id| Ok(thoughts.get(&id).map(|r| r.thought.version())),
+            |id| Ok(areas_of_life.get(&id).map(|r| r.area_of_life.version())),
+        )?;
+        drop((thoughts, areas_of_life));
+        for change in changes {
+            match change {
+                Change::SaveThought(record) => {
+                    self.thoughts.write().insert(record.thought.id(), record);
+                }
+                Change::DeleteThought(id) => {
+                    self.thoughts.write().remove(&id);
+                }
+                Change::SaveAreaOfLife(record) => {
+                    self.areas_of_life
+                        .write()
+                        .insert(record.area_of_life.id(), record);
+                }
+                Change::DeleteAreaOfLife(id) => {
+                    self.areas_of_life.write().remove(&id);
+                }
+                Change::DeleteTag(id) => {
+                    self.tags.write().remove(&id);
+                }
+                Change::SaveRevision(record) => {
+                    let key = (record.thought.id(), record.thought.version());
+                    self.revisions.write().insert(key, record);
+                }
+                Change::DeleteRevisions(id) => {
+                    self.revisions
+                        .write()
+                        .retain(|(thought, _), _| *thought != id);
+                }
+                Change::DeleteRelation(relation) => {
+                    self.relations.write().remove(&relation);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Integrity for MockDb {
+    async fn check_storage(&self) -> Result<Vec<StorageIssue>, integrity::CheckError> {
+        Ok(self.storage_issues.read().clone())
+    }
+    async fn repair_storage(&self, issue: StorageIssue) -> Result<(), integrity::RepairError> {
+        self.storage_issues.write().retain(|i| *i != issue);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SearchIndex for MockDb {
+    async fn index(&self, thought: &Thought) -> Result<(), search_index::IndexError> {
+        let text = format!(
+            "{} {}",
+            thought.title().as_ref(),
+            thought.body().map_or("", AsRef::as_ref)
+        );
+        self.search_index
+            .write()
+            .insert(thought.id(), (thought.version(), text.to_lowercase()));
+        Ok(())
+    }
+    async fn remove(&self, id: Id) -> Result<(), search_index::IndexError> {
+        self.search_index.write().remove(&id);
+        Ok(())
+    }
+    async fn search(&self, query: &str) -> Result<Vec<Hit>, search_index::SearchError> {
+        let query = query.to_lowercase();
+        let mut hits = self
+            .search_index
+            .read()
+            .iter()
+            .filter(|(_, (_, text))| query.split_whitespace().all(|word| text.contains(word)))
+            .map(|(id, _)| Hit {
+                id: *id,
+                score: 1.0,
+            })
+            .collect::<Vec<_>>();
+        hits.sort_by_key(|hit| hit.id);
+        Ok(hits)
+    }
+}
+
+#[async_trait]
+impl NewId<Id> for MockDb {
+    async fn new_id(&self) -> Result<Id, NewIdError> {
+        Ok(Id::new(self.next_id()))
+    }
+}
+
+#[async_trait]
+impl NewId<aol::Id> for MockDb {
+    async fn new_id(&self) -> Result<aol::Id, NewIdError> {
+        Ok(aol::Id::new(self.next_id()))
+    }
+}
+
+#[async_trait]
+impl NewId<tg::Id> for MockDb {
+    async fn new_id(&self) -> Result<tg::Id, NewIdError> {
+        Ok(tg::Id::new(self.next_id()))
+    }
+}
+
+struct FixedClock;
+
+impl Clock for FixedClock {
+    fn now(&self) -> Timestamp {
+        NOW
+    }
+}
+
+#[derive(Default)]
+struct Recorder(RwLock<Vec<Event>>);
+
+impl Recorder {
+    fn take(&self) -> Vec<Event> {
+        std::mem::take(&mut *self.0.write())
+    }
+}
+
+impl EventPublisher for Recorder {
+    fn publish(&self, event: Event) {
+        self.0.write().push(event);
+    }
+}
+
+#[tokio::test]
+async fn thought_usecases() {
+    use usecase::thought::{change_status, create, delete, update};
+    let db = MockDb::default();
+    let events = Recorder::default();
+
+    let req = create::Request {
+        title: "foo".into(),
+        body: None,
+        areas_of_life: HashSet::new(),
+        tags: HashSet::new(),
+        due_at: None,
+        reminder_offset: None,
+    };
+    let create::Response { id } = create::CreateThought::new(&db, &db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    assert_eq!(events.take(), vec![Event::ThoughtCreated(id)]);
+
+    let req = update::Request {
+        id,
+        title: "bar".into(),
+        body: None,
+        areas_of_life: HashSet::new(),
+        tags: HashSet::new(),
+        due_at: None,
+        reminder_offset: None,
+        version: None,
+    };
+    update::UpdateThought::new(&db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    assert_eq!(events.take(), vec![Event::ThoughtUpdated(id)]);
+
+    let req = change_status::Request {
+        id,
+        status: Status::Active,
+    };
+    change_status::ChangeStatus::new(&db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    assert_eq!(
+        events.take(),
+        vec![Event::ThoughtStatusChanged {
+            id,
+            from: Status::Inbox,
+            to: Status::Active
+        }]
+    );
+
+    delete::Delete::new(&db, &FixedClock, &events)
+        .exec(delete::Request { id })
+        .await
+        .unwrap();
+    assert_eq!(events.take(), vec![Event::ThoughtDeleted(id)]);
+    db.assert_revisions_recorded();
+}
+
+#[tokio::test]
+async fn no_events_on_failure() {
+    use usecase::thought::{change_status, delete, update};
+    let db = MockDb::default();
+    let events = Recorder::default();
+    let id = db.add_thought(1);
+    let req = change_status::Request {
+        id,
+        status: Status::Done,
+    };
+    change_status::ChangeStatus::new(&db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    events.take();
+    let req = change_status::Request {
+        id,
+        status: Status::Done,
+    };
+    // Done → Done is no valid transition
+    assert!(change_status::ChangeStatus::new(&db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .is_err());
+    let req = update::Request {
+        id,
+        title: "bar".into(),
+        body: None,
+        areas_of_life: HashSet::new(),
+        tags: HashSet::new(),
+        due_at: None,
+        reminder_offset: None,
+        // The status change bumped the version
+        version: Some(Version::INITIAL),
+    };
+    assert!(matches!(
+        update::UpdateThought::new(&db, &FixedClock, &events)
+            .exec(req)
+            .await,
+        Err(update::Error::Conflict)
+    ));
+    let req = delete::Request { id: Id::new(99) };
+    assert!(delete::Delete::new(&db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .is_err());
+    assert!(events.take().is_empty());
+}
+
+#[tokio::test]
+async fn relation_usecases() {
+    use usecase::{
+        relation::{link, unlink},
+        thought::delete,
+        trash::purge,
+    };
+    let db = MockDb::default();
+    let events = Recorder::default();
+    let a = db.add_thought(1);
+    let b = db.add_thought(2);
+    let c = db.add_thought(3);
+
+    let req = link::Request {
+        source: a,
+        kind: Kind::DependsOn,
+        target: b,
+    };
+    link::LinkThoughts::new(&db, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    let a_b = Relation::new(a, Kind::DependsOn, b);
+    assert_eq!(events.take(), vec![Event::ThoughtsLinked(a_b)]);
+
+    let req = unlink::Request {
+        source: a,
+        kind: Kind::DependsOn,
+        target: b,
+    };
+    unlink::UnlinkThoughts::new(&db, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    assert_eq!(events.take(), vec![Event::ThoughtsUnlinked(a_b)]);
+
+    let c_a = Relation::new(c, Kind::RelatesTo, a);
+    let req = link::Request {
+        source: c,
+        kind: Kind::RelatesTo,
+        target: a,
+    };
+    link::LinkThoughts::new(&db, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    events.take();
+    delete::Delete::new(&db, &FixedClock, &events)
+        .exec(delete::Request { id: a })
+        .await
+        .unwrap();
+    assert_eq!(events.take(), vec![Event::ThoughtDeleted(a)]);
+    let req = purge::Request {
+        retention: Duration::ZERO,
+    };
+    purge::Purge::new(&db, &FixedClock, &events)
+        .exec(&req)
+        .await
+        .unwrap();
+    assert_eq!(
+        events.take(),
+        vec![Event::ThoughtPurged(a), Event::ThoughtsUnlinked(c_a)]
+    );
+}
+
+#[tokio::test]
+async fn area_of_life_usecases() {
+    use usecase::area_of_life::{create, delete, update};
+    let db = MockDb::default();
+    let events = Recorder::default();
+
+    let req = create::Request {
+        name: "Career".into(),
+        parent: None,
+    };
+    let create::Response { id } = create::CreateAreaOfLife::new(&db, &db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    assert_eq!(events.take(), vec![Event::AreaOfLifeCreated(id)]);
+
+    let req = update::Request {
+        id,
+        name: "Profession".into(),
+        parent: None,
+        version: None,
+    };
+    update::UpdateAreaOfLife::new(&db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    assert_eq!(events.take(), vec![Event::AreaOfLifeUpdated(id)]);
+
+    let req = delete::Request {
+        id,
+        children: delete::Children::Reparent,
+        references: delete::References::Detach,
+    };
+    delete::Delete::new(&db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    assert_eq!(events.take(), vec![Event::AreaOfLifeDeleted(id)]);
+}
+
+#[tokio::test]
+async fn delete_nested_areas_of_life() {
+    use usecase::area_of_life::delete::{Children, Delete, References, Request};
+    let db = MockDb::default();
+    let events = Recorder::default();
+
+    // Career → Project X → Research
+    let career = db.add_area_of_life(1, None);
+    let project = db.add_area_of_life(2, Some(1));
+    let research = db.add_area_of_life(3, Some(2));
+
+    let req = Request {
+        id: project,
+        children: Children::Reparent,
+        references: References::Detach,
+    };
+    Delete::new(&db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    assert_eq!(
+        events.take(),
+        vec![
+            Event::AreaOfLifeUpdated(research),
+            Event::AreaOfLifeDeleted(project)
+        ]
+    );
+
+    let req = Request {
+        id: career,
+        children: Children::Cascade,
+        references: References::Detach,
+    };
+    Delete::new(&db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    assert_eq!(
+        events.take(),
+        vec![
+            Event::AreaOfLifeDeleted(research),
+            Event::AreaOfLifeDeleted(career)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn delete_area_of_life_referenced_by_thoughts() {
+    use usecase::area_of_life::delete::{Children, Delete, Error, References, Request};
+    let db = MockDb::default();
+    let events = Recorder::default();
+    let interactor = Delete::new(&db, &FixedClock, &events);
+    let delete = |id, references| {
+        interactor.exec(Request {
+            id,
+            children: Children::Reparent,
+            references,
+        })
+    };
+    let areas_of_life = |id| db.thoughts.read()[&id].thought.areas_of_life().clone();
+
+    let job = db.add_area_of_life(1, None);
+    let career = db.add_area_of_life(2, None);
+    let hobby = db.add_area_of_life(3, None);
+    let id = db.add_thought(1);
+    db.thoughts.write().entry(id).and_modify(|r| {
+        r.thought = r.thought.clone().with_areas_of_life([job, career].into());
+    });
+
+    let err = delete(job, References::Restrict).await.unwrap_err();
+    assert!(matches!(err, Error::Referenced(1)));
+    assert_eq!(areas_of_life(id), HashSet::from([job, career]));
+    assert!(events.take().is_empty());
+
+    let res = delete(job, References::Reassign(career)).await.unwrap();
+    assert_eq!(res.thoughts, 1);
+    assert_eq!(areas_of_life(id), HashSet::from([career]));
+    assert_eq!(
+        events.take(),
+        vec![Event::ThoughtUpdated(id), Event::AreaOfLifeDeleted(job)]
+    );
+
+    let err = delete(hobby, References::Reassign(job)).await.unwrap_err();
+    assert!(matches!(err, Error::TargetNotFound(target) if target == job));
+    assert!(events.take().is_empty());
+
+    let res = delete(career, References::Detach).await.unwrap();
+    assert_eq!(res.thoughts, 1);
+    assert!(areas_of_life(id).is_empty());
+    assert_eq!(
+        events.take(),
+        vec![Event::ThoughtUpdated(id), Event::AreaOfLifeDeleted(career)]
+    );
+
+    let res = delete(hobby, References::Restrict).await.unwrap();
+    assert_eq!(res.thoughts, 0);
+    assert_eq!(events.take(), vec![Event::AreaOfLifeDeleted(hobby)]);
+    db.assert_revisions_recorded();
+}
+
+#[tokio::test]
+async fn merge_areas_of_life() {
+    use usecase::area_of_life::merge::{Error, Merge, Request, Response};
+    let db = MockDb::default();
+    let events = Recorder::default();
+
+    // Work → Project X, Job
+    let work = db.add_area_of_life(1, None);
+    let project = db.add_area_of_life(2, Some(1));
+    let job = db.add_area_of_life(3, None);
+    let id = db.add_thought(1);
+    db.thoughts.write().entry(id).and_modify(|r| {
+        r.thought = r.thought.clone().with_areas_of_life([work, job].into());
+    });
+
+    let err = Merge::new(&db, &FixedClock, &events)
+        .exec(Request {
+            source: job,
+            target: job,
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Same(_)));
+    let err = Merge::new(&db, &FixedClock, &events)
+        .exec(Request {
+            source: work,
+            target: project,
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Cycle(_)));
+
+    let Response { thoughts } = Merge::new(&db, &FixedClock, &events)
+        .exec(Request {
+            source: work,
+            target: job,
+        })
+        .await
+        .unwrap();
+    assert_eq!(thoughts, 1);
+    assert_eq!(
+        events.take(),
+        vec![
+            Event::AreaOfLifeUpdated(project),
+            Event::ThoughtUpdated(id),
+            Event::AreaOfLifeMerged {
+                source: work,
+                target: job
+            }
+        ]
+    );
+    assert_eq!(
+        db.thoughts.read()[&id].thought.areas_of_life(),
+        &[job].into()
+    );
+    assert_eq!(
+        db.areas_of_life.read()[&project].area_of_life.parent(),
+        Some(job)
+    );
+    assert!(!db.areas_of_life.read().contains_key(&work));
+
+    let err = Merge::new(&db, &FixedClock, &events)
+        .exec(Request {
+            source: work,
+            target: job,
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::NotFound(id) if id == work));
+    db.assert_revisions_recorded();
+}
+
+#[tokio::test]
+async fn area_of_life_details() {
+    use usecase::area_of_life::{find_by_id, thoughts};
+    let db = MockDb::default();
+
+    // Health → Sports
+    let health = db.add_area_of_life(1, None);
+    db.add_area_of_life(2, Some(1));
+    for (id, status) in [(1, Status::Inbox), (2, Status::Done), (3, Status::Done)] {
+        let id = db.add_thought(id);
+        db.thoughts.write().entry(id).and_modify(|r| {
+            r.thought = r
+                .thought
+                .clone()
+                .with_areas_of_life([health].into())
+                .with_status(status);
+        });
+    }
+    db.thoughts.write().entry(Id::new(3)).and_modify(|r| {
+        r.thought = r.thought.clone().with_deleted_at(Some(NOW));
+    });
+    db.add_thought(4);
+
+    let find_by_id::Response {
+        area_of_life,
+        children,
+        thoughts,
+    } = find_by_id::FindById::new(&db)
+        .exec(find_by_id::Request { id: health })
+        .await
+        .unwrap();
+    assert_eq!(area_of_life.id, health);
+    assert_eq!(children, 1);
+    assert_eq!(
+        thoughts,
+        find_by_id::Counts {
+            total: 2,
+            inbox: 1,
+            done: 1,
+            ..Default::default()
+        }
+    );
+
+    let thoughts::Response { thoughts } = thoughts::ReadThoughts::new(&db)
+        .exec(thoughts::Request {
+            id: health,
+            sort: Default::default(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        thoughts.iter().map(|t| t.id).collect::<Vec<_>>(),
+        vec![Id::new(1), Id::new(2)]
+    );
+
+    let missing = aol::Id::new(99);
+    let err = find_by_id::FindById::new(&db)
+        .exec(find_by_id::Request { id: missing })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, find_by_id::Error::NotFound(id) if id == missing));
+    let err = thoughts::ReadThoughts::new(&db)
+        .exec(thoughts::Request {
+            id: missing,
+            sort: Default::default(),
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, thoughts::Error::NotFound(id) if id == missing));
+}
+
+#[tokio::test]
+async fn check_integrity() {
+    use usecase::integrity::check::{Check, Finding, Request, Response};
+    let db = MockDb::default();
+    let events = Recorder::default();
+
+    db.storage_issues
+        .write()
+        .push(StorageIssue::IdCounterDrift {
+            entity: integrity::Entity::Thought,
+            counter: 0,
+            max: 1,
+        });
+    let health = db.add_area_of_life(1, Some(9));
+    let invalid = aol::Id::new(2);
+    let area_of_life = AreaOfLife::new(invalid, aol::Name::new("x".into()), NOW, NOW);
+    db.areas_of_life
+        .write()
+        .insert(invalid, area_of_life::Record { area_of_life });
+    let id = db.add_thought(1);
+    db.thoughts.write().entry(id).and_modify(|r| {
+        r.thought = r
+            .thought
+            .clone()
+            .with_areas_of_life([health, aol::Id::new(7)].into())
+            .with_tags([tg::Id::new(8)].into());
+    });
+
+    let Response { findings, repaired } = Check::new(&db, &FixedClock, &events)
+        .exec(Request { fix: false })
+        .await
+        .unwrap();
+    assert_eq!(findings.len(), 5);
+    assert_eq!(repaired, 0);
+    assert!(events.take().is_empty());
+    assert_eq!(db.thoughts.read()[&id].thought.areas_of_life().len(), 2);
+
+    let Response { findings, repaired } = Check::new(&db, &FixedClock, &events)
+        .exec(Request { fix: true })
+        .await
+        .unwrap();
+    assert_eq!(findings.len(), 5);
+    assert_eq!(repaired, 4);
+    assert_eq!(
+        events.take(),
+        vec![Event::AreaOfLifeUpdated(health), Event::ThoughtUpdated(id)]
+    );
+    let thought = db.thoughts.read()[&id].thought.clone();
+    assert_eq!(thought.areas_of_life(), &[health].into());
+    assert!(thought.tags().is_empty());
+    // The repaired thought is indexed
+    let req = usecase::thought::search::Request {
+        query: "foo".into(),
+    };
+    let usecase::thought::search::Response { thoughts } =
+        usecase::thought::search::Search::new(&db)
+            .exec(req)
+            .await
+            .unwrap();
+    assert_eq!(thoughts.len(), 1);
+    assert_eq!(thoughts[0].id, id);
+    assert_eq!(db.indexed_version(id), Some(thought.version()));
+    assert_eq!(db.areas_of_life.read()[&health].area_of_life.parent(), None);
+
+    // Invalid properties are left to the user
+    let Response { findings, repaired } = Check::new(&db, &FixedClock, &events)
+        .exec(Request { fix: true })
+        .await
+        .unwrap();
+    assert_eq!(repaired, 0);
+    assert!(matches!(
+        findings[..],
+        [Finding::InvalidAreaOfLife { area_of_life, .. }] if area_of_life == invalid
+    ));
+    db.assert_revisions_recorded();
+}
+
+#[tokio::test]
+async fn custom_validation_policies() {
+    use usecase::{
+        area_of_life::{self as aol_uc, validate::NameInvalidity as AreaOfLifeNameInvalidity},
+        integrity::check::{Check, Finding, Policies, Request, Response},
+        tag::{self as tag_uc, validate::NameInvalidity as TagNameInvalidity},
+        text::LengthLimits,
+        thought::{
+            create,
+            validate::{ThoughtInvalidity, TitleInvalidity},
+        },
+    };
+    let db = MockDb::default();
+    let events = Recorder::default();
+    let short = LengthLimits { min: 1, max: 2 };
+    let policies = Policies {
+        thought: usecase::thought::validate::Policy {
+            title: short,
+            ..Policies::DEFAULT.thought
+        },
+        area_of_life: aol_uc::validate::Policy { name: short },
+        tag: tag_uc::validate::Policy { name: short },
+    };
+
+    let req = create::Request {
+        title: "Buy milk".into(),
+        body: None,
+        areas_of_life: HashSet::new(),
+        tags: HashSet::new(),
+        due_at: None,
+        reminder_offset: None,
+    };
+    let err = create::CreateThought::new(&db, &db, &FixedClock, &events)
+        .with_policy(policies.thought)
+        .exec(req)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        create::Error::Invalidity(ThoughtInvalidity::Title(TitleInvalidity::MaxLength {
+            max: 2,
+            actual: 8
+        }))
+    ));
+
+    let req = aol_uc::create::Request {
+        name: "Career".into(),
+        parent: None,
+    };
+    let err = aol_uc::create::CreateAreaOfLife::new(&db, &db, &FixedClock, &events)
+        .with_policy(policies.area_of_life)
+        .exec(req)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        aol_uc::create::Error::Invalidity(aol_uc::validate::AreaOfLifeInvalidity::Name(
+            AreaOfLifeNameInvalidity::MaxLength { max: 2, actual: 6 }
+        ))
+    ));
+
+    let tag = db.add_tag(1);
+    let req = tag_uc::update::Request {
+        id: tag,
+        name: "urgent".into(),
+    };
+    let err = tag_uc::update::UpdateTag::new(&db, &FixedClock, &events)
+        .with_policy(policies.tag)
+        .exec(req)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        tag_uc::update::Error::Invalidity(tag_uc::validate::TagInvalidity::Name(
+            TagNameInvalidity::MaxLength { max: 2, actual: 6 }
+        ))
+    ));
+    assert!(events.take().is_empty());
+
+    // Records that are valid by default violate the stricter policies
+    let thought = db.add_thought(1);
+    let Response { findings, .. } = Check::new(&db, &FixedClock, &events)
+        .exec(Request { fix: false })
+        .await
+        .unwrap();
+    assert!(findings.is_empty());
+    let Response { findings, repaired } = Check::new(&db, &FixedClock, &events)
+        .with_policies(policies)
+        .exec(Request { fix: true })
+        .await
+        .unwrap();
+    assert_eq!(repaired, 0);
+    assert_eq!(findings.len(), 2);
+    assert!(findings.iter().any(|f| matches!(
+        f,
+        Finding::InvalidThought { thought: id, .. } if *id == thought
+    )));
+    assert!(findings
+        .iter()
+        .any(|f| matches!(f, Finding::InvalidTag { tag: id, .. } if *id == tag)));
+}
+
+#[tokio::test]
+async fn trash_usecases() {
+    use usecase::{
+        area_of_life::delete as delete_aol,
+        thought::{create, delete, find_by_id},
+        trash::{purge, read_all, restore, Item},
+    };
+    let db = MockDb::default();
+    let events = Recorder::default();
+    let aol = db.add_area_of_life(1, None);
+    let req = create::Request {
+        title: "foo".into(),
+        body: None,
+        areas_of_life: [aol].into_iter().collect(),
+        tags: HashSet::new(),
+        due_at: None,
+        reminder_offset: None,
+    };
+    let create::Response { id } = create::CreateThought::new(&db, &db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    events.take();
+    async fn areas_of_life(db: &MockDb, id: Id) -> HashSet<aol::Id> {
+        find_by_id::FindById::new(db)
+            .exec(find_by_id::Request { id })
+            .await
+            .unwrap()
+            .areas_of_life
+    }
+
+    // Deleting an area of life removes it from its thoughts ...
+    let req = delete_aol::Request {
+        id: aol,
+        children: delete_aol::Children::Reparent,
+        references: delete_aol::References::Detach,
+    };
+    delete_aol::Delete::new(&db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    assert_eq!(
+        events.take(),
+        vec![Event::ThoughtUpdated(id), Event::AreaOfLifeDeleted(aol)]
+    );
+    assert!(areas_of_life(&db, id).await.is_empty());
+    let read_all::Response { entries } = read_all::ReadAll::new(&db)
+        .exec(read_all::Request)
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].item, Item::AreaOfLife(aol));
+    assert_eq!(entries[0].deleted_at, NOW);
+
+    // ... and restoring gives it back.
+    let req = restore::Request {
+        item: Item::AreaOfLife(aol),
+    };
+    restore::Restore::new(&db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    assert_eq!(
+        events.take(),
+        vec![Event::AreaOfLifeRestored(aol), Event::ThoughtUpdated(id)]
+    );
+    assert!(areas_of_life(&db, id).await.contains(&aol));
+
+    delete::Delete::new(&db, &FixedClock, &events)
+        .exec(delete::Request { id })
+        .await
+        .unwrap();
+    assert!(find_by_id::FindById::new(&db)
+        .exec(find_by_id::Request { id })
+        .await
+        .is_err());
+    db.assert_revisions_recorded();
+
+    // Keep everything that is younger than the retention period
+    let req = purge::Request {
+        retention: Duration::from_secs(60),
+    };
+    let res = purge::Purge::new(&db, &FixedClock, &events)
+        .exec(&req)
+        .await
+        .unwrap();
+    assert!(res.purged.is_empty());
+
+    let req = purge::Request {
+        retention: Duration::ZERO,
+    };
+    let res = purge::Purge::new(&db, &FixedClock, &events)
+        .exec(&req)
+        .await
+        .unwrap();
+    assert_eq!(res.purged, vec![Item::Thought(id)]);
+    assert_eq!(
+        events.take(),
+        vec![Event::ThoughtDeleted(id), Event::ThoughtPurged(id)]
+    );
+    let req = restore::Request {
+        item: Item::Thought(id),
+    };
+    assert!(matches!(
+        restore::Restore::new(&db, &FixedClock, &events)
+            .exec(req)
+            .await,
+        Err(restore::Error::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn revision_usecases() {
+    use usecase::{
+        revision::{diff, read_all, revert},
+        thought::{create, find_by_id, update},
+    };
+    let db = MockDb::default();
+    let events = Recorder::default();
+    let tag = db.add_tag(1);
+    let req = create::Request {
+        title: "foo".into(),
+        body: None,
+        areas_of_life: HashSet::new(),
+        tags: [tag].into_iter().collect(),
+        due_at: None,
+        reminder_offset: None,
+    };
+    let create::Response { id } = create::CreateThought::new(&db, &db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    let req = update::Request {
+        id,
+        title: "bar".into(),
+        body: Some("baz".into()),
+        areas_of_life: HashSet::new(),
+        tags: HashSet::new(),
+        due_at: None,
+        reminder_offset: None,
+        version: None,
+    };
+    update::UpdateThought::new(&db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    events.take();
+
+    let read_all::Response { revisions } = read_all::ReadRevisions::new(&db)
+        .exec(read_all::Request { id })
+        .await
+        .unwrap();
+    let titles = revisions
+        .iter()
+        .map(|r| (r.version.to_u64(), r.title.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(titles, vec![(1, "foo"), (2, "bar")]);
+
+    let req = diff::Request {
+        id,
+        from: Version::new(1),
+        to: Version::new(2),
+    };
+    let diff::Response { changes } = diff::DiffRevisions::new(&db).exec(req).await.unwrap();
+    assert_eq!(
+        changes,
+        vec![
+            diff::Change::Title {
+                old: "foo".into(),
+                new: "bar".into()
+            },
+            diff::Change::Body {
+                old: None,
+                new: Some("baz".into())
+            },
+            diff::Change::Tags {
+                added: vec![],
+                removed: vec![tag]
+            },
+        ]
+    );
+
+    let req = revert::Request {
+        id,
+        version: Version::new(99),
+    };
+    assert!(matches!(
+        revert::RevertThought::new(&db, &FixedClock, &events)
+            .exec(req)
+            .await,
+        Err(revert::Error::RevisionNotFound(_))
+    ));
+    assert!(events.take().is_empty());
+
+    let req = revert::Request {
+        id,
+        version: Version::INITIAL,
+    };
+    let revert::Response { version } = revert::RevertThought::new(&db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    assert_eq!(version, Version::new(3));
+    assert_eq!(events.take(), vec![Event::ThoughtUpdated(id)]);
+    let thought = find_by_id::FindById::new(&db)
+        .exec(find_by_id::Request { id })
+        .await
+        .unwrap();
+    assert_eq!(thought.title, "foo");
+    assert_eq!(thought.body, None);
+    assert!(thought.tags.contains(&tag));
+
+    // Reverting is recorded as a revision as well
+    let read_all::Response { revisions } = read_all::ReadRevisions::new(&db)
+        .exec(read_all::Request { id })
+        .await
+        .unwrap();
+    assert_eq!(revisions.len(), 3);
+}
+
+#[tokio::test]
+async fn batch_usecase() {
+    use usecase::thought::batch::{Batch, ItemError, Operation, Outcome, Request, Response};
+    let db = MockDb::default();
+    let events = Recorder::default();
+    let (a, b) = (db.add_thought(1), db.add_thought(2));
+    let aol = db.add_area_of_life(3, None);
+    let missing = Id::new(99);
+    let batch = |operation| async {
+        let req = Request {
+            ids: vec![a, b, a, missing],
+            operation,
+        };
+        let Response { reports } = Batch::new(&db, &FixedClock, &events)
+            .exec(req)
+            .await
+            .unwrap();
+        reports
+    };
+
+    let reports = batch(Operation::AssignAreaOfLife(aol)).await;
+    assert_eq!(reports.len(), 3);
+    assert!(matches!(reports[0].outcome, Outcome::Changed));
+    assert!(matches!(reports[1].outcome, Outcome::Changed));
+    assert!(matches!(
+        reports[2].outcome,
+        Outcome::Failed(ItemError::NotFound)
+    ));
+    assert_eq!(
+        events.take(),
+        vec![Event::ThoughtUpdated(a), Event::ThoughtUpdated(b)]
+    );
+    assert!(db.thoughts.read()[&b]
+        .thought
+        .areas_of_life()
+        .contains(&aol));
+
+    let reports = batch(Operation::AssignAreaOfLife(aol)).await;
+    assert!(matches!(reports[0].outcome, Outcome::Unchanged));
+    assert!(events.take().is_empty());
+
+    let reports = batch(Operation::ReplaceInTitle {
+        find: "o".into(),
+        replace: "u".into(),
+    })
+    .await;
+    assert!(matches!(reports[1].outcome, Outcome::Changed));
+    assert_eq!(db.thoughts.read()[&b].thought.title().as_ref(), "fuu");
+    assert_eq!(events.take().len(), 2);
+
+    let reports = batch(Operation::ReplaceInTitle {
+        find: "fuu".into(),
+        replace: String::new(),
+    })
+    .await;
+    assert!(matches!(
+        reports[0].outcome,
+        Outcome::Failed(ItemError::Invalidity(_))
+    ));
+    assert!(events.take().is_empty());
+
+    batch(Operation::Delete).await;
+    assert_eq!(
+        events.take(),
+        vec![Event::ThoughtDeleted(a), Event::ThoughtDeleted(b)]
+    );
+    let reports = batch(Operation::UnassignAreaOfLife(aol)).await;
+    assert!(matches!(
+        reports[0].outcome,
+        Outcome::Failed(ItemError::NotFound)
+    ));
+    db.assert_revisions_recorded();
+}
+
+#[tokio::test]
+async fn tag_usecases() {
+    use usecase::tag::{create, delete, update};
+    let db = MockDb::default();
+    let events = Recorder::default();
+
+    let req = create::Request { name: "foo".into() };
+    let create::Response { id } = create::CreateTag::new(&db, &db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    assert_eq!(events.take(), vec![Event::TagCreated(id)]);
+
+    let req = update::Request {
+        id,
+        name: "bar".into(),
+    };
+    update::UpdateTag::new(&db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    assert_eq!(events.take(), vec![Event::TagUpdated(id)]);
+
+    let other = db.add_tag(99);
+    let thought = db.add_thought(1);
+    db.thoughts.write().entry(thought).and_modify(|r| {
+        r.thought = r.thought.clone().with_tags([id, other].into());
+    });
+    delete::Delete::new(&db, &FixedClock, &events)
+        .exec(delete::Request { id: other })
+        .await
+        .unwrap();
+    assert_eq!(
+        events.take(),
+        vec![Event::ThoughtUpdated(thought), Event::TagDeleted(other)]
+    );
+    let stored = db.thoughts.read()[&thought].thought.clone();
+    assert_eq!(stored.tags(), &HashSet::from([id]));
+    assert_eq!(stored.version(), Version::INITIAL.next());
+    assert!(db.tags.read().get(&other).is_none());
+
+    let err = delete::Delete::new(&db, &FixedClock, &events)
+        .exec(delete::Request { id: other })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, delete::Error::NotFound));
+    assert!(events.take().is_empty());
+    db.assert_revisions_recorded();
+}
+
+#[tokio::test]
+async fn search_usecase() {
+    use usecase::{
+        thought::{change_status, create, delete, search, update},
+        trash::{restore, Item},
+    };
+    let db = MockDb::default();
+    let events = Recorder::default();
+    let mut ids = vec![];
+    for title in ["Buy milk", "Fix the bike"] {
+        let req = create::Request {
+            title: title.into(),
+            body: None,
+            areas_of_life: HashSet::new(),
+            tags: HashSet::new(),
+            due_at: None,
+            reminder_offset: None,
+        };
+        let create::Response { id } = create::CreateThought::new(&db, &db, &FixedClock, &events)
+            .exec(req)
+            .await
+            .unwrap();
+        ids.push(id);
+    }
+    let search = |query: &str| {
+        let req = search::Request {
+            query: query.into(),
+        };
+        let db = &db;
+        async move {
+            search::Search::new(db)
+                .exec(req)
+                .await
+                .map(|res| res.thoughts.into_iter().map(|t| t.id).collect::<Vec<_>>())
+        }
+    };
+
+    assert!(matches!(search("  ").await, Err(search::Error::EmptyQuery)));
+    assert_eq!(search("MILK").await.unwrap(), vec![ids[0]]);
+
+    // Updates are indexed ...
+    let req = update::Request {
+        id: ids[1],
+        title: "Fix the bike".into(),
+        body: Some("and buy new lights".into()),
+        areas_of_life: HashSet::new(),
+        tags: HashSet::new(),
+        due_at: None,
+        reminder_offset: None,
+        version: None,
+    };
+    update::UpdateThought::new(&db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    assert_eq!(search("buy").await.unwrap(), ids);
+
+    // ... trashed thoughts are not found ...
+    delete::Delete::new(&db, &FixedClock, &events)
+        .exec(delete::Request { id: ids[0] })
+        .await
+        .unwrap();
+    assert_eq!(search("buy").await.unwrap(), vec![ids[1]]);
+
+    // ... until they are restored.
+    let req = restore::Request {
+        item: Item::Thought(ids[0]),
+    };
+    restore::Restore::new(&db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    assert_eq!(search("buy").await.unwrap(), ids);
+
+    // A status change is indexed as well.
+    let req = change_status::Request {
+        id: ids[1],
+        status: Status::Done,
+    };
+    change_status::ChangeStatus::new(&db, &FixedClock, &events)
+        .exec(req)
+        .await
+        .unwrap();
+    assert_eq!(search("bike").await.unwrap(), vec![ids[1]]);
+    let thought = db.thoughts.read()[&ids[1]].thought.clone();
+    assert_eq!(thought.status(), Status::Done);
+    assert_eq!(db.indexed_version(ids[1]), Some(thought.version()));
+    db.assert_revisions_recorded();
+}
+
+#[tokio::test]
+async fn read_all_thoughts_query() {
+    use crate::gateway::repository::{
+        query::{Direction, Page, Sort},
+        thought::{Filter, Query, SortKey},
+    };
+    use usecase::thought::read_all;
+    let db = MockDb::default();
+    let aol = db.add_area_of_life(1, None);
+    for (id, title, due_at) in [(1, "b", Some(20)), (2, "C", None), (3, "a", Some(10))] {
+        let thought = Thought::new(
+            Id::new(id),
+            Title::new(title.into()),
+            HashSet::from([aol]),
+            NOW,
+            NOW,
+        )
+        .with_due(due_at.map(Timestamp::from_unix_millis), None);
+        db.thoughts
+            .write()
+            .insert(thought.id(), thought::Record { thought });
+    }
+    db.add_thought(4);
+    let read_all = |query: Query| {
+        let db = &db;
+        async move {
+            read_all::ReadAll::new(db)
+                .exec(read_all::Request { query })
+                .await
+                .unwrap()
+        }
+    };
+    let ids = |res: &read_all::Response| res.thoughts.iter().map(|t| t.id).collect::<Vec<_>>();
+
+    let res = read_all(Query::default()).await;
+    assert_eq!(ids(&res), [1, 2, 3, 4].map(Id::new));
+    assert_eq!(res.total, 4);
+    assert_eq!(res.next_offset, None);
+
+    let query = Query {
+        filter: Filter {
+            area_of_life: Some(aol),
+            ..Default::default()
+        },
+        sort: Sort {
+            key: SortKey::Title,
+            direction: Direction::Desc,
+        },
+        page: Page {
+            offset: 0,
+            limit: Some(2),
+        },
+    };
+    let res = read_all(query.clone()).await;
+    assert_eq!(ids(&res), [2, 1].map(Id::new));
+    assert_eq!(res.total, 3);
+    assert_eq!(res.next_offset, Some(2));
+
+    let query = Query {
+        page: Page {
+            offset: 2,
+            limit: Some(2),
+        },
+        ..query
+    };
+    let res = read_all(query).await;
+    assert_eq!(ids(&res), [Id::new(3)]);
+    assert_eq!(res.next_offset, None);
+
+    let query = Query {
+        sort: Sort {
+            key: SortKey::DueAt,
+            direction: Direction::Desc,
+        },
+        ..Default::default()
+    };
+    let res = read_all(query).await;
+    assert_eq!(ids(&res), [1, 3, 4, 2].map(Id::new));
+}
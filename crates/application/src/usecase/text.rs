@@ -0,0 +1,86 @@
+//! Normalize and measure user-supplied text.
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The allowed length of a text in grapheme clusters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthLimits {
+    pub min: usize,
+    pub max: usize,
+}
+
+impl LengthLimits {
+    /// The required minimum is never below one grapheme cluster,
+    /// so blank text is rejected whatever the policy allows.
+    #[must_use]
+    pub const fn required_min(self) -> usize {
+        if self.min == 0 {
+            1
+        } else {
+            self.min
+        }
+    }
+}
+
+/// Trim surrounding whitespace and compose the text to Unicode NFC.
+#[must_use]
+pub fn normalize_line(text: &str) -> String {
+    text.trim().nfc().collect()
+}
+
+/// Compose the text to Unicode NFC but keep all whitespace
+/// (e.g. the indentation of Markdown).
+#[must_use]
+pub fn normalize_multiline(text: &str) -> String {
+    text.nfc().collect()
+}
+
+/// Count the user-perceived characters (extended grapheme clusters).
+#[must_use]
+pub fn grapheme_count(text: &str) -> usize {
+    text.graphemes(true).count()
+}
+
+/// Find the position (in grapheme clusters) of the first control character.
+///
+/// Tabs and line breaks are only accepted if `multiline` is `true`.
+#[must_use]
+pub fn find_control_character(text: &str, multiline: bool) -> Option<usize> {
+    text.graphemes(true).position(|g| {
+        g.chars()
+            .any(|c| c.is_control() && !(multiline && matches!(c, '\n' | '\r' | '\t')))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_graphemes_instead_of_bytes() {
+        assert_eq!(grapheme_count("Gesundheit & Größe"), 18);
+        // Family: man, woman, girl, boy
+        assert_eq!(
+            grapheme_count("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}"),
+            1
+        );
+        assert_eq!(grapheme_count(""), 0);
+    }
+
+    #[test]
+    fn compose_and_trim() {
+        // "e" followed by a combining acute accent
+        assert_eq!(normalize_line("  Cafe\u{301} \n"), "Caf\u{e9}");
+        assert_eq!(normalize_multiline("  Cafe\u{301}\n"), "  Caf\u{e9}\n");
+        assert_eq!(normalize_line(" \t "), "");
+    }
+
+    #[test]
+    fn detect_control_characters() {
+        assert_eq!(find_control_character("foo\u{7}bar", false), Some(3));
+        assert_eq!(find_control_character("foo\nbar", false), Some(3));
+        assert_eq!(find_control_character("foo\r\n\tbar", true), None);
+        assert_eq!(find_control_character("foo\u{0}", true), Some(3));
+    }
+}
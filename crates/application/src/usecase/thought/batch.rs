@@ -0,0 +1,243 @@
+use crate::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{
+        repository::{
+            area_of_life,
+            thought::{GetError, Record, Repo, SaveError},
+        },
+        search_index::SearchIndex,
+        unit_of_work::{Changes, CommitError, UnitOfWork},
+    },
+    usecase::{
+        area_of_life::get_untrashed as get_untrashed_aol,
+        text::normalize_line,
+        thought::validate::{self, validate_thought_properties, ThoughtInvalidity},
+    },
+};
+use cawr_domain::{
+    area_of_life as aol,
+    thought::{Id, Thought, Title},
+    Event,
+};
+use std::collections::HashSet;
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request {
+    /// The thoughts to change (duplicates are ignored).
+    pub ids: Vec<Id>,
+    pub operation: Operation,
+}
+
+/// What to do with each thought
+#[derive(Debug, Clone)]
+pub enum Operation {
+    /// Move the thoughts to the trash.
+    Delete,
+    /// Add an area of life to the thoughts.
+    AssignAreaOfLife(aol::Id),
+    /// Remove an area of life from the thoughts.
+    UnassignAreaOfLife(aol::Id),
+    /// Replace every occurrence of `find` in the titles.
+    ReplaceInTitle { find: String, replace: String },
+}
+
+#[derive(Debug)]
+pub struct Response {
+    /// One report per thought in the order of the request.
+    pub reports: Vec<Report>,
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub id: Id,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+    Changed,
+    /// The thought was already in the requested state.
+    Unchanged,
+    Failed(ItemError),
+}
+
+/// Why a single thought could not be changed
+#[derive(Debug, Error)]
+pub enum ItemError {
+    #[error("{}", GetError::NotFound)]
+    NotFound,
+    #[error(transparent)]
+    Invalidity(#[from] ThoughtInvalidity),
+    #[error("{}", SaveError::Conflict)]
+    Conflict,
+    #[error("{}", SaveError::Connection)]
+    Repo,
+}
+
+impl From<GetError> for ItemError {
+    fn from(e: GetError) -> Self {
+        match e {
+            GetError::NotFound => Self::NotFound,
+            GetError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<CommitError> for ItemError {
+    fn from(e: CommitError) -> Self {
+        match e {
+            CommitError::Connection => Self::Repo,
+            CommitError::Conflict => Self::Conflict,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("The text to find must not be empty")]
+    EmptyFind,
+    #[error("Area of life {0} not found")]
+    AreaOfLifeNotFound(aol::Id),
+    #[error("{}", SaveError::Connection)]
+    Repo,
+}
+
+/// Change many thoughts at once usecase interactor
+///
+/// Each thought is changed on its own, so a failure
+/// is reported for that thought and does not affect the others.
+pub struct Batch<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> {
+    repo: &'r R,
+    clock: &'c C,
+    events: &'e E,
+    policy: validate::Policy,
+}
+
+impl<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> Batch<'r, 'c, 'e, R, C, E> {
+    pub const fn new(repo: &'r R, clock: &'c C, events: &'e E) -> Self {
+        Self {
+            repo,
+            clock,
+            events,
+            policy: validate::Policy::DEFAULT,
+        }
+    }
+    /// Replace the default validation [`validate::Policy`].
+    #[must_use]
+    pub const fn with_policy(mut self, policy: validate::Policy) -> Self {
+        self.policy = policy;
+        self
+    }
+}
+
+impl<'r, 'c, 'e, R, C, E> Batch<'r, 'c, 'e, R, C, E>
+where
+    R: Repo + area_of_life::Repo + SearchIndex + UnitOfWork,
+    C: Clock + ?Sized,
+    E: EventPublisher + ?Sized,
+{
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Change thoughts in a batch: {:?}", req);
+        match &req.operation {
+            Operation::AssignAreaOfLife(id) => match get_untrashed_aol(self.repo, *id).await {
+                Ok(_) => {}
+                Err(area_of_life::GetError::NotFound) => {
+                    return Err(Error::AreaOfLifeNotFound(*id));
+                }
+                Err(area_of_life::GetError::Connection) => return Err(Error::Repo),
+            },
+            Operation::ReplaceInTitle { find, .. } if find.is_empty() => {
+                return Err(Error::EmptyFind);
+            }
+            _ => {}
+        }
+        let mut seen = HashSet::new();
+        let mut reports = vec![];
+        for id in req.ids {
+            if !seen.insert(id) {
+                continue;
+            }
+            let outcome = match self.apply(id, &req.operation).await {
+                Ok(true) => Outcome::Changed,
+                Ok(false) => Outcome::Unchanged,
+                Err(err) => {
+                    log::debug!("Unable to change thought {}: {}", id, err);
+                    Outcome::Failed(err)
+                }
+            };
+            reports.push(Report { id, outcome });
+        }
+        Ok(Response { reports })
+    }
+
+    /// Apply the operation to a single thought
+    /// and tell whether it changed.
+    async fn apply(&self, id: Id, operation: &Operation) -> Result<bool, ItemError> {
+        let Record { thought } = super::get_untrashed(self.repo, id).await?;
+        let now = self.clock.now().max(thought.created_at());
+        let version = thought.version().next();
+        let mut areas_of_life = thought.areas_of_life().clone();
+        let title = match operation {
+            Operation::Delete => {
+                let thought = thought.with_deleted_at(Some(now)).with_version(version);
+                let changes = super::save_with_revision(thought.clone(), now);
+                self.commit(changes, &thought, Event::ThoughtDeleted(id))
+                    .await?;
+                return Ok(true);
+            }
+            Operation::AssignAreaOfLife(aol_id) => {
+                if !areas_of_life.insert(*aol_id) {
+                    return Ok(false);
+                }
+                thought.title().clone()
+            }
+            Operation::UnassignAreaOfLife(aol_id) => {
+                if !areas_of_life.remove(aol_id) {
+                    return Ok(false);
+                }
+                thought.title().clone()
+            }
+            Operation::ReplaceInTitle { find, replace } => {
+                let current = thought.title().as_ref();
+                let title = normalize_line(&current.replace(find.as_str(), replace));
+                if title == current {
+                    return Ok(false);
+                }
+                validate_thought_properties(
+                    &validate::Request {
+                        title: &title,
+                        body: thought.body().map(AsRef::as_ref),
+                        due_at: thought.due_at(),
+                        reminder_offset: thought.reminder_offset(),
+                    },
+                    &self.policy,
+                )?;
+                Title::new(title)
+            }
+        };
+        let thought = Thought::new(id, title, areas_of_life, thought.created_at(), now)
+            .with_body(thought.body().cloned())
+            .with_tags(thought.tags().clone())
+            .with_due(thought.due_at(), thought.reminder_offset())
+            .with_status(thought.status())
+            .with_version(version);
+        let changes = super::save_with_revision(thought.clone(), now);
+        self.commit(changes, &thought, Event::ThoughtUpdated(id))
+            .await?;
+        Ok(true)
+    }
+
+    async fn commit(
+        &self,
+        changes: Changes,
+        thought: &Thought,
+        event: Event,
+    ) -> Result<(), ItemError> {
+        self.repo.commit(changes).await?;
+        super::reindex(self.repo, thought).await;
+        self.events.publish(event);
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{
+        repository::thought::{GetError, Record, Repo, SaveError},
+        search_index::SearchIndex,
+        unit_of_work::{CommitError, UnitOfWork},
+    },
+};
+use cawr_domain::{
+    thought::{Id, InvalidTransition, Status},
+    Event,
+};
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request {
+    /// The id of the thought.
+    pub id: Id,
+    /// The new lifecycle status.
+    pub status: Status,
+}
+
+pub type Response = ();
+
+/// Change thought status usecase interactor
+pub struct ChangeStatus<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> {
+    repo: &'r R,
+    clock: &'c C,
+    events: &'e E,
+}
+
+impl<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> ChangeStatus<'r, 'c, 'e, R, C, E> {
+    pub const fn new(repo: &'r R, clock: &'c C, events: &'e E) -> Self {
+        Self {
+            repo,
+            clock,
+            events,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{}", SaveError::Connection)]
+    Repo,
+    #[error("Thought {0} not found")]
+    NotFound(Id),
+    #[error(transparent)]
+    InvalidTransition(#[from] InvalidTransition),
+    #[error("{}", SaveError::Conflict)]
+    Conflict,
+}
+
+impl From<CommitError> for Error {
+    fn from(err: CommitError) -> Self {
+        match err {
+            CommitError::Connection => Self::Repo,
+            CommitError::Conflict => Self::Conflict,
+        }
+    }
+}
+
+impl From<(Id, GetError)> for Error {
+    fn from((id, err): (Id, GetError)) -> Self {
+        match err {
+            GetError::Connection => Self::Repo,
+            GetError::NotFound => Self::NotFound(id),
+        }
+    }
+}
+
+impl<'r, 'c, 'e, R, C, E> ChangeStatus<'r, 'c, 'e, R, C, E>
+where
+    R: Repo + SearchIndex + UnitOfWork,
+    C: Clock + ?Sized,
+    E: EventPublisher + ?Sized,
+{
+    /// Move a thought into another lifecycle status.
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Change status of thought: {:?}", req);
+        let Record { thought } = super::get_untrashed(self.repo, req.id)
+            .await
+            .map_err(|err| (req.id, err))?;
+        let updated_at = self.clock.now().max(thought.created_at());
+        let from = thought.status();
+        let version = thought.version().next();
+        let thought = thought
+            .transition_to(req.status, updated_at)?
+            .with_version(version);
+        let to = thought.status();
+        super::commit_and_reindex(self.repo, super::save_with_revision(thought, updated_at))
+            .await?;
+        self.events.publish(Event::ThoughtStatusChanged {
+            id: req.id,
+            from,
+            to,
+        });
+        Ok(())
+    }
+}
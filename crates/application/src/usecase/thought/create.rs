@@ -1,20 +1,29 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, time::Duration};
 
 use thiserror::Error;
 
 use cawr_domain::{
-    area_of_life as aol,
-    thought::{Id, Thought, Title},
+    area_of_life as aol, tag as tg,
+    thought::{Body, Id, Thought, Title},
+    Event, Timestamp,
 };
 
 use crate::{
-    gateway::repository::{
-        area_of_life,
-        thought::{self, Record, SaveError},
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{
+        repository::{
+            area_of_life, revision, tag,
+            thought::{self, SaveError},
+        },
+        search_index::SearchIndex,
+        unit_of_work::{CommitError, UnitOfWork},
     },
     identifier::{NewId, NewIdError},
     usecase::{
         area_of_life::check_existence::{self as check_aol, CheckAreasOfLifeExistence},
+        tag::check_existence::{self as check_tag, CheckTagsExistence},
+        text::{normalize_line, normalize_multiline},
         thought::validate::{self, validate_thought_properties, ThoughtInvalidity},
     },
 };
@@ -23,8 +32,16 @@ use crate::{
 pub struct Request {
     /// The title of new thought.
     pub title: String,
+    /// An optional body text (Markdown).
+    pub body: Option<String>,
     /// Associated [`aol::AreaOfLife`]s.
     pub areas_of_life: HashSet<aol::Id>,
+    /// Assigned [`tg::Tag`]s.
+    pub tags: HashSet<tg::Id>,
+    /// An optional due date.
+    pub due_at: Option<Timestamp>,
+    /// How long before the due date a reminder should fire.
+    pub reminder_offset: Option<Duration>,
 }
 
 #[derive(Debug)]
@@ -34,14 +51,29 @@ pub struct Response {
 }
 
 /// Create thought usecase interactor
-pub struct CreateThought<'r, 'g, R, G> {
+pub struct CreateThought<'r, 'g, 'c, 'e, R, G: ?Sized, C: ?Sized, E: ?Sized> {
     repo: &'r R,
     id_gen: &'g G,
+    clock: &'c C,
+    events: &'e E,
+    policy: validate::Policy,
 }
 
-impl<'r, 'g, R, G> CreateThought<'r, 'g, R, G> {
-    pub const fn new(repo: &'r R, id_gen: &'g G) -> Self {
-        Self { repo, id_gen }
+impl<'r, 'g, 'c, 'e, R, G: ?Sized, C: ?Sized, E: ?Sized> CreateThought<'r, 'g, 'c, 'e, R, G, C, E> {
+    pub const fn new(repo: &'r R, id_gen: &'g G, clock: &'c C, events: &'e E) -> Self {
+        Self {
+            repo,
+            id_gen,
+            clock,
+            events,
+            policy: validate::Policy::DEFAULT,
+        }
+    }
+    /// Replace the default validation [`validate::Policy`].
+    #[must_use]
+    pub const fn with_policy(mut self, policy: validate::Policy) -> Self {
+        self.policy = policy;
+        self
     }
 }
 
@@ -55,12 +87,17 @@ pub enum Error {
     Invalidity(#[from] ThoughtInvalidity),
     #[error("Areas of life {0:?} not found")]
     AreasOfLifeNotFound(HashSet<aol::Id>),
+    #[error("Tags {0:?} not found")]
+    TagsNotFound(HashSet<tg::Id>),
 }
 
-impl From<SaveError> for Error {
-    fn from(e: SaveError) -> Self {
+impl From<CommitError> for Error {
+    fn from(e: CommitError) -> Self {
         match e {
-            SaveError::Connection => Self::Repo,
+            CommitError::Connection => Self::Repo,
+            // A new ID can't be taken already
+            // unless the storage is broken.
+            CommitError::Conflict => Self::Repo,
         }
     }
 }
@@ -75,24 +112,57 @@ impl From<check_aol::Error> for Error {
     }
 }
 
-impl<'r, 'g, R, G> CreateThought<'r, 'g, R, G>
+impl From<check_tag::Error> for Error {
+    fn from(e: check_tag::Error) -> Self {
+        use check_tag::Error as E;
+        match e {
+            E::Repo => Error::Repo,
+            E::NotFound(tag_ids) => Error::TagsNotFound(tag_ids),
+        }
+    }
+}
+
+impl<'r, 'g, 'c, 'e, R, G, C, E> CreateThought<'r, 'g, 'c, 'e, R, G, C, E>
 where
-    R: thought::Repo + area_of_life::Repo,
-    G: NewId<Id>,
+    R: thought::Repo + area_of_life::Repo + tag::Repo + revision::Repo + SearchIndex + UnitOfWork,
+    G: NewId<Id> + ?Sized,
+    C: Clock + ?Sized,
+    E: EventPublisher + ?Sized,
 {
     /// Create a new thought with the given title.
-    pub fn exec(&self, req: Request) -> Result<Response, Error> {
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
         log::debug!("Create new thought: {:?}", req);
-        validate_thought_properties(&validate::Request { title: &req.title })?;
-        CheckAreasOfLifeExistence::new(self.repo).exec(&req.areas_of_life)?;
-        let title = Title::new(req.title);
-        let id = self.id_gen.new_id().map_err(|err| {
+        let title = normalize_line(&req.title);
+        let body = req.body.as_deref().map(normalize_multiline);
+        validate_thought_properties(
+            &validate::Request {
+                title: &title,
+                body: body.as_deref(),
+                due_at: req.due_at,
+                reminder_offset: req.reminder_offset,
+            },
+            &self.policy,
+        )?;
+        CheckAreasOfLifeExistence::new(self.repo)
+            .exec(&req.areas_of_life)
+            .await?;
+        CheckTagsExistence::new(self.repo).exec(&req.tags).await?;
+        let title = Title::new(title);
+        let body = body.map(Body::new);
+        let id = self.id_gen.new_id().await.map_err(|err| {
             log::warn!("{}", err);
             Error::NewId
         })?;
-        let thought = Thought::new(id, title, req.areas_of_life);
-        let record = Record { thought };
-        thought::Repo::save(self.repo, record)?;
+        let now = self.clock.now();
+        let thought = Thought::new(id, title, req.areas_of_life, now, now)
+            .with_body(body)
+            .with_tags(req.tags)
+            .with_due(req.due_at, req.reminder_offset);
+        self.repo
+            .commit(super::save_with_revision(thought.clone(), now))
+            .await?;
+        super::reindex(self.repo, &thought).await;
+        self.events.publish(Event::ThoughtCreated(id));
         Ok(Response { id })
     }
 }
@@ -100,7 +170,13 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::gateway::repository::thought::{DeleteError, GetAllError, GetError};
+    use crate::gateway::{
+        repository::thought::{DeleteError, GetAllError, GetError, Record},
+        search_index::{Hit, IndexError, SearchError},
+        unit_of_work::{Change, Changes, CommitError, UnitOfWork},
+    };
+    use async_trait::async_trait;
+    use cawr_domain::Version;
     use parking_lot::RwLock;
 
     #[derive(Default)]
@@ -108,55 +184,147 @@ mod tests {
         thought: RwLock<Option<Record>>,
     }
 
+    #[async_trait]
     impl thought::Repo for MockRepo {
-        fn save(&self, record: Record) -> Result<(), SaveError> {
+        async fn save(&self, record: Record) -> Result<(), SaveError> {
             *self.thought.write() = Some(record);
             Ok(())
         }
-        fn get(&self, _: Id) -> Result<Record, GetError> {
+        async fn get(&self, _: Id) -> Result<Record, GetError> {
             todo!()
         }
-        fn get_all(&self) -> Result<Vec<Record>, GetAllError> {
+        async fn get_all(&self) -> Result<Vec<Record>, GetAllError> {
             todo!()
         }
-        fn delete(&self, _: Id) -> Result<(), DeleteError> {
+        async fn delete(&self, _: Id) -> Result<(), DeleteError> {
             todo!()
         }
     }
 
+    #[async_trait]
     impl area_of_life::Repo for MockRepo {
-        fn save(&self, _: area_of_life::Record) -> Result<(), area_of_life::SaveError> {
+        async fn save(&self, _: area_of_life::Record) -> Result<(), area_of_life::SaveError> {
+            todo!()
+        }
+        async fn get(&self, _: aol::Id) -> Result<area_of_life::Record, area_of_life::GetError> {
             todo!()
         }
-        fn get(&self, _: aol::Id) -> Result<area_of_life::Record, area_of_life::GetError> {
+        async fn get_by_normalized_name(
+            &self,
+            _: &str,
+        ) -> Result<area_of_life::Record, area_of_life::GetError> {
             todo!()
         }
-        fn get_all(&self) -> Result<Vec<area_of_life::Record>, area_of_life::GetAllError> {
+        async fn get_all(&self) -> Result<Vec<area_of_life::Record>, area_of_life::GetAllError> {
+            todo!()
+        }
+        async fn delete(&self, _: aol::Id) -> Result<(), area_of_life::DeleteError> {
+            todo!()
+        }
+    }
+
+    #[async_trait]
+    impl revision::Repo for MockRepo {
+        async fn save(&self, _: revision::Record) -> Result<(), revision::SaveError> {
+            Ok(())
+        }
+        async fn get(&self, _: Id, _: Version) -> Result<revision::Record, revision::GetError> {
+            todo!()
+        }
+        async fn get_by_thought(
+            &self,
+            _: Id,
+        ) -> Result<Vec<revision::Record>, revision::GetAllError> {
+            todo!()
+        }
+        async fn delete_by_thought(&self, _: Id) -> Result<(), revision::DeleteError> {
+            todo!()
+        }
+    }
+
+    #[async_trait]
+    impl tag::Repo for MockRepo {
+        async fn save(&self, _: tag::Record) -> Result<(), tag::SaveError> {
+            todo!()
+        }
+        async fn get(&self, _: tg::Id) -> Result<tag::Record, tag::GetError> {
+            Err(tag::GetError::NotFound)
+        }
+        async fn get_all(&self) -> Result<Vec<tag::Record>, tag::GetAllError> {
+            todo!()
+        }
+        async fn delete(&self, _: tg::Id) -> Result<(), tag::DeleteError> {
+            todo!()
+        }
+    }
+
+    #[async_trait]
+    impl SearchIndex for MockRepo {
+        async fn index(&self, _: &Thought) -> Result<(), IndexError> {
+            Ok(())
+        }
+        async fn remove(&self, _: Id) -> Result<(), IndexError> {
             todo!()
         }
-        fn delete(&self, _: aol::Id) -> Result<(), area_of_life::DeleteError> {
+        async fn search(&self, _: &str) -> Result<Vec<Hit>, SearchError> {
             todo!()
         }
     }
 
+    #[async_trait]
+    impl UnitOfWork for MockRepo {
+        async fn commit(&self, changes: Changes) -> Result<(), CommitError> {
+            for change in changes {
+                match change {
+                    Change::SaveThought(record) => *self.thought.write() = Some(record),
+                    Change::SaveRevision(_) => {}
+                    _ => todo!(),
+                }
+            }
+            Ok(())
+        }
+    }
+
     struct IdGen;
 
+    #[async_trait]
     impl NewId<Id> for IdGen {
-        fn new_id(&self) -> Result<Id, NewIdError> {
+        async fn new_id(&self) -> Result<Id, NewIdError> {
             Ok(Id::new(42))
         }
     }
 
-    #[test]
-    fn create_new_thought() {
+    struct FixedClock(Timestamp);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> Timestamp {
+            self.0
+        }
+    }
+
+    struct NoEvents;
+
+    impl EventPublisher for NoEvents {
+        fn publish(&self, _: Event) {}
+    }
+
+    const NOW: Timestamp = Timestamp::from_unix_millis(1_700_000_000_000);
+
+    #[tokio::test]
+    async fn create_new_thought() {
         let repo = MockRepo::default();
         let gen = IdGen {};
-        let usecase = CreateThought::new(&repo, &gen);
+        let clock = FixedClock(NOW);
+        let usecase = CreateThought::new(&repo, &gen, &clock, &NoEvents);
         let req = Request {
             title: "foo".into(),
+            body: None,
             areas_of_life: HashSet::new(),
+            tags: HashSet::new(),
+            due_at: None,
+            reminder_offset: None,
         };
-        let res = usecase.exec(req).unwrap();
+        let res = usecase.exec(req).await.unwrap();
         assert_eq!(
             repo.thought
                 .read()
@@ -170,16 +338,79 @@ mod tests {
         assert_eq!(res.id, Id::new(42));
     }
 
-    #[test]
-    fn create_with_empty_title() {
+    #[tokio::test]
+    async fn create_new_thought_with_timestamps() {
+        let repo = MockRepo::default();
+        let gen = IdGen {};
+        let clock = FixedClock(NOW);
+        let usecase = CreateThought::new(&repo, &gen, &clock, &NoEvents);
+        let req = Request {
+            title: "foo".into(),
+            body: None,
+            areas_of_life: HashSet::new(),
+            tags: HashSet::new(),
+            due_at: None,
+            reminder_offset: None,
+        };
+        usecase.exec(req).await.unwrap();
+        let record = repo.thought.read().clone().unwrap();
+        assert_eq!(record.thought.created_at(), NOW);
+        assert_eq!(record.thought.updated_at(), NOW);
+    }
+
+    #[tokio::test]
+    async fn create_new_thought_with_body() {
+        let repo = MockRepo::default();
+        let gen = IdGen {};
+        let clock = FixedClock(NOW);
+        let usecase = CreateThought::new(&repo, &gen, &clock, &NoEvents);
+        let req = Request {
+            title: "foo".into(),
+            body: Some("# Bar\n\nbaz".into()),
+            areas_of_life: HashSet::new(),
+            tags: HashSet::new(),
+            due_at: None,
+            reminder_offset: None,
+        };
+        usecase.exec(req).await.unwrap();
+        let record = repo.thought.read().clone().unwrap();
+        assert_eq!(record.thought.body().unwrap().as_ref(), "# Bar\n\nbaz");
+    }
+
+    #[tokio::test]
+    async fn create_with_unknown_tag() {
+        let repo = MockRepo::default();
+        let gen = IdGen {};
+        let clock = FixedClock(NOW);
+        let usecase = CreateThought::new(&repo, &gen, &clock, &NoEvents);
+        let req = Request {
+            title: "foo".into(),
+            body: None,
+            areas_of_life: HashSet::new(),
+            tags: [tg::Id::new(7)].into_iter().collect(),
+            due_at: None,
+            reminder_offset: None,
+        };
+        let err = usecase.exec(req).await.err().unwrap();
+        assert!(matches!(err, Error::TagsNotFound(ids) if ids.contains(&tg::Id::new(7))));
+        assert!(repo.thought.read().is_none());
+    }
+
+    #[tokio::test]
+    async fn create_with_empty_title() {
         let repo = MockRepo::default();
         let gen = IdGen {};
-        let usecase = CreateThought::new(&repo, &gen);
+        let clock = FixedClock(NOW);
+        let usecase = CreateThought::new(&repo, &gen, &clock, &NoEvents);
         let req = Request {
             title: String::new(),
+            body: None,
             areas_of_life: HashSet::new(),
+            tags: HashSet::new(),
+            due_at: None,
+            reminder_offset: None,
         };
-        let err = usecase.exec(req).err().unwrap();
+        let err = usecase.exec(req).await.err().unwrap();
         assert!(matches!(err, Error::Invalidity(_)));
     }
 }
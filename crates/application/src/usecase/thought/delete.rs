@@ -1,5 +1,13 @@
-use crate::gateway::repository::thought::{DeleteError, Repo};
-use cawr_domain::thought::Id;
+use crate::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{
+        repository::thought::{GetError, Record, Repo, SaveError},
+        search_index::SearchIndex,
+        unit_of_work::{CommitError, UnitOfWork},
+    },
+};
+use cawr_domain::{thought::Id, Event};
 use std::fmt::Debug;
 use thiserror::Error;
 
@@ -11,41 +19,76 @@ pub struct Request {
 #[derive(Debug)]
 pub struct Response;
 
-/// Delete thought by ID usecase interactor
-pub struct Delete<'r, R> {
+/// Move thought to the trash usecase interactor
+///
+/// The thought keeps its links, so it can be restored
+/// until it is [purged](crate::usecase::trash::purge).
+pub struct Delete<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> {
     repo: &'r R,
+    clock: &'c C,
+    events: &'e E,
 }
 
-impl<'r, R> Delete<'r, R> {
-    pub const fn new(repo: &'r R) -> Self {
-        Self { repo }
+impl<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> Delete<'r, 'c, 'e, R, C, E> {
+    pub const fn new(repo: &'r R, clock: &'c C, events: &'e E) -> Self {
+        Self {
+            repo,
+            clock,
+            events,
+        }
     }
 }
 
 #[derive(Debug, Error)]
 pub enum Error {
-    #[error("{}", DeleteError::NotFound)]
+    #[error("{}", GetError::NotFound)]
     NotFound,
-    #[error("{}", DeleteError::Connection)]
+    #[error("{}", GetError::Connection)]
     Repo,
+    #[error("{}", SaveError::Conflict)]
+    Conflict,
 }
 
-impl From<DeleteError> for Error {
-    fn from(e: DeleteError) -> Self {
+impl From<GetError> for Error {
+    fn from(e: GetError) -> Self {
         match e {
-            DeleteError::NotFound => Self::NotFound,
-            DeleteError::Connection => Self::Repo,
+            GetError::NotFound => Self::NotFound,
+            GetError::Connection => Self::Repo,
         }
     }
 }
 
-impl<'r, R> Delete<'r, R>
+impl From<CommitError> for Error {
+    fn from(e: CommitError) -> Self {
+        match e {
+            CommitError::Connection => Self::Repo,
+            CommitError::Conflict => Self::Conflict,
+        }
+    }
+}
+
+impl<'r, 'c, 'e, R, C, E> Delete<'r, 'c, 'e, R, C, E>
 where
-    R: Repo,
+    R: Repo + SearchIndex + UnitOfWork,
+    C: Clock + ?Sized,
+    E: EventPublisher + ?Sized,
 {
-    pub fn exec(&self, req: Request) -> Result<Response, Error> {
-        log::debug!("Delete thought by ID: {:?}", req);
-        self.repo.delete(req.id)?;
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Move thought to the trash: {:?}", req);
+        let Record { thought } = self.repo.get(req.id).await?;
+        if thought.is_deleted() {
+            return Err(Error::NotFound);
+        }
+        let version = thought.version().next();
+        let deleted_at = self.clock.now();
+        let thought = thought
+            .with_deleted_at(Some(deleted_at))
+            .with_version(version);
+        self.repo
+            .commit(super::save_with_revision(thought.clone(), deleted_at))
+            .await?;
+        super::reindex(self.repo, &thought).await;
+        self.events.publish(Event::ThoughtDeleted(req.id));
         Ok(Response {})
     }
 }
@@ -0,0 +1,160 @@
+use crate::{
+    clock::Clock,
+    gateway::repository::thought::{GetAllError, Repo},
+    usecase::thought::read_all::Thought,
+};
+use cawr_domain::thought::Status;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request {
+    /// How far to look ahead for upcoming thoughts.
+    pub horizon: Duration,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    /// Open thoughts whose due date has passed.
+    pub overdue: Vec<Thought>,
+    /// Open thoughts that are due within the horizon
+    /// or whose reminder has already fired.
+    pub upcoming: Vec<Thought>,
+}
+
+/// Read overdue and upcoming thoughts usecase interactor
+pub struct ReadDue<'r, 'c, R, C: ?Sized> {
+    repo: &'r R,
+    clock: &'c C,
+}
+
+impl<'r, 'c, R, C: ?Sized> ReadDue<'r, 'c, R, C> {
+    pub const fn new(repo: &'r R, clock: &'c C) -> Self {
+        Self { repo, clock }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{}", GetAllError::Connection)]
+    Repo,
+}
+
+impl From<GetAllError> for Error {
+    fn from(e: GetAllError) -> Self {
+        match e {
+            GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl<'r, 'c, R, C> ReadDue<'r, 'c, R, C>
+where
+    R: Repo,
+    C: Clock + ?Sized,
+{
+    pub async fn exec(&self, req: &Request) -> Result<Response, Error> {
+        log::debug!("Read due thoughts: {:?}", req);
+        let now = self.clock.now();
+        let until = now.saturating_add(req.horizon);
+        let mut overdue = vec![];
+        let mut upcoming = vec![];
+        for record in self.repo.get_all().await? {
+            let thought = &record.thought;
+            if thought.is_deleted() || matches!(thought.status(), Status::Done | Status::Archived) {
+                continue;
+            }
+            let Some(due_at) = thought.due_at() else {
+                continue;
+            };
+            if due_at < now {
+                overdue.push(Thought::from(record));
+            } else if due_at <= until || thought.remind_at().is_some_and(|at| at <= now) {
+                upcoming.push(Thought::from(record));
+            }
+        }
+        overdue.sort_by_key(|t| (t.due_at, t.id));
+        upcoming.sort_by_key(|t| (t.due_at, t.id));
+        Ok(Response { overdue, upcoming })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gateway::repository::thought::{DeleteError, GetError, Record, SaveError};
+    use async_trait::async_trait;
+    use cawr_domain::{
+        thought::{Id, Thought, Title},
+        Timestamp,
+    };
+    use std::collections::HashSet;
+
+    const HOUR: Duration = Duration::from_secs(60 * 60);
+    const NOW: Timestamp = Timestamp::from_unix_millis(1_700_000_000_000);
+
+    struct MockRepo;
+
+    #[async_trait]
+    impl Repo for MockRepo {
+        async fn save(&self, _: Record) -> Result<(), SaveError> {
+            todo!()
+        }
+        async fn get(&self, _: Id) -> Result<Record, GetError> {
+            todo!()
+        }
+        async fn get_all(&self) -> Result<Vec<Record>, GetAllError> {
+            let created_at = Timestamp::from_unix_millis(0);
+            let thought = |id, due_at: Option<Timestamp>, reminder_offset| {
+                let thought = Thought::new(
+                    Id::new(id),
+                    Title::new(format!("thought {id}")),
+                    HashSet::new(),
+                    created_at,
+                    created_at,
+                )
+                .with_due(due_at, reminder_offset);
+                Record { thought }
+            };
+            let done = thought(5, Some(NOW.saturating_sub(HOUR)), None);
+            let done = Record {
+                thought: done.thought.with_status(Status::Done),
+            };
+            Ok(vec![
+                thought(1, None, None),
+                thought(2, Some(NOW.saturating_add(HOUR * 2)), None),
+                thought(3, Some(NOW.saturating_sub(HOUR)), None),
+                thought(4, Some(NOW.saturating_add(HOUR * 48)), Some(HOUR * 72)),
+                done,
+                thought(6, Some(NOW.saturating_add(HOUR * 48)), None),
+                thought(7, Some(NOW.saturating_add(HOUR)), None),
+            ])
+        }
+        async fn delete(&self, _: Id) -> Result<(), DeleteError> {
+            todo!()
+        }
+    }
+
+    struct FixedClock(Timestamp);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> Timestamp {
+            self.0
+        }
+    }
+
+    #[tokio::test]
+    async fn split_into_overdue_and_upcoming() {
+        let clock = FixedClock(NOW);
+        let req = Request { horizon: HOUR * 24 };
+        let res = ReadDue::new(&MockRepo, &clock).exec(&req).await.unwrap();
+        let ids = |thoughts: &[super::Thought]| {
+            thoughts
+                .iter()
+                .map(|t| t.id.to_sequential().unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&res.overdue), vec![3]);
+        assert_eq!(ids(&res.upcoming), vec![7, 2, 4]);
+    }
+}
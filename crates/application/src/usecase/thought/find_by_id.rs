@@ -1,6 +1,10 @@
 use crate::gateway::repository::thought::{GetError, Record, Repo};
-use cawr_domain::{area_of_life as aol, thought::Id};
-use std::{collections::HashSet, fmt::Debug};
+use cawr_domain::{
+    area_of_life as aol, tag,
+    thought::{Id, Status},
+    Timestamp, Version,
+};
+use std::{collections::HashSet, fmt::Debug, time::Duration};
 use thiserror::Error;
 
 #[derive(Debug)]
@@ -12,19 +16,43 @@ pub struct Request {
 pub struct Response {
     pub id: Id,
     pub title: String,
+    pub body: Option<String>,
     pub areas_of_life: HashSet<aol::Id>,
+    pub tags: HashSet<tag::Id>,
+    pub status: Status,
+    pub due_at: Option<Timestamp>,
+    pub reminder_offset: Option<Duration>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+    pub version: Version,
 }
 
 impl From<Record> for Response {
     fn from(r: Record) -> Self {
         let Record { thought } = r;
         let title = String::from(thought.title().as_ref());
+        let body = thought.body().map(|b| String::from(b.as_ref()));
         let id = thought.id();
         let areas_of_life = thought.areas_of_life().clone();
+        let tags = thought.tags().clone();
+        let status = thought.status();
+        let due_at = thought.due_at();
+        let reminder_offset = thought.reminder_offset();
+        let created_at = thought.created_at();
+        let updated_at = thought.updated_at();
+        let version = thought.version();
         Self {
             id,
             title,
+            body,
             areas_of_life,
+            tags,
+            status,
+            due_at,
+            reminder_offset,
+            created_at,
+            updated_at,
+            version,
         }
     }
 }
@@ -61,9 +89,9 @@ impl<'r, R> FindById<'r, R>
 where
     R: Repo,
 {
-    pub fn exec(&self, req: Request) -> Result<Response, Error> {
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
         log::debug!("Find thought by ID: {:?}", req);
-        let thought_record = self.repo.get(req.id)?;
+        let thought_record = super::get_untrashed(self.repo, req.id).await?;
         Ok(Response::from(thought_record))
     }
 }
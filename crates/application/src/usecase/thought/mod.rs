@@ -1,6 +1,103 @@
+pub mod batch;
+pub mod change_status;
 pub mod create;
 pub mod delete;
+pub mod due;
 pub mod find_by_id;
 pub mod read_all;
+pub mod search;
 pub mod update;
 pub mod validate;
+
+use crate::gateway::{
+    repository::{
+        revision,
+        thought::{GetError, Record, Repo},
+    },
+    search_index::SearchIndex,
+    unit_of_work::{Change, Changes, CommitError, UnitOfWork},
+};
+use cawr_domain::{
+    thought::{Id, Thought},
+    Timestamp,
+};
+
+/// Get a thought unless it was moved to the trash.
+pub(crate) async fn get_untrashed<R>(repo: &R, id: Id) -> Result<Record, GetError>
+where
+    R: Repo + ?Sized,
+{
+    let record = repo.get(id).await?;
+    if record.thought.is_deleted() {
+        return Err(GetError::NotFound);
+    }
+    Ok(record)
+}
+
+/// Save a thought together with a revision of it.
+pub(crate) fn save_with_revision(thought: Thought, recorded_at: Timestamp) -> Changes {
+    let mut changes = Changes::default();
+    push_with_revision(&mut changes, thought, recorded_at);
+    changes
+}
+
+/// Add the save of a thought together with a revision of it to the `changes`.
+///
+/// Every new version of a thought must be recorded as a revision.
+pub(crate) fn push_with_revision(changes: &mut Changes, thought: Thought, recorded_at: Timestamp) {
+    changes.push(Change::SaveThought(Record {
+        thought: thought.clone(),
+    }));
+    changes.push(Change::SaveRevision(revision::Record {
+        thought,
+        recorded_at,
+    }));
+}
+
+/// Keep the search index in sync with a stored thought.
+///
+/// The index can be rebuilt from the repository at any time,
+/// so a failure is logged instead of failing the change.
+pub(crate) async fn reindex<S>(index: &S, thought: &Thought)
+where
+    S: SearchIndex + ?Sized,
+{
+    let res = if thought.is_deleted() {
+        index.remove(thought.id()).await
+    } else {
+        index.index(thought).await
+    };
+    if let Err(err) = res {
+        log::warn!(
+            "Unable to update search index of thought {}: {}",
+            thought.id(),
+            err
+        );
+    }
+}
+
+/// Commit a unit of work and keep the search index in sync
+/// with all thoughts that it saved or deleted.
+pub(crate) async fn commit_and_reindex<R>(repo: &R, changes: Changes) -> Result<(), CommitError>
+where
+    R: UnitOfWork + SearchIndex + ?Sized,
+{
+    let indexed = changes
+        .iter()
+        .filter(|change| matches!(change, Change::SaveThought(_) | Change::DeleteThought(_)))
+        .cloned()
+        .collect::<Vec<_>>();
+    repo.commit(changes).await?;
+    for change in indexed {
+        match change {
+            Change::SaveThought(Record { thought }) => reindex(repo, &thought).await,
+            Change::DeleteThought(id) => {
+                if let Err(err) = SearchIndex::remove(repo, id).await {
+                    log::warn!("Unable to remove thought {} from search index: {}", id, err);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
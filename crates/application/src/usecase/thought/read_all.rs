@@ -1,33 +1,68 @@
-use crate::gateway::repository::thought::{GetAllError, Record, Repo};
-use cawr_domain::{area_of_life as aol, thought::Id};
-use std::{collections::HashSet, fmt::Debug};
+use crate::gateway::repository::thought::{GetAllError, Query, Record, Repo};
+use cawr_domain::{
+    area_of_life as aol, tag,
+    thought::{Id, Status},
+    Timestamp, Version,
+};
+use std::{collections::HashSet, fmt::Debug, time::Duration};
 use thiserror::Error;
 
-#[derive(Debug)]
-pub struct Request;
+#[derive(Debug, Default)]
+pub struct Request {
+    /// Which thoughts to return in which order.
+    pub query: Query,
+}
 
 #[derive(Debug)]
 pub struct Response {
     pub thoughts: Vec<Thought>,
+    /// The number of all matching thoughts.
+    pub total: usize,
+    /// The offset of the following page if there are more thoughts.
+    pub next_offset: Option<usize>,
 }
 
 #[derive(Debug)]
 pub struct Thought {
     pub id: Id,
     pub title: String,
+    pub body: Option<String>,
     pub areas_of_life: HashSet<aol::Id>,
+    pub tags: HashSet<tag::Id>,
+    pub status: Status,
+    pub due_at: Option<Timestamp>,
+    pub reminder_offset: Option<Duration>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+    pub version: Version,
 }
 
 impl From<Record> for Thought {
     fn from(r: Record) -> Self {
         let Record { thought } = r;
         let title = String::from(thought.title().as_ref());
+        let body = thought.body().map(|b| String::from(b.as_ref()));
         let id = thought.id();
         let areas_of_life = thought.areas_of_life().clone();
+        let tags = thought.tags().clone();
+        let status = thought.status();
+        let due_at = thought.due_at();
+        let reminder_offset = thought.reminder_offset();
+        let created_at = thought.created_at();
+        let updated_at = thought.updated_at();
+        let version = thought.version();
         Self {
             id,
             title,
+            body,
             areas_of_life,
+            tags,
+            status,
+            due_at,
+            reminder_offset,
+            created_at,
+            updated_at,
+            version,
         }
     }
 }
@@ -61,14 +96,13 @@ impl<'r, R> ReadAll<'r, R>
 where
     R: Repo,
 {
-    pub fn exec(&self, _: Request) -> Result<Response, Error> {
-        log::debug!("Read all thoughts");
-        let thoughts = self
-            .repo
-            .get_all()?
-            .into_iter()
-            .map(Thought::from)
-            .collect();
-        Ok(Response { thoughts })
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Read all thoughts: {:?}", req);
+        let page = self.repo.query(&req.query).await?.map(Thought::from);
+        Ok(Response {
+            thoughts: page.items,
+            total: page.total,
+            next_offset: page.next_offset,
+        })
     }
 }
@@ -0,0 +1,75 @@
+use crate::{
+    gateway::{
+        repository::thought::{self, GetError},
+        search_index::{SearchError, SearchIndex},
+    },
+    usecase::thought::read_all::Thought,
+};
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Request {
+    /// The words to search for in titles and bodies.
+    pub query: String,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    /// The matching thoughts, best matches first.
+    pub thoughts: Vec<Thought>,
+}
+
+/// Search thoughts usecase interactor
+pub struct Search<'r, R> {
+    repo: &'r R,
+}
+
+impl<'r, R> Search<'r, R> {
+    pub const fn new(repo: &'r R) -> Self {
+        Self { repo }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("The search query is empty")]
+    EmptyQuery,
+    #[error("{}", SearchError::Connection)]
+    Index,
+    #[error("{}", GetError::Connection)]
+    Repo,
+}
+
+impl From<SearchError> for Error {
+    fn from(e: SearchError) -> Self {
+        match e {
+            SearchError::Connection => Self::Index,
+        }
+    }
+}
+
+impl<'r, R> Search<'r, R>
+where
+    R: thought::Repo + SearchIndex,
+{
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Search thoughts: {:?}", req);
+        let query = req.query.trim();
+        if query.is_empty() {
+            return Err(Error::EmptyQuery);
+        }
+        let mut thoughts = vec![];
+        for hit in SearchIndex::search(self.repo, query).await? {
+            match thought::Repo::get(self.repo, hit.id).await {
+                Ok(record) if !record.thought.is_deleted() => {
+                    thoughts.push(Thought::from(record));
+                }
+                Ok(_) | Err(GetError::NotFound) => {
+                    log::debug!("Skip outdated search hit {}", hit.id);
+                }
+                Err(GetError::Connection) => return Err(Error::Repo),
+            }
+        }
+        Ok(Response { thoughts })
+    }
+}
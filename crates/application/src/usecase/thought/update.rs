@@ -1,18 +1,27 @@
 use crate::{
-    gateway::repository::{
-        area_of_life,
-        thought::{self, GetError, Record, SaveError},
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{
+        repository::{
+            area_of_life, revision, tag,
+            thought::{self, GetError, SaveError},
+        },
+        search_index::SearchIndex,
+        unit_of_work::{CommitError, UnitOfWork},
     },
     usecase::{
         area_of_life::check_existence::{self as check_aol, CheckAreasOfLifeExistence},
+        tag::check_existence::{self as check_tag, CheckTagsExistence},
+        text::{normalize_line, normalize_multiline},
         thought::validate::{self, validate_thought_properties, ThoughtInvalidity},
     },
 };
 use cawr_domain::{
-    area_of_life as aol,
-    thought::{Id, Thought, Title},
+    area_of_life as aol, tag as tg,
+    thought::{Body, Id, Thought, Title},
+    Event, Timestamp, Version,
 };
-use std::collections::HashSet;
+use std::{collections::HashSet, time::Duration};
 use thiserror::Error;
 
 #[derive(Debug)]
@@ -21,20 +30,51 @@ pub struct Request {
     pub id: Id,
     /// The title of the thought.
     pub title: String,
+    /// An optional body text (Markdown).
+    pub body: Option<String>,
     /// Associated [`aol::AreaOfLife`]s.
     pub areas_of_life: HashSet<aol::Id>,
+    /// Assigned [`tg::Tag`]s.
+    pub tags: HashSet<tg::Id>,
+    /// An optional due date.
+    pub due_at: Option<Timestamp>,
+    /// How long before the due date a reminder should fire.
+    pub reminder_offset: Option<Duration>,
+    /// The version the changes are based on.
+    ///
+    /// If it is given but no longer the current version,
+    /// the update is rejected with [`Error::Conflict`].
+    pub version: Option<Version>,
 }
 
-pub type Response = ();
+#[derive(Debug)]
+pub struct Response {
+    /// The version of the updated thought.
+    pub version: Version,
+}
 
 /// Update thought usecase interactor
-pub struct UpdateThought<'r, R> {
+pub struct UpdateThought<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> {
     repo: &'r R,
+    clock: &'c C,
+    events: &'e E,
+    policy: validate::Policy,
 }
 
-impl<'r, R> UpdateThought<'r, R> {
-    pub const fn new(repo: &'r R) -> Self {
-        Self { repo }
+impl<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> UpdateThought<'r, 'c, 'e, R, C, E> {
+    pub const fn new(repo: &'r R, clock: &'c C, events: &'e E) -> Self {
+        Self {
+            repo,
+            clock,
+            events,
+            policy: validate::Policy::DEFAULT,
+        }
+    }
+    /// Replace the default validation [`validate::Policy`].
+    #[must_use]
+    pub const fn with_policy(mut self, policy: validate::Policy) -> Self {
+        self.policy = policy;
+        self
     }
 }
 
@@ -48,12 +88,17 @@ pub enum Error {
     Invalidity(#[from] ThoughtInvalidity),
     #[error("Areas of life {0:?} not found")]
     AreasOfLifeNotFound(HashSet<aol::Id>),
+    #[error("Tags {0:?} not found")]
+    TagsNotFound(HashSet<tg::Id>),
+    #[error("{}", SaveError::Conflict)]
+    Conflict,
 }
 
-impl From<SaveError> for Error {
-    fn from(err: SaveError) -> Self {
+impl From<CommitError> for Error {
+    fn from(err: CommitError) -> Self {
         match err {
-            SaveError::Connection => Self::Repo,
+            CommitError::Connection => Self::Repo,
+            CommitError::Conflict => Self::Conflict,
         }
     }
 }
@@ -77,20 +122,64 @@ impl From<check_aol::Error> for Error {
     }
 }
 
-impl<'r, R> UpdateThought<'r, R>
+impl From<check_tag::Error> for Error {
+    fn from(e: check_tag::Error) -> Self {
+        use check_tag::Error as E;
+        match e {
+            E::Repo => Error::Repo,
+            E::NotFound(tag_ids) => Error::TagsNotFound(tag_ids),
+        }
+    }
+}
+
+impl<'r, 'c, 'e, R, C, E> UpdateThought<'r, 'c, 'e, R, C, E>
 where
-    R: thought::Repo + area_of_life::Repo,
+    R: thought::Repo + area_of_life::Repo + tag::Repo + revision::Repo + SearchIndex + UnitOfWork,
+    C: Clock + ?Sized,
+    E: EventPublisher + ?Sized,
 {
     /// Update a thought.
-    pub fn exec(&self, req: Request) -> Result<Response, Error> {
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
         log::debug!("Update thought: {:?}", req);
-        validate_thought_properties(&validate::Request { title: &req.title })?;
-        CheckAreasOfLifeExistence::new(self.repo).exec(&req.areas_of_life)?;
-        thought::Repo::get(self.repo, req.id).map_err(|err| (req.id, err))?;
-        let title = Title::new(req.title);
-        let thought = Thought::new(req.id, title, req.areas_of_life);
-        let record = Record { thought };
-        thought::Repo::save(self.repo, record)?;
-        Ok(())
+        let title = normalize_line(&req.title);
+        let body = req.body.as_deref().map(normalize_multiline);
+        validate_thought_properties(
+            &validate::Request {
+                title: &title,
+                body: body.as_deref(),
+                due_at: req.due_at,
+                reminder_offset: req.reminder_offset,
+            },
+            &self.policy,
+        )?;
+        CheckAreasOfLifeExistence::new(self.repo)
+            .exec(&req.areas_of_life)
+            .await?;
+        CheckTagsExistence::new(self.repo).exec(&req.tags).await?;
+        let current = super::get_untrashed(self.repo, req.id)
+            .await
+            .map_err(|err| (req.id, err))?;
+        let current_version = current.thought.version();
+        if req.version.is_some_and(|v| v != current_version) {
+            log::debug!("Thought {} is at version {}", req.id, current_version);
+            return Err(Error::Conflict);
+        }
+        let version = current_version.next();
+        let title = Title::new(title);
+        let body = body.map(Body::new);
+        let created_at = current.thought.created_at();
+        let updated_at = self.clock.now().max(created_at);
+        let thought = Thought::new(req.id, title, req.areas_of_life, created_at, updated_at)
+            .with_body(body)
+            .with_tags(req.tags)
+            .with_due(req.due_at, req.reminder_offset)
+            .with_status(current.thought.status())
+            .with_version(version);
+        self.repo
+            .commit(super::save_with_revision(thought.clone(), updated_at))
+            .await?;
+        super::reindex(self.repo, &thought).await;
+        self.events.publish(Event::ThoughtUpdated(req.id));
+        Ok(Response { version })
     }
 }
@@ -1,16 +1,55 @@
-use cawr_domain::thought::Title;
+use crate::usecase::text::{
+    find_control_character, grapheme_count, normalize_line, normalize_multiline, LengthLimits,
+};
+use cawr_domain::{
+    thought::{Body, Thought, Title},
+    Timestamp,
+};
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Debug)]
 pub struct Request<'a> {
     pub title: &'a str,
+    pub body: Option<&'a str>,
+    pub due_at: Option<Timestamp>,
+    pub reminder_offset: Option<Duration>,
 }
 pub type Response = Result<(), ThoughtInvalidity>;
 
+/// The limits that are applied to the properties of a thought
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Policy {
+    pub title: LengthLimits,
+    /// The maximum number of grapheme clusters of the body.
+    pub max_body_len: usize,
+}
+
+impl Policy {
+    /// Use the limits of the domain model.
+    pub const DEFAULT: Self = Self {
+        title: LengthLimits {
+            min: Title::min_len(),
+            max: Title::max_len(),
+        },
+        max_body_len: Body::max_len(),
+    };
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum ThoughtInvalidity {
     #[error(transparent)]
     Title(#[from] TitleInvalidity),
+    #[error(transparent)]
+    Body(#[from] BodyInvalidity),
+    #[error(transparent)]
+    Due(#[from] DueInvalidity),
 }
 
 #[derive(Debug, Error)]
@@ -19,25 +58,85 @@ pub enum TitleInvalidity {
     MinLength { min: usize, actual: usize },
     #[error("The title must have at most {max} but has {actual} chars")]
     MaxLength { max: usize, actual: usize },
+    #[error("The title contains a control character at position {position}")]
+    ControlCharacter { position: usize },
+}
+
+#[derive(Debug, Error)]
+pub enum BodyInvalidity {
+    #[error("The body must have at most {max} but has {actual} chars")]
+    MaxLength { max: usize, actual: usize },
+    #[error("The body contains a control character at position {position}")]
+    ControlCharacter { position: usize },
+}
+
+#[derive(Debug, Error)]
+pub enum DueInvalidity {
+    #[error("A reminder requires a due date")]
+    ReminderWithoutDueDate,
+    #[error(
+        "The reminder must be at most {} but is {} days before the due date",
+        .max.as_secs() / SECS_PER_DAY,
+        .actual.as_secs() / SECS_PER_DAY
+    )]
+    ReminderMaxOffset { max: Duration, actual: Duration },
 }
 
-pub fn validate_thought_properties(req: &Request) -> Response {
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Validate the (not yet normalized) properties of a thought.
+pub fn validate_thought_properties(req: &Request, policy: &Policy) -> Response {
     log::debug!("Validate thought properties {:?}", req);
-    validate_title(req.title).map_err(ThoughtInvalidity::Title)?;
+    validate_title(&normalize_line(req.title), policy.title).map_err(ThoughtInvalidity::Title)?;
+    if let Some(body) = req.body {
+        validate_body(&normalize_multiline(body), policy.max_body_len)
+            .map_err(ThoughtInvalidity::Body)?;
+    }
+    validate_due(req.due_at, req.reminder_offset).map_err(ThoughtInvalidity::Due)?;
     Ok(())
 }
 
-const fn validate_title(title: &str) -> Result<(), TitleInvalidity> {
-    let actual = title.len();
-    let min = Title::min_len();
-
+fn validate_title(title: &str, limits: LengthLimits) -> Result<(), TitleInvalidity> {
+    let actual = grapheme_count(title);
+    let min = limits.required_min();
+    let max = limits.max;
     if actual < min {
         return Err(TitleInvalidity::MinLength { min, actual });
     }
-    let max = Title::max_len();
     if actual > max {
         return Err(TitleInvalidity::MaxLength { max, actual });
     }
+    if let Some(position) = find_control_character(title, false) {
+        return Err(TitleInvalidity::ControlCharacter { position });
+    }
+    Ok(())
+}
+
+fn validate_body(body: &str, max: usize) -> Result<(), BodyInvalidity> {
+    let actual = grapheme_count(body);
+    if actual > max {
+        return Err(BodyInvalidity::MaxLength { max, actual });
+    }
+    if let Some(position) = find_control_character(body, true) {
+        return Err(BodyInvalidity::ControlCharacter { position });
+    }
+    Ok(())
+}
+
+fn validate_due(
+    due_at: Option<Timestamp>,
+    reminder_offset: Option<Duration>,
+) -> Result<(), DueInvalidity> {
+    let Some(actual) = reminder_offset else {
+        return Ok(());
+    };
+    if due_at.is_none() {
+        return Err(DueInvalidity::ReminderWithoutDueDate);
+    }
+    let max = Thought::max_reminder_offset();
+    if actual > max {
+        return Err(DueInvalidity::ReminderMaxOffset { max, actual });
+    }
     Ok(())
 }
 
@@ -51,20 +150,20 @@ mod tests {
 
         #[test]
         fn should_have_min_3_chars() {
-            let res = validate_title("");
+            let res = validate_title("", Policy::default().title);
             assert!(matches!(
                 res.err().unwrap(),
                 TitleInvalidity::MinLength { min: 3, actual: 0 }
             ));
 
             let title = ["a"; 3].join("");
-            assert!(validate_title(&title).is_ok());
+            assert!(validate_title(&title, Policy::default().title).is_ok());
         }
 
         #[test]
         fn should_have_max_80_chars() {
             let title = ["a"; 81].join("");
-            let res = validate_title(&title);
+            let res = validate_title(&title, Policy::default().title);
             assert!(matches!(
                 res.err().unwrap(),
                 TitleInvalidity::MaxLength {
@@ -74,7 +173,99 @@ mod tests {
             ));
 
             let title = ["a"; 80].join("");
-            assert!(validate_title(&title).is_ok());
+            assert!(validate_title(&title, Policy::default().title).is_ok());
+        }
+
+        #[test]
+        fn should_count_graphemes() {
+            let title = ["\u{f6}"; 80].join("");
+            assert!(validate_title(&title, Policy::default().title).is_ok());
+        }
+
+        #[test]
+        fn should_not_be_blank() {
+            let req = Request {
+                title: "    ",
+                body: None,
+                due_at: None,
+                reminder_offset: None,
+            };
+            let res = validate_thought_properties(&req, &Policy::default());
+            assert!(matches!(
+                res.err().unwrap(),
+                ThoughtInvalidity::Title(TitleInvalidity::MinLength { min: 3, actual: 0 })
+            ));
+        }
+
+        #[test]
+        fn should_not_contain_control_characters() {
+            let res = validate_title("foo\u{1b}bar", Policy::default().title);
+            assert!(matches!(
+                res.err().unwrap(),
+                TitleInvalidity::ControlCharacter { position: 3 }
+            ));
+        }
+
+        #[test]
+        fn should_respect_the_policy() {
+            let limits = LengthLimits { min: 1, max: 2 };
+            assert!(validate_title("a", limits).is_ok());
+            assert!(matches!(
+                validate_title("abc", limits).err().unwrap(),
+                TitleInvalidity::MaxLength { max: 2, actual: 3 }
+            ));
+        }
+    }
+
+    #[cfg(test)]
+    mod the_body {
+        use super::*;
+
+        #[test]
+        fn should_have_max_20_000_chars() {
+            let body = ["a"; 20_001].join("");
+            let res = validate_body(&body, Policy::default().max_body_len);
+            assert!(matches!(
+                res.err().unwrap(),
+                BodyInvalidity::MaxLength {
+                    max: 20_000,
+                    actual: 20_001
+                }
+            ));
+
+            let body = ["a"; 20_000].join("");
+            assert!(validate_body(&body, Policy::default().max_body_len).is_ok());
+            assert!(validate_body("", Policy::default().max_body_len).is_ok());
+        }
+    }
+
+    #[cfg(test)]
+    mod the_reminder {
+        use super::*;
+
+        #[test]
+        fn should_require_a_due_date() {
+            let offset = Some(Duration::from_secs(60));
+            assert!(matches!(
+                validate_due(None, offset),
+                Err(DueInvalidity::ReminderWithoutDueDate)
+            ));
+            let due_at = Some(Timestamp::from_unix_millis(0));
+            assert!(validate_due(due_at, offset).is_ok());
+            assert!(validate_due(due_at, None).is_ok());
+            assert!(validate_due(None, None).is_ok());
+        }
+
+        #[test]
+        fn should_be_max_365_days_before_the_due_date() {
+            let due_at = Some(Timestamp::from_unix_millis(0));
+            let offset = Duration::from_secs(366 * SECS_PER_DAY);
+            assert!(matches!(
+                validate_due(due_at, Some(offset)),
+                Err(DueInvalidity::ReminderMaxOffset { .. })
+            ));
+            let offset = Duration::from_secs(365 * SECS_PER_DAY);
+            assert!(validate_due(due_at, Some(offset)).is_ok());
         }
     }
 }
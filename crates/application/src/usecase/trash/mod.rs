@@ -0,0 +1,15 @@
+//! Deleted thoughts and areas of life are moved to the trash first.
+//! From there they can either be restored or purged for good.
+
+use cawr_domain::{area_of_life as aol, thought};
+
+pub mod purge;
+pub mod read_all;
+pub mod restore;
+
+/// An entity in the trash
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Item {
+    Thought(thought::Id),
+    AreaOfLife(aol::Id),
+}
@@ -0,0 +1,141 @@
+use crate::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{
+        repository::{area_of_life, relation, revision, thought},
+        search_index::SearchIndex,
+        unit_of_work::{Change, Changes, CommitError, UnitOfWork},
+    },
+    usecase::thought::commit_and_reindex,
+};
+use cawr_domain::{Event, Timestamp};
+use std::{collections::HashSet, fmt::Debug, time::Duration};
+use thiserror::Error;
+
+use super::Item;
+
+#[derive(Debug)]
+pub struct Request {
+    /// How long entries stay in the trash.
+    pub retention: Duration,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    pub purged: Vec<Item>,
+}
+
+/// Purge expired entries from the trash usecase interactor
+pub struct Purge<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> {
+    repo: &'r R,
+    clock: &'c C,
+    events: &'e E,
+}
+
+impl<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> Purge<'r, 'c, 'e, R, C, E> {
+    pub const fn new(repo: &'r R, clock: &'c C, events: &'e E) -> Self {
+        Self {
+            repo,
+            clock,
+            events,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{}", thought::DeleteError::Connection)]
+    Repo,
+}
+
+impl From<thought::GetAllError> for Error {
+    fn from(e: thought::GetAllError) -> Self {
+        match e {
+            thought::GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<area_of_life::GetAllError> for Error {
+    fn from(e: area_of_life::GetAllError) -> Self {
+        match e {
+            area_of_life::GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<relation::GetAllError> for Error {
+    fn from(e: relation::GetAllError) -> Self {
+        match e {
+            relation::GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<CommitError> for Error {
+    fn from(e: CommitError) -> Self {
+        match e {
+            // Purged entries are not modified anymore,
+            // so a conflict means the storage is broken.
+            CommitError::Connection | CommitError::Conflict => Self::Repo,
+        }
+    }
+}
+
+impl<'r, 'c, 'e, R, C, E> Purge<'r, 'c, 'e, R, C, E>
+where
+    R: thought::Repo
+        + area_of_life::Repo
+        + relation::Repo
+        + revision::Repo
+        + SearchIndex
+        + UnitOfWork,
+    C: Clock + ?Sized,
+    E: EventPublisher + ?Sized,
+{
+    /// Delete all entries that are in the trash
+    /// for at least the retention period.
+    pub async fn exec(&self, req: &Request) -> Result<Response, Error> {
+        log::debug!("Purge the trash: {:?}", req);
+        let now = self.clock.now();
+        let is_expired = |deleted_at: Timestamp| deleted_at.saturating_add(req.retention) <= now;
+        let mut changes = Changes::default();
+        let mut events = vec![];
+        let mut purged = vec![];
+        let mut unlinked = HashSet::new();
+        for thought::Record { thought } in thought::Repo::get_all(self.repo).await? {
+            if !thought.deleted_at().is_some_and(is_expired) {
+                continue;
+            }
+            let id = thought.id();
+            changes.push(Change::DeleteThought(id));
+            changes.push(Change::DeleteRevisions(id));
+            events.push(Event::ThoughtPurged(id));
+            // Remove dangling links from and to the purged thought
+            for record in relation::Repo::get_by_thought(self.repo, id).await? {
+                if unlinked.insert(record.relation) {
+                    changes.push(Change::DeleteRelation(record.relation));
+                    events.push(Event::ThoughtsUnlinked(record.relation));
+                }
+            }
+            purged.push(Item::Thought(id));
+        }
+        for area_of_life::Record { area_of_life } in area_of_life::Repo::get_all(self.repo).await? {
+            if !area_of_life
+                .deletion()
+                .is_some_and(|d| is_expired(d.deleted_at))
+            {
+                continue;
+            }
+            let id = area_of_life.id();
+            changes.push(Change::DeleteAreaOfLife(id));
+            events.push(Event::AreaOfLifePurged(id));
+            purged.push(Item::AreaOfLife(id));
+        }
+        commit_and_reindex(self.repo, changes).await?;
+        for event in events {
+            self.events.publish(event);
+        }
+        Ok(Response { purged })
+    }
+}
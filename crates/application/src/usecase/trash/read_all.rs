@@ -0,0 +1,88 @@
+use crate::gateway::repository::{area_of_life, thought};
+use cawr_domain::Timestamp;
+use std::fmt::Debug;
+use thiserror::Error;
+
+use super::Item;
+
+#[derive(Debug)]
+pub struct Request;
+
+#[derive(Debug)]
+pub struct Response {
+    /// The most recently deleted entries come first.
+    pub entries: Vec<Entry>,
+}
+
+#[derive(Debug)]
+pub struct Entry {
+    pub item: Item,
+    /// The title of a thought or the name of an area of life.
+    pub label: String,
+    pub deleted_at: Timestamp,
+}
+
+/// Read the content of the trash usecase interactor
+pub struct ReadAll<'r, R> {
+    repo: &'r R,
+}
+
+impl<'r, R> ReadAll<'r, R> {
+    pub const fn new(repo: &'r R) -> Self {
+        Self { repo }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{}", thought::GetAllError::Connection)]
+    Repo,
+}
+
+impl From<thought::GetAllError> for Error {
+    fn from(e: thought::GetAllError) -> Self {
+        match e {
+            thought::GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl From<area_of_life::GetAllError> for Error {
+    fn from(e: area_of_life::GetAllError) -> Self {
+        match e {
+            area_of_life::GetAllError::Connection => Self::Repo,
+        }
+    }
+}
+
+impl<'r, R> ReadAll<'r, R>
+where
+    R: thought::Repo + area_of_life::Repo,
+{
+    pub async fn exec(&self, _: Request) -> Result<Response, Error> {
+        log::debug!("Read the trash");
+        let thoughts = thought::Repo::get_all(self.repo)
+            .await?
+            .into_iter()
+            .filter_map(|thought::Record { thought }| {
+                Some(Entry {
+                    deleted_at: thought.deleted_at()?,
+                    item: Item::Thought(thought.id()),
+                    label: thought.title().as_ref().to_string(),
+                })
+            });
+        let areas_of_life = area_of_life::Repo::get_all(self.repo)
+            .await?
+            .into_iter()
+            .filter_map(|area_of_life::Record { area_of_life }| {
+                Some(Entry {
+                    deleted_at: area_of_life.deletion()?.deleted_at,
+                    item: Item::AreaOfLife(area_of_life.id()),
+                    label: area_of_life.name().as_ref().to_string(),
+                })
+            });
+        let mut entries = thoughts.chain(areas_of_life).collect::<Vec<_>>();
+        entries.sort_by_key(|e| std::cmp::Reverse(e.deleted_at));
+        Ok(Response { entries })
+    }
+}
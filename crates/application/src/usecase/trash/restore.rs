@@ -0,0 +1,158 @@
+use crate::{
+    clock::Clock,
+    event::EventPublisher,
+    gateway::{
+        repository::{area_of_life, thought},
+        search_index::SearchIndex,
+        unit_of_work::{Change, Changes, CommitError, UnitOfWork},
+    },
+    usecase::{
+        area_of_life::get_untrashed,
+        thought::{commit_and_reindex, push_with_revision, reindex, save_with_revision},
+    },
+};
+use cawr_domain::{area_of_life as aol, thought::Id, Event};
+use std::fmt::Debug;
+use thiserror::Error;
+
+use super::Item;
+
+#[derive(Debug)]
+pub struct Request {
+    pub item: Item,
+}
+
+#[derive(Debug)]
+pub struct Response;
+
+/// Restore an entity from the trash usecase interactor
+pub struct Restore<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> {
+    repo: &'r R,
+    clock: &'c C,
+    events: &'e E,
+}
+
+impl<'r, 'c, 'e, R, C: ?Sized, E: ?Sized> Restore<'r, 'c, 'e, R, C, E> {
+    pub const fn new(repo: &'r R, clock: &'c C, events: &'e E) -> Self {
+        Self {
+            repo,
+            clock,
+            events,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("The trash does not contain {0:?}")]
+    NotFound(Item),
+    #[error("{}", thought::GetError::Connection)]
+    Repo,
+    #[error("{}", thought::SaveError::Conflict)]
+    Conflict,
+}
+
+impl From<CommitError> for Error {
+    fn from(e: CommitError) -> Self {
+        match e {
+            CommitError::Connection => Self::Repo,
+            CommitError::Conflict => Self::Conflict,
+        }
+    }
+}
+
+impl<'r, 'c, 'e, R, C, E> Restore<'r, 'c, 'e, R, C, E>
+where
+    R: thought::Repo + area_of_life::Repo + SearchIndex + UnitOfWork,
+    C: Clock + ?Sized,
+    E: EventPublisher + ?Sized,
+{
+    pub async fn exec(&self, req: Request) -> Result<Response, Error> {
+        log::debug!("Restore from the trash: {:?}", req);
+        match req.item {
+            Item::Thought(id) => self.restore_thought(id).await?,
+            Item::AreaOfLife(id) => self.restore_area_of_life(id).await?,
+        }
+        Ok(Response {})
+    }
+
+    async fn restore_thought(&self, id: Id) -> Result<(), Error> {
+        let not_found = || Error::NotFound(Item::Thought(id));
+        let thought::Record { thought } = match thought::Repo::get(self.repo, id).await {
+            Ok(record) => record,
+            Err(thought::GetError::NotFound) => return Err(not_found()),
+            Err(thought::GetError::Connection) => return Err(Error::Repo),
+        };
+        if !thought.is_deleted() {
+            return Err(not_found());
+        }
+        let version = thought.version().next();
+        let thought = thought.with_deleted_at(None).with_version(version);
+        self.repo
+            .commit(save_with_revision(thought.clone(), self.clock.now()))
+            .await?;
+        reindex(self.repo, &thought).await;
+        self.events.publish(Event::ThoughtRestored(id));
+        Ok(())
+    }
+
+    async fn restore_area_of_life(&self, id: aol::Id) -> Result<(), Error> {
+        let not_found = || Error::NotFound(Item::AreaOfLife(id));
+        let area_of_life::Record { area_of_life } =
+            match area_of_life::Repo::get(self.repo, id).await {
+                Ok(record) => record,
+                Err(area_of_life::GetError::NotFound) => return Err(not_found()),
+                Err(area_of_life::GetError::Connection) => return Err(Error::Repo),
+            };
+        let Some(deletion) = area_of_life.deletion().cloned() else {
+            return Err(not_found());
+        };
+        let parent = match area_of_life.parent() {
+            Some(parent) => match get_untrashed(self.repo, parent).await {
+                Ok(_) => Some(parent),
+                Err(area_of_life::GetError::NotFound) => {
+                    log::debug!("Parent {} is gone: move {} to the top", parent, id);
+                    None
+                }
+                Err(area_of_life::GetError::Connection) => return Err(Error::Repo),
+            },
+            None => None,
+        };
+        let version = area_of_life.version().next();
+        let area_of_life = area_of_life
+            .with_deletion(None)
+            .with_parent(parent)
+            .with_version(version);
+        let mut changes = Changes::default();
+        changes.push(Change::SaveAreaOfLife(area_of_life::Record {
+            area_of_life,
+        }));
+        let mut events = vec![Event::AreaOfLifeRestored(id)];
+        let recorded_at = self.clock.now();
+        for thought_id in deletion.thoughts {
+            let thought::Record { thought } = match thought::Repo::get(self.repo, thought_id).await
+            {
+                Ok(record) => record,
+                // The thought was purged in the meantime
+                Err(thought::GetError::NotFound) => continue,
+                Err(thought::GetError::Connection) => return Err(Error::Repo),
+            };
+            log::debug!("Re-attach area of life {} to {}", id, thought_id);
+            let mut areas_of_life = thought.areas_of_life().clone();
+            if !areas_of_life.insert(id) {
+                continue;
+            }
+            let version = thought.version().next();
+            let thought = thought
+                .with_areas_of_life(areas_of_life)
+                .with_version(version);
+            push_with_revision(&mut changes, thought, recorded_at);
+            events.push(Event::ThoughtUpdated(thought_id));
+        }
+        commit_and_reindex(self.repo, changes).await?;
+        for event in events {
+            self.events.publish(event);
+        }
+        Ok(())
+    }
+}
@@ -1,31 +1,247 @@
-use std::{collections::HashSet, sync::Arc};
+use std::{collections::HashSet, io, sync::Arc, time::Duration};
 
 use clap::Subcommand;
 
-use cawr_adapter::{api::Api, db::Db, presenter::cli::Presenter};
+use cawr_adapter::{
+    api::Api,
+    db::Db,
+    identifier::IdStrategy,
+    model::app::{
+        area_of_life::delete::References,
+        thought::{batch::Operation, Schedule},
+    },
+    presenter::cli::Presenter,
+};
 
 #[derive(Subcommand)]
 pub enum Command {
     #[clap(about = "Create a new thought")]
-    Create { title: String },
+    Create {
+        title: String,
+        #[clap(help = "Body text (Markdown)", long)]
+        body: Option<String>,
+        #[clap(help = "ID of a tag to assign", long = "tag")]
+        tags: Vec<String>,
+        #[clap(help = "Due date (Unix timestamp in milliseconds)", long)]
+        due: Option<u64>,
+        #[clap(help = "Remind N minutes before the due date", long, requires = "due")]
+        remind: Option<u64>,
+    },
     #[clap(about = "Read an specific thought")]
     Read { id: String },
+    #[clap(about = "Find thoughts that contain all given words")]
+    Search {
+        #[clap(required = true)]
+        words: Vec<String>,
+    },
+    #[clap(about = "List the revisions of a thought")]
+    Revisions { id: String },
+    #[clap(about = "Show what changed between two revisions of a thought")]
+    Diff { id: String, from: u64, to: u64 },
+    #[clap(about = "Revert a thought to an earlier revision")]
+    Revert { id: String, version: u64 },
+    #[clap(about = "Move a thought to the trash")]
+    Delete { id: String },
+    #[clap(
+        about = "Change many thoughts at once",
+        long_about = "Change many thoughts at once.\n\n\
+                      The IDs of the thoughts are read from stdin, \
+                      separated by whitespace.",
+        subcommand
+    )]
+    Batch(BatchCommand),
+    #[clap(about = "Show overdue and upcoming thoughts")]
+    Agenda {
+        #[clap(help = "Number of days to look ahead", long, default_value_t = 7)]
+        days: u64,
+    },
+    #[clap(about = "Manage areas of life", subcommand)]
+    AreaOfLife(AreaOfLifeCommand),
+    #[clap(about = "Manage deleted thoughts and areas of life", subcommand)]
+    Trash(TrashCommand),
+    #[clap(about = "Check the stored data for integrity problems")]
+    Check {
+        #[clap(help = "Repair the problems that can be repaired automatically", long)]
+        fix: bool,
+    },
 }
 
-pub fn run<D>(db: Arc<D>, cmd: Command)
+#[derive(Subcommand)]
+pub enum BatchCommand {
+    #[clap(about = "Move the thoughts to the trash")]
+    Delete,
+    #[clap(about = "Add an area of life to the thoughts")]
+    Assign { area_of_life: String },
+    #[clap(about = "Remove an area of life from the thoughts")]
+    Unassign { area_of_life: String },
+    #[clap(about = "Replace text in the titles of the thoughts")]
+    Replace { find: String, replace: String },
+}
+
+#[derive(Subcommand)]
+pub enum AreaOfLifeCommand {
+    #[clap(about = "Show an area of life with the number of its thoughts")]
+    Show { id: String },
+    #[clap(about = "List the thoughts of an area of life")]
+    Thoughts { id: String },
+    #[clap(about = "Move an area of life to the trash")]
+    Delete {
+        id: String,
+        #[clap(help = "Delete nested areas of life as well", long)]
+        cascade: bool,
+        #[clap(
+            help = "Refuse to delete an area of life that is used by thoughts",
+            long,
+            conflicts_with = "reassign"
+        )]
+        restrict: bool,
+        #[clap(help = "ID of an area of life that replaces the deleted one", long)]
+        reassign: Option<String>,
+    },
+    #[clap(about = "Move all thoughts of an area of life to another one and remove it")]
+    Merge { source: String, target: String },
+}
+
+#[derive(Subcommand)]
+pub enum TrashCommand {
+    #[clap(about = "List the content of the trash")]
+    List,
+    #[clap(about = "Restore a thought from the trash")]
+    RestoreThought { id: String },
+    #[clap(about = "Restore an area of life from the trash")]
+    RestoreAreaOfLife { id: String },
+    #[clap(about = "Permanently delete old entries from the trash")]
+    Purge {
+        #[clap(
+            help = "Keep entries deleted within the last N days",
+            long,
+            default_value_t = 30
+        )]
+        days: u64,
+    },
+}
+
+pub async fn run<D>(db: Arc<D>, ids: IdStrategy, cmd: Command)
 where
     D: Db,
 {
-    let app_api = Api::new(db, Presenter);
+    let app_api = Api::new(db, Presenter).with_id_strategy(ids);
 
     match cmd {
-        Command::Create { title } => {
+        Command::Create {
+            title,
+            body,
+            tags,
+            due,
+            remind,
+        } => {
             let areas_of_life = HashSet::new(); // Areas of life needs to be added later
-            let res = app_api.create_thought(title, &areas_of_life);
+            let tags = tags.into_iter().collect();
+            let schedule = Schedule {
+                due_at: due,
+                reminder_offset: remind.map(|minutes| Duration::from_secs(minutes * 60)),
+            };
+            let res = app_api
+                .create_thought(title, body, &areas_of_life, &tags, schedule)
+                .await;
             println!("{res}");
         }
         Command::Read { id } => {
-            let res = app_api.find_thought(&id);
+            let res = app_api.find_thought(&id).await;
+            println!("{res}");
+        }
+        Command::Search { words } => {
+            let res = app_api.search_thoughts(words.join(" ")).await;
+            println!("{res}");
+        }
+        Command::Revisions { id } => {
+            let res = app_api.read_thought_revisions(&id).await;
+            println!("{res}");
+        }
+        Command::Diff { id, from, to } => {
+            let res = app_api.diff_thought_revisions(&id, from, to).await;
+            println!("{res}");
+        }
+        Command::Revert { id, version } => {
+            let res = app_api.revert_thought(&id, version).await;
+            println!("{res}");
+        }
+        Command::Delete { id } => {
+            let res = app_api.delete_thought(&id).await;
+            println!("{res}");
+        }
+        Command::Batch(cmd) => {
+            let ids = match io::read_to_string(io::stdin()) {
+                Ok(input) => input
+                    .split_whitespace()
+                    .map(String::from)
+                    .collect::<Vec<_>>(),
+                Err(err) => {
+                    println!("Unable to read thought IDs: {err}");
+                    return;
+                }
+            };
+            let operation = match cmd {
+                BatchCommand::Delete => Operation::Delete,
+                BatchCommand::Assign { area_of_life } => Operation::AssignAreaOfLife(area_of_life),
+                BatchCommand::Unassign { area_of_life } => {
+                    Operation::UnassignAreaOfLife(area_of_life)
+                }
+                BatchCommand::Replace { find, replace } => {
+                    Operation::ReplaceInTitle { find, replace }
+                }
+            };
+            let res = app_api.batch_thoughts(&ids, operation).await;
+            println!("{res}");
+        }
+        Command::Agenda { days } => {
+            let res = app_api
+                .read_due_thoughts(Duration::from_secs(days * 24 * 60 * 60))
+                .await;
+            println!("{res}");
+        }
+        Command::AreaOfLife(cmd) => {
+            let res = match cmd {
+                AreaOfLifeCommand::Show { id } => app_api.find_area_of_life(&id).await,
+                AreaOfLifeCommand::Thoughts { id } => {
+                    app_api
+                        .read_area_of_life_thoughts(&id, &Default::default())
+                        .await
+                }
+                AreaOfLifeCommand::Delete {
+                    id,
+                    cascade,
+                    restrict,
+                    reassign,
+                } => {
+                    let references = match reassign {
+                        Some(target) => References::Reassign(target),
+                        None if restrict => References::Restrict,
+                        None => References::Detach,
+                    };
+                    app_api.delete_area_of_life(&id, cascade, references).await
+                }
+                AreaOfLifeCommand::Merge { source, target } => {
+                    app_api.merge_areas_of_life(&source, &target).await
+                }
+            };
+            println!("{res}");
+        }
+        Command::Check { fix } => {
+            let res = app_api.check_integrity(fix).await;
+            println!("{res}");
+        }
+        Command::Trash(cmd) => {
+            let res = match cmd {
+                TrashCommand::List => app_api.read_trash().await,
+                TrashCommand::RestoreThought { id } => app_api.restore_thought(&id).await,
+                TrashCommand::RestoreAreaOfLife { id } => app_api.restore_area_of_life(&id).await,
+                TrashCommand::Purge { days } => {
+                    app_api
+                        .purge_trash(Duration::from_secs(days * 24 * 60 * 60))
+                        .await
+                }
+            };
             println!("{res}");
         }
     }
@@ -1,11 +1,16 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::atomic::{AtomicU64, Ordering},
+};
 
 use parking_lot::RwLock;
 
+use crate::search_index::InvertedIndex;
 use cawr_adapter::db::Db;
 use cawr_application::{
     gateway::repository::{
-        area_of_life::Record as AreaOfLifeRecord, thought::Record as ThoughtRecord,
+        area_of_life::Record as AreaOfLifeRecord, revision::Record as RevisionRecord,
+        tag::Record as TagRecord, thought::Record as ThoughtRecord,
     },
     identifier::{NewId, NewIdError},
 };
@@ -14,39 +19,73 @@ use cawr_application::{
 pub struct InMemory {
     thoughts: RwLock<HashMap<cawr_domain::thought::Id, ThoughtRecord>>,
     areas_of_life: RwLock<HashMap<cawr_domain::area_of_life::Id, AreaOfLifeRecord>>,
+    tags: RwLock<HashMap<cawr_domain::tag::Id, TagRecord>>,
+    relations: RwLock<HashSet<cawr_domain::Relation>>,
+    revisions: RwLock<HashMap<(cawr_domain::thought::Id, cawr_domain::Version), RevisionRecord>>,
+    search_index: InvertedIndex,
+    last_thought_id: AtomicU64,
+    last_area_of_life_id: AtomicU64,
+    last_tag_id: AtomicU64,
+}
+
+impl InMemory {
+    /// Hands out the next sequential ID without reusing
+    /// IDs that were handed out before or are already stored.
+    fn next_id(last: &AtomicU64, stored: impl Iterator<Item = u64>) -> u64 {
+        last.fetch_max(stored.max().unwrap_or(0), Ordering::SeqCst);
+        last.fetch_add(1, Ordering::SeqCst) + 1
+    }
 }
 
 impl Db for InMemory {}
 
 mod thought {
     use super::{InMemory, NewId, NewIdError};
-    use cawr_application::gateway::repository::thought::{
-        DeleteError, GetAllError, GetError, Record, Repo, SaveError,
+    use async_trait::async_trait;
+    use cawr_application::gateway::repository::{
+        query::Paginated,
+        thought::{DeleteError, GetAllError, GetError, Query, Record, Repo, SaveError},
     };
-    use cawr_domain::thought::Id;
+    use cawr_domain::{area_of_life as aol, thought::Id};
 
+    #[async_trait]
     impl Repo for InMemory {
-        fn save(&self, record: Record) -> Result<(), SaveError> {
-            self.thoughts.write().insert(record.thought.id(), record);
+        async fn save(&self, record: Record) -> Result<(), SaveError> {
+            let mut thoughts = self.thoughts.write();
+            if let Some(stored) = thoughts.get(&record.thought.id()) {
+                if stored.thought.version().next() != record.thought.version() {
+                    return Err(SaveError::Conflict);
+                }
+            }
+            thoughts.insert(record.thought.id(), record);
             Ok(())
         }
-        fn get(&self, id: Id) -> Result<Record, GetError> {
+        async fn get(&self, id: Id) -> Result<Record, GetError> {
             self.thoughts
                 .read()
                 .get(&id)
                 .cloned()
                 .ok_or(GetError::NotFound)
         }
-        fn get_all(&self) -> Result<Vec<Record>, GetAllError> {
+        async fn get_all(&self) -> Result<Vec<Record>, GetAllError> {
+            Ok(self.thoughts.read().values().cloned().collect())
+        }
+        async fn query(&self, query: &Query) -> Result<Paginated<Record>, GetAllError> {
+            // Only clone the records of the requested page
+            Ok(query
+                .apply(self.thoughts.read().values())
+                .map(Record::clone))
+        }
+        async fn get_by_area_of_life(&self, id: aol::Id) -> Result<Vec<Record>, GetAllError> {
             Ok(self
                 .thoughts
                 .read()
-                .iter()
-                .map(|(_, r)| r)
+                .values()
+                .filter(|r| r.thought.areas_of_life().contains(&id))
                 .cloned()
                 .collect())
         }
-        fn delete(&self, id: Id) -> Result<(), DeleteError> {
+        async fn delete(&self, id: Id) -> Result<(), DeleteError> {
             self.thoughts
                 .write()
                 .remove(&id)
@@ -55,16 +94,12 @@ mod thought {
         }
     }
 
+    #[async_trait]
     impl NewId<Id> for InMemory {
-        fn new_id(&self) -> Result<Id, NewIdError> {
-            let next = self
-                .thoughts
-                .read()
-                .iter()
-                .map(|(id, _)| id.to_u64())
-                .max()
-                .unwrap_or(0)
-                + 1;
+        async fn new_id(&self) -> Result<Id, NewIdError> {
+            let stored = self.thoughts.read();
+            let ids = stored.keys().filter_map(|id| id.to_sequential());
+            let next = Self::next_id(&self.last_thought_id, ids);
             Ok(Id::from(next))
         }
     }
@@ -72,35 +107,49 @@ mod thought {
 
 mod area_of_life {
     use super::{InMemory, NewId, NewIdError};
-    use cawr_application::gateway::repository::area_of_life::{
-        DeleteError, GetAllError, GetError, Record, Repo, SaveError,
+    use async_trait::async_trait;
+    use cawr_application::gateway::repository::{
+        area_of_life::{DeleteError, GetAllError, GetError, Query, Record, Repo, SaveError},
+        query::Paginated,
     };
     use cawr_domain::area_of_life::Id;
 
+    #[async_trait]
     impl Repo for InMemory {
-        fn save(&self, record: Record) -> Result<(), SaveError> {
-            self.areas_of_life
-                .write()
-                .insert(record.area_of_life.id(), record);
+        async fn save(&self, record: Record) -> Result<(), SaveError> {
+            let mut areas_of_life = self.areas_of_life.write();
+            if let Some(stored) = areas_of_life.get(&record.area_of_life.id()) {
+                if stored.area_of_life.version().next() != record.area_of_life.version() {
+                    return Err(SaveError::Conflict);
+                }
+            }
+            areas_of_life.insert(record.area_of_life.id(), record);
             Ok(())
         }
-        fn get(&self, id: Id) -> Result<Record, GetError> {
+        async fn get(&self, id: Id) -> Result<Record, GetError> {
             self.areas_of_life
                 .read()
                 .get(&id)
                 .cloned()
                 .ok_or(GetError::NotFound)
         }
-        fn get_all(&self) -> Result<Vec<Record>, GetAllError> {
-            Ok(self
-                .areas_of_life
+        async fn get_by_normalized_name(&self, name: &str) -> Result<Record, GetError> {
+            self.areas_of_life
                 .read()
-                .iter()
-                .map(|(_, r)| r)
+                .values()
+                .find(|r| r.area_of_life.name().normalized() == name)
                 .cloned()
-                .collect())
+                .ok_or(GetError::NotFound)
         }
-        fn delete(&self, id: Id) -> Result<(), DeleteError> {
+        async fn get_all(&self) -> Result<Vec<Record>, GetAllError> {
+            Ok(self.areas_of_life.read().values().cloned().collect())
+        }
+        async fn query(&self, query: &Query) -> Result<Paginated<Record>, GetAllError> {
+            Ok(query
+                .apply(self.areas_of_life.read().values())
+                .map(Record::clone))
+        }
+        async fn delete(&self, id: Id) -> Result<(), DeleteError> {
             self.areas_of_life
                 .write()
                 .remove(&id)
@@ -109,17 +158,223 @@ mod area_of_life {
         }
     }
 
+    #[async_trait]
     impl NewId<Id> for InMemory {
-        fn new_id(&self) -> Result<Id, NewIdError> {
-            let next = self
-                .areas_of_life
+        async fn new_id(&self) -> Result<Id, NewIdError> {
+            let stored = self.areas_of_life.read();
+            let ids = stored.keys().filter_map(|id| id.to_sequential());
+            let next = Self::next_id(&self.last_area_of_life_id, ids);
+            Ok(Id::from(next))
+        }
+    }
+}
+
+mod tag {
+    use super::{InMemory, NewId, NewIdError};
+    use async_trait::async_trait;
+    use cawr_application::gateway::repository::tag::{
+        DeleteError, GetAllError, GetError, Record, Repo, SaveError,
+    };
+    use cawr_domain::tag::Id;
+
+    #[async_trait]
+    impl Repo for InMemory {
+        async fn save(&self, record: Record) -> Result<(), SaveError> {
+            self.tags.write().insert(record.tag.id(), record);
+            Ok(())
+        }
+        async fn get(&self, id: Id) -> Result<Record, GetError> {
+            self.tags.read().get(&id).cloned().ok_or(GetError::NotFound)
+        }
+        async fn get_all(&self) -> Result<Vec<Record>, GetAllError> {
+            Ok(self.tags.read().values().cloned().collect())
+        }
+        async fn delete(&self, id: Id) -> Result<(), DeleteError> {
+            self.tags
+                .write()
+                .remove(&id)
+                .map(|_| ())
+                .ok_or(DeleteError::NotFound)
+        }
+    }
+
+    #[async_trait]
+    impl NewId<Id> for InMemory {
+        async fn new_id(&self) -> Result<Id, NewIdError> {
+            let stored = self.tags.read();
+            let ids = stored.keys().filter_map(|id| id.to_sequential());
+            let next = Self::next_id(&self.last_tag_id, ids);
+            Ok(Id::from(next))
+        }
+    }
+}
+
+mod relation {
+    use super::InMemory;
+    use async_trait::async_trait;
+    use cawr_application::gateway::repository::relation::{
+        DeleteError, GetAllError, Record, Repo, SaveError,
+    };
+    use cawr_domain::{relation::Relation, thought};
+
+    #[async_trait]
+    impl Repo for InMemory {
+        async fn save(&self, record: Record) -> Result<(), SaveError> {
+            self.relations.write().insert(record.relation);
+            Ok(())
+        }
+        async fn get_by_thought(&self, id: thought::Id) -> Result<Vec<Record>, GetAllError> {
+            Ok(self
+                .relations
                 .read()
                 .iter()
-                .map(|(id, _)| id.to_u64())
-                .max()
-                .unwrap_or(0)
-                + 1;
-            Ok(Id::from(next))
+                .filter(|r| r.source() == id || r.target() == id)
+                .map(|relation| Record {
+                    relation: *relation,
+                })
+                .collect())
+        }
+        async fn delete(&self, relation: &Relation) -> Result<(), DeleteError> {
+            if self.relations.write().remove(relation) {
+                Ok(())
+            } else {
+                Err(DeleteError::NotFound)
+            }
+        }
+    }
+}
+
+mod revision {
+    use super::InMemory;
+    use async_trait::async_trait;
+    use cawr_application::gateway::repository::revision::{
+        DeleteError, GetAllError, GetError, Record, Repo, SaveError,
+    };
+    use cawr_domain::{thought::Id, Version};
+
+    #[async_trait]
+    impl Repo for InMemory {
+        async fn save(&self, record: Record) -> Result<(), SaveError> {
+            let key = (record.thought.id(), record.thought.version());
+            self.revisions.write().insert(key, record);
+            Ok(())
+        }
+        async fn get(&self, id: Id, version: Version) -> Result<Record, GetError> {
+            self.revisions
+                .read()
+                .get(&(id, version))
+                .cloned()
+                .ok_or(GetError::NotFound)
+        }
+        async fn get_by_thought(&self, id: Id) -> Result<Vec<Record>, GetAllError> {
+            let mut records = self
+                .revisions
+                .read()
+                .values()
+                .filter(|r| r.thought.id() == id)
+                .cloned()
+                .collect::<Vec<_>>();
+            records.sort_by_key(|r| r.thought.version());
+            Ok(records)
+        }
+        async fn delete_by_thought(&self, id: Id) -> Result<(), DeleteError> {
+            self.revisions
+                .write()
+                .retain(|(thought, _), _| *thought != id);
+            Ok(())
+        }
+    }
+}
+
+mod unit_of_work {
+    use super::InMemory;
+    use async_trait::async_trait;
+    use cawr_application::gateway::unit_of_work::{Change, Changes, CommitError, UnitOfWork};
+
+    #[async_trait]
+    impl UnitOfWork for InMemory {
+        async fn commit(&self, changes: Changes) -> Result<(), CommitError> {
+            // Hold all locks until every change is applied
+            // so that nobody sees a partial commit.
+            let mut thoughts = self.thoughts.write();
+            let mut areas_of_life = self.areas_of_life.write();
+            let mut tags = self.tags.write();
+            let mut relations = self.relations.write();
+            let mut revisions = self.revisions.write();
+            changes.check_versions(
+                |id| Ok(thoughts.get(&id).map(|r| r.thought.version())),
+                |id| Ok(areas_of_life.get(&id).map(|r| r.area_of_life.version())),
+            )?;
+            for change in changes {
+                match change {
+                    Change::SaveThought(record) => {
+                        thoughts.insert(record.thought.id(), record);
+                    }
+                    Change::DeleteThought(id) => {
+                        thoughts.remove(&id);
+                    }
+                    Change::SaveAreaOfLife(record) => {
+                        areas_of_life.insert(record.area_of_life.id(), record);
+                    }
+                    Change::DeleteAreaOfLife(id) => {
+                        areas_of_life.remove(&id);
+                    }
+                    Change::DeleteTag(id) => {
+                        tags.remove(&id);
+                    }
+                    Change::SaveRevision(record) => {
+                        let key = (record.thought.id(), record.thought.version());
+                        revisions.insert(key, record);
+                    }
+                    Change::DeleteRevisions(id) => {
+                        revisions.retain(|(thought, _), _| *thought != id);
+                    }
+                    Change::DeleteRelation(relation) => {
+                        relations.remove(&relation);
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+mod search_index {
+    use super::InMemory;
+    use async_trait::async_trait;
+    use cawr_application::gateway::search_index::{Hit, IndexError, SearchError, SearchIndex};
+    use cawr_domain::thought::{Id, Thought};
+
+    #[async_trait]
+    impl SearchIndex for InMemory {
+        async fn index(&self, thought: &Thought) -> Result<(), IndexError> {
+            self.search_index.insert(thought);
+            Ok(())
+        }
+        async fn remove(&self, id: Id) -> Result<(), IndexError> {
+            self.search_index.remove(id);
+            Ok(())
+        }
+        async fn search(&self, query: &str) -> Result<Vec<Hit>, SearchError> {
+            Ok(self.search_index.search(query))
+        }
+    }
+}
+
+mod integrity {
+    use super::InMemory;
+    use async_trait::async_trait;
+    use cawr_application::gateway::integrity::{CheckError, Integrity, RepairError, StorageIssue};
+
+    /// The records are kept by their ID and new IDs follow the highest one,
+    /// so there is nothing that could break.
+    #[async_trait]
+    impl Integrity for InMemory {
+        async fn check_storage(&self) -> Result<Vec<StorageIssue>, CheckError> {
+            Ok(vec![])
+        }
+        async fn repair_storage(&self, _: StorageIssue) -> Result<(), RepairError> {
+            Ok(())
         }
     }
 }
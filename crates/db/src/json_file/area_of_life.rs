@@ -1,39 +1,53 @@
-use super::{models, JsonFile, LAST_AREA_OF_LIFE_ID_KEY, MAP_AREA_OF_LIFE_ID_KEY};
-use cawr_adapter::model::app::area_of_life as app;
+use super::{models, unblock, JsonFile, LAST_AREA_OF_LIFE_ID_KEY, MAP_AREA_OF_LIFE_ID_KEY};
+use async_trait::async_trait;
+use cawr_adapter::model::app::{area_of_life as app, thought};
 use cawr_application::{
-    gateway::repository::{
-        area_of_life::{DeleteError, GetAllError, GetError, Record, Repo, SaveError},
-        thought::Repo as ThoughtRepo,
+    gateway::{
+        repository::{
+            area_of_life::{DeleteError, GetAllError, GetError, Query, Record, Repo, SaveError},
+            query::Paginated,
+        },
+        unit_of_work::{Change, Changes},
     },
     identifier::{NewId, NewIdError},
 };
 use cawr_domain::{
-    area_of_life::{AreaOfLife, Id, Name},
-    thought::Thought,
+    area_of_life::{AreaOfLife, Deletion, Id, Name},
+    Timestamp, Version,
 };
 use std::io;
 
+#[async_trait]
 impl NewId<Id> for JsonFile {
-    fn new_id(&self) -> Result<Id, NewIdError> {
-        let id = self.new_id(LAST_AREA_OF_LIFE_ID_KEY)?;
-        Ok(id)
+    async fn new_id(&self) -> Result<Id, NewIdError> {
+        let db = self.clone();
+        unblock(move || db.new_id(LAST_AREA_OF_LIFE_ID_KEY)).await
     }
 }
 
-impl Repo for JsonFile {
-    fn save(&self, record: Record) -> Result<(), SaveError> {
+impl JsonFile {
+    fn save_area_of_life(&self, record: Record) -> Result<(), SaveError> {
         log::debug!("Save area of life {:?} to JSON file", record);
+        // Plain writes must not interfere with a unit of work.
+        let _lock = self.commit_lock.lock();
         let Record { area_of_life } = record;
-        let name = area_of_life.name();
         let id = area_of_life.id();
-        let model = models::AreaOfLife {
-            area_of_life_id: id.to_string(),
-            name: String::from(name.as_ref()),
-        };
+        let model = to_model(&area_of_life);
 
         match self.storage_id(area_of_life.id(), MAP_AREA_OF_LIFE_ID_KEY) {
             Ok(storage_id) => {
                 log::debug!("Update area of life {}", area_of_life.id());
+                let stored = self
+                    .areas_of_life
+                    .get::<models::AreaOfLife>(&storage_id)
+                    .map_err(|err| {
+                        log::warn!("Unable to fetch area of life: {}", err);
+                        SaveError::Connection
+                    })?;
+                if Version::new(stored.version).next() != area_of_life.version() {
+                    log::debug!("Area of life {} was modified concurrently", id);
+                    return Err(SaveError::Conflict);
+                }
                 let sid = self
                     .areas_of_life
                     .save_with_id(&model, &storage_id)
@@ -63,7 +77,7 @@ impl Repo for JsonFile {
         }
         Ok(())
     }
-    fn get(&self, id: Id) -> Result<Record, GetError> {
+    pub(super) fn get_area_of_life(&self, id: Id) -> Result<Record, GetError> {
         log::debug!("Get area of life {:?} from JSON file", id);
         let sid = self
             .storage_id(id, MAP_AREA_OF_LIFE_ID_KEY)
@@ -88,11 +102,42 @@ impl Repo for JsonFile {
             })?;
         debug_assert_eq!(id.to_string(), model.area_of_life_id);
         Ok(Record {
-            area_of_life: AreaOfLife::new(id, Name::new(model.name)),
+            area_of_life: from_model(id, model),
         })
     }
-    fn get_all(&self) -> Result<Vec<Record>, GetAllError> {
+    fn get_area_of_life_by_normalized_name(&self, name: &str) -> Result<Record, GetError> {
+        log::debug!("Get area of life named '{}' from JSON file", name);
+        self.areas_of_life
+            .all::<models::AreaOfLife>()
+            .map_err(|err| {
+                log::warn!("Unable to load all areas of life: {}", err);
+                GetError::Connection
+            })?
+            .into_values()
+            .find(|model| Name::normalize(&model.name) == name)
+            .and_then(|model| {
+                let id = model.area_of_life_id.parse::<app::Id>().ok()?;
+                Some(Record {
+                    area_of_life: from_model(id.into(), model),
+                })
+            })
+            .ok_or(GetError::NotFound)
+    }
+    fn get_all_areas_of_life(&self) -> Result<Vec<Record>, GetAllError> {
         log::debug!("Get all areas of life from JSON file");
+        self.load_areas_of_life(|_| true)
+    }
+    fn query_areas_of_life(&self, query: &Query) -> Result<Paginated<Record>, GetAllError> {
+        log::debug!("Query areas of life {:?} from JSON file", query);
+        // The trash is skipped before the models are converted.
+        let records = self.load_areas_of_life(|model| model.deletion.is_none())?;
+        Ok(query.apply(records))
+    }
+    /// Load all areas of life whose stored model is accepted by `keep`.
+    fn load_areas_of_life<F>(&self, keep: F) -> Result<Vec<Record>, GetAllError>
+    where
+        F: Fn(&models::AreaOfLife) -> bool,
+    {
         let areas_of_life = self
             .areas_of_life
             .all::<models::AreaOfLife>()
@@ -100,62 +145,107 @@ impl Repo for JsonFile {
                 log::warn!("Unable to load all areas of life: {}", err);
                 GetAllError::Connection
             })?
-            .into_iter()
-            .filter_map(|(_, model)| {
+            .into_values()
+            .filter(keep)
+            .filter_map(|model| {
                 model
                     .area_of_life_id
                     .parse::<app::Id>()
                     .ok()
-                    .map(Into::into)
-                    .map(|id| (id, model.name))
-            })
-            .map(|(id, name)| Record {
-                area_of_life: AreaOfLife::new(id, Name::new(name)),
+                    .map(|id| from_model(id.into(), model))
             })
+            .map(|area_of_life| Record { area_of_life })
             .collect();
         Ok(areas_of_life)
     }
-    fn delete(&self, id: Id) -> Result<(), DeleteError> {
+    fn delete_area_of_life(&self, id: Id) -> Result<(), DeleteError> {
         log::debug!("Delete area of life {:?} from JSON file", id);
-        let sid = self
-            .storage_id(id, MAP_AREA_OF_LIFE_ID_KEY)
-            .map_err(|err| {
-                log::warn!("Unable to get area of life ID: {}", err);
-                if err.kind() == io::ErrorKind::NotFound {
-                    DeleteError::NotFound
-                } else {
-                    DeleteError::Connection
-                }
-            })?;
-        self.areas_of_life.delete(&sid).map_err(|err| {
-            log::warn!("Unable to delete area of life: {}", err);
-            if err.kind() == io::ErrorKind::NotFound {
-                DeleteError::NotFound
-            } else {
-                DeleteError::Connection
-            }
+        self.get_area_of_life(id).map_err(|err| match err {
+            GetError::NotFound => DeleteError::NotFound,
+            GetError::Connection => DeleteError::Connection,
         })?;
-
-        let thoughts = (self as &dyn ThoughtRepo).get_all().map_err(|err| {
-            log::warn!("Unable to load thoughts: {}", err);
+        let mut changes = Changes::default();
+        changes.push(Change::DeleteAreaOfLife(id));
+        self.commit_changes(changes).map_err(|err| {
+            log::warn!("Unable to delete area of life: {}", err);
             DeleteError::Connection
-        })?;
+        })
+    }
+}
 
-        log::debug!("Delete area of life {id} from thoughts");
-        for mut rec in thoughts {
-            if rec.thought.areas_of_life().iter().any(|x| x == &id) {
-                log::debug!("Delete area of life {id} from {:?}", rec.thought);
-                let mut areas_of_life = rec.thought.areas_of_life().clone();
-                areas_of_life.retain(|x| x != &id);
-                let updated_thought =
-                    Thought::new(rec.thought.id(), rec.thought.title().clone(), areas_of_life);
-                rec.thought = updated_thought;
-                (self as &dyn ThoughtRepo).save(rec).map_err(|err| {
-                    log::warn!("Unable to save thought: {}", err);
-                    DeleteError::Connection
-                })?;
-            }
-        }
-        Ok(())
+#[async_trait]
+impl Repo for JsonFile {
+    async fn save(&self, record: Record) -> Result<(), SaveError> {
+        let db = self.clone();
+        unblock(move || db.save_area_of_life(record)).await
+    }
+    async fn get(&self, id: Id) -> Result<Record, GetError> {
+        let db = self.clone();
+        unblock(move || db.get_area_of_life(id)).await
+    }
+    async fn get_by_normalized_name(&self, name: &str) -> Result<Record, GetError> {
+        let (db, name) = (self.clone(), name.to_string());
+        unblock(move || db.get_area_of_life_by_normalized_name(&name)).await
+    }
+    async fn get_all(&self) -> Result<Vec<Record>, GetAllError> {
+        let db = self.clone();
+        unblock(move || db.get_all_areas_of_life()).await
+    }
+    async fn query(&self, query: &Query) -> Result<Paginated<Record>, GetAllError> {
+        let (db, query) = (self.clone(), query.clone());
+        unblock(move || db.query_areas_of_life(&query)).await
     }
+    async fn delete(&self, id: Id) -> Result<(), DeleteError> {
+        let db = self.clone();
+        unblock(move || db.delete_area_of_life(id)).await
+    }
+}
+
+pub(super) fn to_model(area_of_life: &AreaOfLife) -> models::AreaOfLife {
+    models::AreaOfLife {
+        area_of_life_id: area_of_life.id().to_string(),
+        name: String::from(area_of_life.name().as_ref()),
+        parent: area_of_life.parent().map(|id| id.to_string()),
+        created_at: area_of_life.created_at().to_unix_millis(),
+        updated_at: area_of_life.updated_at().to_unix_millis(),
+        version: area_of_life.version().to_u64(),
+        deletion: area_of_life
+            .deletion()
+            .map(|deletion| models::AreaOfLifeDeletion {
+                deleted_at: deletion.deleted_at.to_unix_millis(),
+                thoughts: deletion.thoughts.iter().map(ToString::to_string).collect(),
+            }),
+    }
+}
+
+fn from_model(id: Id, model: models::AreaOfLife) -> AreaOfLife {
+    let created_at = Timestamp::from_unix_millis(model.created_at);
+    let updated_at = Timestamp::from_unix_millis(model.updated_at);
+    let parent = model.parent.and_then(|id| {
+        id.parse::<app::Id>()
+            .map_err(|err| {
+                log::warn!("{}", err);
+            })
+            .map(Into::into)
+            .ok()
+    });
+    let deletion = model.deletion.map(|deletion| Deletion {
+        deleted_at: Timestamp::from_unix_millis(deletion.deleted_at),
+        thoughts: deletion
+            .thoughts
+            .into_iter()
+            .filter_map(|id| {
+                id.parse::<thought::Id>()
+                    .map_err(|err| {
+                        log::warn!("{}", err);
+                    })
+                    .map(Into::into)
+                    .ok()
+            })
+            .collect(),
+    });
+    AreaOfLife::new(id, Name::new(model.name), created_at, updated_at)
+        .with_parent(parent)
+        .with_version(Version::new(model.version))
+        .with_deletion(deletion)
 }
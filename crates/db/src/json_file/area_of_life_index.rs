@@ -0,0 +1,71 @@
+//! An in-process index of the thoughts that belong to each area of life.
+
+use cawr_domain::{
+    area_of_life as aol,
+    thought::{Id, Thought},
+};
+use parking_lot::RwLock;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Default)]
+pub(super) struct AreaOfLifeIndex {
+    inner: RwLock<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    thoughts: HashMap<aol::Id, HashSet<Id>>,
+    /// The indexed areas of life of each thought.
+    areas_of_life: HashMap<Id, HashSet<aol::Id>>,
+}
+
+impl AreaOfLifeIndex {
+    /// Build an index of all thoughts, including those in the trash.
+    pub fn from_thoughts<'t>(thoughts: impl IntoIterator<Item = &'t Thought>) -> Self {
+        let index = Self::default();
+        for thought in thoughts {
+            index.insert(thought);
+        }
+        index
+    }
+    /// Add the thought or replace its previous entry.
+    pub fn insert(&self, thought: &Thought) {
+        let mut inner = self.inner.write();
+        inner.remove(thought.id());
+        for aol_id in thought.areas_of_life() {
+            inner
+                .thoughts
+                .entry(*aol_id)
+                .or_default()
+                .insert(thought.id());
+        }
+        inner
+            .areas_of_life
+            .insert(thought.id(), thought.areas_of_life().clone());
+    }
+    pub fn remove(&self, id: Id) {
+        self.inner.write().remove(id);
+    }
+    /// The IDs of all thoughts that belong to the area of life.
+    pub fn thoughts(&self, id: aol::Id) -> Vec<Id> {
+        self.inner
+            .read()
+            .thoughts
+            .get(&id)
+            .map(|ids| ids.iter().copied().collect())
+            .unwrap_or_default()
+    }
+}
+
+impl Inner {
+    fn remove(&mut self, id: Id) {
+        for aol_id in self.areas_of_life.remove(&id).unwrap_or_default() {
+            if let Some(ids) = self.thoughts.get_mut(&aol_id) {
+                ids.remove(&id);
+                if ids.is_empty() {
+                    self.thoughts.remove(&aol_id);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use super::{
+    models, unblock, JsonFile, StorageId, LAST_AREA_OF_LIFE_ID_KEY, LAST_TAG_ID_KEY,
+    LAST_THOUGHT_ID_KEY, MAP_AREA_OF_LIFE_ID_KEY, MAP_TAG_ID_KEY, MAP_THOUGHT_ID_KEY,
+};
+use async_trait::async_trait;
+use cawr_adapter::model::app::thought as app;
+use cawr_application::gateway::integrity::{
+    CheckError, Entity, Integrity, RepairError, StorageIssue,
+};
+use cawr_domain::thought::Id;
+use jfs::Store;
+use std::{collections::HashMap, io};
+
+impl JsonFile {
+    const fn layout(&self, entity: Entity) -> (&Store, &'static str, &'static str) {
+        match entity {
+            Entity::Thought => (&self.thoughts, MAP_THOUGHT_ID_KEY, LAST_THOUGHT_ID_KEY),
+            Entity::AreaOfLife => (
+                &self.areas_of_life,
+                MAP_AREA_OF_LIFE_ID_KEY,
+                LAST_AREA_OF_LIFE_ID_KEY,
+            ),
+            Entity::Tag => (&self.tags, MAP_TAG_ID_KEY, LAST_TAG_ID_KEY),
+        }
+    }
+    /// The IDs of all stored records by their storage ID.
+    fn stored_ids(&self, entity: Entity) -> Result<HashMap<StorageId, u128>, io::Error> {
+        let ids: HashMap<StorageId, String> = match entity {
+            Entity::Thought => self
+                .thoughts
+                .all::<models::Thought>()?
+                .into_iter()
+                .map(|(sid, m)| (sid, m.thought_id))
+                .collect(),
+            Entity::AreaOfLife => self
+                .areas_of_life
+                .all::<models::AreaOfLife>()?
+                .into_iter()
+                .map(|(sid, m)| (sid, m.area_of_life_id))
+                .collect(),
+            Entity::Tag => self
+                .tags
+                .all::<models::Tag>()?
+                .into_iter()
+                .map(|(sid, m)| (sid, m.tag_id))
+                .collect(),
+        };
+        // Records with an invalid ID are not visible at all.
+        Ok(ids
+            .into_iter()
+            .filter_map(|(sid, id)| parse_id(&id).map(|id| (sid, id)))
+            .collect())
+    }
+    fn id_map(&self, key: &str) -> Result<HashMap<String, StorageId>, io::Error> {
+        match self.ids.get::<HashMap<String, StorageId>>(key) {
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(HashMap::new()),
+            res => res,
+        }
+    }
+    fn check_entity(&self, entity: Entity) -> Result<Vec<StorageIssue>, io::Error> {
+        let (_, map_key, last_key) = self.layout(entity);
+        let map = self.id_map(map_key)?;
+        let stored = self.stored_ids(entity)?;
+        let mut issues = vec![];
+        for (id, sid) in &map {
+            if stored.contains_key(sid) {
+                continue;
+            }
+            if let Some(id) = parse_id(id) {
+                issues.push(StorageIssue::OrphanedId { entity, id });
+            }
+        }
+        let mut by_id: HashMap<u128, Vec<&StorageId>> = HashMap::new();
+        for (sid, id) in &stored {
+            by_id.entry(*id).or_default().push(sid);
+        }
+        for (&id, sids) in &by_id {
+            let mapped = map.get(&to_string(id));
+            if sids.len() > 1 {
+                issues.push(StorageIssue::DuplicateId {
+                    entity,
+                    id,
+                    count: sids.len(),
+                });
+            } else if mapped != Some(sids[0]) {
+                issues.push(StorageIssue::MissingId { entity, id });
+            }
+        }
+        let max = by_id
+            .keys()
+            .filter_map(|&id| u64::try_from(id).ok())
+            .max()
+            .unwrap_or(0);
+        let counter = match self.ids.get::<u64>(last_key) {
+            Err(err) if err.kind() == io::ErrorKind::NotFound => 0,
+            res => res?,
+        };
+        if counter < max {
+            issues.push(StorageIssue::IdCounterDrift {
+                entity,
+                counter,
+                max,
+            });
+        }
+        issues.sort_by_key(|issue| match *issue {
+            StorageIssue::OrphanedId { id, .. }
+            | StorageIssue::MissingId { id, .. }
+            | StorageIssue::DuplicateId { id, .. } => id,
+            StorageIssue::IdCounterDrift { .. } => u128::MAX,
+        });
+        Ok(issues)
+    }
+    fn check(&self) -> Result<Vec<StorageIssue>, io::Error> {
+        let mut issues = vec![];
+        for entity in [Entity::Thought, Entity::AreaOfLife, Entity::Tag] {
+            issues.extend(self.check_entity(entity)?);
+        }
+        Ok(issues)
+    }
+    fn repair(&self, issue: StorageIssue) -> Result<(), io::Error> {
+        match issue {
+            StorageIssue::OrphanedId { entity, id } => {
+                let (_, map_key, _) = self.layout(entity);
+                let mut map = self.id_map(map_key)?;
+                let id = to_string(id);
+                let Some(sid) = map.get(&id) else {
+                    return Ok(());
+                };
+                if !self.stored_ids(entity)?.contains_key(sid) {
+                    map.remove(&id);
+                    self.ids.save_with_id(&map, map_key)?;
+                }
+            }
+            StorageIssue::MissingId { entity, id } => {
+                let (_, map_key, _) = self.layout(entity);
+                let sid = self
+                    .stored_ids(entity)?
+                    .into_iter()
+                    .find_map(|(sid, stored)| (stored == id).then_some(sid));
+                if let Some(sid) = sid {
+                    self.save_id(sid, to_string(id), map_key)?;
+                }
+            }
+            StorageIssue::DuplicateId { entity, id, .. } => {
+                let (store, map_key, _) = self.layout(entity);
+                let mut sids = self
+                    .stored_ids(entity)?
+                    .into_iter()
+                    .filter_map(|(sid, stored)| (stored == id).then_some(sid))
+                    .collect::<Vec<_>>();
+                sids.sort();
+                // Keep the record that is found by its ID
+                // or the one with the lowest storage ID.
+                let keep = match self.id_map(map_key)?.remove(&to_string(id)) {
+                    Some(sid) if sids.contains(&sid) => sid,
+                    _ => match sids.first() {
+                        Some(sid) => sid.clone(),
+                        None => return Ok(()),
+                    },
+                };
+                for sid in sids.iter().filter(|sid| **sid != keep) {
+                    log::info!("Delete duplicate record {}", sid);
+                    store.delete(sid)?;
+                }
+                self.save_id(keep, to_string(id), map_key)?;
+                if entity == Entity::Thought {
+                    let id = Id::from_u128(id);
+                    let record = self.get_thought(id).map_err(io::Error::other)?;
+                    self.search_index.insert(&record.thought);
+                    self.area_of_life_index.insert(&record.thought);
+                }
+            }
+            StorageIssue::IdCounterDrift { entity, max, .. } => {
+                let (_, _, last_key) = self.layout(entity);
+                let counter = match self.ids.get::<u64>(last_key) {
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => 0,
+                    res => res?,
+                };
+                if counter < max {
+                    self.ids.save_with_id(&max, last_key)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// All kinds of IDs share the same textual representation.
+fn parse_id(id: &str) -> Option<u128> {
+    id.parse::<app::Id>().ok().map(app::Id::to_u128)
+}
+
+fn to_string(id: u128) -> String {
+    Id::from_u128(id).to_string()
+}
+
+#[async_trait]
+impl Integrity for JsonFile {
+    async fn check_storage(&self) -> Result<Vec<StorageIssue>, CheckError> {
+        let db = self.clone();
+        unblock(move || {
+            db.check().map_err(|err| {
+                log::warn!("Unable to check JSON files: {}", err);
+                CheckError::Connection
+            })
+        })
+        .await
+    }
+    async fn repair_storage(&self, issue: StorageIssue) -> Result<(), RepairError> {
+        let db = self.clone();
+        unblock(move || {
+            // Repairs must not interfere with a unit of work.
+            let _lock = db.commit_lock.lock();
+            db.repair(issue).map_err(|err| {
+                log::warn!("Unable to repair {:?}: {}", issue, err);
+                RepairError::Connection
+            })
+        })
+        .await
+    }
+}
@@ -1,21 +1,51 @@
+use self::area_of_life_index::AreaOfLifeIndex;
+use crate::search_index::InvertedIndex;
 use cawr_adapter::db::Db;
 use cawr_application::identifier::NewIdError;
 use jfs::{Config, Store};
-use std::{collections::HashMap, fs, io, path::Path};
+use parking_lot::Mutex;
+use std::{collections::HashMap, fs, io, path::Path, sync::Arc};
 
 mod area_of_life;
+mod area_of_life_index;
+mod integrity;
 mod models;
+mod relation;
+mod revision;
+mod search_index;
+mod tag;
 mod thought;
+mod unit_of_work;
 
 const LAST_THOUGHT_ID_KEY: &str = "last-thought-id";
 const LAST_AREA_OF_LIFE_ID_KEY: &str = "last-area-of-life-id";
+const LAST_TAG_ID_KEY: &str = "last-tag-id";
 const MAP_THOUGHT_ID_KEY: &str = "map-thought-id";
 const MAP_AREA_OF_LIFE_ID_KEY: &str = "map-area-of-life-id";
+const MAP_TAG_ID_KEY: &str = "map-tag-id";
+const JOURNAL_KEY: &str = "pending";
 
+/// A JSON file based storage.
+///
+/// All file system access is done on tokio's blocking thread pool,
+/// so a (cheap) clone of the store is moved there for each operation.
+#[derive(Clone)]
 pub struct JsonFile {
     thoughts: Store,
     areas_of_life: Store,
+    tags: Store,
+    relations: Store,
+    revisions: Store,
     ids: Store,
+    /// Changes of a unit of work that are not completely applied yet.
+    journal: Store,
+    /// Only one unit of work is committed at a time.
+    commit_lock: Arc<Mutex<()>>,
+    /// The search index only lives in memory
+    /// and is built from the stored thoughts on startup.
+    search_index: Arc<InvertedIndex>,
+    /// Like the search index this only lives in memory.
+    area_of_life_index: Arc<AreaOfLifeIndex>,
 }
 
 impl JsonFile {
@@ -29,12 +59,30 @@ impl JsonFile {
         fs::create_dir_all(dir)?;
         let thoughts = Store::new_with_cfg(dir.join("thoughts"), cfg)?;
         let areas_of_life = Store::new_with_cfg(dir.join("areas-of-life"), cfg)?;
+        let tags = Store::new_with_cfg(dir.join("tags"), cfg)?;
+        let relations = Store::new_with_cfg(dir.join("relations"), cfg)?;
+        let revisions = Store::new_with_cfg(dir.join("revisions"), cfg)?;
         let ids = Store::new_with_cfg(dir.join("ids"), cfg)?;
-        Ok(Self {
+        let journal = Store::new_with_cfg(dir.join("journal"), cfg)?;
+        let mut db = Self {
             thoughts,
             areas_of_life,
+            tags,
+            relations,
+            revisions,
             ids,
-        })
+            journal,
+            commit_lock: Arc::default(),
+            search_index: Arc::default(),
+            area_of_life_index: Arc::default(),
+        };
+        db.recover()?;
+        let records = db.get_all_thoughts().map_err(io::Error::other)?;
+        let index = InvertedIndex::from_thoughts(records.iter().map(|r| &r.thought));
+        db.search_index = Arc::new(index);
+        let index = AreaOfLifeIndex::from_thoughts(records.iter().map(|r| &r.thought));
+        db.area_of_life_index = Arc::new(index);
+        Ok(db)
     }
     fn save_id<I>(&self, storage_id: StorageId, id: I, key: &str) -> Result<(), io::Error>
     where
@@ -51,6 +99,20 @@ impl JsonFile {
         self.ids.save_with_id(&map, key)?;
         Ok(())
     }
+    /// Remove the entries of the ID map that point to the storage ID.
+    fn remove_storage_id(&self, storage_id: &str, key: &str) -> Result<(), io::Error> {
+        let mut map = match self.ids.get::<HashMap<String, String>>(key) {
+            Ok(map) => map,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        let len = map.len();
+        map.retain(|_, sid| sid != storage_id);
+        if map.len() != len {
+            self.ids.save_with_id(&map, key)?;
+        }
+        Ok(())
+    }
     fn storage_id<I>(&self, id: I, key: &str) -> Result<StorageId, io::Error>
     where
         I: ToString,
@@ -88,6 +150,18 @@ impl JsonFile {
 
 type StorageId = String;
 
+/// Run blocking file system operations without blocking the async runtime.
+async fn unblock<F, T>(f: F) -> T
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    match tokio::task::spawn_blocking(f).await {
+        Ok(res) => res,
+        Err(err) => std::panic::resume_unwind(err.into_panic()),
+    }
+}
+
 impl Db for JsonFile {}
 
 #[cfg(test)]
@@ -98,44 +172,411 @@ mod tests {
         let _ = env_logger::builder().is_test(true).try_init();
     }
 
-    mod area_of_life {
+    mod integrity {
         use super::*;
+        use cawr_adapter::{clock::SystemClock, event::EventBus};
+        use cawr_application::{
+            gateway::{
+                integrity::{Entity, Integrity, StorageIssue},
+                repository::{
+                    area_of_life::{self as aol_repo, Repo as AolRepo},
+                    thought::{Record, Repo},
+                },
+            },
+            identifier::NewId,
+            usecase::integrity::check::{Check, Finding, Request},
+        };
         use cawr_domain::{
             area_of_life::{AreaOfLife, Id as AolId, Name},
-            thought::{Id as ThoughtId, Thought, Title},
+            thought::{Id, Thought, Title},
+            Timestamp,
+        };
+        use tempfile::TempDir;
+
+        #[tokio::test]
+        async fn check_and_repair() {
+            init();
+            let test_dir = TempDir::new().unwrap();
+            let db = JsonFile::try_new(&test_dir).unwrap();
+            let now = Timestamp::from_unix_millis(0);
+            for _ in 0..2 {
+                let thought = Thought::new(
+                    NewId::<Id>::new_id(&db).await.unwrap(),
+                    Title::new("foo".to_string()),
+                    [].into(),
+                    now,
+                    now,
+                );
+                Repo::save(&db, Record { thought }).await.unwrap();
+            }
+            Repo::delete(&db, Id::new(2)).await.unwrap();
+            assert!(db.check_storage().await.unwrap().is_empty());
+
+            let thought = Repo::get(&db, Id::new(1)).await.unwrap().thought;
+            db.thoughts
+                .save(&crate::json_file::thought::to_model(&thought))
+                .unwrap();
+            db.save_id("missing".to_string(), 9, MAP_TAG_ID_KEY)
+                .unwrap();
+            db.ids.save_with_id(&0, LAST_THOUGHT_ID_KEY).unwrap();
+
+            let issues = db.check_storage().await.unwrap();
+            assert_eq!(
+                issues,
+                vec![
+                    StorageIssue::DuplicateId {
+                        entity: Entity::Thought,
+                        id: 1,
+                        count: 2
+                    },
+                    StorageIssue::IdCounterDrift {
+                        entity: Entity::Thought,
+                        counter: 0,
+                        max: 1
+                    },
+                    StorageIssue::OrphanedId {
+                        entity: Entity::Tag,
+                        id: 9
+                    },
+                ]
+            );
+            for issue in issues {
+                db.repair_storage(issue).await.unwrap();
+            }
+            assert!(db.check_storage().await.unwrap().is_empty());
+            assert_eq!(Repo::get_all(&db).await.unwrap().len(), 1);
+            let id = NewId::<Id>::new_id(&db).await.unwrap();
+            assert_eq!(id, Id::new(2));
+        }
+
+        #[tokio::test]
+        async fn report_blank_title_and_name() {
+            init();
+            let test_dir = TempDir::new().unwrap();
+            let db = JsonFile::try_new(&test_dir).unwrap();
+            let now = Timestamp::from_unix_millis(0);
+            let id = NewId::<Id>::new_id(&db).await.unwrap();
+            let aol_id = NewId::<AolId>::new_id(&db).await.unwrap();
+            let area_of_life = AreaOfLife::new(aol_id, Name::new("Health".to_string()), now, now);
+            AolRepo::save(&db, aol_repo::Record { area_of_life })
+                .await
+                .unwrap();
+            let thought =
+                Thought::new(id, Title::new("foo".to_string()), [aol_id].into(), now, now);
+            Repo::save(&db, Record { thought }).await.unwrap();
+
+            // Records that bypassed the validation
+            let storage_id = db.storage_id(id, MAP_THOUGHT_ID_KEY).unwrap();
+            let mut model = db.thoughts.get::<models::Thought>(&storage_id).unwrap();
+            model.title = "  ".to_string();
+            db.thoughts.save_with_id(&model, &storage_id).unwrap();
+            let storage_id = db.storage_id(aol_id, MAP_AREA_OF_LIFE_ID_KEY).unwrap();
+            let mut model = db
+                .areas_of_life
+                .get::<models::AreaOfLife>(&storage_id)
+                .unwrap();
+            model.name = String::new();
+            db.areas_of_life.save_with_id(&model, &storage_id).unwrap();
+            drop(db);
+
+            let db = JsonFile::try_new(&test_dir).unwrap();
+            let events = EventBus::default();
+            let res = Check::new(&db, &SystemClock, &events)
+                .exec(Request { fix: true })
+                .await
+                .unwrap();
+            assert_eq!(res.findings.len(), 2);
+            assert!(res.findings.iter().any(|f| matches!(
+                f,
+                Finding::InvalidThought { thought, .. } if *thought == id
+            )));
+            assert!(res.findings.iter().any(|f| matches!(
+                f,
+                Finding::InvalidAreaOfLife { area_of_life, .. } if *area_of_life == aol_id
+            )));
+            assert_eq!(res.repaired, 0);
+        }
+    }
+
+    mod area_of_life_index {
+        use super::*;
+        use cawr_application::gateway::{
+            repository::thought::{Record, Repo},
+            unit_of_work::{Change, Changes, UnitOfWork},
+        };
+        use cawr_domain::{
+            area_of_life as aol,
+            thought::{Id, Thought, Title},
+            Timestamp,
+        };
+        use tempfile::TempDir;
+
+        async fn thoughts_of(db: &JsonFile, id: u64) -> Vec<Id> {
+            let records = (db as &dyn Repo)
+                .get_by_area_of_life(aol::Id::new(id))
+                .await
+                .unwrap();
+            records.into_iter().map(|r| r.thought.id()).collect()
+        }
+
+        #[tokio::test]
+        async fn follow_changes_of_thoughts() {
+            init();
+            let test_dir = TempDir::new().unwrap();
+            let db = JsonFile::try_new(&test_dir).unwrap();
+            let now = Timestamp::from_unix_millis(0);
+            let thought = Thought::new(
+                Id::new(1),
+                Title::new("foo".to_string()),
+                [aol::Id::new(1)].into(),
+                now,
+                now,
+            );
+            (&db as &dyn Repo)
+                .save(Record {
+                    thought: thought.clone(),
+                })
+                .await
+                .unwrap();
+            assert_eq!(thoughts_of(&db, 1).await, vec![Id::new(1)]);
+
+            let version = thought.version().next();
+            let thought = thought
+                .with_areas_of_life([aol::Id::new(2)].into())
+                .with_version(version);
+            let mut changes = Changes::default();
+            changes.push(Change::SaveThought(Record { thought }));
+            db.commit(changes).await.unwrap();
+            assert!(thoughts_of(&db, 1).await.is_empty());
+            assert_eq!(thoughts_of(&db, 2).await, vec![Id::new(1)]);
+
+            // The index is rebuilt on startup
+            let db = JsonFile::try_new(&test_dir).unwrap();
+            assert_eq!(thoughts_of(&db, 2).await, vec![Id::new(1)]);
+
+            let mut changes = Changes::default();
+            changes.push(Change::DeleteThought(Id::new(1)));
+            db.commit(changes).await.unwrap();
+            assert!(thoughts_of(&db, 2).await.is_empty());
+        }
+    }
+
+    mod unit_of_work {
+        use super::*;
+        use cawr_application::gateway::{
+            repository::thought::{Record, Repo},
+            unit_of_work::{Change, Changes, CommitError, UnitOfWork},
+        };
+        use cawr_domain::{
+            thought::{Id, Thought, Title},
+            Timestamp, Version,
         };
         use std::collections::HashSet;
         use tempfile::TempDir;
 
-        #[test]
-        fn delete_references_in_thoughts() {
-            use cawr_application::{
-                gateway::repository::{
-                    area_of_life::{Record as AolRecord, Repo as AolRepo},
-                    thought::{Record as ThoughtRecord, Repo as ThoughtRepo},
-                },
-                identifier::NewId,
+        fn new_thought(id: u64, title: &str, version: u64) -> Thought {
+            let now = Timestamp::from_unix_millis(0);
+            Thought::new(
+                Id::new(id),
+                Title::new(title.to_string()),
+                HashSet::new(),
+                now,
+                now,
+            )
+            .with_version(Version::new(version))
+        }
+
+        #[tokio::test]
+        async fn commit_all_or_nothing() {
+            init();
+            let test_dir = TempDir::new().unwrap();
+            let db = JsonFile::try_new(&test_dir).unwrap();
+            let record = Record {
+                thought: new_thought(1, "foo", 1),
             };
-            // -- setup --
+            (&db as &dyn Repo).save(record).await.unwrap();
+
+            let mut changes = Changes::default();
+            changes.push(Change::SaveThought(Record {
+                thought: new_thought(2, "bar", 1),
+            }));
+            changes.push(Change::SaveThought(Record {
+                thought: new_thought(1, "baz", 3),
+            }));
+            let res = db.commit(changes).await;
+            assert!(matches!(res, Err(CommitError::Conflict)));
+            assert_eq!((&db as &dyn Repo).get_all().await.unwrap().len(), 1);
+
+            let mut changes = Changes::default();
+            changes.push(Change::SaveThought(Record {
+                thought: new_thought(2, "bar", 1),
+            }));
+            changes.push(Change::SaveThought(Record {
+                thought: new_thought(1, "baz", 2),
+            }));
+            db.commit(changes).await.unwrap();
+            let rec = (&db as &dyn Repo).get(Id::new(1)).await.unwrap();
+            assert_eq!(rec.thought.title().as_ref(), "baz");
+            assert!((&db as &dyn Repo).get(Id::new(2)).await.is_ok());
+        }
+
+        #[tokio::test]
+        async fn complete_interrupted_commit() {
+            init();
+            let test_dir = TempDir::new().unwrap();
+            let db = JsonFile::try_new(&test_dir).unwrap();
+            let entries = vec![models::JournalEntry::SaveThought {
+                storage_id: "1".to_string(),
+                thought: crate::json_file::thought::to_model(&new_thought(1, "foo", 1)),
+            }];
+            db.journal.save_with_id(&entries, JOURNAL_KEY).unwrap();
+            drop(db);
+
+            let db = JsonFile::try_new(&test_dir).unwrap();
+            let rec = (&db as &dyn Repo).get(Id::new(1)).await.unwrap();
+            assert_eq!(rec.thought.title().as_ref(), "foo");
+            assert_eq!(db.search_index.search("foo").len(), 1);
+            assert!(db
+                .journal
+                .get::<Vec<models::JournalEntry>>(JOURNAL_KEY)
+                .is_err());
+        }
+    }
+
+    mod tag {
+        use super::*;
+        use cawr_adapter::{clock::SystemClock, event::EventBus};
+        use cawr_application::{
+            gateway::repository::{
+                tag::{self, Repo as TagRepo},
+                thought::{Record, Repo as ThoughtRepo},
+            },
+            usecase::tag::delete::{Delete, Request},
+        };
+        use cawr_domain::{
+            tag::{Id as TagId, Name, Tag},
+            thought::{Id, Thought, Title},
+            Timestamp, Version,
+        };
+        use tempfile::TempDir;
+
+        #[tokio::test]
+        async fn delete_tag_used_by_thought() {
+            init();
+            let test_dir = TempDir::new().unwrap();
+            let db = JsonFile::try_new(&test_dir).unwrap();
+            let now = Timestamp::from_unix_millis(0);
+            let (work, home) = (TagId::new(1), TagId::new(2));
+            for (id, name) in [(work, "work"), (home, "home")] {
+                let tag = Tag::new(id, Name::new(name.to_string()), now, now);
+                TagRepo::save(&db, tag::Record { tag }).await.unwrap();
+            }
+            let id = Id::new(1);
+            let thought = Thought::new(id, Title::new("foo".to_string()), [].into(), now, now)
+                .with_tags([work, home].into());
+            ThoughtRepo::save(&db, Record { thought }).await.unwrap();
+
+            let events = EventBus::default();
+            Delete::new(&db, &SystemClock, &events)
+                .exec(Request { id: work })
+                .await
+                .unwrap();
+            drop(db);
+
+            let db = JsonFile::try_new(&test_dir).unwrap();
+            assert!(matches!(
+                TagRepo::get(&db, work).await,
+                Err(tag::GetError::NotFound)
+            ));
+            let thought = ThoughtRepo::get(&db, id).await.unwrap().thought;
+            assert_eq!(thought.tags(), &[home].into());
+            assert_eq!(thought.version(), Version::INITIAL.next());
+        }
+    }
+
+    mod thought {
+        use super::*;
+        use cawr_application::gateway::repository::thought::{Record, Repo};
+        use cawr_domain::{
+            area_of_life::Id as AolId,
+            thought::{Id, Thought, Title},
+            Timestamp,
+        };
+        use tempfile::TempDir;
+
+        #[tokio::test]
+        async fn save_and_load_uuid_ids() {
             init();
             let test_dir = TempDir::new().unwrap();
-            log::debug!("Test directory: {}", test_dir.path().display());
             let db = JsonFile::try_new(&test_dir).unwrap();
-            let aol_id = (&db as &dyn NewId<AolId>).new_id().unwrap();
-            let name = Name::new("test aol".to_string());
-            let area_of_life = AreaOfLife::new(aol_id, name);
-            let record = AolRecord { area_of_life };
-            (&db as &dyn AolRepo).save(record).unwrap();
-            let mut areas_of_life = HashSet::new();
-            areas_of_life.insert(aol_id);
-            let id = (&db as &dyn NewId<ThoughtId>).new_id().unwrap();
-            let thought = Thought::new(id, Title::new("foo".to_string()), areas_of_life);
-            let record = ThoughtRecord { thought };
-            (&db as &dyn ThoughtRepo).save(record).unwrap();
-            // -- test --
-            (&db as &dyn AolRepo).delete(aol_id).unwrap();
-            let rec = (&db as &dyn ThoughtRepo).get(id).unwrap();
-            assert!(rec.thought.areas_of_life().is_empty());
+            let id = Id::from_u128(0x0192_a3b4_c5d6_7e8f_9a0b_1c2d_3e4f_5a6b);
+            let aol_id = AolId::from_u128(0x0192_a3b4_c5d6_7e8f_9a0b_1c2d_3e4f_5a6c);
+            let now = Timestamp::from_unix_millis(0);
+            let thought = Thought::new(
+                id,
+                Title::new("foo".to_string()),
+                [aol_id].into_iter().collect(),
+                now,
+                now,
+            );
+            (&db as &dyn Repo).save(Record { thought }).await.unwrap();
+            let rec = (&db as &dyn Repo).get(id).await.unwrap();
+            assert_eq!(rec.thought.id(), id);
+            assert!(rec.thought.areas_of_life().contains(&aol_id));
+            let all = (&db as &dyn Repo).get_all().await.unwrap();
+            assert_eq!(all.len(), 1);
+        }
+    }
+
+    mod revision {
+        use super::*;
+        use cawr_application::gateway::repository::revision::{Record, Repo};
+        use cawr_domain::{
+            thought::{Id, Thought, Title},
+            Timestamp, Version,
+        };
+        use std::collections::HashSet;
+        use tempfile::TempDir;
+
+        #[tokio::test]
+        async fn save_load_and_delete_revisions() {
+            init();
+            let test_dir = TempDir::new().unwrap();
+            let db = JsonFile::try_new(&test_dir).unwrap();
+            let now = Timestamp::from_unix_millis(0);
+            let thought = |id, title: &str, version| {
+                Thought::new(id, Title::new(title.to_string()), HashSet::new(), now, now)
+                    .with_version(Version::new(version))
+            };
+            let (a, b) = (Id::new(1), Id::new(2));
+            for thought in [
+                thought(a, "foo", 2),
+                thought(a, "bar", 1),
+                thought(b, "baz", 1),
+            ] {
+                let record = Record {
+                    thought,
+                    recorded_at: now,
+                };
+                (&db as &dyn Repo).save(record).await.unwrap();
+            }
+            let rec = (&db as &dyn Repo).get(a, Version::new(2)).await.unwrap();
+            assert_eq!(rec.thought.title().as_ref(), "foo");
+            let titles = (&db as &dyn Repo)
+                .get_by_thought(a)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|r| r.thought.title().as_ref().to_string())
+                .collect::<Vec<_>>();
+            assert_eq!(titles, vec!["bar", "foo"]);
+            (&db as &dyn Repo).delete_by_thought(a).await.unwrap();
+            assert!((&db as &dyn Repo)
+                .get_by_thought(a)
+                .await
+                .unwrap()
+                .is_empty());
+            assert_eq!((&db as &dyn Repo).get_by_thought(b).await.unwrap().len(), 1);
         }
     }
 }
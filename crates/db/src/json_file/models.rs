@@ -1,14 +1,135 @@
+use cawr_domain::Version;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Thought {
     pub(crate) thought_id: String,
     pub(crate) title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) body: Option<String>,
     pub(crate) areas_of_life: Vec<String>,
+    #[serde(default)]
+    pub(crate) tags: Vec<String>,
+    #[serde(default)]
+    pub(crate) status: ThoughtStatus,
+    /// Unix timestamp in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) due_at: Option<u64>,
+    /// Milliseconds before the due date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) reminder_offset: Option<u64>,
+    #[serde(default)]
+    pub(crate) created_at: u64,
+    #[serde(default)]
+    pub(crate) updated_at: u64,
+    #[serde(default = "initial_version")]
+    pub(crate) version: u64,
+    /// Unix timestamp in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) deleted_at: Option<u64>,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThoughtStatus {
+    #[default]
+    Inbox,
+    Active,
+    Done,
+    Archived,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AreaOfLife {
     pub(crate) area_of_life_id: String,
     pub(crate) name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) parent: Option<String>,
+    #[serde(default)]
+    pub(crate) created_at: u64,
+    #[serde(default)]
+    pub(crate) updated_at: u64,
+    #[serde(default = "initial_version")]
+    pub(crate) version: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) deletion: Option<AreaOfLifeDeletion>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AreaOfLifeDeletion {
+    /// Unix timestamp in milliseconds
+    pub(crate) deleted_at: u64,
+    /// The thoughts the area of life was removed from
+    pub(crate) thoughts: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Tag {
+    pub(crate) tag_id: String,
+    pub(crate) name: String,
+    pub(crate) created_at: u64,
+    pub(crate) updated_at: u64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RelationKind {
+    DependsOn,
+    RelatesTo,
+    Supersedes,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Relation {
+    pub(crate) source: String,
+    pub(crate) kind: RelationKind,
+    pub(crate) target: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Revision {
+    pub(crate) thought: Thought,
+    pub(crate) recorded_at: u64,
+}
+
+/// A change that is written to the journal before it is applied,
+/// so that an interrupted commit can be completed.
+///
+/// All storage IDs are resolved beforehand
+/// so applying an entry twice has the same effect as applying it once.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "kebab-case")]
+pub enum JournalEntry {
+    SaveThought {
+        storage_id: String,
+        thought: Thought,
+    },
+    DeleteThought {
+        storage_id: String,
+    },
+    SaveAreaOfLife {
+        storage_id: String,
+        area_of_life: AreaOfLife,
+    },
+    DeleteAreaOfLife {
+        storage_id: String,
+    },
+    DeleteTag {
+        storage_id: String,
+    },
+    SaveRevision {
+        storage_id: String,
+        revision: Revision,
+    },
+    DeleteRevisions {
+        thought_id: String,
+    },
+    DeleteRelation {
+        storage_id: String,
+    },
+}
+
+/// Records that were stored before versioning was introduced.
+const fn initial_version() -> u64 {
+    Version::INITIAL.to_u64()
 }
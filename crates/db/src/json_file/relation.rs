@@ -0,0 +1,114 @@
+use super::{models, unblock, JsonFile};
+use async_trait::async_trait;
+use cawr_adapter::model::app::thought as app;
+use cawr_application::gateway::repository::relation::{
+    DeleteError, GetAllError, Record, Repo, SaveError,
+};
+use cawr_domain::{
+    relation::{Kind, Relation},
+    thought,
+};
+use std::io;
+
+impl JsonFile {
+    fn save_relation(&self, record: Record) -> Result<(), SaveError> {
+        log::debug!("Save relation {:?} to JSON file", record);
+        // Plain writes must not interfere with a unit of work.
+        let _lock = self.commit_lock.lock();
+        let Record { relation } = record;
+        self.relations
+            .save_with_id(&to_model(&relation), &storage_id(&relation))
+            .map_err(|err| {
+                log::warn!("Unable to save relation: {}", err);
+                SaveError::Connection
+            })?;
+        Ok(())
+    }
+    fn get_relations_by_thought(&self, id: thought::Id) -> Result<Vec<Record>, GetAllError> {
+        log::debug!("Get relations of thought {:?} from JSON file", id);
+        let relations = self
+            .relations
+            .all::<models::Relation>()
+            .map_err(|err| {
+                log::warn!("Unable to load all relations: {}", err);
+                GetAllError::Connection
+            })?
+            .into_values()
+            .filter_map(from_model)
+            .filter(|r| r.source() == id || r.target() == id)
+            .map(|relation| Record { relation })
+            .collect();
+        Ok(relations)
+    }
+    fn delete_relation(&self, relation: &Relation) -> Result<(), DeleteError> {
+        log::debug!("Delete relation {:?} from JSON file", relation);
+        // Plain writes must not interfere with a unit of work.
+        let _lock = self.commit_lock.lock();
+        self.relations.delete(&storage_id(relation)).map_err(|err| {
+            log::warn!("Unable to delete relation: {}", err);
+            if err.kind() == io::ErrorKind::NotFound {
+                DeleteError::NotFound
+            } else {
+                DeleteError::Connection
+            }
+        })
+    }
+}
+
+#[async_trait]
+impl Repo for JsonFile {
+    async fn save(&self, record: Record) -> Result<(), SaveError> {
+        let db = self.clone();
+        unblock(move || db.save_relation(record)).await
+    }
+    async fn get_by_thought(&self, id: thought::Id) -> Result<Vec<Record>, GetAllError> {
+        let db = self.clone();
+        unblock(move || db.get_relations_by_thought(id)).await
+    }
+    async fn delete(&self, relation: &Relation) -> Result<(), DeleteError> {
+        let (db, relation) = (self.clone(), *relation);
+        unblock(move || db.delete_relation(&relation)).await
+    }
+}
+
+/// Relations have no ID of their own
+/// so they are stored by their (unique) content.
+pub(super) fn storage_id(relation: &Relation) -> String {
+    format!(
+        "{}-{}-{}",
+        relation.source(),
+        relation.kind(),
+        relation.target()
+    )
+}
+
+fn to_model(relation: &Relation) -> models::Relation {
+    let kind = match relation.kind() {
+        Kind::DependsOn => models::RelationKind::DependsOn,
+        Kind::RelatesTo => models::RelationKind::RelatesTo,
+        Kind::Supersedes => models::RelationKind::Supersedes,
+    };
+    models::Relation {
+        source: relation.source().to_string(),
+        kind,
+        target: relation.target().to_string(),
+    }
+}
+
+fn from_model(model: models::Relation) -> Option<Relation> {
+    let kind = match model.kind {
+        models::RelationKind::DependsOn => Kind::DependsOn,
+        models::RelationKind::RelatesTo => Kind::RelatesTo,
+        models::RelationKind::Supersedes => Kind::Supersedes,
+    };
+    let parse = |id: &str| {
+        id.parse::<app::Id>()
+            .map_err(|err| {
+                log::warn!("{}", err);
+            })
+            .ok()
+    };
+    let source = parse(&model.source)?;
+    let target = parse(&model.target)?;
+    Some(Relation::new(source.into(), kind, target.into()))
+}
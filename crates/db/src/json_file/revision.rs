@@ -0,0 +1,121 @@
+use super::{models, thought, unblock, JsonFile};
+use async_trait::async_trait;
+use cawr_adapter::model::app::thought as app;
+use cawr_application::gateway::repository::revision::{
+    DeleteError, GetAllError, GetError, Record, Repo, SaveError,
+};
+use cawr_domain::{thought::Id, Timestamp, Version};
+use std::io;
+
+impl JsonFile {
+    fn save_revision(&self, record: Record) -> Result<(), SaveError> {
+        log::debug!("Save revision {:?} to JSON file", record);
+        // Plain writes must not interfere with a unit of work.
+        let _lock = self.commit_lock.lock();
+        let Record {
+            thought,
+            recorded_at,
+        } = record;
+        let model = models::Revision {
+            thought: thought::to_model(&thought),
+            recorded_at: recorded_at.to_unix_millis(),
+        };
+        self.revisions
+            .save_with_id(&model, &storage_id(thought.id(), thought.version()))
+            .map_err(|err| {
+                log::warn!("Unable to save revision: {}", err);
+                SaveError::Connection
+            })?;
+        Ok(())
+    }
+    fn get_revision(&self, id: Id, version: Version) -> Result<Record, GetError> {
+        log::debug!("Get revision {} of thought {} from JSON file", version, id);
+        let model = self
+            .revisions
+            .get::<models::Revision>(&storage_id(id, version))
+            .map_err(|err| {
+                log::warn!("Unable to fetch revision: {}", err);
+                if err.kind() == io::ErrorKind::NotFound {
+                    GetError::NotFound
+                } else {
+                    GetError::Connection
+                }
+            })?;
+        Ok(from_model(id, model))
+    }
+    fn get_revisions_by_thought(&self, id: Id) -> Result<Vec<Record>, GetAllError> {
+        log::debug!("Get revisions of thought {} from JSON file", id);
+        let mut revisions = self
+            .revisions
+            .all::<models::Revision>()
+            .map_err(|err| {
+                log::warn!("Unable to load all revisions: {}", err);
+                GetAllError::Connection
+            })?
+            .into_values()
+            .filter(|model| {
+                model
+                    .thought
+                    .thought_id
+                    .parse::<app::Id>()
+                    .is_ok_and(|thought_id| Id::from(thought_id) == id)
+            })
+            .map(|model| from_model(id, model))
+            .collect::<Vec<_>>();
+        revisions.sort_by_key(|r| r.thought.version());
+        Ok(revisions)
+    }
+    fn delete_revisions_by_thought(&self, id: Id) -> Result<(), DeleteError> {
+        log::debug!("Delete revisions of thought {} from JSON file", id);
+        // Plain writes must not interfere with a unit of work.
+        let _lock = self.commit_lock.lock();
+        self.remove_revisions_by_thought(id)
+    }
+    /// Delete the revisions without taking the commit lock,
+    /// e.g. while a unit of work that holds it is applied.
+    pub(super) fn remove_revisions_by_thought(&self, id: Id) -> Result<(), DeleteError> {
+        for record in self
+            .get_revisions_by_thought(id)
+            .map_err(|_| DeleteError::Connection)?
+        {
+            let sid = storage_id(id, record.thought.version());
+            self.revisions.delete(&sid).map_err(|err| {
+                log::warn!("Unable to delete revision: {}", err);
+                DeleteError::Connection
+            })?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Repo for JsonFile {
+    async fn save(&self, record: Record) -> Result<(), SaveError> {
+        let db = self.clone();
+        unblock(move || db.save_revision(record)).await
+    }
+    async fn get(&self, id: Id, version: Version) -> Result<Record, GetError> {
+        let db = self.clone();
+        unblock(move || db.get_revision(id, version)).await
+    }
+    async fn get_by_thought(&self, id: Id) -> Result<Vec<Record>, GetAllError> {
+        let db = self.clone();
+        unblock(move || db.get_revisions_by_thought(id)).await
+    }
+    async fn delete_by_thought(&self, id: Id) -> Result<(), DeleteError> {
+        let db = self.clone();
+        unblock(move || db.delete_revisions_by_thought(id)).await
+    }
+}
+
+/// Revisions are stored by the thought ID and its version.
+pub(super) fn storage_id(id: Id, version: Version) -> String {
+    format!("{id}-{version}")
+}
+
+fn from_model(id: Id, model: models::Revision) -> Record {
+    Record {
+        thought: thought::from_model(id, model.thought),
+        recorded_at: Timestamp::from_unix_millis(model.recorded_at),
+    }
+}
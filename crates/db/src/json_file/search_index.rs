@@ -0,0 +1,19 @@
+use super::JsonFile;
+use async_trait::async_trait;
+use cawr_application::gateway::search_index::{Hit, IndexError, SearchError, SearchIndex};
+use cawr_domain::thought::{Id, Thought};
+
+#[async_trait]
+impl SearchIndex for JsonFile {
+    async fn index(&self, thought: &Thought) -> Result<(), IndexError> {
+        self.search_index.insert(thought);
+        Ok(())
+    }
+    async fn remove(&self, id: Id) -> Result<(), IndexError> {
+        self.search_index.remove(id);
+        Ok(())
+    }
+    async fn search(&self, query: &str) -> Result<Vec<Hit>, SearchError> {
+        Ok(self.search_index.search(query))
+    }
+}
@@ -0,0 +1,155 @@
+use super::{models, unblock, JsonFile, LAST_TAG_ID_KEY, MAP_TAG_ID_KEY};
+use async_trait::async_trait;
+use cawr_adapter::model::app::tag as app;
+use cawr_application::{
+    gateway::{
+        repository::tag::{DeleteError, GetAllError, GetError, Record, Repo, SaveError},
+        unit_of_work::{Change, Changes},
+    },
+    identifier::{NewId, NewIdError},
+};
+use cawr_domain::{
+    tag::{Id, Name, Tag},
+    Timestamp,
+};
+use std::io;
+
+#[async_trait]
+impl NewId<Id> for JsonFile {
+    async fn new_id(&self) -> Result<Id, NewIdError> {
+        let db = self.clone();
+        unblock(move || db.new_id(LAST_TAG_ID_KEY)).await
+    }
+}
+
+impl JsonFile {
+    fn save_tag(&self, record: Record) -> Result<(), SaveError> {
+        log::debug!("Save tag {:?} to JSON file", record);
+        // Plain writes must not interfere with a unit of work.
+        let _lock = self.commit_lock.lock();
+        let Record { tag } = record;
+        let id = tag.id();
+        let model = to_model(&tag);
+
+        match self.storage_id(tag.id(), MAP_TAG_ID_KEY) {
+            Ok(storage_id) => {
+                log::debug!("Update tag {}", tag.id());
+                let sid = self.tags.save_with_id(&model, &storage_id).map_err(|err| {
+                    log::warn!("Unable to save tag: {}", err);
+                    SaveError::Connection
+                })?;
+                debug_assert_eq!(sid, storage_id);
+            }
+            Err(err) => match err.kind() {
+                io::ErrorKind::NotFound => {
+                    log::debug!("Create new tag record");
+                    let storage_id = self.tags.save(&model).map_err(|err| {
+                        log::warn!("Unable to save tag: {}", err);
+                        SaveError::Connection
+                    })?;
+                    self.save_id(storage_id, id, MAP_TAG_ID_KEY)
+                        .map_err(|err| {
+                            log::warn!("Unable to save tag ID: {}", err);
+                            SaveError::Connection
+                        })?;
+                }
+                _ => {
+                    return Err(SaveError::Connection);
+                }
+            },
+        }
+        Ok(())
+    }
+    fn get_tag(&self, id: Id) -> Result<Record, GetError> {
+        log::debug!("Get tag {:?} from JSON file", id);
+        let sid = self.storage_id(id, MAP_TAG_ID_KEY).map_err(|err| {
+            log::warn!("Unable to get tag ID: {}", err);
+            if err.kind() == io::ErrorKind::NotFound {
+                GetError::NotFound
+            } else {
+                GetError::Connection
+            }
+        })?;
+        let model = self.tags.get::<models::Tag>(&sid).map_err(|err| {
+            log::warn!("Unable to fetch tag: {}", err);
+            if err.kind() == io::ErrorKind::NotFound {
+                GetError::NotFound
+            } else {
+                GetError::Connection
+            }
+        })?;
+        debug_assert_eq!(id.to_string(), model.tag_id);
+        Ok(Record {
+            tag: from_model(id, model),
+        })
+    }
+    fn get_all_tags(&self) -> Result<Vec<Record>, GetAllError> {
+        log::debug!("Get all tags from JSON file");
+        let tags = self
+            .tags
+            .all::<models::Tag>()
+            .map_err(|err| {
+                log::warn!("Unable to load all tags: {}", err);
+                GetAllError::Connection
+            })?
+            .into_values()
+            .filter_map(|model| {
+                model
+                    .tag_id
+                    .parse::<app::Id>()
+                    .ok()
+                    .map(|id| from_model(id.into(), model))
+            })
+            .map(|tag| Record { tag })
+            .collect();
+        Ok(tags)
+    }
+    fn delete_tag(&self, id: Id) -> Result<(), DeleteError> {
+        log::debug!("Delete tag {:?} from JSON file", id);
+        self.get_tag(id).map_err(|err| match err {
+            GetError::NotFound => DeleteError::NotFound,
+            GetError::Connection => DeleteError::Connection,
+        })?;
+        let mut changes = Changes::default();
+        changes.push(Change::DeleteTag(id));
+        self.commit_changes(changes).map_err(|err| {
+            log::warn!("Unable to delete tag: {}", err);
+            DeleteError::Connection
+        })
+    }
+}
+
+#[async_trait]
+impl Repo for JsonFile {
+    async fn save(&self, record: Record) -> Result<(), SaveError> {
+        let db = self.clone();
+        unblock(move || db.save_tag(record)).await
+    }
+    async fn get(&self, id: Id) -> Result<Record, GetError> {
+        let db = self.clone();
+        unblock(move || db.get_tag(id)).await
+    }
+    async fn get_all(&self) -> Result<Vec<Record>, GetAllError> {
+        let db = self.clone();
+        unblock(move || db.get_all_tags()).await
+    }
+    async fn delete(&self, id: Id) -> Result<(), DeleteError> {
+        let db = self.clone();
+        unblock(move || db.delete_tag(id)).await
+    }
+}
+
+fn to_model(tag: &Tag) -> models::Tag {
+    models::Tag {
+        tag_id: tag.id().to_string(),
+        name: String::from(tag.name().as_ref()),
+        created_at: tag.created_at().to_unix_millis(),
+        updated_at: tag.updated_at().to_unix_millis(),
+    }
+}
+
+fn from_model(id: Id, model: models::Tag) -> Tag {
+    let created_at = Timestamp::from_unix_millis(model.created_at);
+    let updated_at = Timestamp::from_unix_millis(model.updated_at);
+    Tag::new(id, Name::new(model.name), created_at, updated_at)
+}
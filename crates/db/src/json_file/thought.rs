@@ -1,39 +1,50 @@
-use super::{models, JsonFile, LAST_THOUGHT_ID_KEY, MAP_THOUGHT_ID_KEY};
-use cawr_adapter::model::app::{area_of_life as aol, thought as app};
+use super::{models, unblock, JsonFile, LAST_THOUGHT_ID_KEY, MAP_THOUGHT_ID_KEY};
+use async_trait::async_trait;
+use cawr_adapter::model::app::{area_of_life as aol, tag, thought as app};
 use cawr_application::{
-    gateway::repository::thought::{DeleteError, GetAllError, GetError, Record, Repo, SaveError},
+    gateway::repository::{
+        query::Paginated,
+        thought::{DeleteError, GetAllError, GetError, Query, Record, Repo, SaveError},
+    },
     identifier::{NewId, NewIdError},
 };
-use cawr_domain::thought::{Id, Thought, Title};
-use std::io;
+use cawr_domain::{
+    area_of_life as aol_domain,
+    thought::{Body, Id, Status, Thought, Title},
+    Timestamp, Version,
+};
+use std::{io, time::Duration};
 
+#[async_trait]
 impl NewId<Id> for JsonFile {
-    fn new_id(&self) -> Result<Id, NewIdError> {
-        let id = self.new_id(LAST_THOUGHT_ID_KEY)?;
-        Ok(id)
+    async fn new_id(&self) -> Result<Id, NewIdError> {
+        let db = self.clone();
+        unblock(move || db.new_id(LAST_THOUGHT_ID_KEY)).await
     }
 }
 
-impl Repo for JsonFile {
-    fn save(&self, record: Record) -> Result<(), SaveError> {
+impl JsonFile {
+    pub(super) fn save_thought(&self, record: Record) -> Result<(), SaveError> {
         log::debug!("Save thought {:?} to JSON file", record);
+        // Plain writes must not interfere with a unit of work.
+        let _lock = self.commit_lock.lock();
         let Record { thought } = record;
-        let thought_id = thought.id().to_string();
-        let title = String::from(thought.title().as_ref());
-        let areas_of_life = thought
-            .areas_of_life()
-            .iter()
-            .map(ToString::to_string)
-            .collect();
-        let model = models::Thought {
-            thought_id,
-            title,
-            areas_of_life,
-        };
+        let model = to_model(&thought);
 
         match self.storage_id(thought.id(), MAP_THOUGHT_ID_KEY) {
             Ok(storage_id) => {
                 log::debug!("Update thought {}", thought.id());
+                let stored = self
+                    .thoughts
+                    .get::<models::Thought>(&storage_id)
+                    .map_err(|err| {
+                        log::warn!("Unable to fetch thought: {}", err);
+                        SaveError::Connection
+                    })?;
+                if Version::new(stored.version).next() != thought.version() {
+                    log::debug!("Thought {} was modified concurrently", thought.id());
+                    return Err(SaveError::Conflict);
+                }
                 let sid = self
                     .thoughts
                     .save_with_id(&model, &storage_id)
@@ -61,10 +72,10 @@ impl Repo for JsonFile {
                 }
             },
         }
-
+        self.area_of_life_index.insert(&thought);
         Ok(())
     }
-    fn get(&self, id: Id) -> Result<Record, GetError> {
+    pub(super) fn get_thought(&self, id: Id) -> Result<Record, GetError> {
         log::debug!("Get thought {:?} from JSON file", id);
         let sid = self.storage_id(id, MAP_THOUGHT_ID_KEY).map_err(|err| {
             log::warn!("Unable to get thought ID: {}", err);
@@ -83,24 +94,19 @@ impl Repo for JsonFile {
             }
         })?;
         debug_assert_eq!(id.to_string(), model.thought_id);
-        let areas_of_life = model
-            .areas_of_life
-            .into_iter()
-            .filter_map(|id| {
-                id.parse::<aol::Id>()
-                    .map_err(|err| {
-                        log::warn!("{}", err);
-                    })
-                    .map(Into::into)
-                    .ok()
-            })
-            .collect();
         Ok(Record {
-            thought: Thought::new(id, Title::new(model.title), areas_of_life),
+            thought: from_model(id, model),
         })
     }
-    fn get_all(&self) -> Result<Vec<Record>, GetAllError> {
+    pub(super) fn get_all_thoughts(&self) -> Result<Vec<Record>, GetAllError> {
         log::debug!("Get all thoughts from JSON file");
+        self.load_thoughts(|_| true)
+    }
+    /// Load all thoughts whose stored model is accepted by `keep`.
+    fn load_thoughts<F>(&self, keep: F) -> Result<Vec<Record>, GetAllError>
+    where
+        F: Fn(&models::Thought) -> bool,
+    {
         let thoughts = self
             .thoughts
             .all::<models::Thought>()
@@ -108,35 +114,47 @@ impl Repo for JsonFile {
                 log::warn!("Unable to load all thoughts: {}", err);
                 GetAllError::Connection
             })?
-            .into_iter()
-            .filter_map(|(_, model)| {
-                let areas_of_life = model
-                    .areas_of_life
-                    .into_iter()
-                    .filter_map(|id| {
-                        id.parse::<aol::Id>()
-                            .map_err(|err| {
-                                log::warn!("{}", err);
-                            })
-                            .map(Into::into)
-                            .ok()
-                    })
-                    .collect();
-
+            .into_values()
+            .filter(keep)
+            .filter_map(|model| {
                 model
                     .thought_id
                     .parse::<app::Id>()
                     .ok()
-                    .map(Into::into)
-                    .map(|id| (id, model.title, areas_of_life))
+                    .map(|id| from_model(id.into(), model))
             })
-            .map(|(id, title, areas_of_life)| Thought::new(id, Title::new(title), areas_of_life))
             .map(|thought| Record { thought })
             .collect();
         Ok(thoughts)
     }
-    fn delete(&self, id: Id) -> Result<(), DeleteError> {
+    fn get_thoughts_by_area_of_life(&self, id: aol_domain::Id) -> Result<Vec<Record>, GetAllError> {
+        log::debug!("Get thoughts of area of life {:?} from JSON file", id);
+        let mut records = vec![];
+        for id in self.area_of_life_index.thoughts(id) {
+            match self.get_thought(id) {
+                Ok(record) => records.push(record),
+                Err(GetError::NotFound) => {
+                    log::warn!("Indexed thought {} not found", id);
+                }
+                Err(GetError::Connection) => return Err(GetAllError::Connection),
+            }
+        }
+        Ok(records)
+    }
+    fn query_thoughts(&self, query: &Query) -> Result<Paginated<Record>, GetAllError> {
+        log::debug!("Query thoughts {:?} from JSON file", query);
+        // Only the thoughts of the area of life are read from the store,
+        // otherwise the trash is skipped before the models are converted.
+        let records = match query.filter.area_of_life {
+            Some(id) => self.get_thoughts_by_area_of_life(id)?,
+            None => self.load_thoughts(|model| model.deleted_at.is_none())?,
+        };
+        Ok(query.apply(records))
+    }
+    fn delete_thought(&self, id: Id) -> Result<(), DeleteError> {
         log::debug!("Delete thought {:?} from JSON file", id);
+        // Plain writes must not interfere with a unit of work.
+        let _lock = self.commit_lock.lock();
         let sid = self.storage_id(id, MAP_THOUGHT_ID_KEY).map_err(|err| {
             log::warn!("Unable to get thought ID: {}", err);
             if err.kind() == io::ErrorKind::NotFound {
@@ -153,6 +171,131 @@ impl Repo for JsonFile {
                 DeleteError::Connection
             }
         })?;
+        self.remove_storage_id(&sid, MAP_THOUGHT_ID_KEY)
+            .map_err(|err| {
+                log::warn!("Unable to delete thought ID: {}", err);
+                DeleteError::Connection
+            })?;
+        self.area_of_life_index.remove(id);
         Ok(())
     }
 }
+
+#[async_trait]
+impl Repo for JsonFile {
+    async fn save(&self, record: Record) -> Result<(), SaveError> {
+        let db = self.clone();
+        unblock(move || db.save_thought(record)).await
+    }
+    async fn get(&self, id: Id) -> Result<Record, GetError> {
+        let db = self.clone();
+        unblock(move || db.get_thought(id)).await
+    }
+    async fn get_all(&self) -> Result<Vec<Record>, GetAllError> {
+        let db = self.clone();
+        unblock(move || db.get_all_thoughts()).await
+    }
+    async fn query(&self, query: &Query) -> Result<Paginated<Record>, GetAllError> {
+        let (db, query) = (self.clone(), query.clone());
+        unblock(move || db.query_thoughts(&query)).await
+    }
+    async fn get_by_area_of_life(&self, id: aol_domain::Id) -> Result<Vec<Record>, GetAllError> {
+        let db = self.clone();
+        unblock(move || db.get_thoughts_by_area_of_life(id)).await
+    }
+    async fn delete(&self, id: Id) -> Result<(), DeleteError> {
+        let db = self.clone();
+        unblock(move || db.delete_thought(id)).await
+    }
+}
+
+pub(super) fn to_model(thought: &Thought) -> models::Thought {
+    let thought_id = thought.id().to_string();
+    let title = String::from(thought.title().as_ref());
+    let body = thought.body().map(|b| String::from(b.as_ref()));
+    let areas_of_life = thought
+        .areas_of_life()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    let tags = thought.tags().iter().map(ToString::to_string).collect();
+    models::Thought {
+        thought_id,
+        title,
+        body,
+        areas_of_life,
+        tags,
+        status: status_to_model(thought.status()),
+        due_at: thought.due_at().map(Timestamp::to_unix_millis),
+        reminder_offset: thought
+            .reminder_offset()
+            .map(|o| u64::try_from(o.as_millis()).unwrap_or(u64::MAX)),
+        created_at: thought.created_at().to_unix_millis(),
+        updated_at: thought.updated_at().to_unix_millis(),
+        version: thought.version().to_u64(),
+        deleted_at: thought.deleted_at().map(Timestamp::to_unix_millis),
+    }
+}
+
+pub(super) fn from_model(id: Id, model: models::Thought) -> Thought {
+    let areas_of_life = model
+        .areas_of_life
+        .into_iter()
+        .filter_map(|id| {
+            id.parse::<aol::Id>()
+                .map_err(|err| {
+                    log::warn!("{}", err);
+                })
+                .map(Into::into)
+                .ok()
+        })
+        .collect();
+    let tags = model
+        .tags
+        .into_iter()
+        .filter_map(|id| {
+            id.parse::<tag::Id>()
+                .map_err(|err| {
+                    log::warn!("{}", err);
+                })
+                .map(Into::into)
+                .ok()
+        })
+        .collect();
+    let created_at = Timestamp::from_unix_millis(model.created_at);
+    let updated_at = Timestamp::from_unix_millis(model.updated_at);
+    Thought::new(
+        id,
+        Title::new(model.title),
+        areas_of_life,
+        created_at,
+        updated_at,
+    )
+    .with_body(model.body.map(Body::new))
+    .with_tags(tags)
+    .with_status(status_from_model(model.status))
+    .with_due(
+        model.due_at.map(Timestamp::from_unix_millis),
+        model.reminder_offset.map(Duration::from_millis),
+    )
+    .with_version(Version::new(model.version))
+    .with_deleted_at(model.deleted_at.map(Timestamp::from_unix_millis))
+}
+
+const fn status_to_model(status: Status) -> models::ThoughtStatus {
+    match status {
+        Status::Inbox => models::ThoughtStatus::Inbox,
+        Status::Active => models::ThoughtStatus::Active,
+        Status::Done => models::ThoughtStatus::Done,
+        Status::Archived => models::ThoughtStatus::Archived,
+    }
+}
+
+const fn status_from_model(status: models::ThoughtStatus) -> Status {
+    match status {
+        models::ThoughtStatus::Inbox => Status::Inbox,
+        models::ThoughtStatus::Active => Status::Active,
+        models::ThoughtStatus::Done => Status::Done,
+        models::ThoughtStatus::Archived => Status::Archived,
+    }
+}
@@ -0,0 +1,243 @@
+use super::{
+    area_of_life, models, relation, revision, thought, unblock, JsonFile, JOURNAL_KEY,
+    MAP_AREA_OF_LIFE_ID_KEY, MAP_TAG_ID_KEY, MAP_THOUGHT_ID_KEY,
+};
+use async_trait::async_trait;
+use cawr_adapter::model::app::thought as app;
+use cawr_application::gateway::{
+    repository::{area_of_life as aol_repo, thought as thought_repo},
+    unit_of_work::{Change, Changes, CommitError, UnitOfWork},
+};
+use cawr_domain::{area_of_life as aol, thought::Id, Version};
+use std::io;
+
+impl JsonFile {
+    /// Write all changes to the journal before they are applied,
+    /// so an interrupted commit is completed on the next start
+    /// (or the next commit).
+    pub(super) fn commit_changes(&self, changes: Changes) -> Result<(), CommitError> {
+        log::debug!("Commit {:?} to JSON file", changes);
+        let _lock = self.commit_lock.lock();
+        self.recover().map_err(|err| {
+            log::warn!("Unable to recover interrupted commit: {}", err);
+            CommitError::Connection
+        })?;
+        changes.check_versions(
+            |id| self.thought_version(id),
+            |id| self.area_of_life_version(id),
+        )?;
+        let entries = changes
+            .into_iter()
+            .map(|change| self.journal_entry(change))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        if entries.is_empty() {
+            return Ok(());
+        }
+        self.journal
+            .save_with_id(&entries, JOURNAL_KEY)
+            .map_err(|err| {
+                log::warn!("Unable to write journal: {}", err);
+                CommitError::Connection
+            })?;
+        self.apply(&entries)
+            .and_then(|()| self.journal.delete(JOURNAL_KEY))
+            .map_err(|err| {
+                log::warn!("Unable to apply journal: {}", err);
+                CommitError::Connection
+            })
+    }
+    /// Complete a commit that was interrupted.
+    pub(super) fn recover(&self) -> Result<(), io::Error> {
+        let entries = match self.journal.get::<Vec<models::JournalEntry>>(JOURNAL_KEY) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        log::info!("Complete interrupted commit of {} changes", entries.len());
+        self.apply(&entries)?;
+        self.journal.delete(JOURNAL_KEY)
+    }
+    fn thought_version(&self, id: Id) -> Result<Option<Version>, CommitError> {
+        if self.existing_storage_id(id, MAP_THOUGHT_ID_KEY)?.is_none() {
+            return Ok(None);
+        }
+        match self.get_thought(id) {
+            Ok(record) => Ok(Some(record.thought.version())),
+            Err(thought_repo::GetError::NotFound) => Ok(None),
+            Err(thought_repo::GetError::Connection) => Err(CommitError::Connection),
+        }
+    }
+    fn area_of_life_version(&self, id: aol::Id) -> Result<Option<Version>, CommitError> {
+        if self
+            .existing_storage_id(id, MAP_AREA_OF_LIFE_ID_KEY)?
+            .is_none()
+        {
+            return Ok(None);
+        }
+        match self.get_area_of_life(id) {
+            Ok(record) => Ok(Some(record.area_of_life.version())),
+            Err(aol_repo::GetError::NotFound) => Ok(None),
+            Err(aol_repo::GetError::Connection) => Err(CommitError::Connection),
+        }
+    }
+    /// The storage ID of a record or `None` if it was never stored.
+    fn existing_storage_id<I>(&self, id: I, key: &str) -> Result<Option<String>, CommitError>
+    where
+        I: ToString,
+    {
+        match self.storage_id(id, key) {
+            Ok(storage_id) => Ok(Some(storage_id)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => {
+                log::warn!("Unable to get storage ID: {}", err);
+                Err(CommitError::Connection)
+            }
+        }
+    }
+    fn journal_entry(&self, change: Change) -> Result<Option<models::JournalEntry>, CommitError> {
+        use models::JournalEntry as E;
+        let entry = match change {
+            Change::SaveThought(thought_repo::Record { thought }) => {
+                // New records are stored by their ID
+                // so that replaying the journal does not create duplicates.
+                let storage_id = self
+                    .existing_storage_id(thought.id(), MAP_THOUGHT_ID_KEY)?
+                    .unwrap_or_else(|| thought.id().to_string());
+                E::SaveThought {
+                    storage_id,
+                    thought: thought::to_model(&thought),
+                }
+            }
+            Change::DeleteThought(id) => {
+                let Some(storage_id) = self.existing_storage_id(id, MAP_THOUGHT_ID_KEY)? else {
+                    return Ok(None);
+                };
+                E::DeleteThought { storage_id }
+            }
+            Change::SaveAreaOfLife(aol_repo::Record { area_of_life }) => {
+                let storage_id = self
+                    .existing_storage_id(area_of_life.id(), MAP_AREA_OF_LIFE_ID_KEY)?
+                    .unwrap_or_else(|| area_of_life.id().to_string());
+                E::SaveAreaOfLife {
+                    storage_id,
+                    area_of_life: area_of_life::to_model(&area_of_life),
+                }
+            }
+            Change::DeleteAreaOfLife(id) => {
+                let Some(storage_id) = self.existing_storage_id(id, MAP_AREA_OF_LIFE_ID_KEY)?
+                else {
+                    return Ok(None);
+                };
+                E::DeleteAreaOfLife { storage_id }
+            }
+            Change::DeleteTag(id) => {
+                let Some(storage_id) = self.existing_storage_id(id, MAP_TAG_ID_KEY)? else {
+                    return Ok(None);
+                };
+                E::DeleteTag { storage_id }
+            }
+            Change::SaveRevision(record) => E::SaveRevision {
+                storage_id: revision::storage_id(record.thought.id(), record.thought.version()),
+                revision: models::Revision {
+                    thought: thought::to_model(&record.thought),
+                    recorded_at: record.recorded_at.to_unix_millis(),
+                },
+            },
+            Change::DeleteRevisions(id) => E::DeleteRevisions {
+                thought_id: id.to_string(),
+            },
+            Change::DeleteRelation(relation) => E::DeleteRelation {
+                storage_id: relation::storage_id(&relation),
+            },
+        };
+        Ok(Some(entry))
+    }
+    fn apply(&self, entries: &[models::JournalEntry]) -> Result<(), io::Error> {
+        use models::JournalEntry as E;
+        for entry in entries {
+            match entry {
+                E::SaveThought {
+                    storage_id,
+                    thought,
+                } => {
+                    self.thoughts.save_with_id(thought, storage_id)?;
+                    self.save_id(storage_id.clone(), &thought.thought_id, MAP_THOUGHT_ID_KEY)?;
+                    let id = parse_thought_id(&thought.thought_id)?;
+                    self.area_of_life_index
+                        .insert(&thought::from_model(id, thought.clone()));
+                }
+                E::DeleteThought { storage_id } => {
+                    match self.thoughts.get::<models::Thought>(storage_id) {
+                        Ok(model) => {
+                            let id = parse_thought_id(&model.thought_id)?;
+                            self.area_of_life_index.remove(id);
+                        }
+                        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                        Err(err) => return Err(err),
+                    }
+                    ignore_missing(self.thoughts.delete(storage_id))?;
+                    self.remove_storage_id(storage_id, MAP_THOUGHT_ID_KEY)?;
+                }
+                E::SaveAreaOfLife {
+                    storage_id,
+                    area_of_life,
+                } => {
+                    self.areas_of_life.save_with_id(area_of_life, storage_id)?;
+                    self.save_id(
+                        storage_id.clone(),
+                        &area_of_life.area_of_life_id,
+                        MAP_AREA_OF_LIFE_ID_KEY,
+                    )?;
+                }
+                E::DeleteAreaOfLife { storage_id } => {
+                    ignore_missing(self.areas_of_life.delete(storage_id))?;
+                    self.remove_storage_id(storage_id, MAP_AREA_OF_LIFE_ID_KEY)?;
+                }
+                E::DeleteTag { storage_id } => {
+                    ignore_missing(self.tags.delete(storage_id))?;
+                    self.remove_storage_id(storage_id, MAP_TAG_ID_KEY)?;
+                }
+                E::SaveRevision {
+                    storage_id,
+                    revision,
+                } => {
+                    self.revisions.save_with_id(revision, storage_id)?;
+                }
+                E::DeleteRevisions { thought_id } => {
+                    let id = parse_thought_id(thought_id)?;
+                    self.remove_revisions_by_thought(id)
+                        .map_err(io::Error::other)?;
+                }
+                E::DeleteRelation { storage_id } => {
+                    ignore_missing(self.relations.delete(storage_id))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_thought_id(id: &str) -> Result<Id, io::Error> {
+    id.parse::<app::Id>()
+        .map(Into::into)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// Deleting a record that is already gone is fine.
+fn ignore_missing(res: Result<(), io::Error>) -> Result<(), io::Error> {
+    match res {
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+        res => res,
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for JsonFile {
+    async fn commit(&self, changes: Changes) -> Result<(), CommitError> {
+        let db = self.clone();
+        unblock(move || db.commit_changes(changes)).await
+    }
+}
@@ -1,2 +1,43 @@
 pub mod in_memory;
 pub mod json_file;
+pub mod search_index;
+
+/// Behaviour that all backends must share.
+#[cfg(test)]
+mod tests {
+    use crate::{in_memory::InMemory, json_file::JsonFile};
+    use cawr_test_kit::{conformance, policy};
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn in_memory_conformance() {
+        conformance::run(InMemory::default).await;
+    }
+
+    #[tokio::test]
+    async fn in_memory_policy() {
+        policy::run(InMemory::default).await;
+    }
+
+    #[tokio::test]
+    async fn json_file_conformance() {
+        let test_dir = TempDir::new().unwrap();
+        let mut count = 0;
+        conformance::run(|| {
+            count += 1;
+            JsonFile::try_new(test_dir.path().join(count.to_string())).unwrap()
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn json_file_policy() {
+        let test_dir = TempDir::new().unwrap();
+        let mut count = 0;
+        policy::run(|| {
+            count += 1;
+            JsonFile::try_new(test_dir.path().join(count.to_string())).unwrap()
+        })
+        .await;
+    }
+}
@@ -0,0 +1,228 @@
+//! An in-process full-text index of thoughts.
+
+use cawr_application::gateway::search_index::Hit;
+use cawr_domain::thought::{Id, Thought};
+use parking_lot::RwLock;
+use rust_stemmers::{Algorithm, Stemmer};
+use std::collections::{HashMap, HashSet};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A term in the title counts as much as this many terms in the body.
+const TITLE_WEIGHT: f64 = 2.0;
+
+/// Term frequency saturation (BM25 `k1`).
+const K1: f64 = 1.2;
+
+/// Document length normalization (BM25 `b`).
+const B: f64 = 0.75;
+
+/// Words that are too common to tell thoughts apart.
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "in", "is", "it", "of", "on",
+    "or", "that", "the", "to", "was", "with",
+];
+
+/// An inverted index that maps stemmed terms to the thoughts containing them.
+///
+/// Matches are ranked with [Okapi BM25](https://en.wikipedia.org/wiki/Okapi_BM25)
+/// where terms of the title weigh more than those of the body.
+#[derive(Default)]
+pub struct InvertedIndex {
+    inner: RwLock<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    /// The weighted frequency of each term per thought.
+    postings: HashMap<String, HashMap<Id, f64>>,
+    documents: HashMap<Id, Document>,
+    /// The sum of the lengths of all documents.
+    total_len: f64,
+}
+
+struct Document {
+    terms: Vec<String>,
+    /// The weighted number of terms.
+    len: f64,
+}
+
+impl InvertedIndex {
+    /// Build an index of all thoughts that are not in the trash.
+    pub fn from_thoughts<'t>(thoughts: impl IntoIterator<Item = &'t Thought>) -> Self {
+        let index = Self::default();
+        for thought in thoughts {
+            if !thought.is_deleted() {
+                index.insert(thought);
+            }
+        }
+        index
+    }
+    /// Add the thought or replace its previous entry.
+    pub fn insert(&self, thought: &Thought) {
+        let mut frequencies = HashMap::<String, f64>::new();
+        for term in tokenize(thought.title().as_ref()) {
+            *frequencies.entry(term).or_default() += TITLE_WEIGHT;
+        }
+        for term in thought
+            .body()
+            .map(|b| tokenize(b.as_ref()))
+            .unwrap_or_default()
+        {
+            *frequencies.entry(term).or_default() += 1.0;
+        }
+        let id = thought.id();
+        let mut inner = self.inner.write();
+        inner.remove(id);
+        let len = frequencies.values().sum();
+        let mut terms = Vec::with_capacity(frequencies.len());
+        for (term, frequency) in frequencies {
+            inner
+                .postings
+                .entry(term.clone())
+                .or_default()
+                .insert(id, frequency);
+            terms.push(term);
+        }
+        inner.total_len += len;
+        inner.documents.insert(id, Document { terms, len });
+    }
+    pub fn remove(&self, id: Id) {
+        self.inner.write().remove(id);
+    }
+    /// Find the thoughts that contain all terms of the query,
+    /// best matches first.
+    #[must_use]
+    pub fn search(&self, query: &str) -> Vec<Hit> {
+        let terms = tokenize(query).into_iter().collect::<HashSet<_>>();
+        let inner = self.inner.read();
+        let mut postings = Vec::with_capacity(terms.len());
+        for term in &terms {
+            match inner.postings.get(term) {
+                Some(docs) => postings.push(docs),
+                None => return vec![],
+            }
+        }
+        let Some((first, rest)) = postings.split_first() else {
+            return vec![];
+        };
+        #[allow(clippy::cast_precision_loss)]
+        let count = inner.documents.len() as f64;
+        let avg_len = inner.total_len / count;
+        let mut hits = first
+            .keys()
+            .filter(|id| rest.iter().all(|docs| docs.contains_key(id)))
+            .map(|id| {
+                let len = inner.documents[id].len;
+                let score = postings
+                    .iter()
+                    .map(|docs| {
+                        #[allow(clippy::cast_precision_loss)]
+                        let df = docs.len() as f64;
+                        let idf = (1.0 + (count - df + 0.5) / (df + 0.5)).ln();
+                        let tf = docs[id];
+                        idf * tf * (K1 + 1.0) / (tf + K1 * (1.0 - B + B * len / avg_len))
+                    })
+                    .sum();
+                Hit { id: *id, score }
+            })
+            .collect::<Vec<_>>();
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.id.cmp(&b.id)));
+        hits
+    }
+}
+
+impl Inner {
+    fn remove(&mut self, id: Id) {
+        let Some(document) = self.documents.remove(&id) else {
+            return;
+        };
+        for term in document.terms {
+            if let Some(docs) = self.postings.get_mut(&term) {
+                docs.remove(&id);
+                if docs.is_empty() {
+                    self.postings.remove(&term);
+                }
+            }
+        }
+        self.total_len -= document.len;
+    }
+}
+
+/// Split a text into lowercase, stemmed words without stop words.
+fn tokenize(text: &str) -> Vec<String> {
+    let stemmer = Stemmer::create(Algorithm::English);
+    text.unicode_words()
+        .map(str::to_lowercase)
+        .filter(|word| !STOP_WORDS.contains(&word.as_str()))
+        .map(|word| stemmer.stem(&word).into_owned())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cawr_domain::{
+        thought::{Body, Title},
+        Timestamp,
+    };
+
+    fn thought(id: u64, title: &str, body: Option<&str>) -> Thought {
+        let now = Timestamp::from_unix_millis(0);
+        Thought::new(
+            Id::new(id),
+            Title::new(title.to_string()),
+            HashSet::new(),
+            now,
+            now,
+        )
+        .with_body(body.map(|b| Body::new(b.to_string())))
+    }
+
+    fn ids(hits: &[Hit]) -> Vec<Id> {
+        hits.iter().map(|h| h.id).collect()
+    }
+
+    #[test]
+    fn match_stemmed_terms() {
+        let index = InvertedIndex::default();
+        index.insert(&thought(1, "Running shoes", None));
+        index.insert(&thought(2, "Read a book", None));
+        assert_eq!(ids(&index.search("run")), vec![Id::new(1)]);
+        assert_eq!(ids(&index.search("RUNS")), vec![Id::new(1)]);
+        assert_eq!(ids(&index.search("books")), vec![Id::new(2)]);
+        assert!(index.search("the").is_empty());
+        assert!(index.search("").is_empty());
+    }
+
+    #[test]
+    fn require_all_terms() {
+        let index = InvertedIndex::default();
+        index.insert(&thought(1, "Buy milk", None));
+        index.insert(&thought(2, "Buy bread", Some("and milk")));
+        assert_eq!(ids(&index.search("milk bread")), vec![Id::new(2)]);
+        assert!(index.search("milk cheese").is_empty());
+    }
+
+    #[test]
+    fn rank_title_matches_higher() {
+        let index = InvertedIndex::default();
+        index.insert(&thought(1, "Holiday", Some("Plan the garden")));
+        index.insert(&thought(2, "Garden", Some("Plant tomatoes")));
+        index.insert(&thought(3, "Shopping", None));
+        let hits = index.search("garden");
+        assert_eq!(ids(&hits), vec![Id::new(2), Id::new(1)]);
+        assert!(hits[0].score > hits[1].score);
+    }
+
+    #[test]
+    fn replace_and_remove_entries() {
+        let index = InvertedIndex::default();
+        index.insert(&thought(1, "Old title", None));
+        index.insert(&thought(1, "New title", None));
+        assert!(index.search("old").is_empty());
+        assert_eq!(ids(&index.search("new")), vec![Id::new(1)]);
+        index.remove(Id::new(1));
+        assert!(index.search("title").is_empty());
+        assert!(index.inner.read().postings.is_empty());
+    }
+}
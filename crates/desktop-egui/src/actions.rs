@@ -1,13 +1,13 @@
 use crate::{ui::Msg, Api};
 use cawr_adapter::db::Db;
 
-pub fn read_all_areas_of_life<D>(api: Api<D>) -> Option<Msg>
+pub async fn read_all_areas_of_life<D>(api: Api<D>) -> Option<Msg>
 where
     D: Db,
 {
-    match api.read_all_areas_of_life() {
+    match api.read_all_areas_of_life(&Default::default()).await {
         Ok(resp) => {
-            let msg = Msg::AreasOfLifeChanged(resp.data.unwrap());
+            let msg = Msg::AreasOfLifeChanged(resp.data.unwrap().areas_of_life);
             return Some(msg);
         }
         Err(err) => {
@@ -17,13 +17,13 @@ where
     None
 }
 
-pub fn read_all_thoughts<D>(api: Api<D>) -> Option<Msg>
+pub async fn read_all_thoughts<D>(api: Api<D>) -> Option<Msg>
 where
     D: Db,
 {
-    match api.read_all_thoughts() {
+    match api.read_all_thoughts(&Default::default()).await {
         Ok(resp) => {
-            let msg = Msg::ThoughtsChanged(resp.data.unwrap());
+            let msg = Msg::ThoughtsChanged(resp.data.unwrap().thoughts);
             return Some(msg);
         }
         Err(err) => {
@@ -1,7 +1,10 @@
 use anyhow::{anyhow, Result};
 use cawr_adapter::{api, db::Db, presenter::http_json_api::Presenter};
 use eframe::egui;
-use std::sync::{mpsc, Arc};
+use std::{
+    future::Future,
+    sync::{mpsc, Arc},
+};
 use tokio::runtime;
 
 mod actions;
@@ -70,15 +73,16 @@ where
             msg_rx,
         }
     }
-    fn spawn_action<F>(&self, f: F)
+    fn spawn_action<F, Fut>(&self, f: F)
     where
-        F: Fn(Api<D>) -> Option<ui::Msg> + Send + 'static,
+        F: FnOnce(Api<D>) -> Fut,
+        Fut: Future<Output = Option<ui::Msg>> + Send + 'static,
     {
         let tx = self.msg_tx.clone();
-        let api = self.api.clone();
+        let action = f(self.api.clone());
         let egui = self.egui.clone();
         self.rt.spawn(async move {
-            if let Some(msg) = f(api) {
+            if let Some(msg) = action.await {
                 tx.send(msg).unwrap();
                 egui.request_repaint();
             }
@@ -1,7 +1,13 @@
 //! All value objects and information that
 //! belong to [`AreaOfLife`]s.
 
-use crate::value_object;
+use std::collections::HashSet;
+
+use crate::{
+    entity::thought,
+    value_object,
+    value_object::{Timestamp, Version},
+};
 
 pub type Id = value_object::Id<AreaOfLife>;
 pub type Name = value_object::Name<AreaOfLife>;
@@ -11,15 +17,56 @@ pub type Name = value_object::Name<AreaOfLife>;
 pub struct AreaOfLife {
     id: Id,
     name: Name,
+    parent: Option<Id>,
+    created_at: Timestamp,
+    updated_at: Timestamp,
+    version: Version,
+    deletion: Option<Deletion>,
+}
+
+/// How an area of life was moved to the trash
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deletion {
+    pub deleted_at: Timestamp,
+    /// The thoughts the area of life was removed from.
+    ///
+    /// They get the area of life back when it is restored.
+    pub thoughts: HashSet<thought::Id>,
 }
 
 impl AreaOfLife {
     #[must_use]
-    pub fn new(id: Id, name: Name) -> Self {
-        // Never construct an area of life with invalid name
-        debug_assert!(name.as_ref().len() <= Name::max_len());
-        debug_assert!(name.as_ref().len() >= Name::min_len());
-        Self { id, name }
+    pub fn new(id: Id, name: Name, created_at: Timestamp, updated_at: Timestamp) -> Self {
+        // An area of life can't be modified before it was created
+        debug_assert!(created_at <= updated_at);
+        Self {
+            id,
+            name,
+            parent: None,
+            created_at,
+            updated_at,
+            version: Version::INITIAL,
+            deletion: None,
+        }
+    }
+    /// Nest the area of life below another one.
+    #[must_use]
+    pub fn with_parent(mut self, parent: Option<Id>) -> Self {
+        // An area of life can't be its own parent
+        debug_assert!(parent != Some(self.id));
+        self.parent = parent;
+        self
+    }
+    #[must_use]
+    pub const fn with_version(mut self, version: Version) -> Self {
+        self.version = version;
+        self
+    }
+    /// Move the area of life to the trash (`Some`) or restore it (`None`).
+    #[must_use]
+    pub fn with_deletion(mut self, deletion: Option<Deletion>) -> Self {
+        self.deletion = deletion;
+        self
     }
     #[must_use]
     pub const fn id(&self) -> Id {
@@ -29,11 +76,36 @@ impl AreaOfLife {
     pub const fn name(&self) -> &Name {
         &self.name
     }
+    #[must_use]
+    pub const fn parent(&self) -> Option<Id> {
+        self.parent
+    }
+    #[must_use]
+    pub const fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+    #[must_use]
+    pub const fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+    #[must_use]
+    pub const fn version(&self) -> Version {
+        self.version
+    }
+    #[must_use]
+    pub const fn deletion(&self) -> Option<&Deletion> {
+        self.deletion.as_ref()
+    }
+    #[must_use]
+    pub const fn is_deleted(&self) -> bool {
+        self.deletion.is_some()
+    }
 }
 
 const MAX_NAME_LEN: usize = 30;
 const MIN_NAME_LEN: usize = 5;
 
+/// Default limits in grapheme clusters
 impl Name {
     pub const fn min_len() -> usize {
         MIN_NAME_LEN
@@ -41,4 +113,14 @@ impl Name {
     pub const fn max_len() -> usize {
         MAX_NAME_LEN
     }
+    /// The form of a name that is used to detect duplicates,
+    /// e.g. "Health" and "health" refer to the same area of life.
+    #[must_use]
+    pub fn normalize(name: &str) -> String {
+        name.trim().to_lowercase()
+    }
+    #[must_use]
+    pub fn normalized(&self) -> String {
+        Self::normalize(self.as_ref())
+    }
 }
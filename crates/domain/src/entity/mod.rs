@@ -1,2 +1,4 @@
 pub mod area_of_life;
+pub mod relation;
+pub mod tag;
 pub mod thought;
@@ -0,0 +1,60 @@
+//! All value objects and information that
+//! belong to [`Relation`]s between thoughts.
+
+use crate::entity::thought;
+use std::fmt;
+
+/// The meaning of a [`Relation`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Kind {
+    /// The source can't be finished before the target
+    DependsOn,
+    /// The source is somehow connected to the target
+    RelatesTo,
+    /// The source replaces the target
+    Supersedes,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::DependsOn => "depends-on",
+            Self::RelatesTo => "relates-to",
+            Self::Supersedes => "supersedes",
+        };
+        f.write_str(s)
+    }
+}
+
+/// A directed, typed link from one thought to another
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Relation {
+    source: thought::Id,
+    kind: Kind,
+    target: thought::Id,
+}
+
+impl Relation {
+    #[must_use]
+    pub fn new(source: thought::Id, kind: Kind, target: thought::Id) -> Self {
+        // A thought can't be linked to itself
+        debug_assert!(source != target);
+        Self {
+            source,
+            kind,
+            target,
+        }
+    }
+    #[must_use]
+    pub const fn source(&self) -> thought::Id {
+        self.source
+    }
+    #[must_use]
+    pub const fn kind(&self) -> Kind {
+        self.kind
+    }
+    #[must_use]
+    pub const fn target(&self) -> thought::Id {
+        self.target
+    }
+}
@@ -0,0 +1,59 @@
+//! All value objects and information that
+//! belong to [`Tag`]s.
+
+use crate::{value_object, value_object::Timestamp};
+
+pub type Id = value_object::Id<Tag>;
+pub type Name = value_object::Name<Tag>;
+
+/// A free-form label that can be attached to thoughts
+#[derive(Debug, Clone)]
+pub struct Tag {
+    id: Id,
+    name: Name,
+    created_at: Timestamp,
+    updated_at: Timestamp,
+}
+
+impl Tag {
+    #[must_use]
+    pub fn new(id: Id, name: Name, created_at: Timestamp, updated_at: Timestamp) -> Self {
+        // A tag can't be modified before it was created
+        debug_assert!(created_at <= updated_at);
+        Self {
+            id,
+            name,
+            created_at,
+            updated_at,
+        }
+    }
+    #[must_use]
+    pub const fn id(&self) -> Id {
+        self.id
+    }
+    #[must_use]
+    pub const fn name(&self) -> &Name {
+        &self.name
+    }
+    #[must_use]
+    pub const fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+    #[must_use]
+    pub const fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+}
+
+const MAX_NAME_LEN: usize = 30;
+const MIN_NAME_LEN: usize = 1;
+
+/// Default limits in grapheme clusters
+impl Name {
+    pub const fn min_len() -> usize {
+        MIN_NAME_LEN
+    }
+    pub const fn max_len() -> usize {
+        MAX_NAME_LEN
+    }
+}
@@ -1,31 +1,189 @@
 //! All value objects and information that
 //! belong to [Thought]s.
 
-use crate::{entity::area_of_life as aol, value_object};
-use std::collections::HashSet;
+use crate::{
+    entity::{area_of_life as aol, tag},
+    value_object,
+    value_object::{Timestamp, Version},
+};
+use std::{collections::HashSet, error, fmt, time::Duration};
 
 pub type Id = value_object::Id<Thought>;
 pub type Title = value_object::Name<Thought>;
+pub type Body = value_object::Text<Thought>;
 
 /// Anything you want to remember
 #[derive(Debug, Clone)]
 pub struct Thought {
     id: Id,
     title: Title,
+    body: Option<Body>,
     areas_of_life: HashSet<aol::Id>,
+    tags: HashSet<tag::Id>,
+    status: Status,
+    due_at: Option<Timestamp>,
+    reminder_offset: Option<Duration>,
+    created_at: Timestamp,
+    updated_at: Timestamp,
+    version: Version,
+    deleted_at: Option<Timestamp>,
 }
 
+/// The lifecycle status of a [`Thought`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// Captured but not yet processed
+    #[default]
+    Inbox,
+    /// Currently worked on
+    Active,
+    /// Finished
+    Done,
+    /// Put aside (must be unarchived before it can be reopened)
+    Archived,
+}
+
+impl Status {
+    /// Check if a thought may move from `self` to `to`.
+    #[must_use]
+    pub const fn can_transition_to(self, to: Self) -> bool {
+        use Status as S;
+        matches!(
+            (self, to),
+            (S::Inbox, S::Active | S::Done | S::Archived)
+                | (S::Active, S::Inbox | S::Done | S::Archived)
+                | (S::Done, S::Active | S::Archived)
+                | (S::Archived, S::Inbox)
+        )
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::Inbox => "inbox",
+            Self::Active => "active",
+            Self::Done => "done",
+            Self::Archived => "archived",
+        };
+        f.write_str(s)
+    }
+}
+
+/// A status change that is not allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidTransition {
+    pub from: Status,
+    pub to: Status,
+}
+
+impl fmt::Display for InvalidTransition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "A thought can't change its status from '{}' to '{}'",
+            self.from, self.to
+        )
+    }
+}
+
+impl error::Error for InvalidTransition {}
+
 impl Thought {
     #[must_use]
-    pub fn new(id: Id, title: Title, areas_of_life: HashSet<aol::Id>) -> Self {
-        // Never construct a thought with invalid title
-        debug_assert!(title.as_ref().len() <= Title::max_len());
-        debug_assert!(title.as_ref().len() >= Title::min_len());
+    pub fn new(
+        id: Id,
+        title: Title,
+        areas_of_life: HashSet<aol::Id>,
+        created_at: Timestamp,
+        updated_at: Timestamp,
+    ) -> Self {
+        // A thought can't be modified before it was created
+        debug_assert!(created_at <= updated_at);
         Self {
             id,
             title,
+            body: None,
             areas_of_life,
+            tags: HashSet::new(),
+            status: Status::default(),
+            due_at: None,
+            reminder_offset: None,
+            created_at,
+            updated_at,
+            version: Version::INITIAL,
+            deleted_at: None,
+        }
+    }
+    #[must_use]
+    pub fn with_body(mut self, body: Option<Body>) -> Self {
+        self.body = body;
+        self
+    }
+    #[must_use]
+    pub fn with_areas_of_life(mut self, areas_of_life: HashSet<aol::Id>) -> Self {
+        self.areas_of_life = areas_of_life;
+        self
+    }
+    #[must_use]
+    pub fn with_tags(mut self, tags: HashSet<tag::Id>) -> Self {
+        self.tags = tags;
+        self
+    }
+    /// Set the due date and how long before it a reminder should fire.
+    #[must_use]
+    pub fn with_due(
+        mut self,
+        due_at: Option<Timestamp>,
+        reminder_offset: Option<Duration>,
+    ) -> Self {
+        // A reminder is always relative to a due date
+        debug_assert!(reminder_offset.is_none() || due_at.is_some());
+        debug_assert!(reminder_offset.map_or(true, |o| o <= Self::max_reminder_offset()));
+        self.due_at = due_at;
+        self.reminder_offset = reminder_offset;
+        self
+    }
+    /// Restore the status e.g. when loading a thought from a repository.
+    ///
+    /// Use [`Thought::transition_to`] to change the status of an existing thought.
+    #[must_use]
+    pub const fn with_status(mut self, status: Status) -> Self {
+        self.status = status;
+        self
+    }
+    /// Set the version e.g. when loading a thought from a repository
+    /// or before storing a modified thought.
+    #[must_use]
+    pub const fn with_version(mut self, version: Version) -> Self {
+        self.version = version;
+        self
+    }
+    /// Move the thought to the trash (`Some`) or restore it (`None`).
+    #[must_use]
+    pub const fn with_deleted_at(mut self, deleted_at: Option<Timestamp>) -> Self {
+        self.deleted_at = deleted_at;
+        self
+    }
+    /// Move the thought into another lifecycle [`Status`].
+    pub fn transition_to(
+        self,
+        status: Status,
+        updated_at: Timestamp,
+    ) -> Result<Self, InvalidTransition> {
+        if !self.status.can_transition_to(status) {
+            return Err(InvalidTransition {
+                from: self.status,
+                to: status,
+            });
         }
+        // A thought can't be modified before it was created
+        debug_assert!(self.created_at <= updated_at);
+        Ok(Self {
+            status,
+            updated_at,
+            ..self
+        })
     }
     #[must_use]
     pub const fn id(&self) -> Id {
@@ -36,14 +194,68 @@ impl Thought {
         &self.title
     }
     #[must_use]
+    pub const fn body(&self) -> Option<&Body> {
+        self.body.as_ref()
+    }
+    #[must_use]
     pub const fn areas_of_life(&self) -> &HashSet<aol::Id> {
         &self.areas_of_life
     }
+    #[must_use]
+    pub const fn tags(&self) -> &HashSet<tag::Id> {
+        &self.tags
+    }
+    #[must_use]
+    pub const fn status(&self) -> Status {
+        self.status
+    }
+    #[must_use]
+    pub const fn due_at(&self) -> Option<Timestamp> {
+        self.due_at
+    }
+    #[must_use]
+    pub const fn reminder_offset(&self) -> Option<Duration> {
+        self.reminder_offset
+    }
+    /// The point in time a reminder should fire.
+    #[must_use]
+    pub fn remind_at(&self) -> Option<Timestamp> {
+        let offset = self.reminder_offset?;
+        self.due_at.map(|due_at| due_at.saturating_sub(offset))
+    }
+    #[must_use]
+    pub const fn max_reminder_offset() -> Duration {
+        Duration::from_secs(MAX_REMINDER_OFFSET_DAYS * 24 * 60 * 60)
+    }
+    #[must_use]
+    pub const fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+    #[must_use]
+    pub const fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+    #[must_use]
+    pub const fn version(&self) -> Version {
+        self.version
+    }
+    /// When the thought was moved to the trash.
+    #[must_use]
+    pub const fn deleted_at(&self) -> Option<Timestamp> {
+        self.deleted_at
+    }
+    #[must_use]
+    pub const fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
 }
 
 const MAX_TITLE_LEN: usize = 80;
 const MIN_TITLE_LEN: usize = 3;
+const MAX_BODY_LEN: usize = 20_000;
+const MAX_REMINDER_OFFSET_DAYS: u64 = 365;
 
+/// Default limits in grapheme clusters
 impl Title {
     pub const fn min_len() -> usize {
         MIN_TITLE_LEN
@@ -52,3 +264,71 @@ impl Title {
         MAX_TITLE_LEN
     }
 }
+
+/// Default limit in grapheme clusters
+impl Body {
+    pub const fn max_len() -> usize {
+        MAX_BODY_LEN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thought() -> Thought {
+        let now = Timestamp::from_unix_millis(0);
+        Thought::new(
+            Id::new(1),
+            Title::new("foo".to_string()),
+            HashSet::new(),
+            now,
+            now,
+        )
+    }
+
+    #[test]
+    fn new_thoughts_are_in_the_inbox() {
+        assert_eq!(thought().status(), Status::Inbox);
+    }
+
+    #[test]
+    fn archived_thoughts_must_be_unarchived_before_reopening() {
+        let later = Timestamp::from_unix_millis(5);
+        let archived = thought().with_status(Status::Archived);
+        let err = archived
+            .clone()
+            .transition_to(Status::Active, later)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            InvalidTransition {
+                from: Status::Archived,
+                to: Status::Active
+            }
+        );
+        let unarchived = archived.transition_to(Status::Inbox, later).unwrap();
+        assert_eq!(unarchived.status(), Status::Inbox);
+        assert_eq!(unarchived.updated_at(), later);
+        assert!(unarchived.transition_to(Status::Active, later).is_ok());
+    }
+
+    #[test]
+    fn done_thoughts_can_be_reopened() {
+        let done = thought().with_status(Status::Done);
+        let now = done.updated_at();
+        assert!(done.transition_to(Status::Active, now).is_ok());
+    }
+
+    #[test]
+    fn remind_before_the_due_date() {
+        let due_at = Timestamp::from_unix_millis(10_000);
+        let thought = thought().with_due(Some(due_at), Some(Duration::from_secs(4)));
+        assert_eq!(
+            thought.remind_at(),
+            Some(Timestamp::from_unix_millis(6_000))
+        );
+        let thought = thought.with_due(Some(due_at), None);
+        assert_eq!(thought.remind_at(), None);
+    }
+}
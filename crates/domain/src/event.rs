@@ -0,0 +1,43 @@
+//! Things that happened to entities.
+
+use crate::entity::{
+    area_of_life as aol,
+    relation::Relation,
+    tag,
+    thought::{self, Status},
+};
+
+/// A change of the domain state that others might want to react on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    ThoughtCreated(thought::Id),
+    ThoughtUpdated(thought::Id),
+    ThoughtStatusChanged {
+        id: thought::Id,
+        from: Status,
+        to: Status,
+    },
+    /// The thought was moved to the trash.
+    ThoughtDeleted(thought::Id),
+    ThoughtRestored(thought::Id),
+    /// The thought was removed from the trash for good.
+    ThoughtPurged(thought::Id),
+    ThoughtsLinked(Relation),
+    ThoughtsUnlinked(Relation),
+    AreaOfLifeCreated(aol::Id),
+    AreaOfLifeUpdated(aol::Id),
+    /// The area of life was moved to the trash.
+    AreaOfLifeDeleted(aol::Id),
+    AreaOfLifeRestored(aol::Id),
+    /// The area of life was removed from the trash for good.
+    AreaOfLifePurged(aol::Id),
+    /// The source area of life was merged into the target
+    /// and removed for good.
+    AreaOfLifeMerged {
+        source: aol::Id,
+        target: aol::Id,
+    },
+    TagCreated(tag::Id),
+    TagUpdated(tag::Id),
+    TagDeleted(tag::Id),
+}
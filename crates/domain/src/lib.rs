@@ -20,6 +20,11 @@
 //! In this project, therefore, all entites (or root aggregates) use an ID.
 
 mod entity;
+mod event;
 mod value_object;
 
-pub use self::entity::{area_of_life::AreaOfLife, thought::Thought, *};
+pub use self::{
+    entity::{area_of_life::AreaOfLife, relation::Relation, tag::Tag, thought::Thought, *},
+    event::Event,
+    value_object::{Id, Timestamp, Version},
+};
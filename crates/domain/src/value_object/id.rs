@@ -1,6 +1,12 @@
 //! A generalised ID type for entities and aggregates.
+//!
+//! An ID is either a sequential number or a globally unique,
+//! time-ordered 128-bit value like a [UUIDv7](https://www.rfc-editor.org/rfc/rfc9562#name-uuid-version-7).
+//! Both share the same value space: sequential IDs are the ones
+//! that fit into an `u64`.
 
 use std::{
+    cmp::Ordering,
     fmt,
     hash::{Hash, Hasher},
     marker::PhantomData,
@@ -8,21 +14,36 @@ use std::{
 
 #[derive(Debug)]
 pub struct Id<T> {
-    id: u64,
+    id: u128,
     // The `fn() -> T` is a trick to tell the compiler that we don't own anything.
     marker: PhantomData<fn() -> T>,
 }
 
 impl<T> Id<T> {
+    /// Create a sequential ID.
     pub const fn new(id: u64) -> Self {
+        Self::from_u128(id as u128)
+    }
+    /// Create an ID from its 128-bit representation (e.g. a UUID).
+    pub const fn from_u128(id: u128) -> Self {
         Self {
             id,
             marker: PhantomData,
         }
     }
-    pub const fn to_u64(self) -> u64 {
+    pub const fn to_u128(self) -> u128 {
         self.id
     }
+    /// The number of a sequential ID.
+    ///
+    /// Returns `None` for globally unique IDs.
+    pub const fn to_sequential(self) -> Option<u64> {
+        if self.id <= u64::MAX as u128 {
+            Some(self.id as u64)
+        } else {
+            None
+        }
+    }
 }
 
 impl<T> Clone for Id<T> {
@@ -41,6 +62,21 @@ impl<T> PartialEq for Id<T> {
 
 impl<T> Eq for Id<T> {}
 
+impl<T> PartialOrd for Id<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Sequential IDs are ordered by their number,
+/// time-ordered IDs by their creation time
+/// and all of them after all sequential ones.
+impl<T> Ord for Id<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
 impl<T> Hash for Id<T> {
     fn hash<H>(&self, hasher: &mut H)
     where
@@ -56,9 +92,23 @@ impl<T> From<u64> for Id<T> {
     }
 }
 
+/// Sequential IDs are formatted as decimal number,
+/// all others in the hyphenated UUID format.
 impl<T> fmt::Display for Id<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.id)
+        if let Some(id) = self.to_sequential() {
+            return write!(f, "{id}");
+        }
+        let hex = format!("{:032x}", self.id);
+        write!(
+            f,
+            "{}-{}-{}-{}-{}",
+            &hex[..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..]
+        )
     }
 }
 
@@ -71,4 +121,18 @@ mod tests {
         let id: Id<()> = Id::new(33);
         assert_eq!(format!("{id}"), "33");
     }
+
+    #[test]
+    fn format_uuid() {
+        let id: Id<()> = Id::from_u128(0x0192_a3b4_c5d6_7e8f_9a0b_1c2d_3e4f_5a6b);
+        assert_eq!(format!("{id}"), "0192a3b4-c5d6-7e8f-9a0b-1c2d3e4f5a6b");
+        assert_eq!(id.to_sequential(), None);
+    }
+
+    #[test]
+    fn order_sequential_before_unique_ids() {
+        let seq: Id<()> = Id::new(u64::MAX);
+        let uuid: Id<()> = Id::from_u128(u128::from(u64::MAX) + 1);
+        assert!(seq < uuid);
+    }
 }
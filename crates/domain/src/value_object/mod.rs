@@ -1,5 +1,11 @@
 mod id;
 mod name;
+mod text;
+mod timestamp;
+mod version;
 
 pub use id::*;
 pub use name::*;
+pub use text::*;
+pub use timestamp::*;
+pub use version::*;
@@ -0,0 +1,22 @@
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct Text<T>(String, PhantomData<T>);
+
+impl<T> Text<T> {
+    pub const fn new(text: String) -> Self {
+        Self(text, PhantomData)
+    }
+}
+
+impl<T> AsRef<str> for Text<T> {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<T> From<Text<T>> for String {
+    fn from(from: Text<T>) -> Self {
+        from.0
+    }
+}
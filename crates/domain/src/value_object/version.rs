@@ -0,0 +1,39 @@
+//! The revision of an entity.
+
+use std::fmt;
+
+/// Counts how often an entity was stored,
+/// so that concurrent modifications can be detected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version(u64);
+
+impl Version {
+    /// The version of an entity that was never modified.
+    pub const INITIAL: Self = Self(1);
+
+    #[must_use]
+    pub const fn new(version: u64) -> Self {
+        Self(version)
+    }
+    #[must_use]
+    pub const fn to_u64(self) -> u64 {
+        self.0
+    }
+    /// The version that follows this one.
+    #[must_use]
+    pub const fn next(self) -> Self {
+        Self(self.0.saturating_add(1))
+    }
+}
+
+impl Default for Version {
+    fn default() -> Self {
+        Self::INITIAL
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
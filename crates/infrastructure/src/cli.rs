@@ -1,7 +1,8 @@
-use crate::storage::data_storage;
+use crate::storage::{data_storage, id_strategy};
 use cawr_cli::Command;
 use clap::Parser;
 use std::{path::PathBuf, sync::Arc};
+use tokio::runtime::Runtime;
 
 #[derive(Parser)]
 struct Args {
@@ -9,10 +10,16 @@ struct Args {
     command: Command,
     #[clap(help = "Directory to store data ", long)]
     data_dir: Option<PathBuf>,
+    #[clap(
+        help = "Generate globally unique IDs (UUIDv7) instead of sequential numbers",
+        long
+    )]
+    uuid_ids: bool,
 }
 
 pub fn run() {
     let args = Args::parse();
     let db = Arc::new(data_storage(args.data_dir));
-    cawr_cli::run(db, args.command);
+    let rt = Runtime::new().expect("tokio runtime");
+    rt.block_on(cawr_cli::run(db, id_strategy(args.uuid_ids), args.command));
 }
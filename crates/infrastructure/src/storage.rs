@@ -1,3 +1,4 @@
+use cawr_adapter::identifier::IdStrategy;
 use cawr_db::json_file::JsonFile;
 use directories::UserDirs;
 use std::path::{Path, PathBuf};
@@ -9,6 +10,15 @@ pub fn data_storage(data_dir: Option<PathBuf>) -> JsonFile {
     JsonFile::try_new(data_dir).expect("JSON file store")
 }
 
+#[must_use]
+pub const fn id_strategy(uuid_ids: bool) -> IdStrategy {
+    if uuid_ids {
+        IdStrategy::UuidV7
+    } else {
+        IdStrategy::Sequential
+    }
+}
+
 const DEFAULT_STORAGE_DIR_NAME: &str = "clean-architecture-with-rust-data";
 
 // Get storage directory with the following priority:
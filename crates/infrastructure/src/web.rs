@@ -7,7 +7,7 @@ use std::{
 use clap::Parser;
 use tokio::runtime::Runtime;
 
-use crate::storage::data_storage;
+use crate::storage::{data_storage, id_strategy};
 
 #[derive(Parser)]
 struct Args {
@@ -17,6 +17,11 @@ struct Args {
     port: u16,
     #[clap(help = "Directory to store data ", long)]
     data_dir: Option<PathBuf>,
+    #[clap(
+        help = "Generate globally unique IDs (UUIDv7) instead of sequential numbers",
+        long
+    )]
+    uuid_ids: bool,
 }
 
 pub fn run() {
@@ -24,5 +29,9 @@ pub fn run() {
     let db = Arc::new(data_storage(args.data_dir));
     let rt = Runtime::new().expect("tokio runtime");
     let addr = SocketAddr::from((args.bind, args.port));
-    rt.block_on(cawr_web_server_warp::run(db, addr));
+    rt.block_on(cawr_web_server_warp::run(
+        db,
+        id_strategy(args.uuid_ids),
+        addr,
+    ));
 }
@@ -5,41 +5,272 @@ use std::fmt;
 pub struct Thought {
     pub id: ThoughtId,
     pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
     pub areas_of_life: Vec<AreaOfLifeId>,
+    #[serde(default)]
+    pub tags: Vec<TagId>,
+    #[serde(default)]
+    pub status: ThoughtStatus,
+    /// Unix timestamp in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_at: Option<u64>,
+    /// Milliseconds before the due date
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reminder_offset: Option<u64>,
+    /// Unix timestamp in milliseconds
+    pub created_at: u64,
+    /// Unix timestamp in milliseconds
+    pub updated_at: u64,
+    /// Incremented with every modification
+    #[serde(default)]
+    pub version: u64,
+}
+
+/// Open thoughts with a due date
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Agenda {
+    pub overdue: Vec<Thought>,
+    pub upcoming: Vec<Thought>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThoughtStatus {
+    #[default]
+    Inbox,
+    Active,
+    Done,
+    Archived,
+}
+
+impl fmt::Display for ThoughtStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::Inbox => "inbox",
+            Self::Active => "active",
+            Self::Done => "done",
+            Self::Archived => "archived",
+        };
+        f.write_str(s)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RelationKind {
+    DependsOn,
+    RelatesTo,
+    Supersedes,
+}
+
+impl fmt::Display for RelationKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::DependsOn => "depends-on",
+            Self::RelatesTo => "relates-to",
+            Self::Supersedes => "supersedes",
+        };
+        f.write_str(s)
+    }
+}
+
+/// A link from or to another thought
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThoughtLink {
+    pub kind: RelationKind,
+    pub thought: ThoughtId,
+}
+
+/// All links of a thought
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThoughtLinks {
+    pub outgoing: Vec<ThoughtLink>,
+    pub backlinks: Vec<ThoughtLink>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AreaOfLife {
     pub id: AreaOfLifeId,
     pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<AreaOfLifeId>,
+    /// Unix timestamp in milliseconds
+    pub created_at: u64,
+    /// Unix timestamp in milliseconds
+    pub updated_at: u64,
+    /// Incremented with every modification
+    #[serde(default)]
+    pub version: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tag {
+    pub id: TagId,
+    pub name: String,
+    /// Unix timestamp in milliseconds
+    pub created_at: u64,
+    /// Unix timestamp in milliseconds
+    pub updated_at: u64,
+}
+
+/// An area of life with all its nested areas of life
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AreaOfLifeTree {
+    #[serde(flatten)]
+    pub area_of_life: AreaOfLife,
+    pub children: Vec<AreaOfLifeTree>,
+}
+
+/// A saved state of a thought
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThoughtRevision {
+    pub version: u64,
+    /// The title at that time
+    pub title: String,
+    /// Unix timestamp in milliseconds
+    pub recorded_at: u64,
+}
+
+/// A field that differs between two revisions of a thought
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "field", rename_all = "snake_case")]
+pub enum ThoughtChange {
+    Title {
+        old: String,
+        new: String,
+    },
+    Body {
+        old: Option<String>,
+        new: Option<String>,
+    },
+    AreasOfLife {
+        added: Vec<AreaOfLifeId>,
+        removed: Vec<AreaOfLifeId>,
+    },
+    Tags {
+        added: Vec<TagId>,
+        removed: Vec<TagId>,
+    },
+    Status {
+        old: ThoughtStatus,
+        new: ThoughtStatus,
+    },
+    /// Unix timestamps in milliseconds
+    DueAt {
+        old: Option<u64>,
+        new: Option<u64>,
+    },
+    /// Milliseconds before the due date
+    ReminderOffset {
+        old: Option<u64>,
+        new: Option<u64>,
+    },
+}
+
+/// Something that was moved to the trash
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "id", rename_all = "kebab-case")]
+pub enum TrashItem {
+    Thought(ThoughtId),
+    AreaOfLife(AreaOfLifeId),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashEntry {
+    pub item: TrashItem,
+    /// The title of a thought or the name of an area of life
+    pub label: String,
+    /// Unix timestamp in milliseconds
+    pub deleted_at: u64,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct ThoughtId(pub u64);
+pub struct ThoughtId(#[serde(with = "id")] pub u128);
 
-impl From<u64> for ThoughtId {
-    fn from(id: u64) -> Self {
+impl From<u128> for ThoughtId {
+    fn from(id: u128) -> Self {
         Self(id)
     }
 }
 
 impl fmt::Display for ThoughtId {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.0.fmt(f)
+        id::fmt(self.0, f)
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct AreaOfLifeId(pub u64);
+pub struct AreaOfLifeId(#[serde(with = "id")] pub u128);
 
-impl From<u64> for AreaOfLifeId {
-    fn from(id: u64) -> Self {
+impl From<u128> for AreaOfLifeId {
+    fn from(id: u128) -> Self {
         Self(id)
     }
 }
 
 impl fmt::Display for AreaOfLifeId {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.0.fmt(f)
+        id::fmt(self.0, f)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TagId(#[serde(with = "id")] pub u128);
+
+impl From<u128> for TagId {
+    fn from(id: u128) -> Self {
+        Self(id)
+    }
+}
+
+impl fmt::Display for TagId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        id::fmt(self.0, f)
+    }
+}
+
+/// Sequential IDs are represented as JSON numbers,
+/// globally unique IDs as hyphenated UUID strings.
+mod id {
+    use serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+    use std::fmt;
+    use uuid::Uuid;
+
+    pub fn fmt(id: u128, f: &mut fmt::Formatter) -> fmt::Result {
+        match u64::try_from(id) {
+            Ok(id) => write!(f, "{id}"),
+            Err(_) => write!(f, "{}", Uuid::from_u128(id).hyphenated()),
+        }
+    }
+
+    pub fn serialize<S>(id: &u128, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match u64::try_from(*id) {
+            Ok(id) => serializer.serialize_u64(id),
+            Err(_) => serializer.collect_str(&Uuid::from_u128(*id).hyphenated()),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<u128, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Number(u64),
+            Text(String),
+        }
+        match Repr::deserialize(deserializer)? {
+            Repr::Number(id) => Ok(id.into()),
+            Repr::Text(id) => id
+                .parse::<u64>()
+                .map(u128::from)
+                .or_else(|_| Uuid::try_parse(&id).map(|id| id.as_u128()))
+                .map_err(|_| D::Error::custom(format!("invalid ID '{id}'"))),
+        }
     }
 }
@@ -6,6 +6,7 @@ mod status_code;
 pub use self::status_code::StatusCode;
 
 pub mod domain;
+pub mod query;
 pub mod usecase;
 
 #[derive(Debug, Serialize, Deserialize)]
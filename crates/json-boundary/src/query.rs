@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl fmt::Display for SortDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::Asc => "asc",
+            Self::Desc => "desc",
+        };
+        f.write_str(s)
+    }
+}
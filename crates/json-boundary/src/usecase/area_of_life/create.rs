@@ -1,12 +1,20 @@
+use crate::domain::AreaOfLifeId;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Request {
     pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<AreaOfLifeId>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub enum Error {
+    ParentId,
     NameMinLength { min: usize, actual: usize },
     NameMaxLength { max: usize, actual: usize },
+    NameControlCharacter { position: usize },
+    ParentNotFound(AreaOfLifeId),
+    Cycle(AreaOfLifeId),
+    NameAlreadyExists(String),
 }
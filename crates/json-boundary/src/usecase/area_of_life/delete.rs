@@ -1,7 +1,46 @@
+use crate::domain::AreaOfLifeId;
 use serde::{Deserialize, Serialize};
 
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Request {
+    /// Delete nested areas of life as well
+    /// instead of moving them up to the parent.
+    #[serde(default)]
+    pub cascade: bool,
+    /// What happens to thoughts that reference a deleted area of life.
+    #[serde(default)]
+    pub references: References,
+    /// The area of life that replaces the deleted one
+    /// if `references` is [`References::Reassign`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<AreaOfLifeId>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum References {
+    /// Refuse to delete an area of life that is still referenced
+    Restrict,
+    /// Remove the area of life from the thoughts
+    #[default]
+    Detach,
+    /// Replace the area of life by the target
+    Reassign,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Response {
+    /// The number of thoughts that were changed
+    pub thoughts: usize,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub enum Error {
     Id,
+    TargetId,
     NotFound,
+    Referenced(usize),
+    TargetNotFound(AreaOfLifeId),
+    TargetDeleted(AreaOfLifeId),
+    Conflict,
 }
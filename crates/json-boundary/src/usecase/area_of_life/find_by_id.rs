@@ -0,0 +1,26 @@
+use crate::domain::AreaOfLife;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Response {
+    pub area_of_life: AreaOfLife,
+    /// The number of directly nested areas of life
+    pub children: usize,
+    pub thoughts: ThoughtCounts,
+}
+
+/// The number of thoughts (outside the trash) by status
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ThoughtCounts {
+    pub total: usize,
+    pub inbox: usize,
+    pub active: usize,
+    pub done: usize,
+    pub archived: usize,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum Error {
+    Id,
+    NotFound,
+}
@@ -0,0 +1,25 @@
+use crate::domain::AreaOfLifeId;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Request {
+    /// The area of life that takes over
+    /// the thoughts and children of the merged one.
+    pub target: AreaOfLifeId,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Response {
+    /// The number of thoughts that were moved to the target
+    pub thoughts: usize,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum Error {
+    Id,
+    TargetId,
+    NotFound(AreaOfLifeId),
+    Same,
+    Cycle(AreaOfLifeId),
+    Conflict,
+}
@@ -1,6 +1,52 @@
+use crate::{domain::AreaOfLife, query::SortDirection};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Request {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SortKey>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub direction: Option<SortDirection>,
+    /// Number of areas of life to skip
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+    /// Maximum number of areas of life to return
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortKey {
+    Name,
+    CreatedAt,
+    UpdatedAt,
+}
+
+impl fmt::Display for SortKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::Name => "name",
+            Self::CreatedAt => "created_at",
+            Self::UpdatedAt => "updated_at",
+        };
+        f.write_str(s)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Response {
+    pub areas_of_life: Vec<AreaOfLife>,
+    /// Number of all areas of life
+    pub total: u64,
+    /// Offset of the next page if there are more areas of life
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<u64>,
+}
 
 #[derive(Debug, Deserialize, Serialize)]
 pub enum Error {
-    // TODO
+    Sort,
+    Direction,
 }
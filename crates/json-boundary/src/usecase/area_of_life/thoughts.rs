@@ -0,0 +1,23 @@
+use crate::{domain::Thought, query::SortDirection, usecase::thought::read_all::SortKey};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Request {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SortKey>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub direction: Option<SortDirection>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Response {
+    pub thoughts: Vec<Thought>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum Error {
+    Id,
+    Sort,
+    Direction,
+    NotFound,
+}
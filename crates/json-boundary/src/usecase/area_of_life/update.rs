@@ -5,12 +5,26 @@ use serde::{Deserialize, Serialize};
 pub struct Request {
     pub id: AreaOfLifeId,
     pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<AreaOfLifeId>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Response {
+    /// The version of the updated area of life
+    pub version: u64,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub enum Error {
     Id,
+    ParentId,
     NotFound,
     NameMinLength { min: usize, actual: usize },
     NameMaxLength { max: usize, actual: usize },
+    NameControlCharacter { position: usize },
+    ParentNotFound(AreaOfLifeId),
+    Cycle(AreaOfLifeId),
+    NameAlreadyExists(String),
+    Conflict,
 }
@@ -0,0 +1,16 @@
+use crate::domain::{RelationKind, ThoughtId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Request {
+    pub kind: RelationKind,
+    pub target: ThoughtId,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum Error {
+    Id,
+    Kind,
+    ThoughtNotFound(ThoughtId),
+    SelfLink,
+}
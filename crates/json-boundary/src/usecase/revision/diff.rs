@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Request {
+    /// The older revision
+    pub from: u64,
+    /// The newer revision
+    pub to: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum Error {
+    Id,
+    NotFound,
+    RevisionNotFound(u64),
+}
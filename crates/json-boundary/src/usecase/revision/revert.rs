@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Response {
+    /// The version of the reverted thought
+    pub version: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum Error {
+    Id,
+    NotFound,
+    RevisionNotFound(u64),
+    Conflict,
+}
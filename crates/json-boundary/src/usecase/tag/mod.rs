@@ -0,0 +1,4 @@
+pub mod create;
+pub mod delete;
+pub mod read_all;
+pub mod update;
@@ -0,0 +1,17 @@
+use crate::domain::TagId;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Request {
+    pub id: TagId,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum Error {
+    Id,
+    NotFound,
+    NameMinLength { min: usize, actual: usize },
+    NameMaxLength { max: usize, actual: usize },
+    NameControlCharacter { position: usize },
+}
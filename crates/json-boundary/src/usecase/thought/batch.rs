@@ -0,0 +1,62 @@
+use crate::domain::{AreaOfLifeId, ThoughtId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Request {
+    pub ids: Vec<ThoughtId>,
+    pub operation: Operation,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Operation {
+    Delete,
+    AssignAreaOfLife { area_of_life: AreaOfLifeId },
+    UnassignAreaOfLife { area_of_life: AreaOfLifeId },
+    ReplaceInTitle { find: String, replace: String },
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Response {
+    /// One report per thought in the order of the request
+    pub reports: Vec<Report>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Report {
+    pub id: ThoughtId,
+    pub outcome: Outcome,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<ItemError>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Changed,
+    Unchanged,
+    Failed,
+}
+
+/// Why a single thought could not be changed
+#[derive(Debug, Deserialize, Serialize)]
+pub enum ItemError {
+    NotFound,
+    TitleMinLength { min: usize, actual: usize },
+    TitleMaxLength { max: usize, actual: usize },
+    TitleControlCharacter { position: usize },
+    BodyMaxLength { max: usize, actual: usize },
+    BodyControlCharacter { position: usize },
+    ReminderWithoutDueDate,
+    ReminderMaxOffset { max: u64, actual: u64 },
+    Conflict,
+    Internal,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum Error {
+    Id,
+    AreaOfLifeId,
+    EmptyFind,
+    AreaOfLifeNotFound(AreaOfLifeId),
+}
@@ -0,0 +1,19 @@
+use crate::domain::{ThoughtId, ThoughtStatus};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Request {
+    pub status: ThoughtStatus,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum Error {
+    Id,
+    Status,
+    NotFound(ThoughtId),
+    InvalidTransition {
+        from: ThoughtStatus,
+        to: ThoughtStatus,
+    },
+    Conflict,
+}
@@ -1,16 +1,33 @@
-use crate::domain::AreaOfLifeId;
+use crate::domain::{AreaOfLifeId, TagId};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Request {
     pub title: String,
+    #[serde(default)]
+    pub body: Option<String>,
     pub areas_of_life: Vec<AreaOfLifeId>,
+    #[serde(default)]
+    pub tags: Vec<TagId>,
+    /// Unix timestamp in milliseconds
+    #[serde(default)]
+    pub due_at: Option<u64>,
+    /// Milliseconds before the due date
+    #[serde(default)]
+    pub reminder_offset: Option<u64>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub enum Error {
     AreaOfLifeId,
+    TagId,
     TitleMinLength { min: usize, actual: usize },
     TitleMaxLength { max: usize, actual: usize },
+    TitleControlCharacter { position: usize },
+    BodyMaxLength { max: usize, actual: usize },
+    BodyControlCharacter { position: usize },
+    ReminderWithoutDueDate,
+    ReminderMaxOffset { max: u64, actual: u64 },
     AreasOfLifeNotFound(Vec<AreaOfLifeId>),
+    TagsNotFound(Vec<TagId>),
 }
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Request {
+    /// Milliseconds to look ahead for upcoming thoughts
+    pub horizon: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum Error {
+    // TODO
+}
@@ -1,6 +1,63 @@
+use crate::{
+    domain::{AreaOfLifeId, Thought, ThoughtStatus},
+    query::SortDirection,
+};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Request {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<ThoughtStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub area_of_life: Option<AreaOfLifeId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SortKey>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub direction: Option<SortDirection>,
+    /// Number of thoughts to skip
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+    /// Maximum number of thoughts to return
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortKey {
+    CreatedAt,
+    UpdatedAt,
+    Title,
+    DueAt,
+}
+
+impl fmt::Display for SortKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::CreatedAt => "created_at",
+            Self::UpdatedAt => "updated_at",
+            Self::Title => "title",
+            Self::DueAt => "due_at",
+        };
+        f.write_str(s)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Response {
+    pub thoughts: Vec<Thought>,
+    /// Number of all matching thoughts
+    pub total: u64,
+    /// Offset of the next page if there are more thoughts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<u64>,
+}
 
 #[derive(Debug, Deserialize, Serialize)]
 pub enum Error {
-    // TODO
+    Status,
+    AreaOfLife,
+    Sort,
+    Direction,
 }
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Request {
+    /// The words to search for in titles and bodies
+    pub q: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum Error {
+    EmptyQuery,
+}
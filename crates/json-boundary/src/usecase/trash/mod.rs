@@ -0,0 +1,3 @@
+pub mod purge;
+pub mod read_all;
+pub mod restore;
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// How many days entries stay in the trash if no retention is given.
+pub const DEFAULT_RETENTION_DAYS: u64 = 30;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Request {
+    /// Only purge entries that have been in the trash
+    /// for at least this many days.
+    #[serde(default = "default_retention_days")]
+    pub retention_days: u64,
+}
+
+impl Default for Request {
+    fn default() -> Self {
+        Self {
+            retention_days: DEFAULT_RETENTION_DAYS,
+        }
+    }
+}
+
+const fn default_retention_days() -> u64 {
+    DEFAULT_RETENTION_DAYS
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum Error {
+    // TODO
+}
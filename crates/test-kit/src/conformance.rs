@@ -0,0 +1,431 @@
+//! A conformance suite for [`Db`] implementations.
+//!
+//! It checks the semantics that the usecases rely on
+//! but that the signatures of the repositories can't express.
+//! Every backend should pass it:
+//!
+//! ```ignore
+//! #[tokio::test]
+//! async fn conformance() {
+//!     cawr_test_kit::conformance::run(MyDb::default).await;
+//! }
+//! ```
+
+use crate::fixture::{
+    add_area_of_life, add_tag, add_thought, areas_of_life, ids, thoughts_of, NOW,
+};
+use cawr_adapter::db::Db;
+use cawr_application::{
+    gateway::{
+        repository::{
+            area_of_life::{self as aol_repo, Repo as AolRepo},
+            query::{Direction, Page, Paginated, Sort},
+            tag::{self as tag_repo, Repo as TagRepo},
+            thought::{self as thought_repo, Repo as ThoughtRepo},
+        },
+        unit_of_work::{Change, Changes, CommitError},
+    },
+    identifier::NewId,
+};
+use cawr_domain::{
+    area_of_life::{self as aol, Deletion, Name},
+    tag,
+    thought::{self, Status, Thought, Title},
+    Version,
+};
+use std::collections::HashSet;
+
+/// Run all checks, each one with a new and empty database.
+pub async fn run<D, F>(mut new_db: F)
+where
+    D: Db,
+    F: FnMut() -> D,
+{
+    thought_round_trip(&new_db()).await;
+    area_of_life_round_trip(&new_db()).await;
+    not_found(&new_db()).await;
+    version_conflicts(&new_db()).await;
+    new_ids_increase(&new_db()).await;
+    thoughts_by_area_of_life(&new_db()).await;
+    query_with_filter_sort_and_page(&new_db()).await;
+    commit_all_or_nothing(&new_db()).await;
+    delete_referenced_area_of_life(&new_db()).await;
+    delete_referenced_tag(&new_db()).await;
+}
+
+/// Save, get, update and delete a thought.
+pub async fn thought_round_trip<D: Db>(db: &D) {
+    let id = add_thought(db, &[]).await;
+    let stored = ThoughtRepo::get(db, id).await.unwrap().thought;
+    assert_eq!(stored.id(), id);
+    assert_eq!(stored.title().as_ref(), "foo");
+    assert_eq!(stored.version(), Version::default());
+    assert_eq!(ids(ThoughtRepo::get_all(db).await.unwrap()), vec![id]);
+
+    let version = stored.version().next();
+    let thought = Thought::new(id, Title::new("bar".to_string()), HashSet::new(), NOW, NOW)
+        .with_version(version);
+    ThoughtRepo::save(db, thought_repo::Record { thought })
+        .await
+        .unwrap();
+    let stored = ThoughtRepo::get(db, id).await.unwrap().thought;
+    assert_eq!(stored.title().as_ref(), "bar");
+    assert_eq!(stored.version(), version);
+    assert_eq!(ThoughtRepo::get_all(db).await.unwrap().len(), 1);
+
+    ThoughtRepo::delete(db, id).await.unwrap();
+    assert!(matches!(
+        ThoughtRepo::get(db, id).await,
+        Err(thought_repo::GetError::NotFound)
+    ));
+    assert!(ThoughtRepo::get_all(db).await.unwrap().is_empty());
+
+    // The ID of a deleted thought can be used again
+    let thought = Thought::new(id, Title::new("baz".to_string()), HashSet::new(), NOW, NOW);
+    ThoughtRepo::save(db, thought_repo::Record { thought })
+        .await
+        .unwrap();
+    let stored = ThoughtRepo::get(db, id).await.unwrap().thought;
+    assert_eq!(stored.title().as_ref(), "baz");
+}
+
+/// Save, get, update and delete an area of life.
+pub async fn area_of_life_round_trip<D: Db>(db: &D) {
+    let id = add_area_of_life(db, "Career").await;
+    let stored = AolRepo::get(db, id).await.unwrap().area_of_life;
+    assert_eq!(stored.id(), id);
+    assert_eq!(stored.name().as_ref(), "Career");
+    let by_name = AolRepo::get_by_normalized_name(db, &Name::normalize("CAREER"))
+        .await
+        .unwrap();
+    assert_eq!(by_name.area_of_life.id(), id);
+    assert_eq!(
+        AolRepo::get_all(db)
+            .await
+            .unwrap()
+            .iter()
+            .map(|r| r.area_of_life.id())
+            .collect::<Vec<_>>(),
+        vec![id]
+    );
+
+    let parent = add_area_of_life(db, "Work life").await;
+    let version = stored.version().next();
+    let area_of_life = stored.with_parent(Some(parent)).with_version(version);
+    AolRepo::save(db, aol_repo::Record { area_of_life })
+        .await
+        .unwrap();
+    let stored = AolRepo::get(db, id).await.unwrap().area_of_life;
+    assert_eq!(stored.parent(), Some(parent));
+    assert_eq!(stored.version(), version);
+
+    AolRepo::delete(db, id).await.unwrap();
+    assert!(matches!(
+        AolRepo::get(db, id).await,
+        Err(aol_repo::GetError::NotFound)
+    ));
+    assert!(matches!(
+        AolRepo::get_by_normalized_name(db, "career").await,
+        Err(aol_repo::GetError::NotFound)
+    ));
+    assert_eq!(AolRepo::get_all(db).await.unwrap().len(), 1);
+}
+
+/// Missing records are reported as such and not as connection problems.
+pub async fn not_found<D: Db>(db: &D) {
+    let id = thought::Id::new(42);
+    assert!(matches!(
+        ThoughtRepo::get(db, id).await,
+        Err(thought_repo::GetError::NotFound)
+    ));
+    assert!(matches!(
+        ThoughtRepo::delete(db, id).await,
+        Err(thought_repo::DeleteError::NotFound)
+    ));
+    let id = aol::Id::new(42);
+    assert!(matches!(
+        AolRepo::get(db, id).await,
+        Err(aol_repo::GetError::NotFound)
+    ));
+    assert!(matches!(
+        AolRepo::delete(db, id).await,
+        Err(aol_repo::DeleteError::NotFound)
+    ));
+    assert!(matches!(
+        AolRepo::get_by_normalized_name(db, "nothing").await,
+        Err(aol_repo::GetError::NotFound)
+    ));
+}
+
+/// A record is only replaced by its direct successor.
+pub async fn version_conflicts<D: Db>(db: &D) {
+    let id = add_thought(db, &[]).await;
+    let stale = Thought::new(id, Title::new("bar".to_string()), HashSet::new(), NOW, NOW);
+    assert!(matches!(
+        ThoughtRepo::save(db, thought_repo::Record { thought: stale }).await,
+        Err(thought_repo::SaveError::Conflict)
+    ));
+    let stored = ThoughtRepo::get(db, id).await.unwrap().thought;
+    assert_eq!(stored.title().as_ref(), "foo");
+
+    let id = add_area_of_life(db, "Career").await;
+    let stored = AolRepo::get(db, id).await.unwrap().area_of_life;
+    let skipped = stored.version().next().next();
+    let area_of_life = stored.with_version(skipped);
+    assert!(matches!(
+        AolRepo::save(db, aol_repo::Record { area_of_life }).await,
+        Err(aol_repo::SaveError::Conflict)
+    ));
+}
+
+/// New IDs are never handed out twice,
+/// not even after the record with the highest ID was deleted.
+pub async fn new_ids_increase<D: Db>(db: &D) {
+    let first = NewId::<thought::Id>::new_id(db).await.unwrap();
+    let second = NewId::<thought::Id>::new_id(db).await.unwrap();
+    assert!(first < second);
+    let id = add_thought(db, &[]).await;
+    assert!(second < id);
+    ThoughtRepo::delete(db, id).await.unwrap();
+    assert!(id < NewId::<thought::Id>::new_id(db).await.unwrap());
+
+    let first = NewId::<aol::Id>::new_id(db).await.unwrap();
+    let second = NewId::<aol::Id>::new_id(db).await.unwrap();
+    assert!(first < second);
+    let id = add_area_of_life(db, "Career").await;
+    assert!(second < id);
+    AolRepo::delete(db, id).await.unwrap();
+    assert!(id < NewId::<aol::Id>::new_id(db).await.unwrap());
+
+    let first = NewId::<tag::Id>::new_id(db).await.unwrap();
+    let second = NewId::<tag::Id>::new_id(db).await.unwrap();
+    assert!(first < second);
+}
+
+/// The thoughts of an area of life follow every kind of write.
+pub async fn thoughts_by_area_of_life<D: Db>(db: &D) {
+    let career = add_area_of_life(db, "Career").await;
+    let health = add_area_of_life(db, "Health").await;
+    let first = add_thought(db, &[career]).await;
+    let second = add_thought(db, &[career, health]).await;
+    add_thought(db, &[]).await;
+    assert_eq!(thoughts_of(db, career).await, vec![first, second]);
+    assert_eq!(thoughts_of(db, health).await, vec![second]);
+
+    let stored = ThoughtRepo::get(db, first).await.unwrap().thought;
+    let version = stored.version().next();
+    let thought = stored
+        .with_areas_of_life([health].into())
+        .with_version(version);
+    ThoughtRepo::save(db, thought_repo::Record { thought })
+        .await
+        .unwrap();
+    assert_eq!(thoughts_of(db, career).await, vec![second]);
+    assert_eq!(thoughts_of(db, health).await, vec![first, second]);
+
+    let mut changes = Changes::default();
+    changes.push(Change::DeleteThought(second));
+    db.commit(changes).await.unwrap();
+    assert!(thoughts_of(db, career).await.is_empty());
+    assert_eq!(thoughts_of(db, health).await, vec![first]);
+
+    ThoughtRepo::delete(db, first).await.unwrap();
+    assert!(thoughts_of(db, health).await.is_empty());
+}
+
+/// Queries skip the trash, filter, sort and return the requested page.
+pub async fn query_with_filter_sort_and_page<D: Db>(db: &D) {
+    let career = add_area_of_life(db, "Career").await;
+    let health = add_area_of_life(db, "Health").await;
+    for (title, area_of_life) in [
+        ("delta", career),
+        ("alpha", career),
+        ("charlie", career),
+        ("bravo", career),
+        ("echo", health),
+        ("foxtrot", career),
+        ("golf", career),
+    ] {
+        let id = NewId::<thought::Id>::new_id(db).await.unwrap();
+        let title = Title::new(title.to_string());
+        let thought = Thought::new(id, title, [area_of_life].into(), NOW, NOW);
+        let thought = match thought.title().as_ref() {
+            "foxtrot" => thought.with_deleted_at(Some(NOW)),
+            "golf" => thought.with_status(Status::Active),
+            _ => thought,
+        };
+        ThoughtRepo::save(db, thought_repo::Record { thought })
+            .await
+            .unwrap();
+    }
+    let titles = |page: &Paginated<thought_repo::Record>| {
+        page.items
+            .iter()
+            .map(|r| r.thought.title().as_ref().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    let query = thought_repo::Query {
+        filter: thought_repo::Filter {
+            status: None,
+            area_of_life: Some(career),
+        },
+        sort: Sort {
+            key: thought_repo::SortKey::Title,
+            direction: Direction::Desc,
+        },
+        page: Page {
+            offset: 1,
+            limit: Some(2),
+        },
+    };
+    let page = ThoughtRepo::query(db, &query).await.unwrap();
+    assert_eq!(titles(&page), ["delta", "charlie"]);
+    assert_eq!(page.total, 5);
+    assert_eq!(page.next_offset, Some(3));
+
+    let query = thought_repo::Query {
+        filter: thought_repo::Filter {
+            status: Some(Status::Active),
+            area_of_life: None,
+        },
+        ..Default::default()
+    };
+    let page = ThoughtRepo::query(db, &query).await.unwrap();
+    assert_eq!(titles(&page), ["golf"]);
+    assert_eq!(page.total, 1);
+
+    let query = thought_repo::Query {
+        page: Page {
+            offset: 4,
+            limit: Some(10),
+        },
+        ..Default::default()
+    };
+    let page = ThoughtRepo::query(db, &query).await.unwrap();
+    assert_eq!(titles(&page), ["echo", "golf"]);
+    assert_eq!(page.total, 6);
+    assert_eq!(page.next_offset, None);
+
+    let query = aol_repo::Query {
+        sort: Sort {
+            key: aol_repo::SortKey::Name,
+            direction: Direction::Desc,
+        },
+        page: Page {
+            offset: 0,
+            limit: Some(1),
+        },
+    };
+    let page = AolRepo::query(db, &query).await.unwrap();
+    let ids = page
+        .items
+        .iter()
+        .map(|r| r.area_of_life.id())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [health]);
+    assert_eq!(page.total, 2);
+    assert_eq!(page.next_offset, Some(1));
+
+    let stored = AolRepo::get(db, health).await.unwrap().area_of_life;
+    let version = stored.version().next();
+    let deletion = Deletion {
+        deleted_at: NOW,
+        thoughts: HashSet::new(),
+    };
+    let area_of_life = stored.with_deletion(Some(deletion)).with_version(version);
+    AolRepo::save(db, aol_repo::Record { area_of_life })
+        .await
+        .unwrap();
+    let page = AolRepo::query(db, &query).await.unwrap();
+    assert_eq!(page.items[0].area_of_life.id(), career);
+    assert_eq!(page.total, 1);
+}
+
+/// A unit of work with a conflicting change writes nothing.
+pub async fn commit_all_or_nothing<D: Db>(db: &D) {
+    let first = add_thought(db, &[]).await;
+    let second = add_thought(db, &[]).await;
+    let stored = ThoughtRepo::get(db, first).await.unwrap().thought;
+    let version = stored.version().next();
+    let mut changes = Changes::default();
+    changes.push(Change::DeleteThought(second));
+    changes.push(Change::SaveThought(thought_repo::Record {
+        thought: stored.clone(),
+    }));
+    assert!(matches!(
+        db.commit(changes).await,
+        Err(CommitError::Conflict)
+    ));
+    assert_eq!(
+        ids(ThoughtRepo::get_all(db).await.unwrap()),
+        vec![first, second]
+    );
+
+    let mut changes = Changes::default();
+    changes.push(Change::DeleteThought(second));
+    changes.push(Change::SaveThought(thought_repo::Record {
+        thought: stored.with_version(version),
+    }));
+    db.commit(changes).await.unwrap();
+    assert_eq!(ids(ThoughtRepo::get_all(db).await.unwrap()), vec![first]);
+    let stored = ThoughtRepo::get(db, first).await.unwrap().thought;
+    assert_eq!(stored.version(), version);
+}
+
+/// Detaching the thoughts from an area of life and deleting it
+/// in one unit of work leaves no reference behind.
+pub async fn delete_referenced_area_of_life<D: Db>(db: &D) {
+    let career = add_area_of_life(db, "Career").await;
+    let health = add_area_of_life(db, "Health").await;
+    let id = add_thought(db, &[career, health]).await;
+
+    let stored = ThoughtRepo::get(db, id).await.unwrap().thought;
+    let version = stored.version().next();
+    let thought = stored
+        .with_areas_of_life([health].into())
+        .with_version(version);
+    let mut changes = Changes::default();
+    changes.push(Change::SaveThought(thought_repo::Record { thought }));
+    changes.push(Change::DeleteAreaOfLife(career));
+    db.commit(changes).await.unwrap();
+
+    assert!(matches!(
+        AolRepo::get(db, career).await,
+        Err(aol_repo::GetError::NotFound)
+    ));
+    assert_eq!(areas_of_life(db, id).await, HashSet::from([health]));
+    assert!(thoughts_of(db, career).await.is_empty());
+    assert_eq!(thoughts_of(db, health).await, vec![id]);
+}
+
+/// Removing a tag from its thoughts and deleting it
+/// in one unit of work leaves no reference behind.
+pub async fn delete_referenced_tag<D: Db>(db: &D) {
+    let work = add_tag(db, "work").await;
+    let home = add_tag(db, "home").await;
+    let id = add_thought(db, &[]).await;
+    let stored = ThoughtRepo::get(db, id).await.unwrap().thought;
+    let version = stored.version().next();
+    let thought = stored.with_tags([work, home].into()).with_version(version);
+    ThoughtRepo::save(db, thought_repo::Record { thought })
+        .await
+        .unwrap();
+
+    let stored = ThoughtRepo::get(db, id).await.unwrap().thought;
+    let version = stored.version().next();
+    let thought = stored.with_tags([home].into()).with_version(version);
+    let mut changes = Changes::default();
+    changes.push(Change::SaveThought(thought_repo::Record { thought }));
+    changes.push(Change::DeleteTag(work));
+    db.commit(changes).await.unwrap();
+
+    assert!(matches!(
+        TagRepo::get(db, work).await,
+        Err(tag_repo::GetError::NotFound)
+    ));
+    assert_eq!(TagRepo::get_all(db).await.unwrap().len(), 1);
+    let stored = ThoughtRepo::get(db, id).await.unwrap().thought;
+    assert_eq!(stored.tags(), &HashSet::from([home]));
+    assert_eq!(stored.version(), version);
+}
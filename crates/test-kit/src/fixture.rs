@@ -0,0 +1,65 @@
+//! Records and lookups shared by the suites.
+
+use cawr_adapter::db::Db;
+use cawr_application::{
+    gateway::repository::{
+        area_of_life::{self as aol_repo, Repo as AolRepo},
+        tag::{self as tag_repo, Repo as TagRepo},
+        thought::{self as thought_repo, Repo as ThoughtRepo},
+    },
+    identifier::NewId,
+};
+use cawr_domain::{
+    area_of_life::{self as aol, AreaOfLife, Name},
+    tag::{self, Tag},
+    thought::{self, Thought, Title},
+    Timestamp,
+};
+use std::collections::HashSet;
+
+pub(crate) const NOW: Timestamp = Timestamp::from_unix_millis(1_700_000_000_000);
+
+pub(crate) async fn add_area_of_life<D: Db>(db: &D, name: &str) -> aol::Id {
+    let id = NewId::<aol::Id>::new_id(db).await.unwrap();
+    let area_of_life = AreaOfLife::new(id, Name::new(name.to_string()), NOW, NOW);
+    AolRepo::save(db, aol_repo::Record { area_of_life })
+        .await
+        .unwrap();
+    id
+}
+
+pub(crate) async fn add_tag<D: Db>(db: &D, name: &str) -> tag::Id {
+    let id = NewId::<tag::Id>::new_id(db).await.unwrap();
+    let tag = Tag::new(id, tag::Name::new(name.to_string()), NOW, NOW);
+    TagRepo::save(db, tag_repo::Record { tag }).await.unwrap();
+    id
+}
+
+pub(crate) async fn add_thought<D: Db>(db: &D, areas_of_life: &[aol::Id]) -> thought::Id {
+    let id = NewId::<thought::Id>::new_id(db).await.unwrap();
+    let areas_of_life = areas_of_life.iter().copied().collect();
+    let thought = Thought::new(id, Title::new("foo".to_string()), areas_of_life, NOW, NOW);
+    ThoughtRepo::save(db, thought_repo::Record { thought })
+        .await
+        .unwrap();
+    id
+}
+
+pub(crate) async fn areas_of_life<D: Db>(db: &D, id: thought::Id) -> HashSet<aol::Id> {
+    let record = ThoughtRepo::get(db, id).await.unwrap();
+    record.thought.areas_of_life().clone()
+}
+
+pub(crate) async fn thoughts_of<D: Db>(db: &D, id: aol::Id) -> Vec<thought::Id> {
+    ids(ThoughtRepo::get_by_area_of_life(db, id).await.unwrap())
+}
+
+/// The sorted IDs of the records.
+pub(crate) fn ids(records: Vec<thought_repo::Record>) -> Vec<thought::Id> {
+    let mut ids = records
+        .into_iter()
+        .map(|r| r.thought.id())
+        .collect::<Vec<_>>();
+    ids.sort();
+    ids
+}
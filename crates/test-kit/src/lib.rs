@@ -0,0 +1,6 @@
+//! Reusable tests for the implementations of the gateways.
+
+pub mod conformance;
+pub mod policy;
+
+mod fixture;
@@ -0,0 +1,111 @@
+//! A suite that runs the delete policies of areas of life
+//! through the usecase against a [`Db`] implementation.
+//!
+//! Unlike the [`conformance`](crate::conformance) suite it checks
+//! that a whole usecase leaves every backend in the same state:
+//!
+//! ```ignore
+//! #[tokio::test]
+//! async fn policy() {
+//!     cawr_test_kit::policy::run(MyDb::default).await;
+//! }
+//! ```
+
+use crate::fixture::{add_area_of_life, add_thought, areas_of_life, thoughts_of};
+use cawr_adapter::{clock::SystemClock, db::Db, event::EventBus};
+use cawr_application::{
+    gateway::repository::area_of_life::Repo as AolRepo,
+    usecase::area_of_life::delete::{Children, Delete, Error, References, Request, Response},
+};
+use cawr_domain::area_of_life as aol;
+use std::collections::HashSet;
+
+/// Run all checks, each one with a new and empty database.
+pub async fn run<D, F>(mut new_db: F)
+where
+    D: Db,
+    F: FnMut() -> D,
+{
+    restrict_references(&new_db()).await;
+    reassign_references(&new_db()).await;
+    detach_references(&new_db()).await;
+}
+
+/// A referenced area of life is kept, an unreferenced one is deleted.
+pub async fn restrict_references<D: Db>(db: &D) {
+    let career = add_area_of_life(db, "Career").await;
+    let hobby = add_area_of_life(db, "Hobby").await;
+    let id = add_thought(db, &[career]).await;
+
+    let err = delete(db, career, References::Restrict).await.unwrap_err();
+    assert!(matches!(err, Error::Referenced(1)));
+    assert!(!is_trashed(db, career).await);
+    assert_eq!(areas_of_life(db, id).await, HashSet::from([career]));
+    assert_eq!(thoughts_of(db, career).await, vec![id]);
+
+    let Response { thoughts } = delete(db, hobby, References::Restrict).await.unwrap();
+    assert_eq!(thoughts, 0);
+    assert!(is_trashed(db, hobby).await);
+}
+
+/// The thoughts get the target instead of the deleted area of life.
+pub async fn reassign_references<D: Db>(db: &D) {
+    let job = add_area_of_life(db, "Job stuff").await;
+    let career = add_area_of_life(db, "Career").await;
+    let first = add_thought(db, &[job]).await;
+    let second = add_thought(db, &[job, career]).await;
+    let other = add_thought(db, &[]).await;
+
+    let missing = aol::Id::new(99);
+    let err = delete(db, job, References::Reassign(missing))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::TargetNotFound(target) if target == missing));
+    assert!(!is_trashed(db, job).await);
+
+    let Response { thoughts } = delete(db, job, References::Reassign(career)).await.unwrap();
+    assert_eq!(thoughts, 2);
+    assert!(is_trashed(db, job).await);
+    assert_eq!(areas_of_life(db, first).await, HashSet::from([career]));
+    assert_eq!(areas_of_life(db, second).await, HashSet::from([career]));
+    assert!(areas_of_life(db, other).await.is_empty());
+    assert!(thoughts_of(db, job).await.is_empty());
+    assert_eq!(thoughts_of(db, career).await, vec![first, second]);
+}
+
+/// The deleted area of life is removed from its thoughts,
+/// which are remembered to get it back on restore.
+pub async fn detach_references<D: Db>(db: &D) {
+    let career = add_area_of_life(db, "Career").await;
+    let health = add_area_of_life(db, "Health").await;
+    let first = add_thought(db, &[career]).await;
+    let second = add_thought(db, &[career, health]).await;
+
+    let Response { thoughts } = delete(db, career, References::Detach).await.unwrap();
+    assert_eq!(thoughts, 2);
+    let stored = AolRepo::get(db, career).await.unwrap().area_of_life;
+    assert_eq!(
+        stored.deletion().map(|d| d.thoughts.clone()),
+        Some(HashSet::from([first, second]))
+    );
+    assert!(areas_of_life(db, first).await.is_empty());
+    assert_eq!(areas_of_life(db, second).await, HashSet::from([health]));
+    assert!(thoughts_of(db, career).await.is_empty());
+    assert_eq!(thoughts_of(db, health).await, vec![second]);
+}
+
+async fn delete<D: Db>(db: &D, id: aol::Id, references: References) -> Result<Response, Error> {
+    let events = EventBus::default();
+    Delete::new(db, &SystemClock, &events)
+        .exec(Request {
+            id,
+            children: Children::Reparent,
+            references,
+        })
+        .await
+}
+
+async fn is_trashed<D: Db>(db: &D, id: aol::Id) -> bool {
+    let record = AolRepo::get(db, id).await.unwrap();
+    record.area_of_life.is_deleted()
+}
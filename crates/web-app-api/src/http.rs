@@ -23,6 +23,18 @@ where
     to_result(res).await
 }
 
+pub async fn get_json_with_query<T, E>(url: &str, query: &[(&str, &str)]) -> Result<T, E>
+where
+    T: for<'de> Deserialize<'de> + 'static,
+    E: for<'de> Deserialize<'de> + 'static,
+{
+    let res = Request::get(url)
+        .query(query.iter().copied())
+        .send()
+        .await?;
+    to_result(res).await
+}
+
 pub async fn post_json<R, T, E>(url: &str, req: &R) -> Result<T, E>
 where
     R: Serialize,
@@ -45,6 +57,22 @@ where
     to_result(res).await
 }
 
+/// Like [`put_json`] but the request fails with `412 Precondition Failed`
+/// if the resource is no longer at the given `version`.
+pub async fn put_json_if_match<R, T, E>(url: &str, req: &R, version: Option<u64>) -> Result<T, E>
+where
+    R: Serialize,
+    T: for<'de> Deserialize<'de> + 'static,
+    E: for<'de> Deserialize<'de> + 'static,
+{
+    let mut builder = Request::put(url);
+    if let Some(version) = version {
+        builder = builder.header("If-Match", &format!("\"{version}\""));
+    }
+    let res = builder.json(req)?.send().await?;
+    to_result(res).await
+}
+
 pub async fn delete_json<R, T, E>(url: &str, req: &R) -> Result<T, E>
 where
     R: Serialize,
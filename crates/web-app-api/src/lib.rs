@@ -1,75 +1,256 @@
 pub mod http;
 
-pub use self::{area_of_life::*, thought::*};
+pub use self::{area_of_life::*, relation::*, revision::*, tag::*, thought::*, trash::*};
 pub use http::Error;
 
 mod thought {
     use crate::http::{self, Result};
     use cawr_json_boundary::{
-        domain::{AreaOfLifeId, Thought, ThoughtId},
-        usecase::thought::{create, delete, find_by_id, read_all, update},
+        domain::{AreaOfLifeId, TagId, Thought, ThoughtId, ThoughtStatus},
+        usecase::thought::{change_status, create, delete, find_by_id, read_all, search, update},
     };
 
     pub async fn fetch_thought(id: &ThoughtId) -> Result<Thought, find_by_id::Error> {
-        http::get_json(&format!("/api/thought/{}", id.0)).await
+        http::get_json(&format!("/api/thought/{id}")).await
     }
 
     pub async fn fetch_all_thoughts() -> Result<Vec<Thought>, read_all::Error> {
-        http::get_json("/api/thought").await
+        http::get_json("/api/thought")
+            .await
+            .map(|res: read_all::Response| res.thoughts)
+    }
+
+    pub async fn search_thoughts(query: &str) -> Result<Vec<Thought>, search::Error> {
+        http::get_json_with_query("/api/thought", &[("q", query)]).await
     }
 
     pub async fn create_thought(
         title: String,
+        body: Option<String>,
         areas_of_life: Vec<AreaOfLifeId>,
+        tags: Vec<TagId>,
+        due_at: Option<u64>,
+        reminder_offset: Option<u64>,
     ) -> Result<ThoughtId, create::Error> {
         http::post_json(
             "/api/thought",
             &create::Request {
                 title,
+                body,
                 areas_of_life,
+                tags,
+                due_at,
+                reminder_offset,
             },
         )
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_thought(
         id: ThoughtId,
         title: String,
+        body: Option<String>,
         areas_of_life: Vec<AreaOfLifeId>,
-    ) -> Result<(), update::Error> {
-        http::put_json(
+        tags: Vec<TagId>,
+        due_at: Option<u64>,
+        reminder_offset: Option<u64>,
+        version: Option<u64>,
+    ) -> Result<update::Response, update::Error> {
+        http::put_json_if_match(
             &format!("/api/thought/{id}"),
             &update::Request {
                 id,
                 title,
+                body,
                 areas_of_life,
+                tags,
+                due_at,
+                reminder_offset,
             },
+            version,
+        )
+        .await
+    }
+
+    pub async fn change_thought_status(
+        id: ThoughtId,
+        status: ThoughtStatus,
+    ) -> Result<(), change_status::Error> {
+        http::put_json(
+            &format!("/api/thought/{id}/status"),
+            &change_status::Request { status },
         )
         .await
     }
 
     pub async fn delete_thought(id: &ThoughtId) -> Result<(), delete::Error> {
-        http::delete_json(&format!("/api/thought/{}", id.0), &()).await
+        http::delete_json(&format!("/api/thought/{id}"), &()).await
+    }
+}
+
+mod relation {
+    use crate::http::{self, Result};
+    use cawr_json_boundary::{
+        domain::{RelationKind, ThoughtId, ThoughtLinks},
+        usecase::relation::{link, read_links, unlink},
+    };
+
+    pub async fn fetch_thought_links(id: &ThoughtId) -> Result<ThoughtLinks, read_links::Error> {
+        http::get_json(&format!("/api/thought/{id}/links")).await
+    }
+
+    pub async fn link_thoughts(
+        source: &ThoughtId,
+        kind: RelationKind,
+        target: ThoughtId,
+    ) -> Result<(), link::Error> {
+        http::post_json(
+            &format!("/api/thought/{source}/links"),
+            &link::Request { kind, target },
+        )
+        .await
+    }
+
+    pub async fn unlink_thoughts(
+        source: &ThoughtId,
+        kind: RelationKind,
+        target: &ThoughtId,
+    ) -> Result<(), unlink::Error> {
+        http::delete_json(&format!("/api/thought/{source}/links/{kind}/{target}"), &()).await
+    }
+}
+
+mod revision {
+    use crate::http::{self, Result};
+    use cawr_json_boundary::{
+        domain::{ThoughtChange, ThoughtId, ThoughtRevision},
+        usecase::revision::{diff, read_all, revert},
+    };
+
+    pub async fn fetch_thought_revisions(
+        id: &ThoughtId,
+    ) -> Result<Vec<ThoughtRevision>, read_all::Error> {
+        http::get_json(&format!("/api/thought/{id}/revisions")).await
+    }
+
+    pub async fn diff_thought_revisions(
+        id: &ThoughtId,
+        from: u64,
+        to: u64,
+    ) -> Result<Vec<ThoughtChange>, diff::Error> {
+        http::get_json(&format!(
+            "/api/thought/{id}/revisions/diff?from={from}&to={to}"
+        ))
+        .await
+    }
+
+    pub async fn revert_thought(
+        id: &ThoughtId,
+        version: u64,
+    ) -> Result<revert::Response, revert::Error> {
+        http::post_json(
+            &format!("/api/thought/{id}/revisions/{version}/revert"),
+            &(),
+        )
+        .await
     }
 }
 
 mod area_of_life {
     use crate::http::{self, Result};
     use cawr_json_boundary::{
-        domain::{AreaOfLife, AreaOfLifeId},
-        usecase::area_of_life::{create, delete, read_all, update},
+        domain::{AreaOfLife, AreaOfLifeId, AreaOfLifeTree, Thought},
+        usecase::area_of_life::{
+            create, delete, find_by_id, merge, read_all, read_subtree, thoughts, update,
+        },
     };
     const RESOURCE: &str = "area-of-life";
 
     pub async fn fetch_all_areas_of_life() -> Result<Vec<AreaOfLife>, read_all::Error> {
+        http::get_json(&format!("/api/{RESOURCE}"))
+            .await
+            .map(|res: read_all::Response| res.areas_of_life)
+    }
+
+    pub async fn fetch_area_of_life(
+        id: &AreaOfLifeId,
+    ) -> Result<find_by_id::Response, find_by_id::Error> {
+        http::get_json(&format!("/api/{RESOURCE}/{id}")).await
+    }
+
+    pub async fn fetch_area_of_life_thoughts(
+        id: &AreaOfLifeId,
+    ) -> Result<Vec<Thought>, thoughts::Error> {
+        http::get_json(&format!("/api/{RESOURCE}/{id}/thoughts"))
+            .await
+            .map(|res: thoughts::Response| res.thoughts)
+    }
+
+    pub async fn fetch_area_of_life_subtree(
+        id: &AreaOfLifeId,
+    ) -> Result<AreaOfLifeTree, read_subtree::Error> {
+        http::get_json(&format!("/api/{RESOURCE}/{id}/subtree")).await
+    }
+
+    pub async fn create_area_of_life(
+        name: String,
+        parent: Option<AreaOfLifeId>,
+    ) -> Result<AreaOfLifeId, create::Error> {
+        http::post_json(
+            &format!("/api/{RESOURCE}"),
+            &create::Request { name, parent },
+        )
+        .await
+    }
+
+    pub async fn update_area_of_life(
+        id: AreaOfLifeId,
+        name: String,
+        parent: Option<AreaOfLifeId>,
+        version: Option<u64>,
+    ) -> Result<update::Response, update::Error> {
+        http::put_json_if_match(
+            &format!("/api/{RESOURCE}/{id}"),
+            &update::Request { id, name, parent },
+            version,
+        )
+        .await
+    }
+
+    pub async fn delete_area_of_life(id: &AreaOfLifeId) -> Result<delete::Response, delete::Error> {
+        http::delete_json(&format!("/api/{RESOURCE}/{id}"), &()).await
+    }
+
+    pub async fn merge_area_of_life(
+        id: &AreaOfLifeId,
+        target: AreaOfLifeId,
+    ) -> Result<merge::Response, merge::Error> {
+        http::post_json(
+            &format!("/api/{RESOURCE}/{id}/merge"),
+            &merge::Request { target },
+        )
+        .await
+    }
+}
+
+mod tag {
+    use crate::http::{self, Result};
+    use cawr_json_boundary::{
+        domain::{Tag, TagId},
+        usecase::tag::{create, delete, read_all, update},
+    };
+    const RESOURCE: &str = "tag";
+
+    pub async fn fetch_all_tags() -> Result<Vec<Tag>, read_all::Error> {
         http::get_json(&format!("/api/{RESOURCE}")).await
     }
 
-    pub async fn create_area_of_life(name: String) -> Result<AreaOfLifeId, create::Error> {
+    pub async fn create_tag(name: String) -> Result<TagId, create::Error> {
         http::post_json(&format!("/api/{RESOURCE}"), &create::Request { name }).await
     }
 
-    pub async fn update_area_of_life(id: AreaOfLifeId, name: String) -> Result<(), update::Error> {
+    pub async fn update_tag(id: TagId, name: String) -> Result<(), update::Error> {
         http::put_json(
             &format!("/api/{RESOURCE}/{id}"),
             &update::Request { id, name },
@@ -77,7 +258,36 @@ mod area_of_life {
         .await
     }
 
-    pub async fn delete_area_of_life(id: &AreaOfLifeId) -> Result<(), delete::Error> {
+    pub async fn delete_tag(id: &TagId) -> Result<(), delete::Error> {
         http::delete_json(&format!("/api/{RESOURCE}/{id}"), &()).await
     }
 }
+
+mod trash {
+    use crate::http::{self, Result};
+    use cawr_json_boundary::{
+        domain::{AreaOfLifeId, ThoughtId, TrashEntry, TrashItem},
+        usecase::trash::{purge, read_all, restore},
+    };
+    const RESOURCE: &str = "trash";
+
+    pub async fn fetch_trash() -> Result<Vec<TrashEntry>, read_all::Error> {
+        http::get_json(&format!("/api/{RESOURCE}")).await
+    }
+
+    pub async fn restore_thought(id: &ThoughtId) -> Result<(), restore::Error> {
+        http::post_json(&format!("/api/{RESOURCE}/thought/{id}/restore"), &()).await
+    }
+
+    pub async fn restore_area_of_life(id: &AreaOfLifeId) -> Result<(), restore::Error> {
+        http::post_json(&format!("/api/{RESOURCE}/area-of-life/{id}/restore"), &()).await
+    }
+
+    pub async fn purge_trash(retention_days: u64) -> Result<Vec<TrashItem>, purge::Error> {
+        http::delete_json(
+            &format!("/api/{RESOURCE}?retention_days={retention_days}"),
+            &(),
+        )
+        .await
+    }
+}
@@ -27,10 +27,12 @@ pub enum UsecaseResult {
     CreateAreaOfLife(Result<AreaOfLifeId>),
     UpdateAreaOfLife(AreaOfLifeId, Result<()>),
     FetchAllThoughts(Result<Vec<Thought>>),
+    SearchThoughts(Result<Vec<Thought>>),
     FetchAllAreasOfLife(Result<Vec<AreaOfLife>>),
     FindThought(Result<Thought>),
     DeleteThought(Result<ThoughtId>),
     DeleteAreaOfLife(Result<AreaOfLifeId>),
+    MergeAreaOfLife(Result<AreaOfLifeId>),
 }
 
 // -- Map usecases to messages -- //
@@ -52,6 +54,11 @@ pub async fn fetch_all_thoughts() -> UsecaseResult {
     UsecaseResult::FetchAllThoughts(res)
 }
 
+pub async fn search_thoughts(query: String) -> UsecaseResult {
+    let res = usecase::thought::search(&query).await;
+    UsecaseResult::SearchThoughts(res)
+}
+
 pub async fn find_thought_by_id(id: domain::ThoughtId) -> UsecaseResult {
     let res = usecase::thought::find_by_id(&id).await;
     UsecaseResult::FindThought(res)
@@ -82,3 +89,11 @@ pub async fn delete_area_of_life(id: domain::AreaOfLifeId) -> UsecaseResult {
     let res = usecase::area_of_life::delete(&id).await;
     UsecaseResult::DeleteAreaOfLife(res.map(|()| id))
 }
+
+pub async fn merge_area_of_life(
+    id: domain::AreaOfLifeId,
+    target: domain::AreaOfLifeId,
+) -> UsecaseResult {
+    let res = usecase::area_of_life::merge(&id, target).await;
+    UsecaseResult::MergeAreaOfLife(res.map(|()| id))
+}
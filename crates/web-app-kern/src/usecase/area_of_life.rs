@@ -10,16 +10,23 @@ use crate::{
 
 pub async fn create(name: String) -> Result<AreaOfLifeId, String> {
     let presenter = ErrorPresenter;
-    api::create_area_of_life(name)
+    api::create_area_of_life(name, None)
         .await
         .map_err(|e| presenter.present(e))
 }
 
 pub async fn update(aol: AreaOfLife) -> Result<(), String> {
-    let AreaOfLife { id, name } = aol;
+    let AreaOfLife {
+        id,
+        name,
+        parent,
+        version,
+        ..
+    } = aol;
     let presenter = ErrorPresenter;
-    api::update_area_of_life(id, name)
+    api::update_area_of_life(id, name, parent, Some(version))
         .await
+        .map(|_| ())
         .map_err(|e| presenter.present(e))
 }
 
@@ -34,5 +41,14 @@ pub async fn delete(id: &AreaOfLifeId) -> Result<(), String> {
     let presenter = ErrorPresenter;
     api::delete_area_of_life(id)
         .await
+        .map(|_| ())
+        .map_err(|e| presenter.present(e))
+}
+
+pub async fn merge(id: &AreaOfLifeId, target: AreaOfLifeId) -> Result<(), String> {
+    let presenter = ErrorPresenter;
+    api::merge_area_of_life(id, target)
+        .await
+        .map(|_| ())
         .map_err(|e| presenter.present(e))
 }
@@ -10,7 +10,7 @@ use crate::{
 
 pub async fn create(title: String, areas_of_life: Vec<AreaOfLifeId>) -> Result<ThoughtId, String> {
     let presenter = ErrorPresenter;
-    api::create_thought(title, areas_of_life)
+    api::create_thought(title, None, areas_of_life, vec![], None, None)
         .await
         .map_err(|e| presenter.present(e))
 }
@@ -19,12 +19,28 @@ pub async fn update(thought: Thought) -> Result<(), String> {
     let Thought {
         id,
         title,
+        body,
         areas_of_life,
+        tags,
+        due_at,
+        reminder_offset,
+        version,
+        ..
     } = thought;
     let presenter = ErrorPresenter;
-    api::update_thought(id, title, areas_of_life)
-        .await
-        .map_err(|e| presenter.present(e))
+    api::update_thought(
+        id,
+        title,
+        body,
+        areas_of_life,
+        tags,
+        due_at,
+        reminder_offset,
+        Some(version),
+    )
+    .await
+    .map(|_| ())
+    .map_err(|e| presenter.present(e))
 }
 
 pub async fn find_by_id(id: &ThoughtId) -> Result<Thought, String> {
@@ -41,6 +57,13 @@ pub async fn fetch_all() -> Result<Vec<Thought>, String> {
         .map_err(|e| presenter.present(e))
 }
 
+pub async fn search(query: &str) -> Result<Vec<Thought>, String> {
+    let presenter = ErrorPresenter;
+    api::search_thoughts(query)
+        .await
+        .map_err(|e| presenter.present(e))
+}
+
 pub async fn delete(id: &ThoughtId) -> Result<(), String> {
     let presenter = ErrorPresenter;
     api::delete_thought(id)
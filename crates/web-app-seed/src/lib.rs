@@ -43,6 +43,9 @@ fn update(msg: Msg, mdl: &mut Mdl, orders: &mut impl Orders<Msg>) {
                     view::Cmd::UpdateThought(thought) => {
                         run_usecase(orders, kern::update_thought(thought));
                     }
+                    view::Cmd::SearchThoughts(query) => {
+                        run_usecase(orders, kern::search_thoughts(query));
+                    }
                     view::Cmd::CreateAreaOfLife(name) => {
                         run_usecase(orders, kern::create_area_of_life(name));
                     }
@@ -52,6 +55,9 @@ fn update(msg: Msg, mdl: &mut Mdl, orders: &mut impl Orders<Msg>) {
                     view::Cmd::DeleteAreaOfLife(id) => {
                         run_usecase(orders, kern::delete_area_of_life(id));
                     }
+                    view::Cmd::MergeAreaOfLife(id, target) => {
+                        run_usecase(orders, kern::merge_area_of_life(id, target));
+                    }
                     view::Cmd::UpdateAreaOfLife(aol) => {
                         run_usecase(orders, kern::update_area_of_life(aol));
                     }
@@ -99,6 +105,10 @@ fn update(msg: Msg, mdl: &mut Mdl, orders: &mut impl Orders<Msg>) {
                 let msg = view::Msg::FetchAllThoughtsResult(res);
                 orders.send_msg(msg.into());
             }
+            UsecaseResult::SearchThoughts(res) => {
+                let msg = view::Msg::SearchThoughtsResult(res);
+                orders.send_msg(msg.into());
+            }
             UsecaseResult::FetchAllAreasOfLife(res) => {
                 let msg = view::Msg::FetchAllAreasOfLifeResult(res);
                 orders.send_msg(msg.into());
@@ -111,6 +121,15 @@ fn update(msg: Msg, mdl: &mut Mdl, orders: &mut impl Orders<Msg>) {
                 let msg = view::Msg::DeleteAreaOfLifeResult(res);
                 orders.send_msg(msg.into());
             }
+            UsecaseResult::MergeAreaOfLife(res) => {
+                if res.is_ok() {
+                    // The thoughts and nested areas of life were moved
+                    run_usecase(orders, kern::fetch_all_thoughts());
+                    run_usecase(orders, kern::fetch_all_areas_of_life());
+                }
+                let msg = view::Msg::MergeAreaOfLifeResult(res);
+                orders.send_msg(msg.into());
+            }
         },
     }
 }
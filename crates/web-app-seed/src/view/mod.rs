@@ -29,9 +29,11 @@ pub enum Msg {
     CreateAreaOfLifeResult(Result<AreaOfLifeId>),
     FindThoughtResult(Result<Thought>),
     FetchAllThoughtsResult(Result<Vec<Thought>>),
+    SearchThoughtsResult(Result<Vec<Thought>>),
     FetchAllAreasOfLifeResult(Result<Vec<AreaOfLife>>),
     DeleteThoughtResult(Result<ThoughtId>),
     DeleteAreaOfLifeResult(Result<AreaOfLifeId>),
+    MergeAreaOfLifeResult(Result<AreaOfLifeId>),
     UpdateAreaOfLifeResult(Result<()>),
 }
 
@@ -44,8 +46,10 @@ pub enum Cmd {
     CreateThought(String, Option<AreaOfLifeId>),
     UpdateThought(Thought),
     DeleteThought(ThoughtId),
+    SearchThoughts(String),
     CreateAreaOfLife(String),
     DeleteAreaOfLife(AreaOfLifeId),
+    MergeAreaOfLife(AreaOfLifeId, AreaOfLifeId),
     UpdateAreaOfLife(AreaOfLife),
     SendMessages(Vec<Msg>),
 }
@@ -57,8 +61,10 @@ impl From<page::Cmd> for Cmd {
             C::CreateThought(title, aol) => Self::CreateThought(title, aol),
             C::UpdateThought(thought) => Self::UpdateThought(thought),
             C::DeleteThought(id) => Self::DeleteThought(id),
+            C::SearchThoughts(query) => Self::SearchThoughts(query),
             C::CreateAreaOfLife(name) => Self::CreateAreaOfLife(name),
             C::DeleteAreaOfLife(id) => Self::DeleteAreaOfLife(id),
+            C::MergeAreaOfLife(id, target) => Self::MergeAreaOfLife(id, target),
             C::UpdateAreaOfLife(aol) => Self::UpdateAreaOfLife(aol),
             C::SendMessages(m) => Self::SendMessages(m.into_iter().map(Msg::Page).collect()),
         }
@@ -83,12 +89,18 @@ pub fn update(msg: Msg, mdl: &mut Mdl) -> Option<Cmd> {
         Msg::DeleteAreaOfLifeResult(res) => {
             page::Msg::Home(page::home::Msg::DeleteAreaOfLifeResult(res))
         }
+        Msg::MergeAreaOfLifeResult(res) => {
+            page::Msg::Home(page::home::Msg::MergeAreaOfLifeResult(res))
+        }
         Msg::UpdateAreaOfLifeResult(res) => {
             page::Msg::Home(page::home::Msg::UpdateAreaOfLifeResult(res))
         }
         Msg::FetchAllThoughtsResult(res) => {
             page::Msg::Home(page::home::Msg::FetchAllThoughtsResult(res))
         }
+        Msg::SearchThoughtsResult(res) => {
+            page::Msg::Home(page::home::Msg::SearchThoughtsResult(res))
+        }
         Msg::FetchAllAreasOfLifeResult(res) => {
             page::Msg::Home(page::home::Msg::FetchAllAreasOfLifeResult(res))
         }
@@ -1,8 +1,8 @@
 use std::collections::HashMap;
 
 use seed::{
-    a, aside, attrs, button, div, empty, h1, h3, i, id, input, li, main, nav, p, prelude::*,
-    section, span, style, ul, C, IF,
+    a, aside, attrs, button, div, empty, h1, h3, i, id, input, li, main, nav, option, p,
+    prelude::*, section, select, span, style, textarea, ul, C, IF,
 };
 
 use crate::{
@@ -25,6 +25,10 @@ pub struct Mdl {
     input: String,
     title_input: String,
     title_input_el: ElRef<web_sys::HtmlInputElement>,
+    body_input: String,
+    search_input: String,
+    /// The IDs of the found thoughts, best matches first.
+    search_results: Option<Vec<ThoughtId>>,
     input_error: Option<String>,
     error: Option<String>,
     wait_for_deletion: Option<ThoughtId>,
@@ -47,12 +51,18 @@ pub enum Msg {
     InputChanged(String),
     TitleChanged(String),
     CancleTitleEdit,
+    BodyChanged(String),
+    UpdateBody,
+    SearchInputChanged(String),
+    SearchRequest,
+    ClearSearch,
     SelectRequest(ThoughtId),
     DeleteRequest(ThoughtId),
     CreateRequest,
     DeleteThoughtResult(Result<ThoughtId>),
     FindThoughtResult(Result<Thought>),
     FetchAllThoughtsResult(Result<Vec<Thought>>),
+    SearchThoughtsResult(Result<Vec<Thought>>),
     CreateThoughtResult(Result<ThoughtId>),
     UpdateThoughtResult(Result<()>),
     // -- Area of Life -- //
@@ -61,10 +71,13 @@ pub enum Msg {
     SelectAreaOfLife(AreaOfLifeId),
     DeselectAreaOfLife,
     DeleteAreaOfLife(AreaOfLifeId),
+    /// Merge the area of life into the one with the given ID.
+    MergeAreaOfLife(AreaOfLifeId, String),
     UpdateAreaOfLifeName(AreaOfLifeId),
     CreateAreaOfLifeResult(Result<AreaOfLifeId>),
     FetchAllAreasOfLifeResult(Result<Vec<AreaOfLife>>),
     DeleteAreaOfLifeResult(Result<AreaOfLifeId>),
+    MergeAreaOfLifeResult(Result<AreaOfLifeId>),
     UpdateAreaOfLifeResult(Result<()>),
     ShowNewAreaOfLifeDialog,
     EditAreasOfLife(bool),
@@ -81,10 +94,12 @@ pub enum Cmd {
     CreateThought(String, Option<AreaOfLifeId>),
     UpdateThought(Thought),
     DeleteThought(ThoughtId),
+    SearchThoughts(String),
     // -- Area of Life -- //
     UpdateAreaOfLife(AreaOfLife),
     CreateAreaOfLife(String),
     DeleteAreaOfLife(AreaOfLifeId),
+    MergeAreaOfLife(AreaOfLifeId, AreaOfLifeId),
     // -- Misc -- //
     SendMessages(Vec<Msg>),
 }
@@ -105,15 +120,48 @@ pub fn update(msg: Msg, mdl: &mut Mdl) -> Option<Cmd> {
             if let Some(id) = &mdl.current_thought {
                 if let Some(thought) = mdl.thoughts.get_mut(id) {
                     let updated = Thought {
-                        id: thought.id,
                         title,
-                        areas_of_life: thought.areas_of_life.clone(),
+                        ..thought.clone()
                     };
                     let cmd = Cmd::UpdateThought(updated);
                     return Some(cmd);
                 }
             }
         }
+        Msg::BodyChanged(body) => {
+            mdl.body_input = body;
+        }
+        Msg::UpdateBody => {
+            if let Some(id) = &mdl.current_thought {
+                if let Some(thought) = mdl.thoughts.get(id) {
+                    let body = Some(mdl.body_input.clone()).filter(|b| !b.trim().is_empty());
+                    if body == thought.body {
+                        return None;
+                    }
+                    let updated = Thought {
+                        body,
+                        ..thought.clone()
+                    };
+                    let cmd = Cmd::UpdateThought(updated);
+                    return Some(cmd);
+                }
+            }
+        }
+        Msg::SearchInputChanged(query) => {
+            mdl.search_input = query;
+        }
+        Msg::SearchRequest => {
+            let query = mdl.search_input.trim();
+            if query.is_empty() {
+                mdl.search_results = None;
+            } else {
+                return Some(Cmd::SearchThoughts(query.to_string()));
+            }
+        }
+        Msg::ClearSearch => {
+            mdl.search_input.clear();
+            mdl.search_results = None;
+        }
         Msg::AreaOfLifeNameChanged(id, name) => {
             if let Some(original_aol) = mdl.areas_of_life.iter_mut().find(|aol| aol.id == id) {
                 mdl.areas_of_life_edits
@@ -153,6 +201,11 @@ pub fn update(msg: Msg, mdl: &mut Mdl) -> Option<Cmd> {
                 .get(&id)
                 .map(|t| t.title.clone())
                 .unwrap_or_default();
+            mdl.body_input = mdl
+                .thoughts
+                .get(&id)
+                .and_then(|t| t.body.clone())
+                .unwrap_or_default();
             mdl.current_thought = Some(id);
         }
         Msg::DeleteRequest(id) => {
@@ -169,6 +222,16 @@ pub fn update(msg: Msg, mdl: &mut Mdl) -> Option<Cmd> {
             let cmd = Cmd::DeleteAreaOfLife(id);
             return Some(cmd);
         }
+        Msg::MergeAreaOfLife(id, target) => {
+            if let Some(target) = mdl
+                .areas_of_life
+                .iter()
+                .find(|aol| aol.id.to_string() == target)
+            {
+                let cmd = Cmd::MergeAreaOfLife(id, target.id);
+                return Some(cmd);
+            }
+        }
         Msg::FindThoughtResult(Err(err)) => {
             mdl.error = Some(err);
         }
@@ -189,10 +252,7 @@ pub fn update(msg: Msg, mdl: &mut Mdl) -> Option<Cmd> {
             }
         }
         Msg::UpdateThoughtResult(res) => {
-            mdl.error = match res {
-                Ok(()) => None,
-                Err(err) => Some(err),
-            };
+            mdl.error = res.err();
         }
         Msg::CreateAreaOfLifeResult(res) => {
             mdl.new_aol_dialog.wait = false;
@@ -214,6 +274,15 @@ pub fn update(msg: Msg, mdl: &mut Mdl) -> Option<Cmd> {
                 mdl.error = Some(err);
             }
         },
+        Msg::SearchThoughtsResult(res) => match res {
+            Ok(thoughts) => {
+                mdl.search_results = Some(thoughts.iter().map(|t| t.id).collect());
+                mdl.thoughts.extend(thoughts.into_iter().map(|t| (t.id, t)));
+            }
+            Err(err) => {
+                mdl.error = Some(err);
+            }
+        },
         Msg::FetchAllAreasOfLifeResult(res) => match res {
             Ok(areas_of_life) => {
                 mdl.areas_of_life = areas_of_life;
@@ -241,6 +310,18 @@ pub fn update(msg: Msg, mdl: &mut Mdl) -> Option<Cmd> {
                 mdl.error = Some(err);
             }
         },
+        Msg::MergeAreaOfLifeResult(res) => match res {
+            Ok(id) => {
+                if mdl.current_aol == Some(id) {
+                    mdl.current_aol = None;
+                }
+                mdl.areas_of_life_edits.remove(&id);
+                mdl.areas_of_life.retain(|a| a.id != id);
+            }
+            Err(err) => {
+                mdl.error = Some(err);
+            }
+        },
         Msg::UpdateAreaOfLifeResult(res) => {
             if let Err(err) = res {
                 mdl.error = Some(err);
@@ -283,7 +364,13 @@ pub fn view(mdl: &Mdl) -> Vec<Node<Msg>> {
 }
 
 fn main(mdl: &Mdl) -> Node<Msg> {
-    let thoughts = mdl.thoughts.values().collect::<Vec<_>>();
+    let thoughts = match &mdl.search_results {
+        Some(ids) => ids
+            .iter()
+            .filter_map(|id| mdl.thoughts.get(id))
+            .collect::<Vec<_>>(),
+        None => mdl.thoughts.values().collect(),
+    };
     main![
         id!["main"],
         error_message(mdl),
@@ -292,6 +379,7 @@ fn main(mdl: &Mdl) -> Node<Msg> {
             div![
                 C!["container"],
                 new_thought_input(mdl),
+                search_input(mdl),
                 thoughts_list(&thoughts, &mdl.wait_for_deletion, &mdl.current_aol)
             ]
         ],
@@ -355,6 +443,22 @@ fn edit_sidebar(mdl: &Mdl) -> Node<Msg> {
                     attrs! { At::Value => &mdl.title_input }
                 ]
             ]
+        ],
+        div![
+            C!["field"],
+            div![
+                C!["control"],
+                textarea![
+                    C!["textarea"],
+                    input_ev(Ev::Input, Msg::BodyChanged),
+                    ev(Ev::Blur, |_| Msg::UpdateBody),
+                    attrs! {
+                        At::Value => &mdl.body_input;
+                        At::Placeholder => "Markdown";
+                        At::Disabled => mdl.current_thought.is_none().as_at_value();
+                    }
+                ]
+            ]
         ]
     ]
 }
@@ -412,6 +516,7 @@ fn aol_list(mdl: &Mdl) -> Node<Msg> {
                                 }),
                             ]
                         ],
+                        merge_area_of_life_select(id, &mdl.areas_of_life),
                         div![
                             C!["control"],
                             button![
@@ -441,6 +546,27 @@ fn aol_list(mdl: &Mdl) -> Node<Msg> {
     }
 }
 
+fn merge_area_of_life_select(id: AreaOfLifeId, areas_of_life: &[AreaOfLife]) -> Node<Msg> {
+    div![
+        C!["control"],
+        div![
+            C!["select"],
+            select![
+                attrs! { At::Title => "Merge into another area of life" },
+                input_ev(Ev::Change, move |target| Msg::MergeAreaOfLife(id, target)),
+                option![
+                    attrs! { At::Value => ""; At::Selected => true.as_at_value() },
+                    "Merge into"
+                ],
+                areas_of_life
+                    .iter()
+                    .filter(|aol| aol.id != id)
+                    .map(|aol| option![attrs! { At::Value => aol.id.to_string() }, &aol.name]),
+            ]
+        ]
+    ]
+}
+
 fn new_area_of_life_button() -> Node<Msg> {
     button![
         ev(Ev::Click, move |_| Msg::ShowNewAreaOfLifeDialog),
@@ -543,6 +669,47 @@ fn new_thought_input(mdl: &Mdl) -> Node<Msg> {
     ]
 }
 
+fn search_input(mdl: &Mdl) -> Node<Msg> {
+    div![
+        C!["block"],
+        div![
+            C!["field", "has-addons"],
+            div![
+                C!["control", "is-expanded", "has-icons-left"],
+                input![
+                    C!["input"],
+                    input_ev(Ev::Input, Msg::SearchInputChanged),
+                    keyboard_ev(Ev::KeyDown, |ev| match &*ev.key() {
+                        "Enter" => Some(Msg::SearchRequest),
+                        "Escape" => Some(Msg::ClearSearch),
+                        _ => None,
+                    }),
+                    attrs! {
+                        At::Value => mdl.search_input;
+                        At::Placeholder => "search thoughts";
+                    },
+                ],
+                span![
+                    C!["icon", "is-small", "is-left"],
+                    i![C!["fas", "fa-search"]]
+                ]
+            ],
+            if mdl.search_results.is_some() {
+                div![
+                    C!["control"],
+                    button![
+                        C!["button"],
+                        ev(Ev::Click, |_| Msg::ClearSearch),
+                        span![C!["icon", "is-small"], i![C!["fas", "fa-times"]]]
+                    ]
+                ]
+            } else {
+                empty!()
+            }
+        ]
+    ]
+}
+
 fn thoughts_list(
     thoughts: &[&Thought],
     wait_for_deletion: &Option<ThoughtId>,
@@ -37,7 +37,9 @@ pub enum Cmd {
     UpdateThought(Thought),
     CreateAreaOfLife(String),
     DeleteThought(ThoughtId),
+    SearchThoughts(String),
     DeleteAreaOfLife(AreaOfLifeId),
+    MergeAreaOfLife(AreaOfLifeId, AreaOfLifeId),
     UpdateAreaOfLife(AreaOfLife),
     SendMessages(Vec<Msg>),
 }
@@ -50,7 +52,9 @@ impl From<home::Cmd> for Cmd {
             C::UpdateThought(thought) => Self::UpdateThought(thought),
             C::CreateAreaOfLife(name) => Self::CreateAreaOfLife(name),
             C::DeleteThought(id) => Self::DeleteThought(id),
+            C::SearchThoughts(query) => Self::SearchThoughts(query),
             C::DeleteAreaOfLife(id) => Self::DeleteAreaOfLife(id),
+            C::MergeAreaOfLife(id, target) => Self::MergeAreaOfLife(id, target),
             C::UpdateAreaOfLife(aol) => Self::UpdateAreaOfLife(aol),
             C::SendMessages(m) => Self::SendMessages(m.into_iter().map(Msg::Home).collect()),
         }
@@ -9,8 +9,59 @@ pub async fn handle<D>(req: Request, api: AppApi<D>) -> Result<impl Reply>
 where
     D: Db,
 {
-    match api.create_area_of_life(req.name) {
+    let parent = req.parent.map(|id| id.to_string());
+    match api.create_area_of_life(req.name, parent.as_deref()).await {
         Ok(res) => Ok(reply_json(&res.data, res.status)),
         Err(err) => Ok(reply_error(err)),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{handle, Request};
+    use crate::tests::{app_api, blank_db, response_json_body};
+    use cawr_adapter::model::view::json::{area_of_life::create as uc, Error};
+    use warp::{http::StatusCode, Reply};
+
+    #[tokio::test]
+    async fn reject_duplicate_names() {
+        let db = blank_db();
+        let api = app_api(db);
+        api.create_area_of_life("Health", None).await.unwrap();
+
+        let req = Request {
+            name: "health".to_string(),
+            parent: None,
+        };
+        let res = handle(req, api).await.unwrap().into_response();
+
+        assert_eq!(res.status(), StatusCode::CONFLICT);
+
+        let err: Error<uc::Error> = response_json_body(res).await.unwrap();
+
+        assert!(matches!(
+            err.details.unwrap(),
+            uc::Error::NameAlreadyExists(name) if name == "Health"
+        ));
+    }
+
+    #[tokio::test]
+    async fn reject_control_characters() {
+        let db = blank_db();
+        let api = app_api(db);
+        let req = Request {
+            name: "Health\u{7}".to_string(),
+            parent: None,
+        };
+        let res = handle(req, api).await.unwrap().into_response();
+
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+        let err: Error<uc::Error> = response_json_body(res).await.unwrap();
+
+        assert!(matches!(
+            err.details.unwrap(),
+            uc::Error::NameControlCharacter { position: 6 }
+        ));
+    }
+}
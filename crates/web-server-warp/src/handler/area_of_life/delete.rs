@@ -2,17 +2,97 @@ use crate::{
     handler::{reply_error, reply_json, Result},
     AppApi,
 };
-use cawr_adapter::db::Db;
+use cawr_adapter::{
+    db::Db,
+    model::{
+        app::area_of_life::delete::References,
+        view::json::area_of_life::delete::{References as JsonReferences, Request},
+    },
+};
 use warp::Reply;
 
-pub type Request = String;
-
-pub async fn handle<D>(req: Request, api: AppApi<D>) -> Result<impl Reply>
+pub async fn handle<D>(id: String, req: Request, api: AppApi<D>) -> Result<impl Reply>
 where
     D: Db,
 {
-    match api.delete_area_of_life(&req) {
+    let references = match req.references {
+        JsonReferences::Restrict => References::Restrict,
+        JsonReferences::Detach => References::Detach,
+        JsonReferences::Reassign => {
+            References::Reassign(req.target.map(|id| id.to_string()).unwrap_or_default())
+        }
+    };
+    match api.delete_area_of_life(&id, req.cascade, references).await {
         Ok(res) => Ok(reply_json(&res.data, res.status)),
         Err(err) => Ok(reply_error(err)),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{handle, JsonReferences, Request};
+    use crate::tests::{app_api, blank_db, response_json_body};
+    use cawr_adapter::model::{
+        app::thought::Schedule,
+        view::json::{area_of_life::delete as uc, Error},
+    };
+    use std::collections::HashSet;
+    use warp::{http::StatusCode, Reply};
+
+    #[tokio::test]
+    async fn restrict_referenced() {
+        let db = blank_db();
+        let api = app_api(db);
+        api.create_area_of_life("Hobby", None).await.unwrap();
+        let areas_of_life = HashSet::from(["1".to_string()]);
+        api.create_thought(
+            "Write a book",
+            None,
+            &areas_of_life,
+            &HashSet::new(),
+            Schedule::default(),
+        )
+        .await
+        .unwrap();
+
+        let req = Request {
+            references: JsonReferences::Restrict,
+            ..Default::default()
+        };
+        let res = handle("1".to_string(), req, api.clone())
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::CONFLICT);
+        let err: Error<uc::Error> = response_json_body(res).await.unwrap();
+        assert!(matches!(err.details.unwrap(), uc::Error::Referenced(1)));
+
+        let req = Request::default();
+        let res = handle("1".to_string(), req, api)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        let body: uc::Response = response_json_body(res).await.unwrap();
+        assert_eq!(body.thoughts, 1);
+    }
+
+    #[tokio::test]
+    async fn reassign_requires_target() {
+        let db = blank_db();
+        let api = app_api(db);
+        api.create_area_of_life("Hobby", None).await.unwrap();
+
+        let req = Request {
+            references: JsonReferences::Reassign,
+            ..Default::default()
+        };
+        let res = handle("1".to_string(), req, api)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+        let err: Error<uc::Error> = response_json_body(res).await.unwrap();
+        assert!(matches!(err.details.unwrap(), uc::Error::TargetId));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    handler::{reply_error, reply_json, with_etag, Result},
+    AppApi,
+};
+use cawr_adapter::db::Db;
+use warp::Reply;
+
+pub type Request = String;
+
+pub async fn handle<D>(req: Request, api: AppApi<D>) -> Result<impl Reply>
+where
+    D: Db,
+{
+    match api.find_area_of_life(&req).await {
+        Ok(res) => {
+            let reply = reply_json(&res.data, res.status);
+            Ok(match &res.data {
+                Some(data) => with_etag(reply, data.area_of_life.version),
+                None => reply.into_response(),
+            })
+        }
+        Err(err) => Ok(reply_error(err).into_response()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::handle;
+    use crate::tests::{app_api, blank_db, response_json_body};
+    use cawr_adapter::model::{
+        app::thought::Schedule,
+        view::json::{area_of_life::find_by_id as uc, Error},
+    };
+    use std::collections::HashSet;
+    use warp::{
+        http::{header::ETAG, StatusCode},
+        Reply,
+    };
+
+    #[tokio::test]
+    async fn find_with_counts() {
+        let db = blank_db();
+        let api = app_api(db);
+        api.create_area_of_life("Health", None).await.unwrap();
+        api.create_area_of_life("Sports", Some("1")).await.unwrap();
+        let areas_of_life = HashSet::from(["1".to_string()]);
+        api.create_thought(
+            "Go running",
+            None,
+            &areas_of_life,
+            &HashSet::new(),
+            Schedule::default(),
+        )
+        .await
+        .unwrap();
+
+        let res = handle("1".to_string(), api).await.unwrap().into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers()[ETAG], "\"1\"");
+        let body: uc::Response = response_json_body(res).await.unwrap();
+        assert_eq!(body.area_of_life.name, "Health");
+        assert_eq!(body.children, 1);
+        assert_eq!(body.thoughts.total, 1);
+        assert_eq!(body.thoughts.inbox, 1);
+    }
+
+    #[tokio::test]
+    async fn not_found() {
+        let db = blank_db();
+        let api = app_api(db);
+
+        let res = handle("5".to_string(), api).await.unwrap().into_response();
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+        let err: Error<uc::Error> = response_json_body(res).await.unwrap();
+        assert!(matches!(err.details.unwrap(), uc::Error::NotFound));
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    handler::{reply_error, reply_json, Result},
+    AppApi,
+};
+use cawr_adapter::{db::Db, model::view::json::area_of_life::merge::Request};
+use warp::Reply;
+
+pub async fn handle<D>(id: String, req: Request, api: AppApi<D>) -> Result<impl Reply>
+where
+    D: Db,
+{
+    let target = req.target.to_string();
+    match api.merge_areas_of_life(&id, &target).await {
+        Ok(res) => Ok(reply_json(&res.data, res.status)),
+        Err(err) => Ok(reply_error(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{handle, Request};
+    use crate::tests::{app_api, blank_db, response_json_body};
+    use cawr_adapter::model::{
+        app::thought::Schedule,
+        view::json::{area_of_life::merge as uc, Error},
+    };
+    use std::collections::HashSet;
+    use warp::{http::StatusCode, Reply};
+
+    #[tokio::test]
+    async fn merge_into_target() {
+        let db = blank_db();
+        let api = app_api(db);
+        api.create_area_of_life("Hobby", None).await.unwrap();
+        api.create_area_of_life("Career", None).await.unwrap();
+        let areas_of_life = HashSet::from(["1".to_string()]);
+        api.create_thought(
+            "Write a book",
+            None,
+            &areas_of_life,
+            &HashSet::new(),
+            Schedule::default(),
+        )
+        .await
+        .unwrap();
+
+        let req = Request { target: 2.into() };
+        let res = handle("1".to_string(), req, api.clone())
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        let body: uc::Response = response_json_body(res).await.unwrap();
+        assert_eq!(body.thoughts, 1);
+
+        let req = Request { target: 2.into() };
+        let res = handle("1".to_string(), req, api)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+        let err: Error<uc::Error> = response_json_body(res).await.unwrap();
+        assert!(matches!(err.details.unwrap(), uc::Error::NotFound(id) if id.0 == 1));
+    }
+
+    #[tokio::test]
+    async fn reject_merge_into_itself() {
+        let db = blank_db();
+        let api = app_api(db);
+        api.create_area_of_life("Hobby", None).await.unwrap();
+
+        let req = Request { target: 1.into() };
+        let res = handle("1".to_string(), req, api)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+        let err: Error<uc::Error> = response_json_body(res).await.unwrap();
+        assert!(matches!(err.details.unwrap(), uc::Error::Same));
+    }
+}
@@ -1,4 +1,8 @@
 pub mod create;
 pub mod delete;
+pub mod find_by_id;
+pub mod merge;
 pub mod read_all;
+pub mod read_subtree;
+pub mod thoughts;
 pub mod update;
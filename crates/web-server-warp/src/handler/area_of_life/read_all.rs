@@ -2,14 +2,23 @@ use crate::{
     handler::{reply_error, reply_json, Result},
     AppApi,
 };
-use cawr_adapter::db::Db;
+use cawr_adapter::{
+    db::Db,
+    model::{app::area_of_life::read_all::Params, view::json::area_of_life::read_all::Request},
+};
 use warp::Reply;
 
-pub async fn handle<D>(api: AppApi<D>) -> Result<impl Reply>
+pub async fn handle<D>(req: Request, api: AppApi<D>) -> Result<impl Reply>
 where
     D: Db,
 {
-    match api.read_all_areas_of_life() {
+    let params = Params {
+        sort: req.sort.map(|key| key.to_string()),
+        direction: req.direction.map(|d| d.to_string()),
+        offset: req.offset,
+        limit: req.limit,
+    };
+    match api.read_all_areas_of_life(&params).await {
         Ok(res) => Ok(reply_json(&res.data, res.status)),
         Err(err) => Ok(reply_error(err)),
     }
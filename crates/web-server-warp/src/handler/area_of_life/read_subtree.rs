@@ -0,0 +1,51 @@
+use crate::{
+    handler::{reply_error, reply_json, Result},
+    AppApi,
+};
+use cawr_adapter::db::Db;
+use warp::Reply;
+
+pub type Request = String;
+
+pub async fn handle<D>(req: Request, api: AppApi<D>) -> Result<impl Reply>
+where
+    D: Db,
+{
+    match api.read_area_of_life_subtree(&req).await {
+        Ok(res) => Ok(reply_json(&res.data, res.status)),
+        Err(err) => Ok(reply_error(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::handle;
+    use crate::tests::{app_api, blank_db, response_json_body};
+    use cawr_adapter::model::view::json::area_of_life::AreaOfLifeTree;
+    use warp::{http::StatusCode, Reply};
+
+    #[tokio::test]
+    async fn read_subtree() {
+        let db = blank_db();
+        let api = app_api(db);
+        api.create_area_of_life("Career", None).await.unwrap();
+        api.create_area_of_life("Project X", Some("1"))
+            .await
+            .unwrap();
+        api.create_area_of_life("Research", Some("2"))
+            .await
+            .unwrap();
+        api.create_area_of_life("Health", None).await.unwrap();
+
+        let res = handle("1".to_string(), api).await.unwrap().into_response();
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let tree: AreaOfLifeTree = response_json_body(res).await.unwrap();
+
+        assert_eq!(tree.area_of_life.name, "Career");
+        assert_eq!(tree.children.len(), 1);
+        assert_eq!(tree.children[0].area_of_life.name, "Project X");
+        assert_eq!(tree.children[0].children[0].area_of_life.name, "Research");
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    handler::{reply_error, reply_json, Result},
+    AppApi,
+};
+use cawr_adapter::{
+    db::Db,
+    model::{app::area_of_life::thoughts::Params, view::json::area_of_life::thoughts::Request},
+};
+use warp::Reply;
+
+pub async fn handle<D>(id: String, req: Request, api: AppApi<D>) -> Result<impl Reply>
+where
+    D: Db,
+{
+    let params = Params {
+        sort: req.sort.map(|key| key.to_string()),
+        direction: req.direction.map(|d| d.to_string()),
+    };
+    match api.read_area_of_life_thoughts(&id, &params).await {
+        Ok(res) => Ok(reply_json(&res.data, res.status)),
+        Err(err) => Ok(reply_error(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{handle, Request};
+    use crate::tests::{app_api, blank_db, response_json_body};
+    use cawr_adapter::model::{
+        app::thought::Schedule,
+        view::json::{
+            area_of_life::thoughts as uc, thought::read_all::SortKey, Error, SortDirection,
+        },
+    };
+    use std::collections::HashSet;
+    use warp::{http::StatusCode, Reply};
+
+    #[tokio::test]
+    async fn read_sorted_thoughts() {
+        let db = blank_db();
+        let api = app_api(db);
+        api.create_area_of_life("Health", None).await.unwrap();
+        let areas_of_life = HashSet::from(["1".to_string()]);
+        for title in ["Go running", "Eat vegetables"] {
+            api.create_thought(
+                title,
+                None,
+                &areas_of_life,
+                &HashSet::new(),
+                Schedule::default(),
+            )
+            .await
+            .unwrap();
+        }
+        api.create_thought(
+            "Read a book",
+            None,
+            &HashSet::new(),
+            &HashSet::new(),
+            Schedule::default(),
+        )
+        .await
+        .unwrap();
+
+        let req = Request {
+            sort: Some(SortKey::Title),
+            direction: Some(SortDirection::Asc),
+        };
+        let res = handle("1".to_string(), req, api)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        let body: uc::Response = response_json_body(res).await.unwrap();
+        let titles: Vec<_> = body.thoughts.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, ["Eat vegetables", "Go running"]);
+    }
+
+    #[tokio::test]
+    async fn not_found() {
+        let db = blank_db();
+        let api = app_api(db);
+
+        let res = handle("5".to_string(), Request::default(), api)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+        let err: Error<uc::Error> = response_json_body(res).await.unwrap();
+        assert!(matches!(err.details.unwrap(), uc::Error::NotFound));
+    }
+}
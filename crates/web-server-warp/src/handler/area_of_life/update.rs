@@ -1,16 +1,134 @@
 use crate::{
-    handler::{reply_error, reply_json, Result},
+    handler::{parse_if_match, reply_error, reply_json, with_etag, Result},
     AppApi,
 };
-use cawr_adapter::{db::Db, model::view::json::area_of_life::update::Request};
+use cawr_adapter::{
+    db::Db,
+    model::view::json::{
+        area_of_life::update::{Error as UpdateError, Request},
+        Error, StatusCode,
+    },
+};
 use warp::Reply;
 
-pub async fn handle<D>(id: String, req: Request, api: AppApi<D>) -> Result<impl Reply>
+pub async fn handle<D>(
+    id: String,
+    if_match: Option<String>,
+    req: Request,
+    api: AppApi<D>,
+) -> Result<impl Reply>
 where
     D: Db,
 {
-    match api.update_area_of_life(&id, req.name) {
-        Ok(res) => Ok(reply_json(&res.data, res.status)),
-        Err(err) => Ok(reply_error(err)),
+    let Ok(version) = if_match.as_deref().map(parse_if_match).transpose() else {
+        let err = Error {
+            msg: Some("Invalid If-Match header".to_string()),
+            status: StatusCode::BAD_REQUEST,
+            details: None::<UpdateError>,
+        };
+        return Ok(reply_error(err).into_response());
+    };
+    let parent = req.parent.map(|id| id.to_string());
+    match api
+        .update_area_of_life(&id, req.name, parent.as_deref(), version.flatten())
+        .await
+    {
+        Ok(res) => {
+            let reply = reply_json(&res.data, res.status);
+            Ok(match &res.data {
+                Some(data) => with_etag(reply, data.version),
+                None => reply.into_response(),
+            })
+        }
+        Err(err) => Ok(reply_error(err).into_response()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{handle, Request};
+    use crate::tests::{app_api, blank_db, response_json_body};
+    use cawr_adapter::model::view::json::{area_of_life::update as uc, Error};
+    use warp::{
+        http::{header::ETAG, StatusCode},
+        Reply,
+    };
+
+    #[tokio::test]
+    async fn reject_cycles() {
+        let db = blank_db();
+        let api = app_api(db);
+        api.create_area_of_life("Career", None).await.unwrap();
+        api.create_area_of_life("Project X", Some("1"))
+            .await
+            .unwrap();
+
+        let req = Request {
+            id: 1.into(),
+            name: "Career".to_string(),
+            parent: Some(2.into()),
+        };
+        let res = handle("1".to_string(), None, req, api)
+            .await
+            .unwrap()
+            .into_response();
+
+        assert_eq!(res.status(), StatusCode::CONFLICT);
+
+        let err: Error<uc::Error> = response_json_body(res).await.unwrap();
+
+        assert!(matches!(err.details.unwrap(), uc::Error::Cycle(id) if id.0 == 1));
+    }
+
+    #[tokio::test]
+    async fn reject_stale_if_match() {
+        let db = blank_db();
+        let api = app_api(db);
+        api.create_area_of_life("Career", None).await.unwrap();
+
+        let req = |name: &str| Request {
+            id: 1.into(),
+            name: name.to_string(),
+            parent: None,
+        };
+        let if_match = Some("\"1\"".to_string());
+        let res = handle(
+            "1".to_string(),
+            if_match.clone(),
+            req("Job and career"),
+            api.clone(),
+        )
+        .await
+        .unwrap()
+        .into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers()[ETAG], "\"2\"");
+
+        let res = handle("1".to_string(), if_match, req("Profession"), api)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::PRECONDITION_FAILED);
+        let err: Error<uc::Error> = response_json_body(res).await.unwrap();
+        assert!(matches!(err.details.unwrap(), uc::Error::Conflict));
+    }
+
+    #[tokio::test]
+    async fn reject_invalid_if_match() {
+        let db = blank_db();
+        let api = app_api(db);
+        api.create_area_of_life("Career", None).await.unwrap();
+
+        let req = Request {
+            id: 1.into(),
+            name: "Profession".to_string(),
+            parent: None,
+        };
+        let if_match = Some("1".to_string());
+        let res = handle("1".to_string(), if_match, req, api)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
     }
 }
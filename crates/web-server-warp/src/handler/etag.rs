@@ -0,0 +1,69 @@
+//! Entity versions as HTTP entity tags.
+
+use warp::{
+    http::{
+        header::{HeaderValue, ETAG},
+        StatusCode,
+    },
+    reply::Response,
+    Reply,
+};
+
+/// The `If-Match` header didn't contain a version.
+#[derive(Debug)]
+pub struct InvalidEntityTag;
+
+/// Add the version as a strong `ETag` to the reply.
+pub fn with_etag(reply: impl Reply, version: u64) -> Response {
+    match HeaderValue::try_from(format!("\"{version}\"")) {
+        Ok(value) => {
+            let mut res = reply.into_response();
+            res.headers_mut().insert(ETAG, value);
+            res
+        }
+        Err(err) => {
+            log::error!("Unable to build entity tag of version {}: {}", version, err);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// The version an `If-Match` header requires.
+///
+/// `None` means that any version matches (`*`).
+/// Weak tags are accepted as well because
+/// a version always identifies the whole entity.
+pub fn parse_if_match(value: &str) -> Result<Option<u64>, InvalidEntityTag> {
+    let value = value.trim();
+    if value == "*" {
+        return Ok(None);
+    }
+    let tag = value.strip_prefix("W/").unwrap_or(value);
+    tag.strip_prefix('"')
+        .and_then(|tag| tag.strip_suffix('"'))
+        .and_then(|version| version.parse().ok())
+        .map(Some)
+        .ok_or(InvalidEntityTag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_if_match, with_etag};
+    use warp::http::{header::ETAG, StatusCode};
+
+    #[test]
+    fn add_entity_tag() {
+        let res = with_etag(StatusCode::OK, 42);
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers()[ETAG], "\"42\"");
+    }
+
+    #[test]
+    fn parse_entity_tags() {
+        assert_eq!(parse_if_match("\"3\"").unwrap(), Some(3));
+        assert_eq!(parse_if_match(" W/\"12\"").unwrap(), Some(12));
+        assert_eq!(parse_if_match("*").unwrap(), None);
+        assert!(parse_if_match("3").is_err());
+        assert!(parse_if_match("\"3\", \"4\"").is_err());
+    }
+}
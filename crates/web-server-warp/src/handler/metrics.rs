@@ -0,0 +1,63 @@
+use crate::{handler::Result, AppApi};
+use cawr_adapter::db::Db;
+use warp::{http::header::CONTENT_TYPE, reply, Reply};
+
+/// Content type of the Prometheus text exposition format.
+const TEXT_FORMAT: &str = "text/plain; version=0.0.4";
+
+pub async fn handle<D>(api: AppApi<D>) -> Result<impl Reply>
+where
+    D: Db,
+{
+    let body = api.metrics().render();
+    Ok(reply::with_header(body, CONTENT_TYPE, TEXT_FORMAT))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::handle;
+    use crate::tests::{app_api, blank_db};
+    use warp::{
+        http::{header::CONTENT_TYPE, StatusCode},
+        Reply,
+    };
+
+    #[tokio::test]
+    async fn count_usecase_calls_by_outcome() {
+        let db = blank_db();
+        let api = app_api(db);
+        api.create_tag("work").await.unwrap();
+        api.find_thought("1").await.unwrap_err();
+        api.find_thought("x").await.unwrap_err();
+        api.find_thought("2").await.unwrap_err();
+
+        let res = handle(api).await.unwrap().into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers()[CONTENT_TYPE], "text/plain; version=0.0.4");
+        let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        let lines = body.lines().collect::<Vec<_>>();
+        assert!(lines.contains(&"# TYPE cawr_usecase_calls_total counter"));
+        assert!(lines.contains(&r#"cawr_usecase_calls_total{usecase="create_tag",outcome="ok"} 1"#));
+        assert!(lines
+            .contains(&r#"cawr_usecase_calls_total{usecase="find_thought",outcome="NotFound"} 2"#));
+        assert!(
+            lines.contains(&r#"cawr_usecase_calls_total{usecase="find_thought",outcome="Id"} 1"#)
+        );
+        assert!(lines.contains(&"# TYPE cawr_usecase_duration_seconds histogram"));
+        assert!(lines.contains(
+            &r#"cawr_usecase_duration_seconds_bucket{usecase="find_thought",le="+Inf"} 3"#
+        ));
+        assert!(lines.contains(&r#"cawr_usecase_duration_seconds_count{usecase="find_thought"} 3"#));
+    }
+
+    #[tokio::test]
+    async fn no_calls_yet() {
+        let api = app_api(blank_db());
+
+        let res = handle(api).await.unwrap().into_response();
+        let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(!body.contains("usecase="));
+    }
+}
@@ -1,5 +1,14 @@
 mod error;
-use self::error::{reply_error, reply_json, Result};
+mod etag;
+use self::{
+    error::{reply_error, reply_json, Result},
+    etag::{parse_if_match, with_etag},
+};
 
 pub mod area_of_life;
+pub mod metrics;
+pub mod relation;
+pub mod revision;
+pub mod tag;
 pub mod thought;
+pub mod trash;
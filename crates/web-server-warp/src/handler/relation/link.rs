@@ -0,0 +1,52 @@
+use crate::{
+    handler::{reply_error, reply_json, Result},
+    AppApi,
+};
+use cawr_adapter::{db::Db, model::view::json::relation::link::Request};
+use warp::Reply;
+
+pub async fn handle<D>(id: String, req: Request, api: AppApi<D>) -> Result<impl Reply>
+where
+    D: Db,
+{
+    let kind = req.kind.to_string();
+    let target = req.target.to_string();
+    match api.link_thoughts(&id, &kind, &target).await {
+        Ok(res) => Ok(reply_json(&res.data, res.status)),
+        Err(err) => Ok(reply_error(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::handle;
+    use crate::tests::{add_thought_to_db, app_api, blank_db, response_json_body};
+    use cawr_adapter::model::view::json::{
+        relation::{link as uc, RelationKind},
+        thought::ThoughtId,
+        Error,
+    };
+    use warp::{http::StatusCode, Reply};
+
+    #[tokio::test]
+    async fn reject_self_links() {
+        let db = blank_db();
+        add_thought_to_db(&db, "foo").await;
+        let api = app_api(db);
+        let req = super::Request {
+            kind: RelationKind::RelatesTo,
+            target: ThoughtId(1),
+        };
+
+        let res = handle("1".to_string(), req, api)
+            .await
+            .unwrap()
+            .into_response();
+
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+        let err: Error<uc::Error> = response_json_body(res).await.unwrap();
+
+        assert!(matches!(err.details.unwrap(), uc::Error::SelfLink));
+    }
+}
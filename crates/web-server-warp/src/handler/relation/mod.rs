@@ -0,0 +1,3 @@
+pub mod link;
+pub mod read_links;
+pub mod unlink;
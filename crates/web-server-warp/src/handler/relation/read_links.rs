@@ -0,0 +1,65 @@
+use crate::{
+    handler::{reply_error, reply_json, Result},
+    AppApi,
+};
+use cawr_adapter::db::Db;
+use warp::Reply;
+
+pub type Request = String;
+
+pub async fn handle<D>(req: Request, api: AppApi<D>) -> Result<impl Reply>
+where
+    D: Db,
+{
+    match api.read_thought_links(&req).await {
+        Ok(res) => Ok(reply_json(&res.data, res.status)),
+        Err(err) => Ok(reply_error(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::handle;
+    use crate::tests::{add_thought_to_db, app_api, blank_db, response_json_body};
+    use cawr_adapter::model::view::json::relation::{RelationKind, ThoughtLinks};
+    use warp::{http::StatusCode, Reply};
+
+    #[tokio::test]
+    async fn read_outgoing_links_and_backlinks() {
+        let db = blank_db();
+        add_thought_to_db(&db, "foo").await;
+        add_thought_to_db(&db, "bar").await;
+        add_thought_to_db(&db, "baz").await;
+        let api = app_api(db);
+        api.link_thoughts("1", "depends-on", "2").await.unwrap();
+        api.link_thoughts("3", "supersedes", "1").await.unwrap();
+
+        let res = handle("1".to_string(), api).await.unwrap().into_response();
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let links: ThoughtLinks = response_json_body(res).await.unwrap();
+
+        assert_eq!(links.outgoing.len(), 1);
+        assert_eq!(links.outgoing[0].kind, RelationKind::DependsOn);
+        assert_eq!(links.outgoing[0].thought.0, 2);
+        assert_eq!(links.backlinks.len(), 1);
+        assert_eq!(links.backlinks[0].kind, RelationKind::Supersedes);
+        assert_eq!(links.backlinks[0].thought.0, 3);
+    }
+
+    #[tokio::test]
+    async fn remove_dangling_links_on_delete() {
+        let db = blank_db();
+        add_thought_to_db(&db, "foo").await;
+        add_thought_to_db(&db, "bar").await;
+        let api = app_api(db);
+        api.link_thoughts("1", "relates-to", "2").await.unwrap();
+        api.delete_thought("1").await.unwrap();
+
+        let res = handle("2".to_string(), api).await.unwrap().into_response();
+        let links: ThoughtLinks = response_json_body(res).await.unwrap();
+
+        assert!(links.backlinks.is_empty());
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{
+    handler::{reply_error, reply_json, Result},
+    AppApi,
+};
+use cawr_adapter::db::Db;
+use warp::Reply;
+
+pub async fn handle<D>(
+    id: String,
+    kind: String,
+    target: String,
+    api: AppApi<D>,
+) -> Result<impl Reply>
+where
+    D: Db,
+{
+    match api.unlink_thoughts(&id, &kind, &target).await {
+        Ok(res) => Ok(reply_json(&res.data, res.status)),
+        Err(err) => Ok(reply_error(err)),
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{
+    handler::{reply_error, reply_json, Result},
+    AppApi,
+};
+use cawr_adapter::{db::Db, model::view::json::revision::diff::Request};
+use warp::Reply;
+
+pub async fn handle<D>(id: String, req: Request, api: AppApi<D>) -> Result<impl Reply>
+where
+    D: Db,
+{
+    match api.diff_thought_revisions(&id, req.from, req.to).await {
+        Ok(res) => Ok(reply_json(&res.data, res.status)),
+        Err(err) => Ok(reply_error(err)),
+    }
+}
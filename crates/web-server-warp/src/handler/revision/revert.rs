@@ -0,0 +1,94 @@
+use crate::{
+    handler::{reply_error, reply_json, Result},
+    AppApi,
+};
+use cawr_adapter::db::Db;
+use warp::Reply;
+
+pub async fn handle<D>(id: String, version: u64, api: AppApi<D>) -> Result<impl Reply>
+where
+    D: Db,
+{
+    match api.revert_thought(&id, version).await {
+        Ok(res) => Ok(reply_json(&res.data, res.status)),
+        Err(err) => Ok(reply_error(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::handle;
+    use crate::{
+        handler::revision::{diff, read_all},
+        tests::{app_api, blank_db, response_json_body},
+    };
+    use cawr_adapter::model::{
+        app::thought::Schedule,
+        view::json::{
+            revision::{diff::Request, revert::Response, ThoughtChange, ThoughtRevision},
+            thought::Thought,
+        },
+    };
+    use std::collections::HashSet;
+    use warp::{http::StatusCode, Reply};
+
+    #[tokio::test]
+    async fn list_diff_and_revert_revisions() {
+        let app_api = app_api(blank_db());
+        let none = HashSet::new();
+        let id = app_api
+            .create_thought("foo", None, &none, &none, Schedule::default())
+            .await
+            .unwrap()
+            .data
+            .unwrap()
+            .to_string();
+        app_api
+            .update_thought(&id, "bar", None, &none, &none, Schedule::default(), None)
+            .await
+            .unwrap();
+
+        let res = read_all::handle(id.clone(), app_api.clone())
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        let revisions: Vec<ThoughtRevision> = response_json_body(res).await.unwrap();
+        let titles = revisions
+            .iter()
+            .map(|r| (r.version, r.title.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(titles, vec![(1, "foo"), (2, "bar")]);
+
+        let req = Request { from: 1, to: 2 };
+        let res = diff::handle(id.clone(), req, app_api.clone())
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        let changes: Vec<ThoughtChange> = response_json_body(res).await.unwrap();
+        assert_eq!(
+            changes,
+            vec![ThoughtChange::Title {
+                old: "foo".to_string(),
+                new: "bar".to_string()
+            }]
+        );
+
+        let res = handle(id.clone(), 7, app_api.clone())
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+        let res = handle(id.clone(), 1, app_api.clone())
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        let Response { version } = response_json_body(res).await.unwrap();
+        assert_eq!(version, 3);
+        let thought: Thought = app_api.find_thought(&id).await.unwrap().data.unwrap();
+        assert_eq!(thought.title, "foo");
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    handler::{reply_error, reply_json, Result},
+    AppApi,
+};
+use cawr_adapter::{
+    db::Db,
+    model::{
+        app::thought::batch::Operation,
+        view::json::thought::batch::{Operation as JsonOperation, Request},
+    },
+};
+use warp::Reply;
+
+pub async fn handle<D>(req: Request, api: AppApi<D>) -> Result<impl Reply>
+where
+    D: Db,
+{
+    let ids = req
+        .ids
+        .into_iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>();
+    let operation = match req.operation {
+        JsonOperation::Delete => Operation::Delete,
+        JsonOperation::AssignAreaOfLife { area_of_life } => {
+            Operation::AssignAreaOfLife(area_of_life.to_string())
+        }
+        JsonOperation::UnassignAreaOfLife { area_of_life } => {
+            Operation::UnassignAreaOfLife(area_of_life.to_string())
+        }
+        JsonOperation::ReplaceInTitle { find, replace } => {
+            Operation::ReplaceInTitle { find, replace }
+        }
+    };
+    match api.batch_thoughts(&ids, operation).await {
+        Ok(res) => Ok(reply_json(&res.data, res.status)),
+        Err(err) => Ok(reply_error(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{handle, Request};
+    use crate::tests::{add_thought_to_db, app_api, blank_db, response_json_body};
+    use cawr_adapter::model::view::json::{
+        thought::{
+            batch::{Error, Operation, Outcome, Response},
+            ThoughtId,
+        },
+        Error as JsonError,
+    };
+    use cawr_application::gateway::repository::thought::Repo;
+    use warp::{http::StatusCode, Reply};
+
+    #[tokio::test]
+    async fn replace_in_titles() {
+        let db = blank_db();
+        add_thought_to_db(&db, "foo").await;
+        add_thought_to_db(&db, "bar").await;
+        let app_api = app_api(db.clone());
+        let req = Request {
+            ids: vec![ThoughtId(1), ThoughtId(2), ThoughtId(3)],
+            operation: Operation::ReplaceInTitle {
+                find: "o".to_string(),
+                replace: "0".to_string(),
+            },
+        };
+        let res = handle(req, app_api).await.unwrap().into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        let body: Response = response_json_body(res).await.unwrap();
+        let outcomes = body
+            .reports
+            .iter()
+            .map(|report| report.outcome)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            vec![Outcome::Changed, Outcome::Unchanged, Outcome::Failed]
+        );
+        let rec = db.get(cawr_domain::thought::Id::new(1)).await.unwrap();
+        assert_eq!(rec.thought.title().as_ref(), "f00");
+    }
+
+    #[tokio::test]
+    async fn reject_empty_find() {
+        let db = blank_db();
+        add_thought_to_db(&db, "foo").await;
+        let app_api = app_api(db);
+        let req = Request {
+            ids: vec![ThoughtId(1)],
+            operation: Operation::ReplaceInTitle {
+                find: String::new(),
+                replace: "bar".to_string(),
+            },
+        };
+        let res = handle(req, app_api).await.unwrap().into_response();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+        let err: JsonError<Error> = response_json_body(res).await.unwrap();
+        assert!(matches!(err.details, Some(Error::EmptyFind)));
+    }
+}
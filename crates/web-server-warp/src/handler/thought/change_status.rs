@@ -0,0 +1,87 @@
+use crate::{
+    handler::{reply_error, reply_json, Result},
+    AppApi,
+};
+use cawr_adapter::{db::Db, model::view::json::thought::change_status::Request};
+use warp::Reply;
+
+pub async fn handle<D>(id: String, req: Request, api: AppApi<D>) -> Result<impl Reply>
+where
+    D: Db,
+{
+    match api
+        .change_thought_status(&id, &req.status.to_string())
+        .await
+    {
+        Ok(res) => Ok(reply_json(&res.data, res.status)),
+        Err(err) => Ok(reply_error(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{handle, Request};
+    use crate::tests::{add_thought_to_db, app_api, blank_db, response_json_body};
+    use cawr_adapter::model::view::json::{
+        thought::{change_status as uc, ThoughtStatus},
+        Error,
+    };
+    use cawr_application::gateway::repository::thought::Repo;
+    use cawr_domain::thought::Status;
+    use warp::{http::StatusCode, Reply};
+
+    #[tokio::test]
+    async fn change_status() {
+        let db = blank_db();
+        add_thought_to_db(&db, "foo").await;
+        let api = app_api(db.clone());
+        let req = Request {
+            status: ThoughtStatus::Done,
+        };
+        let res = handle("1".to_string(), req, api)
+            .await
+            .unwrap()
+            .into_response();
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let record = db.as_ref().get(1.into()).await.unwrap();
+        assert_eq!(record.thought.status(), Status::Done);
+    }
+
+    #[tokio::test]
+    async fn reject_invalid_transition() {
+        let db = blank_db();
+        add_thought_to_db(&db, "foo").await;
+        let api = app_api(db.clone());
+        let req = Request {
+            status: ThoughtStatus::Archived,
+        };
+        let res = handle("1".to_string(), req, api.clone())
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let req = Request {
+            status: ThoughtStatus::Active,
+        };
+        let res = handle("1".to_string(), req, api)
+            .await
+            .unwrap()
+            .into_response();
+
+        assert_eq!(res.status(), StatusCode::CONFLICT);
+
+        let err: Error<uc::Error> = response_json_body(res).await.unwrap();
+        assert!(matches!(
+            err.details.unwrap(),
+            uc::Error::InvalidTransition {
+                from: ThoughtStatus::Archived,
+                to: ThoughtStatus::Active
+            }
+        ));
+        let record = db.as_ref().get(1.into()).await.unwrap();
+        assert_eq!(record.thought.status(), Status::Archived);
+    }
+}
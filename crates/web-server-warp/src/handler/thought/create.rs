@@ -2,7 +2,11 @@ use crate::{
     handler::{reply_error, reply_json, Result},
     AppApi,
 };
-use cawr_adapter::{db::Db, model::view::json::thought::create::Request};
+use cawr_adapter::{
+    db::Db,
+    model::{app::thought::Schedule, view::json::thought::create::Request},
+};
+use std::time::Duration;
 use warp::Reply;
 
 pub async fn handle<D>(req: Request, api: AppApi<D>) -> Result<impl Reply>
@@ -12,9 +16,17 @@ where
     let areas_of_life = req
         .areas_of_life
         .into_iter()
-        .map(|id| id.0.to_string())
+        .map(|id| id.to_string())
         .collect();
-    match api.create_thought(req.title, &areas_of_life) {
+    let tags = req.tags.into_iter().map(|id| id.to_string()).collect();
+    let schedule = Schedule {
+        due_at: req.due_at,
+        reminder_offset: req.reminder_offset.map(Duration::from_millis),
+    };
+    match api
+        .create_thought(req.title, req.body, &areas_of_life, &tags, schedule)
+        .await
+    {
         Ok(res) => Ok(reply_json(&res.data, res.status)),
         Err(err) => Ok(reply_error(err)),
     }
@@ -23,10 +35,18 @@ where
 #[cfg(test)]
 mod tests {
     use super::{handle, Request};
-    use crate::tests::{app_api, blank_db, response_json_body};
-    use cawr_adapter::model::view::json::{self as json, thought::create as uc, Error};
+    use crate::{
+        handler::thought::find_by_id,
+        tests::{app_api, blank_db, response_json_body, FixedClock},
+    };
+    use cawr_adapter::{
+        identifier::IdStrategy,
+        model::view::json::{self as json, thought::create as uc, Error},
+    };
     use cawr_application::gateway::repository::thought::Repo;
+    use cawr_domain::Timestamp;
     use serde_json::Value;
+    use std::sync::Arc;
     use warp::{http::StatusCode, Reply};
 
     #[tokio::test]
@@ -35,7 +55,11 @@ mod tests {
         let app_api = app_api(db.clone());
         let req = Request {
             title: "test 1".to_string(),
+            body: None,
             areas_of_life: vec![],
+            tags: vec![],
+            due_at: None,
+            reminder_offset: None,
         };
         let res = handle(req, app_api).await.unwrap().into_response();
 
@@ -43,18 +67,77 @@ mod tests {
 
         let body: Value = response_json_body(res).await.unwrap();
         let id = body.as_u64().unwrap();
-        let record = db.as_ref().get(id.into()).unwrap();
+        let record = db.as_ref().get(id.into()).await.unwrap();
 
         assert_eq!(record.thought.title().as_ref(), "test 1");
     }
 
+    #[tokio::test]
+    async fn create_with_fixed_clock() {
+        let db = blank_db();
+        let now = Timestamp::from_unix_millis(1_700_000_000_000);
+        let app_api = app_api(db.clone()).with_clock(Arc::new(FixedClock(now)));
+        let req = Request {
+            title: "test 1".to_string(),
+            body: None,
+            areas_of_life: vec![],
+            tags: vec![],
+            due_at: None,
+            reminder_offset: None,
+        };
+        let res = handle(req, app_api).await.unwrap().into_response();
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let body: Value = response_json_body(res).await.unwrap();
+        let id = body.as_u64().unwrap();
+        let record = db.as_ref().get(id.into()).await.unwrap();
+
+        assert_eq!(record.thought.created_at(), now);
+        assert_eq!(record.thought.updated_at(), now);
+    }
+
+    #[tokio::test]
+    async fn create_with_uuid_ids() {
+        let db = blank_db();
+        let app_api = app_api(db).with_id_strategy(IdStrategy::UuidV7);
+        let req = Request {
+            title: "test 1".to_string(),
+            body: None,
+            areas_of_life: vec![],
+            tags: vec![],
+            due_at: None,
+            reminder_offset: None,
+        };
+        let res = handle(req, app_api.clone()).await.unwrap().into_response();
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+
+        let id: json::thought::ThoughtId = response_json_body(res).await.unwrap();
+        let id = id.to_string();
+        assert_eq!(id.len(), 36);
+        assert_eq!(&id[14..15], "7");
+
+        let res = find_by_id::handle(id.clone(), app_api)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        let body: Value = response_json_body(res).await.unwrap();
+        assert_eq!(body["id"].as_str().unwrap(), id);
+    }
+
     #[tokio::test]
     async fn create_with_too_short_title() {
         let db = blank_db();
         let app_api = app_api(db);
         let req = Request {
             title: "t".to_string(),
+            body: None,
             areas_of_life: vec![],
+            tags: vec![],
+            due_at: None,
+            reminder_offset: None,
         };
         let res = handle(req, app_api).await.unwrap().into_response();
 
@@ -79,7 +162,11 @@ mod tests {
         let app_api = app_api(db);
         let req = Request {
             title: ["t"; 100].join(""),
+            body: None,
             areas_of_life: vec![],
+            tags: vec![],
+            due_at: None,
+            reminder_offset: None,
         };
         let res = handle(req, app_api).await.unwrap().into_response();
 
@@ -100,4 +187,52 @@ mod tests {
             }
         ));
     }
+
+    #[tokio::test]
+    async fn create_with_unknown_tag() {
+        let db = blank_db();
+        let app_api = app_api(db);
+        let req = Request {
+            title: "test 1".to_string(),
+            body: None,
+            areas_of_life: vec![],
+            tags: vec![json::tag::TagId(42)],
+            due_at: None,
+            reminder_offset: None,
+        };
+        let res = handle(req, app_api).await.unwrap().into_response();
+
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+        let err: Error<uc::Error> = response_json_body(res).await.unwrap();
+
+        assert!(matches!(
+            err.details.unwrap(),
+            uc::Error::TagsNotFound(ids) if ids == vec![json::tag::TagId(42)]
+        ));
+    }
+
+    #[tokio::test]
+    async fn create_with_reminder_but_without_due_date() {
+        let db = blank_db();
+        let app_api = app_api(db);
+        let req = Request {
+            title: "test 1".to_string(),
+            body: None,
+            areas_of_life: vec![],
+            tags: vec![],
+            due_at: None,
+            reminder_offset: Some(60_000),
+        };
+        let res = handle(req, app_api).await.unwrap().into_response();
+
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+        let err: Error<uc::Error> = response_json_body(res).await.unwrap();
+
+        assert!(matches!(
+            err.details.unwrap(),
+            uc::Error::ReminderWithoutDueDate
+        ));
+    }
 }
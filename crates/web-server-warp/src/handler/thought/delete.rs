@@ -11,7 +11,7 @@ pub async fn handle<D>(req: Request, api: AppApi<D>) -> Result<impl Reply>
 where
     D: Db,
 {
-    match api.delete_thought(&req) {
+    match api.delete_thought(&req).await {
         Ok(res) => Ok(reply_json(&res.data, res.status)),
         Err(err) => Ok(reply_error(err)),
     }
@@ -23,23 +23,54 @@ mod tests {
     use crate::tests::{add_thought_to_db, app_api, blank_db};
     use cawr_adapter::model::app::thought as app;
     use cawr_application::gateway::repository::thought::Repo;
+    use cawr_domain::Event;
+    use std::sync::{Arc, Mutex};
     use warp::{http::StatusCode, Reply};
 
     #[tokio::test]
     async fn delete() {
         let db = blank_db();
-        add_thought_to_db(&db, "foo");
-        add_thought_to_db(&db, "bar");
+        add_thought_to_db(&db, "foo").await;
+        add_thought_to_db(&db, "bar").await;
 
         let id = "2".parse::<app::Id>().unwrap().into();
 
-        assert!(db.get(id).is_ok());
+        assert!(db.get(id).await.is_ok());
 
         let app_api = app_api(db.clone());
         let req = id.to_string();
         let res = handle(req, app_api).await.unwrap().into_response();
 
         assert_eq!(res.status(), StatusCode::OK);
-        assert!(db.get(id).is_err());
+        assert!(db.get(id).await.unwrap().thought.is_deleted());
+    }
+
+    #[tokio::test]
+    async fn publish_deleted_event() {
+        let db = blank_db();
+        add_thought_to_db(&db, "foo").await;
+        let id: cawr_domain::thought::Id = "1".parse::<app::Id>().unwrap().into();
+
+        let app_api = app_api(db);
+        let events = Arc::new(Mutex::new(vec![]));
+        let received = Arc::clone(&events);
+        app_api
+            .events()
+            .subscribe(move |event| received.lock().unwrap().push(*event));
+
+        let res = handle(id.to_string(), app_api.clone())
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(*events.lock().unwrap(), vec![Event::ThoughtDeleted(id)]);
+
+        // Failed usecases don't publish anything
+        let res = handle(id.to_string(), app_api)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+        assert_eq!(events.lock().unwrap().len(), 1);
     }
 }
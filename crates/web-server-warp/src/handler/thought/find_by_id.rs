@@ -1,5 +1,5 @@
 use crate::{
-    handler::{reply_error, reply_json, Result},
+    handler::{reply_error, reply_json, with_etag, Result},
     AppApi,
 };
 use cawr_adapter::db::Db;
@@ -11,9 +11,15 @@ pub async fn handle<D>(req: Request, api: AppApi<D>) -> Result<impl Reply>
 where
     D: Db,
 {
-    match api.find_thought(&req) {
-        Ok(res) => Ok(reply_json(&res.data, res.status)),
-        Err(err) => Ok(reply_error(err)),
+    match api.find_thought(&req).await {
+        Ok(res) => {
+            let reply = reply_json(&res.data, res.status);
+            Ok(match &res.data {
+                Some(thought) => with_etag(reply, thought.version),
+                None => reply.into_response(),
+            })
+        }
+        Err(err) => Ok(reply_error(err).into_response()),
     }
 }
 
@@ -28,8 +34,8 @@ mod tests {
     #[tokio::test]
     async fn read() {
         let db = blank_db();
-        add_thought_to_db(&db, "foo");
-        add_thought_to_db(&db, "bar");
+        add_thought_to_db(&db, "foo").await;
+        add_thought_to_db(&db, "bar").await;
 
         let app_api = app_api(db.clone());
         let req = "2".to_string();
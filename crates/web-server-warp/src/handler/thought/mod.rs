@@ -1,5 +1,8 @@
+pub mod batch;
+pub mod change_status;
 pub mod create;
 pub mod delete;
 pub mod find_by_id;
 pub mod read_all;
+pub mod search;
 pub mod update;
@@ -2,14 +2,25 @@ use crate::{
     handler::{reply_error, reply_json, Result},
     AppApi,
 };
-use cawr_adapter::db::Db;
+use cawr_adapter::{
+    db::Db,
+    model::{app::thought::read_all::Params, view::json::thought::read_all::Request},
+};
 use warp::Reply;
 
-pub async fn handle<D>(api: AppApi<D>) -> Result<impl Reply>
+pub async fn handle<D>(req: Request, api: AppApi<D>) -> Result<impl Reply>
 where
     D: Db,
 {
-    match api.read_all_thoughts() {
+    let params = Params {
+        status: req.status.map(|s| s.to_string()),
+        area_of_life: req.area_of_life.map(|id| id.to_string()),
+        sort: req.sort.map(|key| key.to_string()),
+        direction: req.direction.map(|d| d.to_string()),
+        offset: req.offset,
+        limit: req.limit,
+    };
+    match api.read_all_thoughts(&params).await {
         Ok(res) => Ok(reply_json(&res.data, res.status)),
         Err(err) => Ok(reply_error(err)),
     }
@@ -19,28 +30,103 @@ where
 mod tests {
     use super::*;
     use crate::tests::{add_thought_to_db, app_api, blank_db, response_json_body};
+    use cawr_adapter::model::view::json::{
+        thought::{read_all::SortKey, ThoughtStatus},
+        SortDirection,
+    };
     use serde_json::Value;
     use warp::{http::StatusCode, Reply};
 
     #[tokio::test]
     async fn read_all() {
         let db = blank_db();
-        add_thought_to_db(&db, "foo");
-        add_thought_to_db(&db, "bar");
+        add_thought_to_db(&db, "foo").await;
+        add_thought_to_db(&db, "bar").await;
 
         let api = app_api(db.clone());
-        let res = handle(api).await.unwrap().into_response();
+        let res = handle(Request::default(), api)
+            .await
+            .unwrap()
+            .into_response();
 
         assert_eq!(res.status(), StatusCode::OK);
 
         let body: Value = response_json_body(res).await.unwrap();
-        let thoughts = body.as_array().unwrap();
+        let thoughts = body["thoughts"].as_array().unwrap();
 
         assert_eq!(thoughts.len(), 2);
+        assert_eq!(body["total"], 2);
+        assert!(body.get("next_offset").is_none());
 
         let t = thoughts[0].as_object().unwrap();
 
         assert!(t.get("title").unwrap().is_string());
         assert!(t.get("id").unwrap().is_number());
     }
+
+    #[tokio::test]
+    async fn read_all_with_status() {
+        let db = blank_db();
+        add_thought_to_db(&db, "foo").await;
+        add_thought_to_db(&db, "bar").await;
+
+        let api = app_api(db.clone());
+        let req = Request {
+            status: Some(ThoughtStatus::Inbox),
+            ..Default::default()
+        };
+        let res = handle(req, api.clone()).await.unwrap().into_response();
+        let body: Value = response_json_body(res).await.unwrap();
+        assert_eq!(body["thoughts"].as_array().unwrap().len(), 2);
+
+        let req = Request {
+            status: Some(ThoughtStatus::Done),
+            ..Default::default()
+        };
+        let res = handle(req, api).await.unwrap().into_response();
+        let body: Value = response_json_body(res).await.unwrap();
+        assert!(body["thoughts"].as_array().unwrap().is_empty());
+        assert_eq!(body["total"], 0);
+    }
+
+    #[tokio::test]
+    async fn read_sorted_pages() {
+        let db = blank_db();
+        for title in ["b", "d", "a", "c"] {
+            add_thought_to_db(&db, title).await;
+        }
+
+        let api = app_api(db.clone());
+        let titles = |body: &Value| {
+            body["thoughts"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|t| t["title"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+        let req = Request {
+            sort: Some(SortKey::Title),
+            direction: Some(SortDirection::Desc),
+            limit: Some(3),
+            ..Default::default()
+        };
+        let res = handle(req, api.clone()).await.unwrap().into_response();
+        let body: Value = response_json_body(res).await.unwrap();
+        assert_eq!(titles(&body), ["d", "c", "b"]);
+        assert_eq!(body["total"], 4);
+        assert_eq!(body["next_offset"], 3);
+
+        let req = Request {
+            sort: Some(SortKey::Title),
+            direction: Some(SortDirection::Desc),
+            offset: Some(3),
+            limit: Some(3),
+            ..Default::default()
+        };
+        let res = handle(req, api).await.unwrap().into_response();
+        let body: Value = response_json_body(res).await.unwrap();
+        assert_eq!(titles(&body), ["a"]);
+        assert!(body.get("next_offset").is_none());
+    }
 }
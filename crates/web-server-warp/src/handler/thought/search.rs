@@ -0,0 +1,61 @@
+use crate::{
+    handler::{reply_error, reply_json, Result},
+    AppApi,
+};
+use cawr_adapter::{db::Db, model::view::json::thought::search::Request};
+use warp::Reply;
+
+pub async fn handle<D>(req: Request, api: AppApi<D>) -> Result<impl Reply>
+where
+    D: Db,
+{
+    match api.search_thoughts(req.q).await {
+        Ok(res) => Ok(reply_json(&res.data, res.status)),
+        Err(err) => Ok(reply_error(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{add_thought_to_db, app_api, blank_db, response_json_body};
+    use cawr_adapter::model::view::json::{thought::search as uc, Error};
+    use serde_json::Value;
+    use warp::{http::StatusCode, Reply};
+
+    #[tokio::test]
+    async fn search() {
+        let db = blank_db();
+        add_thought_to_db(&db, "Buy milk").await;
+        add_thought_to_db(&db, "Water the plants").await;
+
+        let api = app_api(db.clone());
+        let req = Request {
+            q: "milk".to_string(),
+        };
+        let res = handle(req, api).await.unwrap().into_response();
+
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let body: Value = response_json_body(res).await.unwrap();
+        let thoughts = body.as_array().unwrap();
+
+        assert_eq!(thoughts.len(), 1);
+        assert_eq!(thoughts[0]["title"], "Buy milk");
+    }
+
+    #[tokio::test]
+    async fn search_with_empty_query() {
+        let db = blank_db();
+        let api = app_api(db.clone());
+        let req = Request {
+            q: "  ".to_string(),
+        };
+        let res = handle(req, api).await.unwrap().into_response();
+
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+        let err: Error<uc::Error> = response_json_body(res).await.unwrap();
+        assert!(matches!(err.details.unwrap(), uc::Error::EmptyQuery));
+    }
+}
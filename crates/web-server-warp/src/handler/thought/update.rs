@@ -1,21 +1,164 @@
 use crate::{
-    handler::{reply_error, reply_json, Result},
+    handler::{parse_if_match, reply_error, reply_json, with_etag, Result},
     AppApi,
 };
-use cawr_adapter::{db::Db, model::view::json::thought::update::Request};
+use cawr_adapter::{
+    db::Db,
+    model::{
+        app::thought::Schedule,
+        view::json::{
+            thought::update::{Error as UpdateError, Request},
+            Error, StatusCode,
+        },
+    },
+};
+use std::time::Duration;
 use warp::Reply;
 
-pub async fn handle<D>(id: String, req: Request, api: AppApi<D>) -> Result<impl Reply>
+pub async fn handle<D>(
+    id: String,
+    if_match: Option<String>,
+    req: Request,
+    api: AppApi<D>,
+) -> Result<impl Reply>
 where
     D: Db,
 {
+    let Ok(version) = if_match.as_deref().map(parse_if_match).transpose() else {
+        let err = Error {
+            msg: Some("Invalid If-Match header".to_string()),
+            status: StatusCode::BAD_REQUEST,
+            details: None::<UpdateError>,
+        };
+        return Ok(reply_error(err).into_response());
+    };
     let areas_of_life = req
         .areas_of_life
         .into_iter()
-        .map(|id| id.0.to_string())
+        .map(|id| id.to_string())
         .collect();
-    match api.update_thought(&id, req.title, &areas_of_life) {
-        Ok(res) => Ok(reply_json(&res.data, res.status)),
-        Err(err) => Ok(reply_error(err)),
+    let tags = req.tags.into_iter().map(|id| id.to_string()).collect();
+    let schedule = Schedule {
+        due_at: req.due_at,
+        reminder_offset: req.reminder_offset.map(Duration::from_millis),
+    };
+    match api
+        .update_thought(
+            &id,
+            req.title,
+            req.body,
+            &areas_of_life,
+            &tags,
+            schedule,
+            version.flatten(),
+        )
+        .await
+    {
+        Ok(res) => {
+            let reply = reply_json(&res.data, res.status);
+            Ok(match &res.data {
+                Some(data) => with_etag(reply, data.version),
+                None => reply.into_response(),
+            })
+        }
+        Err(err) => Ok(reply_error(err).into_response()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{handle, Request};
+    use crate::{
+        handler::thought::find_by_id,
+        tests::{add_thought_to_db, app_api, blank_db, response_json_body},
+    };
+    use cawr_adapter::model::view::json::{thought::update as uc, Error};
+    use warp::{
+        http::{header::ETAG, StatusCode},
+        Reply,
+    };
+
+    fn request(title: &str) -> Request {
+        Request {
+            id: 1.into(),
+            title: title.to_string(),
+            body: None,
+            areas_of_life: vec![],
+            tags: vec![],
+            due_at: None,
+            reminder_offset: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn expose_versions_as_etag() {
+        let db = blank_db();
+        add_thought_to_db(&db, "foo").await;
+        let api = app_api(db);
+
+        let res = find_by_id::handle("1".to_string(), api.clone())
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.headers()[ETAG], "\"1\"");
+
+        let res = handle("1".to_string(), None, request("bar"), api.clone())
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers()[ETAG], "\"2\"");
+        let data: uc::Response = response_json_body(res).await.unwrap();
+        assert_eq!(data.version, 2);
+    }
+
+    #[tokio::test]
+    async fn reject_stale_if_match() {
+        let db = blank_db();
+        add_thought_to_db(&db, "foo").await;
+        let api = app_api(db);
+
+        // First tab
+        let if_match = Some("\"1\"".to_string());
+        let res = handle(
+            "1".to_string(),
+            if_match.clone(),
+            request("bar"),
+            api.clone(),
+        )
+        .await
+        .unwrap()
+        .into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // Second tab
+        let res = handle("1".to_string(), if_match, request("baz"), api.clone())
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::PRECONDITION_FAILED);
+        let err: Error<uc::Error> = response_json_body(res).await.unwrap();
+        assert!(matches!(err.details.unwrap(), uc::Error::Conflict));
+
+        let res = handle("1".to_string(), Some("*".to_string()), request("baz"), api)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers()[ETAG], "\"3\"");
+    }
+
+    #[tokio::test]
+    async fn reject_invalid_if_match() {
+        let db = blank_db();
+        add_thought_to_db(&db, "foo").await;
+        let api = app_api(db);
+
+        let if_match = Some("1".to_string());
+        let res = handle("1".to_string(), if_match, request("bar"), api)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
     }
 }
@@ -0,0 +1,4 @@
+pub mod purge;
+pub mod read_all;
+pub mod restore_area_of_life;
+pub mod restore_thought;
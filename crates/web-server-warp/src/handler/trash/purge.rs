@@ -0,0 +1,85 @@
+use crate::{
+    handler::{reply_error, reply_json, Result},
+    AppApi,
+};
+use cawr_adapter::{db::Db, model::view::json::trash::purge::Request};
+use std::time::Duration;
+use warp::Reply;
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+pub async fn handle<D>(req: Request, api: AppApi<D>) -> Result<impl Reply>
+where
+    D: Db,
+{
+    let retention = DAY.saturating_mul(u32::try_from(req.retention_days).unwrap_or(u32::MAX));
+    match api.purge_trash(retention).await {
+        Ok(res) => Ok(reply_json(&res.data, res.status)),
+        Err(err) => Ok(reply_error(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::handle;
+    use crate::{
+        handler::thought,
+        tests::{add_thought_to_db, app_api, blank_db, response_json_body},
+    };
+    use cawr_adapter::model::view::json::{
+        thought::ThoughtId,
+        trash::{purge::Request, TrashItem},
+    };
+    use cawr_application::gateway::repository::thought::Repo;
+    use cawr_domain::thought::Id;
+    use warp::{http::StatusCode, Reply};
+
+    #[tokio::test]
+    async fn purge_expired_entries() {
+        let db = blank_db();
+        add_thought_to_db(&db, "foo").await;
+        add_thought_to_db(&db, "bar").await;
+        let app_api = app_api(db.clone());
+        thought::delete::handle("1".to_string(), app_api.clone())
+            .await
+            .unwrap();
+
+        // Nothing has been in the trash for a week
+        let req = Request { retention_days: 7 };
+        let res = handle(req, app_api.clone()).await.unwrap().into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        let purged: Vec<TrashItem> = response_json_body(res).await.unwrap();
+        assert!(purged.is_empty());
+
+        let req = Request { retention_days: 0 };
+        let res = handle(req, app_api).await.unwrap().into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        let purged: Vec<TrashItem> = response_json_body(res).await.unwrap();
+        assert_eq!(purged, vec![TrashItem::Thought(ThoughtId(1))]);
+        assert!(db.get(Id::new(1)).await.is_err());
+        assert!(db.get(Id::new(2)).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn keep_recent_entries_by_default() {
+        let db = blank_db();
+        add_thought_to_db(&db, "foo").await;
+        let app_api = app_api(db.clone());
+        thought::delete::handle("1".to_string(), app_api.clone())
+            .await
+            .unwrap();
+
+        let req: Request = warp::test::request()
+            .method("DELETE")
+            .path("/")
+            .filter(&warp::query())
+            .await
+            .unwrap();
+        assert_eq!(req.retention_days, 30);
+        let res = handle(req, app_api).await.unwrap().into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        let purged: Vec<TrashItem> = response_json_body(res).await.unwrap();
+        assert!(purged.is_empty());
+        assert!(db.get(Id::new(1)).await.is_ok());
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    handler::{reply_error, reply_json, Result},
+    AppApi,
+};
+use cawr_adapter::db::Db;
+use warp::Reply;
+
+pub async fn handle<D>(api: AppApi<D>) -> Result<impl Reply>
+where
+    D: Db,
+{
+    match api.read_trash().await {
+        Ok(res) => Ok(reply_json(&res.data, res.status)),
+        Err(err) => Ok(reply_error(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::handle;
+    use crate::{
+        handler::{area_of_life, thought},
+        tests::{add_thought_to_db, app_api, blank_db, response_json_body},
+    };
+    use cawr_adapter::model::view::json::{
+        area_of_life::delete,
+        thought::ThoughtId,
+        trash::{TrashEntry, TrashItem},
+    };
+    use warp::{http::StatusCode, Reply};
+
+    #[tokio::test]
+    async fn read_deleted_thoughts_and_areas_of_life() {
+        let db = blank_db();
+        add_thought_to_db(&db, "foo").await;
+        add_thought_to_db(&db, "bar").await;
+        let app_api = app_api(db);
+        let aol_id = app_api
+            .create_area_of_life("health", None)
+            .await
+            .unwrap()
+            .data
+            .unwrap();
+
+        let res = handle(app_api.clone()).await.unwrap().into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        let entries: Vec<TrashEntry> = response_json_body(res).await.unwrap();
+        assert!(entries.is_empty());
+
+        thought::delete::handle("2".to_string(), app_api.clone())
+            .await
+            .unwrap();
+        area_of_life::delete::handle(
+            aol_id.to_string(),
+            delete::Request::default(),
+            app_api.clone(),
+        )
+        .await
+        .unwrap();
+
+        let res = handle(app_api).await.unwrap().into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        let entries: Vec<TrashEntry> = response_json_body(res).await.unwrap();
+        let mut items = entries
+            .into_iter()
+            .map(|e| (e.item, e.label))
+            .collect::<Vec<_>>();
+        items.sort_by_key(|(_, label)| label.clone());
+        assert_eq!(
+            items,
+            vec![
+                (TrashItem::Thought(ThoughtId(2)), "bar".to_string()),
+                (TrashItem::AreaOfLife(aol_id), "health".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    handler::{reply_error, reply_json, Result},
+    AppApi,
+};
+use cawr_adapter::db::Db;
+use warp::Reply;
+
+pub type Request = String;
+
+pub async fn handle<D>(req: Request, api: AppApi<D>) -> Result<impl Reply>
+where
+    D: Db,
+{
+    match api.restore_thought(&req).await {
+        Ok(res) => Ok(reply_json(&res.data, res.status)),
+        Err(err) => Ok(reply_error(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::handle;
+    use crate::{
+        handler::thought,
+        tests::{add_thought_to_db, app_api, blank_db},
+    };
+    use warp::{http::StatusCode, Reply};
+
+    #[tokio::test]
+    async fn restore_deleted_thought() {
+        let db = blank_db();
+        add_thought_to_db(&db, "foo").await;
+        let app_api = app_api(db);
+
+        thought::delete::handle("1".to_string(), app_api.clone())
+            .await
+            .unwrap();
+        let res = thought::find_by_id::handle("1".to_string(), app_api.clone())
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+        let res = handle("1".to_string(), app_api.clone())
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+        let res = thought::find_by_id::handle("1".to_string(), app_api.clone())
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::OK);
+
+        // The thought is not in the trash anymore
+        let res = handle("1".to_string(), app_api)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn reject_invalid_id() {
+        let app_api = app_api(blank_db());
+        let res = handle("x".to_string(), app_api)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -1,4 +1,4 @@
-use cawr_adapter::{api::Api, db::Db, presenter::http_json_api::Presenter};
+use cawr_adapter::{api::Api, db::Db, identifier::IdStrategy, presenter::http_json_api::Presenter};
 use std::{net::SocketAddr, sync::Arc};
 use warp::Filter;
 
@@ -10,12 +10,16 @@ mod webapp;
 
 type AppApi<D> = Api<D, Presenter>;
 
-pub async fn run<D>(db: Arc<D>, addr: SocketAddr)
+pub async fn run<D>(db: Arc<D>, ids: IdStrategy, addr: SocketAddr)
 where
     D: Db,
 {
-    let web_app_api = Api::new(db, Presenter);
+    let web_app_api = Api::new(db, Presenter).with_id_strategy(ids);
+    let metrics = route::metrics(web_app_api.clone());
     let api = route::api(web_app_api);
-    let routes = api.or(webapp::get_index()).or(webapp::get_assets());
+    let routes = api
+        .or(metrics)
+        .or(webapp::get_index())
+        .or(webapp::get_assets());
     warp::serve(routes).run(addr).await;
 }
@@ -14,17 +14,86 @@ where
         .and(with_app(app.clone()))
         .and_then(handler::thought::create::handle);
 
+    // POST /api/thought/batch
+    let post_thought_batch = warp::post()
+        .and(path!("batch"))
+        .and(path::end())
+        .and(body::json())
+        .and(with_app(app.clone()))
+        .and_then(handler::thought::batch::handle);
+
     // PUT /api/thought/<ID>
     let put_thought = warp::put()
         .and(path!(String))
         .and(path::end())
+        .and(warp::header::optional("if-match"))
         .and(body::json())
         .and(with_app(app.clone()))
         .and_then(handler::thought::update::handle);
 
-    // GET /api/thought
+    // PUT /api/thought/<ID>/status
+    let put_thought_status = warp::put()
+        .and(path!(String / "status"))
+        .and(path::end())
+        .and(body::json())
+        .and(with_app(app.clone()))
+        .and_then(handler::thought::change_status::handle);
+
+    // GET /api/thought/<ID>/links
+    let get_thought_links = warp::get()
+        .and(path!(String / "links"))
+        .and(path::end())
+        .and(with_app(app.clone()))
+        .and_then(handler::relation::read_links::handle);
+
+    // POST /api/thought/<ID>/links
+    let post_thought_link = warp::post()
+        .and(path!(String / "links"))
+        .and(path::end())
+        .and(body::json())
+        .and(with_app(app.clone()))
+        .and_then(handler::relation::link::handle);
+
+    // DELETE /api/thought/<ID>/links/<KIND>/<TARGET>
+    let delete_thought_link = warp::delete()
+        .and(path!(String / "links" / String / String))
+        .and(path::end())
+        .and(with_app(app.clone()))
+        .and_then(handler::relation::unlink::handle);
+
+    // GET /api/thought/<ID>/revisions
+    let get_thought_revisions = warp::get()
+        .and(path!(String / "revisions"))
+        .and(path::end())
+        .and(with_app(app.clone()))
+        .and_then(handler::revision::read_all::handle);
+
+    // GET /api/thought/<ID>/revisions/diff?from=<VERSION>&to=<VERSION>
+    let get_thought_revisions_diff = warp::get()
+        .and(path!(String / "revisions" / "diff"))
+        .and(path::end())
+        .and(warp::query())
+        .and(with_app(app.clone()))
+        .and_then(handler::revision::diff::handle);
+
+    // POST /api/thought/<ID>/revisions/<VERSION>/revert
+    let revert_thought = warp::post()
+        .and(path!(String / "revisions" / u64 / "revert"))
+        .and(path::end())
+        .and(with_app(app.clone()))
+        .and_then(handler::revision::revert::handle);
+
+    // GET /api/thought?q=<QUERY>
+    let search_thoughts = warp::get()
+        .and(path::end())
+        .and(warp::query())
+        .and(with_app(app.clone()))
+        .and_then(handler::thought::search::handle);
+
+    // GET /api/thought?status=<STATUS>&area_of_life=<ID>&sort=<KEY>&direction=<DIRECTION>&offset=<N>&limit=<N>
     let get_thoughts = warp::get()
         .and(path::end())
+        .and(warp::query())
         .and(with_app(app.clone()))
         .and_then(handler::thought::read_all::handle);
 
@@ -53,27 +122,124 @@ where
     let put_area_of_life = warp::put()
         .and(path!(String))
         .and(path::end())
+        .and(warp::header::optional("if-match"))
         .and(body::json())
         .and(with_app(app.clone()))
         .and_then(handler::area_of_life::update::handle);
 
-    // GET /api/area-of-life
+    // POST /api/area-of-life/<ID>/merge
+    let merge_area_of_life = warp::post()
+        .and(path!(String / "merge"))
+        .and(path::end())
+        .and(body::json())
+        .and(with_app(app.clone()))
+        .and_then(handler::area_of_life::merge::handle);
+
+    // GET /api/area-of-life/<ID>/subtree
+    let get_area_of_life_subtree = warp::get()
+        .and(path!(String / "subtree"))
+        .and(path::end())
+        .and(with_app(app.clone()))
+        .and_then(handler::area_of_life::read_subtree::handle);
+
+    // GET /api/area-of-life/<ID>/thoughts?sort=<KEY>&direction=<DIRECTION>
+    let get_area_of_life_thoughts = warp::get()
+        .and(path!(String / "thoughts"))
+        .and(path::end())
+        .and(warp::query())
+        .and(with_app(app.clone()))
+        .and_then(handler::area_of_life::thoughts::handle);
+
+    // GET /api/area-of-life/<ID>
+    let get_area_of_life = warp::get()
+        .and(path!(String))
+        .and(path::end())
+        .and(with_app(app.clone()))
+        .and_then(handler::area_of_life::find_by_id::handle);
+
+    // GET /api/area-of-life?sort=<KEY>&direction=<DIRECTION>&offset=<N>&limit=<N>
     let get_areas_of_life = warp::get()
         .and(path::end())
+        .and(warp::query())
         .and(with_app(app.clone()))
         .and_then(handler::area_of_life::read_all::handle);
 
-    // DELETE /api/area-of-life/<ID>
+    // DELETE /api/area-of-life/<ID>?cascade=<BOOL>&references=<POLICY>&target=<ID>
     let delete_area_of_life = warp::delete()
         .and(path!(String))
         .and(path::end())
-        .and(with_app(app))
+        .and(warp::query())
+        .and(with_app(app.clone()))
         .and_then(handler::area_of_life::delete::handle);
 
+    // POST /api/tag
+    let post_tag = warp::post()
+        .and(path::end())
+        .and(body::json())
+        .and(with_app(app.clone()))
+        .and_then(handler::tag::create::handle);
+
+    // PUT /api/tag/<ID>
+    let put_tag = warp::put()
+        .and(path!(String))
+        .and(path::end())
+        .and(body::json())
+        .and(with_app(app.clone()))
+        .and_then(handler::tag::update::handle);
+
+    // GET /api/tag
+    let get_tags = warp::get()
+        .and(path::end())
+        .and(with_app(app.clone()))
+        .and_then(handler::tag::read_all::handle);
+
+    // DELETE /api/tag/<ID>
+    let delete_tag = warp::delete()
+        .and(path!(String))
+        .and(path::end())
+        .and(with_app(app.clone()))
+        .and_then(handler::tag::delete::handle);
+
+    // GET /api/trash
+    let get_trash = warp::get()
+        .and(path::end())
+        .and(with_app(app.clone()))
+        .and_then(handler::trash::read_all::handle);
+
+    // POST /api/trash/thought/<ID>/restore
+    let restore_thought = warp::post()
+        .and(path!("thought" / String / "restore"))
+        .and(path::end())
+        .and(with_app(app.clone()))
+        .and_then(handler::trash::restore_thought::handle);
+
+    // POST /api/trash/area-of-life/<ID>/restore
+    let restore_area_of_life = warp::post()
+        .and(path!("area-of-life" / String / "restore"))
+        .and(path::end())
+        .and(with_app(app.clone()))
+        .and_then(handler::trash::restore_area_of_life::handle);
+
+    // DELETE /api/trash?retention_days=<DAYS>
+    let purge_trash = warp::delete()
+        .and(path::end())
+        .and(warp::query())
+        .and(with_app(app))
+        .and_then(handler::trash::purge::handle);
+
     let base_path = path("api");
     let thought = path("thought").and(
         post_thought
+            .or(post_thought_batch)
             .or(put_thought)
+            .or(put_thought_status)
+            .or(get_thought_links)
+            .or(post_thought_link)
+            .or(delete_thought_link)
+            .or(get_thought_revisions)
+            .or(get_thought_revisions_diff)
+            .or(revert_thought)
+            .or(search_thoughts)
             .or(get_thoughts)
             .or(get_thought)
             .or(delete_thought),
@@ -81,10 +247,33 @@ where
     let area_of_life = path("area-of-life").and(
         post_area_of_life
             .or(put_area_of_life)
+            .or(merge_area_of_life)
+            .or(get_area_of_life_subtree)
+            .or(get_area_of_life_thoughts)
+            .or(get_area_of_life)
             .or(get_areas_of_life)
             .or(delete_area_of_life),
     );
-    base_path.and(thought.or(area_of_life))
+    let tag = path("tag").and(post_tag.or(put_tag).or(get_tags).or(delete_tag));
+    let trash = path("trash").and(
+        get_trash
+            .or(restore_thought)
+            .or(restore_area_of_life)
+            .or(purge_trash),
+    );
+    base_path.and(thought.or(area_of_life).or(tag).or(trash))
+}
+
+pub fn metrics<D>(app: AppApi<D>) -> impl Filter<Extract = (impl Reply,), Error = Rejection> + Clone
+where
+    D: Db,
+{
+    // GET /metrics
+    warp::get()
+        .and(path("metrics"))
+        .and(path::end())
+        .and(with_app(app))
+        .and_then(handler::metrics::handle)
 }
 
 fn with_app<C>(app: AppApi<C>) -> impl Filter<Extract = (AppApi<C>,), Error = Infallible> + Clone
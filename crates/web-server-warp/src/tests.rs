@@ -1,11 +1,14 @@
 use crate::AppApi;
 use anyhow::Result;
+use async_trait::async_trait;
 use cawr_adapter::{api::Api, db::Db, presenter::http_json_api::Presenter};
 use cawr_application::{
+    clock::Clock,
     gateway::repository::thought::Record as ThoughtRecord,
     identifier::{NewId, NewIdError},
 };
 use cawr_db::in_memory::InMemory;
+use cawr_domain::Timestamp;
 use serde::Deserialize;
 use std::sync::Arc;
 use warp::reply::Response;
@@ -18,13 +21,21 @@ pub fn corrupt_db() -> Arc<CorruptTestDb> {
     Arc::new(CorruptTestDb)
 }
 
-pub const fn app_api<D>(db: Arc<D>) -> AppApi<D>
+pub fn app_api<D>(db: Arc<D>) -> AppApi<D>
 where
     D: Db,
 {
     Api::new(db, Presenter)
 }
 
+pub struct FixedClock(pub Timestamp);
+
+impl Clock for FixedClock {
+    fn now(&self) -> Timestamp {
+        self.0
+    }
+}
+
 #[derive(Default)]
 pub struct CorruptTestDb;
 
@@ -35,23 +46,27 @@ mod thought {
     use cawr_application::gateway::repository::thought::{self as repo, Record, Repo};
     use cawr_domain::thought::Id;
 
+    #[async_trait]
+
     impl Repo for CorruptTestDb {
-        fn save(&self, _: Record) -> Result<(), repo::SaveError> {
+        async fn save(&self, _: Record) -> Result<(), repo::SaveError> {
             Err(repo::SaveError::Connection)
         }
-        fn get(&self, _: Id) -> Result<Record, repo::GetError> {
+        async fn get(&self, _: Id) -> Result<Record, repo::GetError> {
             Err(repo::GetError::Connection)
         }
-        fn get_all(&self) -> Result<Vec<Record>, repo::GetAllError> {
+        async fn get_all(&self) -> Result<Vec<Record>, repo::GetAllError> {
             Err(repo::GetAllError::Connection)
         }
-        fn delete(&self, _: Id) -> Result<(), repo::DeleteError> {
+        async fn delete(&self, _: Id) -> Result<(), repo::DeleteError> {
             Err(repo::DeleteError::Connection)
         }
     }
 
+    #[async_trait]
+
     impl NewId<Id> for CorruptTestDb {
-        fn new_id(&self) -> Result<Id, NewIdError> {
+        async fn new_id(&self) -> Result<Id, NewIdError> {
             Err(NewIdError)
         }
     }
@@ -62,28 +77,155 @@ mod area_of_life {
     use cawr_application::gateway::repository::area_of_life::{self as repo, Record, Repo};
     use cawr_domain::area_of_life::Id;
 
+    #[async_trait]
+
+    impl Repo for CorruptTestDb {
+        async fn save(&self, _: Record) -> Result<(), repo::SaveError> {
+            Err(repo::SaveError::Connection)
+        }
+        async fn get(&self, _: Id) -> Result<Record, repo::GetError> {
+            Err(repo::GetError::Connection)
+        }
+        async fn get_by_normalized_name(&self, _: &str) -> Result<Record, repo::GetError> {
+            Err(repo::GetError::Connection)
+        }
+        async fn get_all(&self) -> Result<Vec<Record>, repo::GetAllError> {
+            Err(repo::GetAllError::Connection)
+        }
+        async fn delete(&self, _: Id) -> Result<(), repo::DeleteError> {
+            Err(repo::DeleteError::Connection)
+        }
+    }
+
+    #[async_trait]
+
+    impl NewId<Id> for CorruptTestDb {
+        async fn new_id(&self) -> Result<Id, NewIdError> {
+            Err(NewIdError)
+        }
+    }
+}
+
+mod tag {
+    use super::*;
+    use cawr_application::gateway::repository::tag::{self as repo, Record, Repo};
+    use cawr_domain::tag::Id;
+
+    #[async_trait]
+
     impl Repo for CorruptTestDb {
-        fn save(&self, _: Record) -> Result<(), repo::SaveError> {
+        async fn save(&self, _: Record) -> Result<(), repo::SaveError> {
             Err(repo::SaveError::Connection)
         }
-        fn get(&self, _: Id) -> Result<Record, repo::GetError> {
+        async fn get(&self, _: Id) -> Result<Record, repo::GetError> {
             Err(repo::GetError::Connection)
         }
-        fn get_all(&self) -> Result<Vec<Record>, repo::GetAllError> {
+        async fn get_all(&self) -> Result<Vec<Record>, repo::GetAllError> {
             Err(repo::GetAllError::Connection)
         }
-        fn delete(&self, _: Id) -> Result<(), repo::DeleteError> {
+        async fn delete(&self, _: Id) -> Result<(), repo::DeleteError> {
             Err(repo::DeleteError::Connection)
         }
     }
 
+    #[async_trait]
+
     impl NewId<Id> for CorruptTestDb {
-        fn new_id(&self) -> Result<Id, NewIdError> {
+        async fn new_id(&self) -> Result<Id, NewIdError> {
             Err(NewIdError)
         }
     }
 }
 
+mod relation {
+    use super::*;
+    use cawr_application::gateway::repository::relation::{self as repo, Record, Repo};
+    use cawr_domain::{relation::Relation, thought};
+
+    #[async_trait]
+
+    impl Repo for CorruptTestDb {
+        async fn save(&self, _: Record) -> Result<(), repo::SaveError> {
+            Err(repo::SaveError::Connection)
+        }
+        async fn get_by_thought(&self, _: thought::Id) -> Result<Vec<Record>, repo::GetAllError> {
+            Err(repo::GetAllError::Connection)
+        }
+        async fn delete(&self, _: &Relation) -> Result<(), repo::DeleteError> {
+            Err(repo::DeleteError::Connection)
+        }
+    }
+}
+
+mod revision {
+    use super::*;
+    use cawr_application::gateway::repository::revision::{self as repo, Record, Repo};
+    use cawr_domain::{thought, Version};
+
+    #[async_trait]
+
+    impl Repo for CorruptTestDb {
+        async fn save(&self, _: Record) -> Result<(), repo::SaveError> {
+            Err(repo::SaveError::Connection)
+        }
+        async fn get(&self, _: thought::Id, _: Version) -> Result<Record, repo::GetError> {
+            Err(repo::GetError::Connection)
+        }
+        async fn get_by_thought(&self, _: thought::Id) -> Result<Vec<Record>, repo::GetAllError> {
+            Err(repo::GetAllError::Connection)
+        }
+        async fn delete_by_thought(&self, _: thought::Id) -> Result<(), repo::DeleteError> {
+            Err(repo::DeleteError::Connection)
+        }
+    }
+}
+
+mod search_index {
+    use super::*;
+    use cawr_application::gateway::search_index::{Hit, IndexError, SearchError, SearchIndex};
+    use cawr_domain::thought::{Id, Thought};
+
+    #[async_trait]
+    impl SearchIndex for CorruptTestDb {
+        async fn index(&self, _: &Thought) -> Result<(), IndexError> {
+            Err(IndexError::Connection)
+        }
+        async fn remove(&self, _: Id) -> Result<(), IndexError> {
+            Err(IndexError::Connection)
+        }
+        async fn search(&self, _: &str) -> Result<Vec<Hit>, SearchError> {
+            Err(SearchError::Connection)
+        }
+    }
+}
+
+mod integrity {
+    use super::*;
+    use cawr_application::gateway::integrity::{CheckError, Integrity, RepairError, StorageIssue};
+
+    #[async_trait]
+    impl Integrity for CorruptTestDb {
+        async fn check_storage(&self) -> Result<Vec<StorageIssue>, CheckError> {
+            Err(CheckError::Connection)
+        }
+        async fn repair_storage(&self, _: StorageIssue) -> Result<(), RepairError> {
+            Err(RepairError::Connection)
+        }
+    }
+}
+
+mod unit_of_work {
+    use super::*;
+    use cawr_application::gateway::unit_of_work::{Changes, CommitError, UnitOfWork};
+
+    #[async_trait]
+    impl UnitOfWork for CorruptTestDb {
+        async fn commit(&self, _: Changes) -> Result<(), CommitError> {
+            Err(CommitError::Connection)
+        }
+    }
+}
+
 pub async fn response_json_body<T>(mut res: Response) -> Result<T>
 where
     for<'de> T: Deserialize<'de>,
@@ -94,15 +236,20 @@ where
     Ok(json)
 }
 
-pub fn add_thought_to_db(db: &Arc<InMemory>, title: &str) {
-    use cawr_application::gateway::repository::thought::Repo;
+pub async fn add_thought_to_db(db: &Arc<InMemory>, title: &str) {
+    use cawr_adapter::clock::SystemClock;
+    use cawr_application::gateway::{repository::thought::Repo, search_index::SearchIndex};
     use cawr_domain::thought::*;
     use std::collections::HashSet;
+    let now = SystemClock.now();
     let thought = Thought::new(
-        db.new_id().unwrap(),
+        db.new_id().await.unwrap(),
         Title::new(title.to_string()),
         HashSet::new(),
+        now,
+        now,
     );
+    db.as_ref().index(&thought).await.unwrap();
     let thought = ThoughtRecord { thought };
-    db.as_ref().save(thought).unwrap();
+    db.as_ref().save(thought).await.unwrap();
 }